@@ -6,14 +6,18 @@ use clap::AppSettings;
 use clap::Arg;
 use clap::SubCommand;
 use deno_lint::diagnostic::LintDiagnostic;
+use deno_lint::diagnostic::LintSummary;
 use deno_lint::diagnostic::Range;
+use deno_lint::diagnostic::Severity;
+use deno_lint::formatters::compact::format_compact;
+use deno_lint::formatters::github::format_github_annotations;
+use deno_lint::formatters::sarif::format_sarif;
 use deno_lint::linter::LinterBuilder;
 use deno_lint::linter::SourceFile;
 use deno_lint::rules::{get_all_rules, get_recommended_rules, LintRule};
 use rayon::prelude::*;
 use serde::Serialize;
 use std::rc::Rc;
-use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 
 fn create_cli_app<'a, 'b>() -> App<'a, 'b> {
@@ -41,6 +45,13 @@ fn create_cli_app<'a, 'b>() -> App<'a, 'b> {
             .long("rule")
             .help("Runs a certain rule")
             .takes_value(true),
+        )
+        .arg(
+          Arg::with_name("FORMAT")
+            .long("format")
+            .help("Sets the output format")
+            .possible_values(&["pretty", "compact", "github", "sarif"])
+            .default_value("pretty"),
         ),
     )
 }
@@ -85,7 +96,12 @@ fn display_diagnostics(
       source_code,
       &diagnostic.range,
     );
-    let footer = if let Some(hint) = &diagnostic.hint {
+    let annotation_type = match diagnostic.severity {
+      Severity::Error => snippet::AnnotationType::Error,
+      Severity::Warning => snippet::AnnotationType::Warning,
+      Severity::Info => snippet::AnnotationType::Info,
+    };
+    let mut footer = if let Some(hint) = &diagnostic.hint {
       vec![snippet::Annotation {
         label: Some(hint),
         id: None,
@@ -94,12 +110,19 @@ fn display_diagnostics(
     } else {
       vec![]
     };
+    footer.extend(diagnostic.suggestions.iter().map(|suggestion| {
+      snippet::Annotation {
+        label: Some(&suggestion.label),
+        id: None,
+        annotation_type: snippet::AnnotationType::Help,
+      }
+    }));
 
     let snippet = snippet::Snippet {
       title: Some(snippet::Annotation {
         label: Some(&diagnostic.message),
         id: Some(&diagnostic.code),
-        annotation_type: snippet::AnnotationType::Error,
+        annotation_type,
       }),
       footer,
       slices: vec![snippet::Slice {
@@ -110,7 +133,7 @@ fn display_diagnostics(
         annotations: vec![snippet::SourceAnnotation {
           range,
           label: "",
-          annotation_type: snippet::AnnotationType::Error,
+          annotation_type,
         }],
       }],
       opt: display_list::FormatOptions {
@@ -124,42 +147,94 @@ fn display_diagnostics(
   }
 }
 
-fn run_linter(paths: Vec<String>, filter_rule_name: Option<&str>) {
-  let error_counts = Arc::new(AtomicUsize::new(0));
+#[derive(Clone, Copy, PartialEq)]
+enum OutputFormat {
+  Pretty,
+  Compact,
+  Github,
+  Sarif,
+}
+
+fn get_rules(filter_rule_name: Option<&str>) -> Vec<Box<dyn LintRule>> {
+  if let Some(rule_name) = filter_rule_name {
+    get_recommended_rules()
+      .into_iter()
+      .filter(|r| r.code() == rule_name)
+      .collect()
+  } else {
+    get_recommended_rules()
+  }
+}
+
+fn run_linter(
+  paths: Vec<String>,
+  filter_rule_name: Option<&str>,
+  format: OutputFormat,
+) {
+  let summary = Arc::new(Mutex::new(LintSummary::default()));
   let output_lock = Arc::new(Mutex::new(())); // prevent threads outputting at the same time
 
+  // Structured formats are printed as a single document once all files are
+  // linted.
+  let all_diagnostics = Arc::new(Mutex::new(vec![]));
+
   paths.par_iter().for_each(|file_path| {
     let source_code =
       std::fs::read_to_string(&file_path).expect("Failed to read file");
 
-    let rules = if let Some(rule_name) = filter_rule_name {
-      get_recommended_rules()
-        .into_iter()
-        .filter(|r| r.code() == rule_name)
-        .collect()
-    } else {
-      get_recommended_rules()
-    };
-
     let mut linter = LinterBuilder::default()
-      .rules(rules)
+      .rules(get_rules(filter_rule_name))
       .lint_unknown_rules(true)
       .lint_unused_ignore_directives(true)
-      .build();
+      .build()
+      .expect("Failed to create linter");
 
     let (source_file, file_diagnostics) = linter
       .lint(file_path.to_string(), source_code)
       .expect("Failed to lint");
 
-    error_counts.fetch_add(file_diagnostics.len(), Ordering::Relaxed);
-    let _g = output_lock.lock().unwrap();
-
-    display_diagnostics(&file_diagnostics, source_file);
+    {
+      let mut summary = summary.lock().unwrap();
+      for diagnostic in &file_diagnostics {
+        summary.add(diagnostic);
+      }
+    }
+    match format {
+      OutputFormat::Pretty => {
+        let _g = output_lock.lock().unwrap();
+        display_diagnostics(&file_diagnostics, source_file);
+      }
+      OutputFormat::Compact | OutputFormat::Github | OutputFormat::Sarif => {
+        all_diagnostics.lock().unwrap().extend(file_diagnostics);
+      }
+    }
   });
 
-  let err_count = error_counts.load(Ordering::Relaxed);
-  if err_count > 0 {
-    eprintln!("Found {} problems", err_count);
+  if format != OutputFormat::Pretty {
+    let mut diagnostics = all_diagnostics.lock().unwrap();
+    diagnostics.sort_by(|a, b| {
+      (&a.filename, a.range.start.byte_pos)
+        .cmp(&(&b.filename, b.range.start.byte_pos))
+    });
+    match format {
+      OutputFormat::Compact => print!("{}", format_compact(&diagnostics)),
+      OutputFormat::Github => {
+        print!("{}", format_github_annotations(&diagnostics))
+      }
+      OutputFormat::Sarif => {
+        let rules = get_rules(filter_rule_name);
+        println!("{}", format_sarif(&diagnostics, &rules, None));
+      }
+      OutputFormat::Pretty => unreachable!(),
+    }
+  }
+
+  let summary = summary.lock().unwrap();
+  if summary.total > 0 && format == OutputFormat::Pretty {
+    eprintln!("Found {} problems", summary.total);
+  }
+  // Only diagnostics with the error severity fail the run.
+  if summary.has_errors() {
     std::process::exit(1);
   }
 }
@@ -268,7 +343,13 @@ fn main() {
         .unwrap()
         .map(|p| p.to_string())
         .collect();
-      run_linter(paths, run_matches.value_of("RULE_CODE"));
+      let format = match run_matches.value_of("FORMAT") {
+        Some("compact") => OutputFormat::Compact,
+        Some("github") => OutputFormat::Github,
+        Some("sarif") => OutputFormat::Sarif,
+        _ => OutputFormat::Pretty,
+      };
+      run_linter(paths, run_matches.value_of("RULE_CODE"), format);
     }
     ("rules", Some(rules_matches)) => {
       let json = rules_matches.is_present("json");
@@ -25,7 +25,6 @@ use swc_ecmascript::parser::TsConfig;
 use swc_ecmascript::transforms::resolver::ts_resolver;
 use swc_ecmascript::visit::FoldWith;
 
-#[allow(unused)]
 pub fn get_default_es_config() -> Syntax {
   let mut config = EsConfig::default();
   config.num_sep = true;
@@ -49,6 +48,63 @@ pub fn get_default_ts_config() -> Syntax {
   Syntax::Typescript(ts_config)
 }
 
+/// Kind of source code, which determines the syntax it's parsed with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MediaType {
+  JavaScript,
+  Jsx,
+  TypeScript,
+  Tsx,
+  Dts,
+}
+
+impl MediaType {
+  /// Detects the media type of a file from the extension of its name, which
+  /// is one of `.ts`, `.tsx`, `.d.ts`, `.js`, `.jsx`, `.mjs` or `.cjs`.
+  pub fn from_file_name(file_name: &str) -> Option<Self> {
+    let file_name = file_name.to_ascii_lowercase();
+    if file_name.ends_with(".d.ts") {
+      return Some(MediaType::Dts);
+    }
+    let extension = file_name.rsplit('.').next()?;
+    match extension {
+      "ts" => Some(MediaType::TypeScript),
+      "tsx" => Some(MediaType::Tsx),
+      "js" | "mjs" | "cjs" => Some(MediaType::JavaScript),
+      "jsx" => Some(MediaType::Jsx),
+      _ => None,
+    }
+  }
+
+  /// Whether the media type is one of the TypeScript dialects.
+  pub fn is_typescript(self) -> bool {
+    match self {
+      MediaType::TypeScript | MediaType::Tsx | MediaType::Dts => true,
+      MediaType::JavaScript | MediaType::Jsx => false,
+    }
+  }
+
+  pub fn syntax(self) -> Syntax {
+    match self {
+      MediaType::JavaScript | MediaType::Jsx => {
+        let mut syntax = get_default_es_config();
+        if let Syntax::Es(config) = &mut syntax {
+          config.jsx = self == MediaType::Jsx;
+        }
+        syntax
+      }
+      MediaType::TypeScript | MediaType::Tsx | MediaType::Dts => {
+        let mut syntax = get_default_ts_config();
+        if let Syntax::Typescript(config) = &mut syntax {
+          config.tsx = self == MediaType::Tsx;
+          config.dts = self == MediaType::Dts;
+        }
+        syntax
+      }
+    }
+  }
+}
+
 #[derive(Clone, Debug)]
 pub struct SwcDiagnosticBuffer {
   pub diagnostics: Vec<String>,
@@ -0,0 +1,239 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+//! Single-pass traversal shared by rules which only inspect individual nodes.
+//!
+//! Most rules walk the whole AST just to look at a handful of node types.
+//! Rules implementing `NodeRule` declare those node types up front, which
+//! allows the linter to visit the program once and dispatch every node to all
+//! rules interested in it, instead of traversing the program once per rule.
+use crate::linter::Context;
+use swc_ecmascript::ast::{
+  ArrowExpr, AssignExpr, BinExpr, CallExpr, CondExpr, ForStmt, Function,
+  MemberExpr, NewExpr, ObjectLit, Program, SwitchCase, TryStmt, UnaryExpr,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+/// A rule which can be run as part of a `CombinedVisitor`.
+pub trait NodeRule {
+  /// Kinds of nodes passed to `check_node`.
+  fn node_kinds(&self) -> &'static [NodeKind];
+  /// Checks a single node, which is of one of the kinds from `node_kinds`.
+  fn check_node(&self, context: &mut Context, node: AstNode);
+}
+
+macro_rules! node_kinds {
+  ($($kind:ident($node:ident) => $visit:ident,)*) => {
+    /// Types of nodes a `NodeRule` can be interested in.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub enum NodeKind {
+      $($kind,)*
+    }
+
+    const NODE_KIND_COUNT: usize = [$(NodeKind::$kind,)*].len();
+
+    /// A reference to a node visited by a `CombinedVisitor`.
+    #[derive(Clone, Copy)]
+    pub enum AstNode<'a> {
+      $($kind(&'a $node),)*
+    }
+
+    impl<'a> AstNode<'a> {
+      pub fn kind(&self) -> NodeKind {
+        match self {
+          $(AstNode::$kind(_) => NodeKind::$kind,)*
+        }
+      }
+    }
+
+    impl<'c, 'r> VisitAll for CombinedVisitor<'c, 'r> {
+      noop_visit_type!();
+
+      $(
+        fn $visit(&mut self, node: &$node, _parent: &dyn Node) {
+          self.dispatch(AstNode::$kind(node));
+        }
+      )*
+    }
+  };
+}
+
+node_kinds! {
+  ArrowExpr(ArrowExpr) => visit_arrow_expr,
+  AssignExpr(AssignExpr) => visit_assign_expr,
+  BinExpr(BinExpr) => visit_bin_expr,
+  CallExpr(CallExpr) => visit_call_expr,
+  CondExpr(CondExpr) => visit_cond_expr,
+  ForStmt(ForStmt) => visit_for_stmt,
+  Function(Function) => visit_function,
+  MemberExpr(MemberExpr) => visit_member_expr,
+  NewExpr(NewExpr) => visit_new_expr,
+  ObjectLit(ObjectLit) => visit_object_lit,
+  SwitchCase(SwitchCase) => visit_switch_case,
+  TryStmt(TryStmt) => visit_try_stmt,
+  UnaryExpr(UnaryExpr) => visit_unary_expr,
+}
+
+/// Visits every node of a program once, passing it to each rule which
+/// registered interest in its kind.
+pub struct CombinedVisitor<'c, 'r> {
+  context: &'c mut Context,
+  /// Rules interested in each node kind, indexed by `NodeKind as usize`.
+  handlers: Vec<Vec<&'r dyn NodeRule>>,
+}
+
+impl<'c, 'r> CombinedVisitor<'c, 'r> {
+  pub fn new(
+    context: &'c mut Context,
+    rules: impl IntoIterator<Item = &'r dyn NodeRule>,
+  ) -> Self {
+    let mut handlers = vec![vec![]; NODE_KIND_COUNT];
+    for rule in rules {
+      for kind in rule.node_kinds() {
+        handlers[*kind as usize].push(rule);
+      }
+    }
+    Self { context, handlers }
+  }
+
+  pub fn visit_program(&mut self, program: &Program) {
+    program.visit_all_with(program, self);
+  }
+
+  fn dispatch(&mut self, node: AstNode) {
+    for rule in &self.handlers[node.kind() as usize] {
+      rule.check_node(self.context, node);
+    }
+  }
+}
+
+/// Runs a single `NodeRule` over a program. This is how node rules implement
+/// `LintRule::lint_program`, so that they behave the same whether or not they
+/// are run in a combined pass.
+pub fn run_node_rule(
+  rule: &dyn NodeRule,
+  context: &mut Context,
+  program: &Program,
+) {
+  CombinedVisitor::new(context, std::iter::once(rule)).visit_program(program);
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::diagnostic::LintDiagnostic;
+  use crate::linter::LinterBuilder;
+  use crate::rules::{get_all_rules, get_recommended_rules, LintRule};
+  use std::time::{Duration, Instant};
+
+  fn lint(
+    source: &str,
+    rules: Vec<Box<dyn LintRule>>,
+    single_pass: bool,
+  ) -> Vec<LintDiagnostic> {
+    let mut linter = LinterBuilder::default()
+      .rules(rules)
+      .single_pass(single_pass)
+      .build()
+      .expect("Failed to create linter");
+    let (_, mut diagnostics) = linter
+      .lint("combined_visitor_test.ts".to_string(), source.to_string())
+      .expect("Failed to lint");
+    diagnostics.sort_by(|a, b| {
+      (a.range.start.line, a.range.start.col, &a.code).cmp(&(
+        b.range.start.line,
+        b.range.start.col,
+        &b.code,
+      ))
+    });
+    diagnostics
+  }
+
+  const SOURCE: &str = r#"
+function foo(x) {
+  switch (x) {
+    case 1:
+      let y = x ? 1 : 2;
+      break;
+  }
+  if (x === -0) {}
+  // deno-lint-ignore no-unsafe-finally
+  try {} finally {
+    return;
+  }
+  try {} finally {
+    return x === -0 ? 1 : 2;
+  }
+}
+"#;
+
+  #[test]
+  fn node_rules_declare_their_kinds() {
+    let rules = get_all_rules();
+    let node_rules = rules.iter().filter_map(|rule| rule.node_rule());
+    for node_rule in node_rules {
+      assert!(!node_rule.node_kinds().is_empty());
+    }
+    assert!(rules.iter().any(|rule| rule.node_rule().is_some()));
+  }
+
+  #[test]
+  fn single_pass_matches_per_rule_passes() {
+    for rules in &[get_recommended_rules, get_all_rules] {
+      let expected = lint(SOURCE, rules(), false);
+      let actual = lint(SOURCE, rules(), true);
+      assert!(expected.iter().any(|d| d.code == "no-compare-neg-zero"));
+      assert!(expected.iter().any(|d| d.code == "no-case-declarations"));
+      assert!(expected.iter().any(|d| d.code == "no-unsafe-finally"));
+
+      assert_eq!(actual.len(), expected.len());
+      for (actual, expected) in actual.iter().zip(&expected) {
+        assert_eq!(actual.code, expected.code);
+        assert_eq!(actual.message, expected.message);
+        assert_eq!(actual.range, expected.range);
+      }
+    }
+  }
+
+  #[test]
+  fn single_pass_respects_ignore_directives() {
+    let diagnostics = lint(SOURCE, get_recommended_rules(), true);
+    let unsafe_finally = diagnostics
+      .iter()
+      .filter(|d| d.code == "no-unsafe-finally")
+      .collect::<Vec<_>>();
+    assert_eq!(unsafe_finally.len(), 1);
+    assert_eq!(unsafe_finally[0].range.start.line, 13);
+  }
+
+  fn time_lint(source: &str, single_pass: bool) -> Duration {
+    let node_rules = || {
+      get_all_rules()
+        .into_iter()
+        .filter(|rule| rule.node_rule().is_some())
+        .collect::<Vec<_>>()
+    };
+    let start = Instant::now();
+    lint(source, node_rules(), single_pass);
+    start.elapsed()
+  }
+
+  /// Compares linting a large file with the migrated rules in one combined
+  /// pass against one pass per rule. Run with
+  /// `cargo test --release bench_single_pass -- --ignored --nocapture`.
+  #[test]
+  #[ignore]
+  fn bench_single_pass() {
+    let source = SOURCE.repeat(5_000);
+    let runs = 5;
+    let per_rule = (0..runs).map(|_| time_lint(&source, false)).min().unwrap();
+    let single_pass =
+      (0..runs).map(|_| time_lint(&source, true)).min().unwrap();
+    println!(
+      "{} lines: per-rule passes {:?}, single pass {:?}",
+      source.lines().count(),
+      per_rule,
+      single_pass
+    );
+  }
+}
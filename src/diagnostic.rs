@@ -1,5 +1,7 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use serde::Deserialize;
 use serde::Serialize;
+use std::collections::BTreeMap;
 use std::convert::TryInto;
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize)]
@@ -30,6 +32,42 @@ pub struct Range {
   pub end: Position,
 }
 
+/// How serious a diagnostic is.
+///
+/// Severities are configured per rule code, as `"error"`, `"warn"` or
+/// `"info"`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Severity {
+  Error,
+  #[serde(rename = "warn", alias = "warning")]
+  Warning,
+  Info,
+}
+
+impl Default for Severity {
+  fn default() -> Self {
+    Severity::Error
+  }
+}
+
+/// Replacement of the text in `range` with `new_text`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextEdit {
+  pub range: Range,
+  pub new_text: String,
+}
+
+/// One of possibly several alternative ways to fix a problem, which editors
+/// can offer as quick fixes. Suggestions are never applied automatically.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Suggestion {
+  /// Human readable description of the change, e.g. "Replace with `!!x`".
+  pub label: String,
+  pub edit: TextEdit,
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct LintDiagnostic {
   pub range: Range,
@@ -37,4 +75,56 @@ pub struct LintDiagnostic {
   pub message: String,
   pub code: String,
   pub hint: Option<String>,
+  pub severity: Severity,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub suggestions: Vec<Suggestion>,
+}
+
+/// Tallies of the diagnostics of a lint run, e.g. to decide the exit status
+/// of a CI job without parsing the output.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintSummary {
+  pub total: usize,
+  /// Number of diagnostics of each rule code.
+  pub by_code: BTreeMap<String, usize>,
+  pub error_count: usize,
+  pub warning_count: usize,
+  pub info_count: usize,
+}
+
+impl LintSummary {
+  pub fn from_diagnostics(diagnostics: &[LintDiagnostic]) -> Self {
+    let mut summary = Self::default();
+    for diagnostic in diagnostics {
+      summary.add(diagnostic);
+    }
+    summary
+  }
+
+  /// Counts `diagnostic`, e.g. while diagnostics are still being reported.
+  pub fn add(&mut self, diagnostic: &LintDiagnostic) {
+    self.total += 1;
+    *self.by_code.entry(diagnostic.code.clone()).or_insert(0) += 1;
+    match diagnostic.severity {
+      Severity::Error => self.error_count += 1,
+      Severity::Warning => self.warning_count += 1,
+      Severity::Info => self.info_count += 1,
+    }
+  }
+
+  /// Number of diagnostics with the given severity.
+  pub fn count(&self, severity: Severity) -> usize {
+    match severity {
+      Severity::Error => self.error_count,
+      Severity::Warning => self.warning_count,
+      Severity::Info => self.info_count,
+    }
+  }
+
+  /// Whether any diagnostic has the error severity, which should fail the
+  /// run.
+  pub fn has_errors(&self) -> bool {
+    self.error_count > 0
+  }
 }
@@ -0,0 +1,5 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+//! Machine-readable output formats for lint diagnostics.
+pub mod compact;
+pub mod github;
+pub mod sarif;
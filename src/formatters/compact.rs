@@ -0,0 +1,84 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+//! Single-line output, one diagnostic per line, similar to ESLint's compact
+//! format.
+use crate::diagnostic::LintDiagnostic;
+
+/// Formats diagnostics as `filename:line:col: code: message` lines followed
+/// by a line with the number of problems. Lines and columns are 1-based.
+///
+/// Returns an empty string if there are no diagnostics.
+pub fn format_compact(diagnostics: &[LintDiagnostic]) -> String {
+  if diagnostics.is_empty() {
+    return String::new();
+  }
+
+  let mut output = String::new();
+  for diagnostic in diagnostics {
+    output.push_str(&format!(
+      "{}:{}:{}: {}: {}\n",
+      diagnostic.filename,
+      diagnostic.range.start.line,
+      diagnostic.range.start.col + 1,
+      diagnostic.code,
+      // Keep multi-line messages on a single line.
+      diagnostic.message.lines().collect::<Vec<_>>().join(" "),
+    ));
+  }
+  let count = diagnostics.len();
+  output.push_str(&format!(
+    "\nFound {} problem{}\n",
+    count,
+    if count == 1 { "" } else { "s" }
+  ));
+  output
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::linter::LinterBuilder;
+  use crate::rules::no_debugger::NoDebugger;
+  use crate::rules::no_var::NoVar;
+  use crate::rules::LintRule;
+
+  fn lint(source: &str) -> Vec<LintDiagnostic> {
+    let mut linter = LinterBuilder::default()
+      .rules(vec![NoDebugger::new(), NoVar::new()])
+      .build()
+      .expect("Failed to create linter");
+    let (_, diagnostics) = linter
+      .lint("src/compact_test.ts".to_string(), source.to_string())
+      .expect("Failed to lint");
+    diagnostics
+  }
+
+  #[test]
+  fn compact_format() {
+    let diagnostics = lint("var a = 1;\nfunction foo() {\n  debugger;\n}");
+    assert_eq!(
+      format_compact(&diagnostics),
+      "src/compact_test.ts:1:1: no-var: `var` keyword is not allowed
+src/compact_test.ts:3:3: no-debugger: `debugger` statement is not allowed
+
+Found 2 problems
+"
+    );
+  }
+
+  #[test]
+  fn compact_format_single_problem() {
+    let diagnostics = lint("  debugger;");
+    assert_eq!(
+      format_compact(&diagnostics),
+      "src/compact_test.ts:1:3: no-debugger: `debugger` statement is not allowed
+
+Found 1 problem
+"
+    );
+  }
+
+  #[test]
+  fn compact_format_no_problems() {
+    assert_eq!(format_compact(&lint("let a = 1;")), "");
+  }
+}
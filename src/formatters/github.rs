@@ -0,0 +1,122 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+//! GitHub Actions workflow commands, which show diagnostics as annotations
+//! on the lines of a pull request.
+//!
+//! See https://docs.github.com/en/actions/reference/workflow-commands-for-github-actions
+use crate::diagnostic::{LintDiagnostic, Severity};
+
+/// Formats a diagnostic as a workflow command such as
+/// `::error file=a.ts,line=1,col=1,endLine=1,endColumn=9,title=no-var::message`.
+///
+/// Errors, warnings and infos are reported with the `error`, `warning` and
+/// `notice` commands. The hint, if any, is added to the message on a new
+/// line.
+pub fn format_github_annotation(diagnostic: &LintDiagnostic) -> String {
+  let command = match diagnostic.severity {
+    Severity::Error => "error",
+    Severity::Warning => "warning",
+    Severity::Info => "notice",
+  };
+  let message = match &diagnostic.hint {
+    Some(hint) => format!("{}\n{}", diagnostic.message, hint),
+    None => diagnostic.message.clone(),
+  };
+  format!(
+    "::{} file={},line={},col={},endLine={},endColumn={},title={}::{}",
+    command,
+    escape_property(&diagnostic.filename),
+    diagnostic.range.start.line,
+    diagnostic.range.start.col + 1,
+    diagnostic.range.end.line,
+    diagnostic.range.end.col + 1,
+    escape_property(&diagnostic.code),
+    escape_data(&message),
+  )
+}
+
+/// Formats diagnostics as workflow commands, one per line.
+pub fn format_github_annotations(diagnostics: &[LintDiagnostic]) -> String {
+  diagnostics
+    .iter()
+    .map(|diagnostic| format_github_annotation(diagnostic) + "\n")
+    .collect()
+}
+
+/// Escapes the message of a workflow command.
+fn escape_data(data: &str) -> String {
+  data
+    .replace('%', "%25")
+    .replace('\r', "%0D")
+    .replace('\n', "%0A")
+}
+
+/// Escapes a property value of a workflow command, which additionally can't
+/// contain the `:` and `,` separators.
+fn escape_property(value: &str) -> String {
+  escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::diagnostic::{Position, Range};
+
+  fn diagnostic(
+    filename: &str,
+    message: &str,
+    hint: Option<&str>,
+    severity: Severity,
+  ) -> LintDiagnostic {
+    LintDiagnostic {
+      range: Range {
+        start: Position {
+          line: 3,
+          col: 2,
+          byte_pos: 20,
+        },
+        end: Position {
+          line: 4,
+          col: 0,
+          byte_pos: 30,
+        },
+      },
+      filename: filename.to_string(),
+      message: message.to_string(),
+      code: "no-foo".to_string(),
+      hint: hint.map(|hint| hint.to_string()),
+      severity,
+      suggestions: vec![],
+    }
+  }
+
+  #[test]
+  fn github_annotation_escapes_message() {
+    let diagnostic = diagnostic(
+      "src/a,b:c.ts",
+      "100% wrong:\r\nfoo\nbar",
+      Some("Remove foo"),
+      Severity::Error,
+    );
+    assert_eq!(
+      format_github_annotation(&diagnostic),
+      "::error file=src/a%2Cb%3Ac.ts,line=3,col=3,endLine=4,endColumn=1,\
+       title=no-foo::100%25 wrong:%0D%0Afoo%0Abar%0ARemove foo"
+    );
+  }
+
+  #[test]
+  fn github_annotation_commands_follow_severity() {
+    let annotations = format_github_annotations(&[
+      diagnostic("a.ts", "a", None, Severity::Error),
+      diagnostic("b.ts", "b", None, Severity::Warning),
+      diagnostic("c.ts", "c", None, Severity::Info),
+    ]);
+    assert_eq!(
+      annotations,
+      "::error file=a.ts,line=3,col=3,endLine=4,endColumn=1,title=no-foo::a
+::warning file=b.ts,line=3,col=3,endLine=4,endColumn=1,title=no-foo::b
+::notice file=c.ts,line=3,col=3,endLine=4,endColumn=1,title=no-foo::c
+"
+    );
+  }
+}
@@ -0,0 +1,343 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+//! Output in the Static Analysis Results Interchange Format (SARIF) 2.1.0,
+//! as consumed by GitHub code scanning.
+//!
+//! See https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+use crate::diagnostic::{LintDiagnostic, Severity};
+use crate::rules::LintRule;
+use serde::Serialize;
+use std::collections::HashMap;
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const VERSION: &str = "2.1.0";
+const TOOL_NAME: &str = "deno_lint";
+const TOOL_URI: &str = "https://github.com/denoland/deno_lint";
+
+#[derive(Serialize)]
+pub struct SarifLog {
+  #[serde(rename = "$schema")]
+  pub schema: &'static str,
+  pub version: &'static str,
+  pub runs: Vec<Run>,
+}
+
+#[derive(Serialize)]
+pub struct Run {
+  pub tool: Tool,
+  pub results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+pub struct Tool {
+  pub driver: Driver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Driver {
+  pub name: &'static str,
+  pub version: &'static str,
+  pub information_uri: &'static str,
+  pub rules: Vec<ReportingDescriptor>,
+}
+
+/// Metadata of a rule.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportingDescriptor {
+  pub id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub short_description: Option<Message>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub help: Option<MultiformatMessage>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub help_uri: Option<String>,
+  pub default_configuration: Configuration,
+}
+
+#[derive(Serialize)]
+pub struct Configuration {
+  pub level: Level,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Level {
+  Error,
+  Warning,
+  Note,
+}
+
+impl From<Severity> for Level {
+  fn from(severity: Severity) -> Self {
+    match severity {
+      Severity::Error => Level::Error,
+      Severity::Warning => Level::Warning,
+      Severity::Info => Level::Note,
+    }
+  }
+}
+
+#[derive(Serialize)]
+pub struct Message {
+  pub text: String,
+}
+
+#[derive(Serialize)]
+pub struct MultiformatMessage {
+  pub text: String,
+  pub markdown: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+  pub rule_id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub rule_index: Option<usize>,
+  pub level: Level,
+  pub message: Message,
+  pub locations: Vec<Location>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+  pub physical_location: PhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhysicalLocation {
+  pub artifact_location: ArtifactLocation,
+  pub region: Region,
+}
+
+#[derive(Serialize)]
+pub struct ArtifactLocation {
+  pub uri: String,
+}
+
+/// Lines and columns in SARIF are 1-based; the end column is exclusive.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Region {
+  pub start_line: usize,
+  pub start_column: usize,
+  pub end_line: usize,
+  pub end_column: usize,
+}
+
+/// Builds a SARIF log with a single run from diagnostics and the rules that
+/// produced them.
+///
+/// Rules are described by their code and docs. If `docs_base_url` is given,
+/// each rule links to its documentation at `docs_base_url` followed by the
+/// rule code.
+pub fn to_sarif(
+  diagnostics: &[LintDiagnostic],
+  rules: &[Box<dyn LintRule>],
+  docs_base_url: Option<&str>,
+) -> SarifLog {
+  let rule_indexes = rules
+    .iter()
+    .enumerate()
+    .map(|(index, rule)| (rule.code(), index))
+    .collect::<HashMap<_, _>>();
+
+  let rules = rules
+    .iter()
+    .map(|rule| describe_rule(rule.as_ref(), docs_base_url))
+    .collect();
+  let results = diagnostics
+    .iter()
+    .map(|diagnostic| SarifResult {
+      rule_id: diagnostic.code.clone(),
+      rule_index: rule_indexes.get(diagnostic.code.as_str()).copied(),
+      level: diagnostic.severity.into(),
+      message: Message {
+        text: match &diagnostic.hint {
+          Some(hint) => format!("{}\n{}", diagnostic.message, hint),
+          None => diagnostic.message.clone(),
+        },
+      },
+      locations: vec![Location {
+        physical_location: PhysicalLocation {
+          artifact_location: ArtifactLocation {
+            uri: diagnostic.filename.replace('\\', "/"),
+          },
+          region: Region {
+            start_line: diagnostic.range.start.line,
+            start_column: diagnostic.range.start.col + 1,
+            end_line: diagnostic.range.end.line,
+            end_column: diagnostic.range.end.col + 1,
+          },
+        },
+      }],
+    })
+    .collect();
+
+  SarifLog {
+    schema: SCHEMA,
+    version: VERSION,
+    runs: vec![Run {
+      tool: Tool {
+        driver: Driver {
+          name: TOOL_NAME,
+          version: env!("CARGO_PKG_VERSION"),
+          information_uri: TOOL_URI,
+          rules,
+        },
+      },
+      results,
+    }],
+  }
+}
+
+/// Formats diagnostics as a SARIF JSON document. See `to_sarif`.
+pub fn format_sarif(
+  diagnostics: &[LintDiagnostic],
+  rules: &[Box<dyn LintRule>],
+  docs_base_url: Option<&str>,
+) -> String {
+  serde_json::to_string_pretty(&to_sarif(diagnostics, rules, docs_base_url))
+    .expect("Failed to serialize SARIF log")
+}
+
+fn describe_rule(
+  rule: &dyn LintRule,
+  docs_base_url: Option<&str>,
+) -> ReportingDescriptor {
+  let docs = rule.docs().trim();
+  let (short_description, help) = if docs.is_empty() {
+    (None, None)
+  } else {
+    // The summary is the first paragraph of the docs, which may be wrapped.
+    let summary = docs
+      .lines()
+      .take_while(|line| !line.trim().is_empty())
+      .map(str::trim)
+      .collect::<Vec<_>>()
+      .join(" ");
+    let help = MultiformatMessage {
+      text: docs.to_string(),
+      markdown: docs.to_string(),
+    };
+    (Some(Message { text: summary }), Some(help))
+  };
+  ReportingDescriptor {
+    id: rule.code().to_string(),
+    short_description,
+    help,
+    help_uri: docs_base_url.map(|base| format!("{}{}", base, rule.code())),
+    default_configuration: Configuration {
+      level: rule.severity().into(),
+    },
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::linter::LinterBuilder;
+  use crate::rules::no_debugger::NoDebugger;
+  use crate::rules::no_var::NoVar;
+  use serde_json::Value;
+
+  fn lint_to_sarif(docs_base_url: Option<&str>) -> Value {
+    let rules =
+      || -> Vec<Box<dyn LintRule>> { vec![NoDebugger::new(), NoVar::new()] };
+    let mut linter = LinterBuilder::default()
+      .rules(rules())
+      .build()
+      .expect("Failed to create linter");
+    let (_, diagnostics) = linter
+      .lint(
+        "src/sarif_test.ts".to_string(),
+        "function foo() {\n  debugger;\n}\nvar a = 1;".to_string(),
+      )
+      .expect("Failed to lint");
+    assert_eq!(diagnostics.len(), 2);
+    let sarif = format_sarif(&diagnostics, &rules(), docs_base_url);
+    serde_json::from_str(&sarif).expect("Failed to parse SARIF")
+  }
+
+  #[test]
+  fn sarif_log_shape() {
+    let log = lint_to_sarif(None);
+    assert_eq!(log["$schema"], SCHEMA);
+    assert_eq!(log["version"], "2.1.0");
+    let runs = log["runs"].as_array().unwrap();
+    assert_eq!(runs.len(), 1);
+
+    let driver = &runs[0]["tool"]["driver"];
+    assert_eq!(driver["name"], "deno_lint");
+    assert_eq!(driver["version"], env!("CARGO_PKG_VERSION"));
+    let rules = driver["rules"].as_array().unwrap();
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules[0]["id"], "no-debugger");
+    assert_eq!(rules[1]["id"], "no-var");
+    for rule in rules {
+      assert!(rule["shortDescription"]["text"].is_string());
+      assert!(rule["help"]["text"].is_string());
+      assert!(rule["help"]["markdown"].is_string());
+      assert!(rule.get("helpUri").is_none());
+      assert_eq!(rule["defaultConfiguration"]["level"], "error");
+    }
+
+    let results = runs[0]["results"].as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    for result in results {
+      let locations = result["locations"].as_array().unwrap();
+      assert_eq!(locations.len(), 1);
+      let physical_location = &locations[0]["physicalLocation"];
+      assert_eq!(
+        physical_location["artifactLocation"]["uri"],
+        "src/sarif_test.ts"
+      );
+      for key in &["startLine", "startColumn", "endLine", "endColumn"] {
+        assert!(physical_location["region"][key].as_u64().unwrap() >= 1);
+      }
+      assert_eq!(result["level"], "error");
+      assert!(result["message"]["text"].is_string());
+    }
+  }
+
+  #[test]
+  fn sarif_results_reference_rules() {
+    let log = lint_to_sarif(Some("https://example.com/rules#"));
+    let run = &log["runs"][0];
+    assert_eq!(
+      run["tool"]["driver"]["rules"][0]["helpUri"],
+      "https://example.com/rules#no-debugger"
+    );
+
+    let results = run["results"].as_array().unwrap();
+    let debugger = results
+      .iter()
+      .find(|result| result["ruleId"] == "no-debugger")
+      .unwrap();
+    assert_eq!(debugger["ruleIndex"], 0);
+    let region = &debugger["locations"][0]["physicalLocation"]["region"];
+    assert_eq!(region["startLine"], 2);
+    assert_eq!(region["startColumn"], 3);
+    assert_eq!(region["endLine"], 2);
+    assert_eq!(region["endColumn"], 12);
+
+    let var = results
+      .iter()
+      .find(|result| result["ruleId"] == "no-var")
+      .unwrap();
+    assert_eq!(var["ruleIndex"], 1);
+    let region = &var["locations"][0]["physicalLocation"]["region"];
+    assert_eq!(region["startLine"], 4);
+    assert_eq!(region["startColumn"], 1);
+  }
+
+  #[test]
+  fn sarif_levels_follow_severity() {
+    assert_eq!(Level::from(Severity::Error), Level::Error);
+    assert_eq!(Level::from(Severity::Warning), Level::Warning);
+    assert_eq!(Level::from(Severity::Info), Level::Note);
+  }
+}
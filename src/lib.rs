@@ -10,14 +10,16 @@ extern crate log;
 mod test_util;
 
 pub mod ast_parser;
+pub mod combined_visitor;
 mod control_flow;
 pub mod diagnostic;
+pub mod formatters;
 mod globals;
 mod ignore_directives;
 mod js_regex;
 pub mod linter;
 pub mod rules;
-mod scopes;
+pub mod scopes;
 pub mod swc_util;
 
 #[cfg(test)]
@@ -37,7 +39,8 @@ mod lint_tests {
       .lint_unknown_rules(unknown_rules)
       .lint_unused_ignore_directives(unused_dir)
       .rules(rules)
-      .build();
+      .build()
+      .expect("Failed to create linter");
 
     let (_, diagnostics) = linter
       .lint("lint_test.ts".to_string(), source.to_string())
@@ -207,4 +210,610 @@ const fooBar = 42;
     assert_eq!(diagnostics.len(), 1);
     assert_diagnostic(&diagnostics[0], "ban-unused-ignore", 4, 1, src);
   }
+
+  #[test]
+  fn lint_with_callback_matches_lint() {
+    let src = r#"
+ // deno-lint-ignore no-explicit-any
+ function foo(p: any) {
+   var bar_baz = p;
+   // deno-lint-ignore no-empty
+   if (bar_baz) {}
+   if (bar_baz) {}
+   debugger;
+ }
+      "#;
+    let expected = lint_recommended_rules(src, true, true);
+    assert_eq!(expected.len(), 3);
+
+    let mut linter = LinterBuilder::default()
+      .lint_unknown_rules(true)
+      .lint_unused_ignore_directives(true)
+      .build()
+      .expect("Failed to create linter");
+    let mut diagnostics = vec![];
+    linter
+      .lint_with_callback(
+        "lint_test.ts".to_string(),
+        src.to_string(),
+        &get_recommended_rules(),
+        |diagnostic| diagnostics.push(diagnostic),
+      )
+      .expect("Failed to lint");
+    diagnostics.sort_by_key(|d| d.range.start.line);
+
+    assert_eq!(diagnostics.len(), expected.len());
+    for (actual, expected) in diagnostics.iter().zip(&expected) {
+      assert_eq!(actual.code, expected.code);
+      assert_eq!(actual.range, expected.range);
+    }
+  }
+
+  #[test]
+  fn builder_with_subset_of_rules() {
+    use crate::rules::no_compare_neg_zero::NoCompareNegZero;
+    use crate::rules::no_debugger::NoDebugger;
+
+    let src = "debugger;\nif (a === -0) {}\nvar foo_bar = 1;";
+    let diagnostics = lint(
+      src,
+      false,
+      false,
+      vec![NoDebugger::new(), NoCompareNegZero::new()],
+    );
+    assert_eq!(diagnostics.len(), 2);
+    assert_diagnostic(&diagnostics[0], "no-debugger", 1, 0, src);
+    assert_diagnostic(&diagnostics[1], "no-compare-neg-zero", 2, 4, src);
+  }
+
+  #[test]
+  fn builder_rejects_duplicate_rule_codes() {
+    use crate::rules::no_debugger::NoDebugger;
+    use crate::rules::no_empty::NoEmpty;
+
+    let result = LinterBuilder::default()
+      .rules(vec![NoDebugger::new(), NoEmpty::new(), NoDebugger::new()])
+      .build();
+    let error = result.err().expect("Duplicate rule codes were accepted");
+    assert_eq!(error.code, "no-debugger");
+    assert_eq!(
+      error.to_string(),
+      "Rule code \"no-debugger\" is used by more than one rule"
+    );
+  }
+
+  #[test]
+  fn builder_ignore_file_directives() {
+    use crate::rules::no_debugger::NoDebugger;
+
+    let src = "// deno-lint-ignore-file\ndebugger;";
+    let lint_with = |ignore_file_directives| {
+      let mut linter = LinterBuilder::default()
+        .ignore_file_directives(ignore_file_directives)
+        .rules(vec![NoDebugger::new()])
+        .build()
+        .expect("Failed to create linter");
+      let (_, diagnostics) = linter
+        .lint("lint_test.ts".to_string(), src.to_string())
+        .expect("Failed to lint");
+      diagnostics
+    };
+
+    assert!(lint_with(true).is_empty());
+    let diagnostics = lint_with(false);
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], "no-debugger", 2, 0, src);
+  }
+
+  #[test]
+  fn configured_severity() {
+    use crate::diagnostic::Severity;
+    use crate::rules::no_debugger::NoDebugger;
+    use crate::rules::no_var::NoVar;
+    use std::collections::HashMap;
+
+    let severities: HashMap<String, Severity> = serde_json::from_str(
+      r#"{ "no-var": "warn", "ban-unused-ignore": "info" }"#,
+    )
+    .unwrap();
+    let mut linter = LinterBuilder::default()
+      .rules(vec![NoDebugger::new(), NoVar::new()])
+      .severities(severities)
+      .build()
+      .expect("Failed to create linter");
+    let (_, diagnostics) = linter
+      .lint(
+        "lint_test.ts".to_string(),
+        "var a = 1;\ndebugger;\n// deno-lint-ignore no-debugger\nconst b = 2;"
+          .to_string(),
+      )
+      .expect("Failed to lint");
+
+    assert_eq!(diagnostics.len(), 3);
+    assert_eq!(diagnostics[0].code, "no-var");
+    assert_eq!(diagnostics[0].severity, Severity::Warning);
+    assert_eq!(diagnostics[1].code, "no-debugger");
+    assert_eq!(diagnostics[1].severity, Severity::Error);
+    assert_eq!(diagnostics[2].code, "ban-unused-ignore");
+    assert_eq!(diagnostics[2].severity, Severity::Info);
+  }
+
+  #[test]
+  fn lint_summary_tallies() {
+    use crate::diagnostic::{LintSummary, Severity};
+    use crate::rules::no_debugger::NoDebugger;
+    use crate::rules::no_explicit_any::NoExplicitAny;
+    use crate::rules::no_var::NoVar;
+    use std::collections::HashMap;
+
+    let severities: HashMap<String, Severity> = serde_json::from_str(
+      r#"{ "no-var": "warn", "ban-unused-ignore": "info" }"#,
+    )
+    .unwrap();
+    let mut linter = LinterBuilder::default()
+      .rules(vec![NoDebugger::new(), NoExplicitAny::new(), NoVar::new()])
+      .severities(severities)
+      .build()
+      .expect("Failed to create linter");
+    let (_, diagnostics) = linter
+      .lint(
+        "lint_test.ts".to_string(),
+        r#"var a = 1;
+var b = 2;
+debugger;
+// deno-lint-ignore no-explicit-any
+const c = a + b;"#
+          .to_string(),
+      )
+      .expect("Failed to lint");
+
+    let summary = LintSummary::from_diagnostics(&diagnostics);
+    assert_eq!(summary.total, 4);
+    assert_eq!(
+      summary
+        .by_code
+        .iter()
+        .map(|(code, count)| (code.as_str(), *count))
+        .collect::<Vec<_>>(),
+      vec![("ban-unused-ignore", 1), ("no-debugger", 1), ("no-var", 2)]
+    );
+    assert_eq!(summary.error_count, 1);
+    assert_eq!(summary.warning_count, 2);
+    assert_eq!(summary.info_count, 1);
+    assert_eq!(summary.count(Severity::Warning), 2);
+    assert!(summary.has_errors());
+
+    let warnings = diagnostics
+      .into_iter()
+      .filter(|diagnostic| diagnostic.severity == Severity::Warning)
+      .collect::<Vec<_>>();
+    let summary = LintSummary::from_diagnostics(&warnings);
+    assert_eq!(summary.total, 2);
+    assert!(!summary.has_errors());
+    assert_eq!(LintSummary::from_diagnostics(&[]), LintSummary::default());
+  }
+
+  #[test]
+  fn severity_config_values() {
+    use crate::diagnostic::Severity;
+
+    let parse = |value| serde_json::from_str::<Severity>(value).unwrap();
+    assert_eq!(parse(r#""error""#), Severity::Error);
+    assert_eq!(parse(r#""warn""#), Severity::Warning);
+    assert_eq!(parse(r#""warning""#), Severity::Warning);
+    assert_eq!(parse(r#""info""#), Severity::Info);
+    assert!(serde_json::from_str::<Severity>(r#""off""#).is_err());
+  }
+
+  #[test]
+  fn builder_media_type() {
+    use crate::ast_parser::MediaType;
+
+    let parses = |media_type, src: &str| {
+      let mut linter = LinterBuilder::default()
+        .media_type(media_type)
+        .rules(get_recommended_rules())
+        .build()
+        .expect("Failed to create linter");
+      linter
+        .lint("lint_test".to_string(), src.to_string())
+        .is_ok()
+    };
+
+    assert!(parses(MediaType::TypeScript, "let a: number = <number>b;"));
+    assert!(!parses(MediaType::Tsx, "let a: number = <number>b;"));
+    assert!(!parses(MediaType::JavaScript, "interface A {}"));
+    assert!(parses(MediaType::JavaScript, "let a = 1;"));
+    assert!(!parses(MediaType::JavaScript, "let a = <div />;"));
+    assert!(parses(MediaType::Jsx, "let a = <div />;"));
+    assert!(parses(MediaType::Tsx, "let a: JSX.Element = <div />;"));
+    assert!(parses(MediaType::Dts, "declare const a: number;"));
+  }
+
+  #[test]
+  fn media_type_from_file_name() {
+    use crate::ast_parser::MediaType;
+
+    let detect = MediaType::from_file_name;
+    assert_eq!(detect("a.ts"), Some(MediaType::TypeScript));
+    assert_eq!(detect("dir.js/a.tsx"), Some(MediaType::Tsx));
+    assert_eq!(detect("a.d.ts"), Some(MediaType::Dts));
+    assert_eq!(detect("a.js"), Some(MediaType::JavaScript));
+    assert_eq!(detect("a.mjs"), Some(MediaType::JavaScript));
+    assert_eq!(detect("a.cjs"), Some(MediaType::JavaScript));
+    assert_eq!(detect("A.JSX"), Some(MediaType::Jsx));
+    assert_eq!(detect("a.json"), None);
+    assert_eq!(detect("Makefile"), None);
+  }
+
+  #[test]
+  fn media_type_detected_from_file_name() {
+    let parses = |file_name: &str, src: &str| {
+      let mut linter = LinterBuilder::default()
+        .rules(get_recommended_rules())
+        .build()
+        .expect("Failed to create linter");
+      linter.lint(file_name.to_string(), src.to_string()).is_ok()
+    };
+
+    assert!(parses("a.jsx", "let a = <div>{b}</div>;"));
+    assert!(parses("a.tsx", "let a: JSX.Element = <div />;"));
+    assert!(parses("a.ts", "let a: number = <number>b;"));
+    assert!(parses("a.mjs", "export const a = 1;"));
+    assert!(parses("a.cjs", "module.exports = { a: 1 };"));
+    assert!(parses("a.d.ts", "declare const a: number;"));
+    assert!(parses("a", "let a: number = <number>b;"));
+    assert!(!parses("a.js", "interface A {}"));
+    assert!(!parses("a.ts", "let a = <div />;"));
+  }
+
+  #[test]
+  fn typescript_only_rules_skipped_for_javascript() {
+    use crate::ast_parser::MediaType;
+    use crate::rules::explicit_function_return_type::ExplicitFunctionReturnType;
+    use crate::rules::no_debugger::NoDebugger;
+
+    let lint = |file_name: &str, media_type: Option<MediaType>| {
+      let mut builder = LinterBuilder::default()
+        .rules(vec![ExplicitFunctionReturnType::new(), NoDebugger::new()]);
+      if let Some(media_type) = media_type {
+        builder = builder.media_type(media_type);
+      }
+      let (_, diagnostics) = builder
+        .build()
+        .expect("Failed to create linter")
+        .lint(
+          file_name.to_string(),
+          r#"export function f() { return 1; }
+// deno-lint-ignore explicit-function-return-type
+export function g() { debugger; }"#
+            .to_string(),
+        )
+        .expect("Failed to lint");
+      diagnostics
+        .into_iter()
+        .map(|diagnostic| diagnostic.code)
+        .collect::<Vec<_>>()
+    };
+
+    assert_eq!(lint("a.js", None), vec!["no-debugger"]);
+    assert_eq!(lint("a.mjs", None), vec!["no-debugger"]);
+    assert_eq!(lint("a", Some(MediaType::Jsx)), vec!["no-debugger"]);
+    assert_eq!(
+      lint("a.ts", None),
+      vec!["explicit-function-return-type", "no-debugger"]
+    );
+    assert_eq!(
+      lint("a.js", Some(MediaType::TypeScript)),
+      vec!["explicit-function-return-type", "no-debugger"]
+    );
+  }
+
+  #[test]
+  fn lint_with_ast_returns_program() {
+    use crate::rules::no_debugger::NoDebugger;
+    use swc_ecmascript::ast::Program;
+
+    let mut linter = LinterBuilder::default()
+      .rules(vec![NoDebugger::new()])
+      .build()
+      .expect("Failed to create linter");
+    let (program, diagnostics) = linter
+      .lint_with_ast(
+        "lint_test.ts".to_string(),
+        "import a from './a.ts';\ndebugger;".to_string(),
+      )
+      .expect("Failed to lint");
+
+    match program {
+      Program::Module(module) => assert_eq!(module.body.len(), 2),
+      Program::Script(_) => panic!("Expected a module"),
+    }
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, "no-debugger");
+  }
+
+  #[test]
+  fn unresolvable_spans_dont_panic() {
+    use crate::rules::no_debugger::NoDebugger;
+    use crate::rules::no_magic_numbers::NoMagicNumbers;
+    use crate::rules::no_octal::NoOctal;
+    use swc_common::{BytePos, Span, SyntaxContext};
+    use swc_ecmascript::ast::{Number, Program};
+    use swc_ecmascript::visit::{VisitMut, VisitMutWith};
+
+    fn unresolvable_span() -> Span {
+      Span::new(
+        BytePos(u32::MAX - 2),
+        BytePos(u32::MAX),
+        SyntaxContext::empty(),
+      )
+    }
+
+    // Runs rules on a copy of the program in which the spans of all numbers
+    // point outside of the source.
+    struct SyntheticSpans;
+
+    impl VisitMut for SyntheticSpans {
+      fn visit_mut_number(&mut self, number: &mut Number) {
+        number.span = unresolvable_span();
+      }
+    }
+
+    impl LintRule for SyntheticSpans {
+      fn new() -> Box<Self> {
+        Box::new(SyntheticSpans)
+      }
+
+      fn code(&self) -> &'static str {
+        "synthetic-spans"
+      }
+
+      fn lint_program(
+        &self,
+        context: &mut crate::linter::Context,
+        program: &Program,
+      ) {
+        let mut program = program.clone();
+        program.visit_mut_with(&mut SyntheticSpans);
+        NoOctal::new().lint_program(context, &program);
+        NoMagicNumbers::new().lint_program(context, &program);
+        context.add_diagnostic(unresolvable_span(), self.code(), "synthetic");
+      }
+    }
+
+    let src = "const a = 010 * 42;\ndebugger;";
+    let diagnostics = lint(
+      src,
+      false,
+      false,
+      vec![SyntheticSpans::new(), NoDebugger::new()],
+    );
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], "no-debugger", 2, 0, src);
+  }
+
+  #[test]
+  fn diagnostic_with_suggestions() {
+    use crate::diagnostic::Suggestion;
+    use crate::rules::no_debugger::NoDebugger;
+    use swc_common::Spanned;
+    use swc_ecmascript::ast::{CondExpr, Program};
+    use swc_ecmascript::visit::{Node, Visit};
+
+    // Reports `x ? true : false`, suggesting `!!x` or `Boolean(x)`.
+    struct BooleanTernary;
+
+    struct BooleanTernaryVisitor<'c> {
+      context: &'c mut Context,
+    }
+
+    impl<'c> Visit for BooleanTernaryVisitor<'c> {
+      fn visit_cond_expr(&mut self, cond_expr: &CondExpr, _parent: &dyn Node) {
+        let test = self.context.snippet(cond_expr.test.span()).unwrap();
+        let suggestions = vec![
+          Suggestion {
+            label: "Replace with `!!x`".to_string(),
+            edit: self
+              .context
+              .text_edit(cond_expr.span, format!("!!{}", test))
+              .unwrap(),
+          },
+          Suggestion {
+            label: "Replace with `Boolean(x)`".to_string(),
+            edit: self
+              .context
+              .text_edit(cond_expr.span, format!("Boolean({})", test))
+              .unwrap(),
+          },
+        ];
+        self.context.add_diagnostic_with_suggestions(
+          cond_expr.span,
+          "boolean-ternary",
+          "Unnecessary ternary",
+          None,
+          suggestions,
+        );
+      }
+    }
+
+    impl LintRule for BooleanTernary {
+      fn new() -> Box<Self> {
+        Box::new(BooleanTernary)
+      }
+
+      fn code(&self) -> &'static str {
+        "boolean-ternary"
+      }
+
+      fn lint_program(&self, context: &mut Context, program: &Program) {
+        let mut visitor = BooleanTernaryVisitor { context };
+        visitor.visit_program(program, program);
+      }
+    }
+
+    let src = "const a = 1;\nconst b = a ? true : false;";
+    let diagnostics = lint(src, false, false, vec![BooleanTernary::new()]);
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], "boolean-ternary", 2, 10, src);
+    assert_eq!(diagnostics[0].hint, None);
+
+    let suggestions = &diagnostics[0].suggestions;
+    assert_eq!(suggestions.len(), 2);
+    assert_eq!(suggestions[0].label, "Replace with `!!x`");
+    assert_eq!(suggestions[0].edit.new_text, "!!a");
+    assert_eq!(suggestions[1].label, "Replace with `Boolean(x)`");
+    assert_eq!(suggestions[1].edit.new_text, "Boolean(a)");
+    for suggestion in suggestions {
+      assert_eq!(suggestion.edit.range, diagnostics[0].range);
+    }
+
+    let json = serde_json::to_value(&diagnostics[0]).unwrap();
+    assert_eq!(json["suggestions"][1]["edit"]["newText"], "Boolean(a)");
+    assert_eq!(json["suggestions"][1]["edit"]["range"]["start"]["col"], 10);
+
+    // Diagnostics without suggestions serialize as before.
+    let diagnostics = lint("debugger;", false, false, vec![NoDebugger::new()]);
+    assert!(diagnostics[0].suggestions.is_empty());
+    let json = serde_json::to_value(&diagnostics[0]).unwrap();
+    assert!(json.get("suggestions").is_none());
+  }
+
+  fn lint_files_input() -> Vec<(String, String)> {
+    (0..20)
+      .map(|i| {
+        let source = if i == 7 {
+          "function {".to_string()
+        } else {
+          format!(
+            "var foo_{} = {};\nif (foo_{} === -0) {{ debugger; }}",
+            i, i, i
+          )
+        };
+        (format!("file_{}.ts", i), source)
+      })
+      .collect()
+  }
+
+  #[test]
+  fn lint_files_matches_lint() {
+    let input = lint_files_input();
+    let results = lint_files(input.clone());
+
+    assert_eq!(results.len(), input.len());
+    for ((file_name, result), (expected_name, source)) in
+      results.iter().zip(&input)
+    {
+      assert_eq!(file_name, expected_name);
+      if file_name == "file_7.ts" {
+        assert!(result.is_err());
+        continue;
+      }
+      let diagnostics = result.as_ref().expect("Failed to lint");
+      let expected = lint_recommended_rules(source, true, true);
+      assert_eq!(diagnostics.len(), 3);
+      assert_eq!(diagnostics.len(), expected.len());
+      for (actual, expected) in diagnostics.iter().zip(&expected) {
+        assert_eq!(actual.filename, *file_name);
+        assert_eq!(actual.code, expected.code);
+        assert_eq!(actual.range, expected.range);
+      }
+    }
+  }
+
+  #[test]
+  fn lint_files_with_thread_count() {
+    use crate::rules::no_debugger::NoDebugger;
+
+    for num_threads in &[1, 4] {
+      let results = lint_files_with(lint_files_input(), *num_threads, || {
+        LinterBuilder::default()
+          .rules(vec![NoDebugger::new()])
+          .build()
+          .expect("Failed to create linter")
+      });
+      assert_eq!(results.len(), 20);
+      for (_, result) in results.iter().filter(|(f, _)| f != "file_7.ts") {
+        let diagnostics = result.as_ref().expect("Failed to lint");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, "no-debugger");
+      }
+    }
+  }
+
+  /// Compares linting a set of files on one thread against linting them on
+  /// all CPUs. Uses the oak corpus from `benchmarks/` when it's checked out,
+  /// and the examples from the docs of all rules otherwise. Run with
+  /// `cargo test --release bench_lint_files -- --ignored --nocapture`.
+  #[test]
+  #[ignore]
+  fn bench_lint_files() {
+    use crate::rules::get_all_rules;
+    use std::path::Path;
+    use std::time::Instant;
+
+    fn collect_ts_files(dir: &Path, files: &mut Vec<(String, String)>) {
+      let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+      };
+      for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+          collect_ts_files(&path, files);
+        } else if path.extension() == Some("ts".as_ref()) {
+          if let Ok(source) = std::fs::read_to_string(&path) {
+            files.push((path.display().to_string(), source));
+          }
+        }
+      }
+    }
+
+    let mut files = vec![];
+    collect_ts_files(Path::new("benchmarks/oak"), &mut files);
+    if files.is_empty() {
+      let examples = get_all_rules()
+        .iter()
+        .flat_map(|rule| {
+          rule
+            .docs()
+            .split("```typescript")
+            .skip(1)
+            .filter_map(|block| block.split("```").next())
+            .map(|block| block.to_string())
+            .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+      files = (0..200)
+        .map(|i| (format!("file_{}.ts", i), examples.clone()))
+        .collect();
+    }
+
+    let runs = 5;
+    let time = |num_threads: usize| {
+      (0..runs)
+        .map(|_| {
+          let start = Instant::now();
+          lint_files_with(files.clone(), num_threads, || {
+            LinterBuilder::default()
+              .rules(get_all_rules())
+              .build()
+              .expect("Failed to create linter")
+          });
+          start.elapsed()
+        })
+        .min()
+        .unwrap()
+    };
+    let sequential = time(1);
+    let parallel = time(0);
+    println!(
+      "{} files: 1 thread {:?}, {} threads {:?}",
+      files.len(),
+      sequential,
+      rayon::current_num_threads(),
+      parallel
+    );
+  }
 }
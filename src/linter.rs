@@ -1,16 +1,22 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
-use crate::ast_parser::get_default_ts_config;
 use crate::ast_parser::AstParser;
+use crate::ast_parser::MediaType;
 use crate::ast_parser::SwcDiagnosticBuffer;
+use crate::combined_visitor::CombinedVisitor;
 use crate::control_flow::ControlFlow;
-use crate::diagnostic::{LintDiagnostic, Position, Range};
+use crate::diagnostic::{
+  LintDiagnostic, Position, Range, Severity, Suggestion, TextEdit,
+};
 use crate::ignore_directives::parse_ignore_comment;
 use crate::ignore_directives::parse_ignore_directives;
 use crate::ignore_directives::IgnoreDirective;
-use crate::rules::{get_all_rules, LintRule};
+use crate::rules::{get_all_rules, get_recommended_rules, LintRule};
 use crate::scopes::Scope;
+use rayon::prelude::*;
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
 use std::rc::Rc;
 use std::time::Instant;
 use swc_common::comments::SingleThreadedComments;
@@ -19,12 +25,15 @@ use swc_common::SourceMap;
 use swc_common::Span;
 use swc_common::Spanned;
 use swc_common::{comments::Comment, SyntaxContext};
+use swc_ecmascript::ast::Program;
 use swc_ecmascript::parser::Syntax;
 
 pub use swc_common::SourceFile;
 
 pub struct Context {
   pub file_name: String,
+  /// Kind of the linted file, e.g. to tell JavaScript and TypeScript apart.
+  pub media_type: MediaType,
   pub diagnostics: Vec<LintDiagnostic>,
   pub source_map: Rc<SourceMap>,
   pub(crate) leading_comments: HashMap<BytePos, Vec<Comment>>,
@@ -33,40 +42,123 @@ pub struct Context {
   pub(crate) scope: Scope,
   pub(crate) control_flow: ControlFlow,
   pub(crate) top_level_ctxt: SyntaxContext,
+  /// Severity of the diagnostics of each rule, by rule code.
+  pub(crate) severities: HashMap<String, Severity>,
 }
 
 impl Context {
-  pub(crate) fn add_diagnostic(
+  /// Reports a problem found by the rule with the given `code` at `span`.
+  pub fn add_diagnostic(
     &mut self,
     span: Span,
     code: impl ToString,
     message: impl ToString,
   ) {
-    let diagnostic =
-      self.create_diagnostic(span, code.to_string(), message.to_string(), None);
-    self.diagnostics.push(diagnostic);
+    self.add_diagnostic_with_maybe_hint(span, code, message, None);
   }
 
-  pub(crate) fn add_diagnostic_with_hint(
+  /// Like `add_diagnostic`, with a hint on how to fix the problem.
+  pub fn add_diagnostic_with_hint(
     &mut self,
     span: Span,
     code: impl ToString,
     message: impl ToString,
     hint: impl ToString,
   ) {
-    let diagnostic =
-      self.create_diagnostic(span, code, message, Some(hint.to_string()));
-    self.diagnostics.push(diagnostic);
+    self.add_diagnostic_with_maybe_hint(
+      span,
+      code,
+      message,
+      Some(hint.to_string()),
+    );
   }
 
-  fn create_diagnostic(
+  /// Like `add_diagnostic`, with an optional hint and alternative fixes for
+  /// the problem. Edits of the suggestions are created with `text_edit`.
+  pub fn add_diagnostic_with_suggestions(
+    &mut self,
+    span: Span,
+    code: impl ToString,
+    message: impl ToString,
+    maybe_hint: Option<String>,
+    suggestions: Vec<Suggestion>,
+  ) {
+    if let Some(diagnostic) =
+      self.add_diagnostic_with_maybe_hint(span, code, message, maybe_hint)
+    {
+      diagnostic.suggestions = suggestions;
+    }
+  }
+
+  /// Returns an edit replacing the text in `span` by `new_text`, for a
+  /// `Suggestion`, or `None` if `span` doesn't resolve to a location in the
+  /// source.
+  pub fn text_edit(
     &self,
     span: Span,
+    new_text: impl ToString,
+  ) -> Option<TextEdit> {
+    self.source_file(span)?;
+    Some(TextEdit {
+      range: self.range(span),
+      new_text: new_text.to_string(),
+    })
+  }
+
+  /// Adds a diagnostic, unless `span` doesn't resolve to a location in the
+  /// source, in which case the diagnostic is logged and dropped instead.
+  fn add_diagnostic_with_maybe_hint(
+    &mut self,
+    span: Span,
     code: impl ToString,
     message: impl ToString,
     maybe_hint: Option<String>,
-  ) -> LintDiagnostic {
-    let time_start = Instant::now();
+  ) -> Option<&mut LintDiagnostic> {
+    if self.source_file(span).is_none() {
+      warn!(
+        "Dropping diagnostic \"{}\" for unresolvable span {:?}",
+        code.to_string(),
+        span
+      );
+      return None;
+    }
+    let diagnostic = self.create_diagnostic(span, code, message, maybe_hint);
+    self.diagnostics.push(diagnostic);
+    self.diagnostics.last_mut()
+  }
+
+  /// Scope analysis of the linted program, to tell which variable an
+  /// identifier refers to.
+  pub fn scope(&self) -> &Scope {
+    &self.scope
+  }
+
+  /// Returns the source file `span` lies in, or `None` if it doesn't resolve
+  /// to a location in any source file, like spans of synthesized nodes.
+  pub fn source_file(&self, span: Span) -> Option<Rc<SourceFile>> {
+    if span.lo() > span.hi() {
+      return None;
+    }
+    let files = self.source_map.files();
+    let file = SourceMap::lookup_source_file_in(&files, span.lo())?;
+    if span.lo() < file.start_pos || span.hi() > file.end_pos {
+      return None;
+    }
+    Some(file)
+  }
+
+  /// Returns the source text of `span`, or `None` if it can't be resolved.
+  pub fn snippet(&self, span: Span) -> Option<String> {
+    let snippet = self
+      .source_file(span)
+      .and_then(|_| self.source_map.span_to_snippet(span).ok());
+    if snippet.is_none() {
+      warn!("Failed to get the source text of span {:?}", span);
+    }
+    snippet
+  }
+
+  fn range(&self, span: Span) -> Range {
     let start = Position::new(
       self.source_map.lookup_byte_offset(span.lo()).pos,
       self.source_map.lookup_char_pos(span.lo()),
@@ -75,13 +167,27 @@ impl Context {
       self.source_map.lookup_byte_offset(span.hi()).pos,
       self.source_map.lookup_char_pos(span.hi()),
     );
+    Range { start, end }
+  }
 
+  fn create_diagnostic(
+    &self,
+    span: Span,
+    code: impl ToString,
+    message: impl ToString,
+    maybe_hint: Option<String>,
+  ) -> LintDiagnostic {
+    let time_start = Instant::now();
+    let code = code.to_string();
+    let severity = self.severities.get(&code).copied().unwrap_or_default();
     let diagnostic = LintDiagnostic {
-      range: Range { start, end },
+      range: self.range(span),
       filename: self.file_name.clone(),
       message: message.to_string(),
-      code: code.to_string(),
+      code,
       hint: maybe_hint,
+      severity,
+      suggestions: vec![],
     };
 
     let time_end = Instant::now();
@@ -93,36 +199,89 @@ impl Context {
   }
 }
 
+/// Error returned by `LinterBuilder::build` when more than one of the rules
+/// has the same code.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DuplicateRuleCodeError {
+  pub code: String,
+}
+
+impl Error for DuplicateRuleCodeError {}
+
+impl fmt::Display for DuplicateRuleCodeError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "Rule code \"{}\" is used by more than one rule",
+      self.code
+    )
+  }
+}
+
 pub struct LinterBuilder {
+  ignore_file_directives: bool,
   ignore_file_directive: String,
   ignore_diagnostic_directive: String,
   lint_unused_ignore_directives: bool,
   lint_unknown_rules: bool,
-  syntax: swc_ecmascript::parser::Syntax,
+  syntax: Option<Syntax>,
+  media_type: Option<MediaType>,
   rules: Vec<Box<dyn LintRule>>,
+  severities: HashMap<String, Severity>,
+  single_pass: bool,
 }
 
 impl LinterBuilder {
   pub fn default() -> Self {
     Self {
+      ignore_file_directives: true,
       ignore_file_directive: "deno-lint-ignore-file".to_string(),
       ignore_diagnostic_directive: "deno-lint-ignore".to_string(),
       lint_unused_ignore_directives: true,
       lint_unknown_rules: true,
-      syntax: get_default_ts_config(),
+      syntax: None,
+      media_type: None,
       rules: vec![],
+      severities: HashMap::new(),
+      single_pass: false,
     }
   }
 
-  pub fn build(self) -> Linter {
-    Linter::new(
-      self.ignore_file_directive,
-      self.ignore_diagnostic_directive,
-      self.lint_unused_ignore_directives,
-      self.lint_unknown_rules,
-      self.syntax,
-      self.rules,
-    )
+  /// Creates the `Linter`, failing if more than one rule has the same code.
+  pub fn build(self) -> Result<Linter, DuplicateRuleCodeError> {
+    let mut codes = HashSet::new();
+    for rule in &self.rules {
+      if !codes.insert(rule.code()) {
+        return Err(DuplicateRuleCodeError {
+          code: rule.code().to_string(),
+        });
+      }
+    }
+
+    Ok(Linter {
+      has_linted: false,
+      ast_parser: AstParser::new(),
+      ignore_file_directives: self.ignore_file_directives,
+      ignore_file_directive: self.ignore_file_directive,
+      ignore_diagnostic_directive: self.ignore_diagnostic_directive,
+      lint_unused_ignore_directives: self.lint_unused_ignore_directives,
+      lint_unknown_rules: self.lint_unknown_rules,
+      syntax: self.syntax,
+      media_type: self.media_type,
+      rules: self.rules,
+      severities: self.severities,
+      single_pass: self.single_pass,
+    })
+  }
+
+  /// Whether `deno-lint-ignore-file` directives are honored. When disabled,
+  /// files are linted even if they contain one.
+  pub fn ignore_file_directives(
+    mut self,
+    ignore_file_directives: bool,
+  ) -> Self {
+    self.ignore_file_directives = ignore_file_directives;
+    self
   }
 
   pub fn ignore_file_directive(mut self, directive: &str) -> Self {
@@ -148,8 +307,18 @@ impl LinterBuilder {
     self
   }
 
+  /// Sets the syntax used to parse files, instead of the syntax of their
+  /// media type.
   pub fn syntax(mut self, syntax: Syntax) -> Self {
-    self.syntax = syntax;
+    self.syntax = Some(syntax);
+    self
+  }
+
+  /// Sets the media type of linted files, instead of detecting it from the
+  /// extension of their name. Files with an unknown extension are linted as
+  /// TypeScript.
+  pub fn media_type(mut self, media_type: MediaType) -> Self {
+    self.media_type = Some(media_type);
     self
   }
 
@@ -157,38 +326,52 @@ impl LinterBuilder {
     self.rules = rules;
     self
   }
+
+  /// Overrides the default severity of rules, by rule code, e.g. as
+  /// configured with `{ "no-var": "warn" }`.
+  pub fn severities(mut self, severities: HashMap<String, Severity>) -> Self {
+    self.severities = severities;
+    self
+  }
+
+  /// Runs all rules which support it in a single traversal of the program,
+  /// see `combined_visitor`. Other rules still traverse the program on their
+  /// own.
+  pub fn single_pass(mut self, single_pass: bool) -> Self {
+    self.single_pass = single_pass;
+    self
+  }
 }
 
 pub struct Linter {
   has_linted: bool,
   ast_parser: AstParser,
+  ignore_file_directives: bool,
   ignore_file_directive: String,
   ignore_diagnostic_directive: String,
   lint_unused_ignore_directives: bool,
   lint_unknown_rules: bool,
-  syntax: Syntax,
+  syntax: Option<Syntax>,
+  media_type: Option<MediaType>,
   rules: Vec<Box<dyn LintRule>>,
+  severities: HashMap<String, Severity>,
+  single_pass: bool,
 }
 
 impl Linter {
-  fn new(
-    ignore_file_directive: String,
-    ignore_diagnostic_directive: String,
-    lint_unused_ignore_directives: bool,
-    lint_unknown_rules: bool,
-    syntax: Syntax,
-    rules: Vec<Box<dyn LintRule>>,
-  ) -> Self {
-    Linter {
-      has_linted: false,
-      ast_parser: AstParser::new(),
-      ignore_file_directive,
-      ignore_diagnostic_directive,
-      lint_unused_ignore_directives,
-      lint_unknown_rules,
-      syntax,
-      rules,
+  /// Adds a rule, like one defined outside of this crate, to the rules the
+  /// linter runs. Fails if the linter already has a rule with the same code.
+  pub fn add_rule(
+    &mut self,
+    rule: Box<dyn LintRule>,
+  ) -> Result<(), DuplicateRuleCodeError> {
+    if self.rules.iter().any(|r| r.code() == rule.code()) {
+      return Err(DuplicateRuleCodeError {
+        code: rule.code().to_string(),
+      });
     }
+    self.rules.push(rule);
+    Ok(())
   }
 
   pub fn lint(
@@ -199,112 +382,199 @@ impl Linter {
     (Rc<swc_common::SourceFile>, Vec<LintDiagnostic>),
     SwcDiagnosticBuffer,
   > {
+    let mut diagnostics = vec![];
+    self.parse_and_lint(
+      file_name.clone(),
+      source_code,
+      None,
+      &mut |diagnostic| diagnostics.push(diagnostic),
+    )?;
+    diagnostics.sort_by(|a, b| a.range.start.line.cmp(&b.range.start.line));
+    Ok((self.source_file(file_name), diagnostics))
+  }
+
+  /// Like `lint`, but returns the parsed program instead of the source file.
+  pub fn lint_with_ast(
+    &mut self,
+    file_name: String,
+    source_code: String,
+  ) -> Result<(Program, Vec<LintDiagnostic>), SwcDiagnosticBuffer> {
+    let mut diagnostics = vec![];
+    let program =
+      self.parse_and_lint(file_name, source_code, None, &mut |diagnostic| {
+        diagnostics.push(diagnostic)
+      })?;
+    diagnostics.sort_by_key(|diagnostic| diagnostic.range.start.line);
+    Ok((program, diagnostics))
+  }
+
+  /// Lints a single module with `rules`, instead of the rules the linter was
+  /// built with, passing every diagnostic to `on_diagnostic` as soon as the
+  /// rule that reported it has finished running.
+  ///
+  /// Ignore directives are applied before the callback is invoked. Unlike
+  /// `lint`, diagnostics are delivered in the order they are reported rather
+  /// than sorted by line.
+  pub fn lint_with_callback(
+    &mut self,
+    file_name: String,
+    source_code: String,
+    rules: &[Box<dyn LintRule>],
+    mut on_diagnostic: impl FnMut(LintDiagnostic),
+  ) -> Result<Rc<swc_common::SourceFile>, SwcDiagnosticBuffer> {
+    self.parse_and_lint(
+      file_name.clone(),
+      source_code,
+      Some(rules),
+      &mut on_diagnostic,
+    )?;
+    Ok(self.source_file(file_name))
+  }
+
+  fn source_file(&self, file_name: String) -> Rc<swc_common::SourceFile> {
+    self
+      .ast_parser
+      .source_map
+      .get_source_file(&swc_common::FileName::Custom(file_name))
+      .unwrap()
+  }
+
+  /// Parses and lints a single module with `rules`, or the rules the linter
+  /// was built with if `None`.
+  fn parse_and_lint(
+    &mut self,
+    file_name: String,
+    source_code: String,
+    rules: Option<&[Box<dyn LintRule>]>,
+    sink: &mut dyn FnMut(LintDiagnostic),
+  ) -> Result<Program, SwcDiagnosticBuffer> {
     assert!(
       !self.has_linted,
       "Linter can be used only on a single module."
     );
     self.has_linted = true;
     let start = Instant::now();
+    let rules = rules.unwrap_or(&self.rules);
 
+    let media_type = self
+      .media_type
+      .or_else(|| MediaType::from_file_name(&file_name))
+      .unwrap_or(MediaType::TypeScript);
+    let syntax = self.syntax.unwrap_or_else(|| media_type.syntax());
     let parse_result =
       self
         .ast_parser
-        .parse_program(&file_name, self.syntax, &source_code);
+        .parse_program(&file_name, syntax, &source_code);
     let end_parse_program = Instant::now();
     debug!(
       "ast_parser.parse_program took {:#?}",
       end_parse_program - start
     );
     let (program, comments) = parse_result?;
-    let diagnostics = self.lint_program(file_name.clone(), program, comments);
+    self.lint_program(file_name, media_type, &program, comments, rules, sink);
 
-    let source_file = self
-      .ast_parser
-      .source_map
-      .get_source_file(&swc_common::FileName::Custom(file_name))
-      .unwrap();
     let end = Instant::now();
     debug!("Linter::lint took {:#?}", end - start);
-    Ok((source_file, diagnostics))
+    Ok(program)
   }
 
-  fn filter_diagnostics(
+  /// Passes diagnostics reported so far to `sink`, dropping those that are
+  /// suppressed by an ignore directive.
+  fn flush_diagnostics(
     &self,
     context: &mut Context,
+    sink: &mut dyn FnMut(LintDiagnostic),
+  ) {
+    let diagnostics = std::mem::take(&mut context.diagnostics);
+    let mut ignore_directives = context.ignore_directives.borrow_mut();
+
+    for diagnostic in diagnostics {
+      let is_ignored = ignore_directives.iter_mut().any(|ignore_directive| {
+        ignore_directive.maybe_ignore_diagnostic(&diagnostic)
+      });
+      if !is_ignored {
+        sink(diagnostic);
+      }
+    }
+  }
+
+  /// Returns the severity of the diagnostics of each rule code, as
+  /// configured or by default.
+  fn rule_severities(
+    &self,
     rules: &[Box<dyn LintRule>],
-  ) -> Vec<LintDiagnostic> {
-    let start = Instant::now();
-    let ignore_directives = context.ignore_directives.clone();
-    let diagnostics = &context.diagnostics;
+  ) -> HashMap<String, Severity> {
+    let mut severities = rules
+      .iter()
+      .map(|rule| (rule.code().to_string(), rule.severity()))
+      .collect::<HashMap<_, _>>();
+    severities.extend(self.severities.clone());
+    severities
+  }
+
+  fn report_ignore_directives(
+    &self,
+    context: &Context,
+    rules: &[&dyn LintRule],
+    sink: &mut dyn FnMut(LintDiagnostic),
+  ) {
+    if !self.lint_unused_ignore_directives && !self.lint_unknown_rules {
+      return;
+    }
 
+    let start = Instant::now();
     let executed_rule_codes = rules
       .iter()
       .map(|r| r.code().to_string())
       .collect::<HashSet<String>>();
+    // Codes of rules added to the linter which are not built in are known
+    // too.
     let available_rule_codes = get_all_rules()
       .iter()
       .map(|r| r.code().to_string())
+      .chain(executed_rule_codes.iter().cloned())
       .collect::<HashSet<String>>();
 
-    let mut filtered_diagnostics: Vec<LintDiagnostic> = diagnostics
-      .as_slice()
-      .iter()
-      .cloned()
-      .filter(|diagnostic| {
-        !ignore_directives
-          .borrow_mut()
-          .iter_mut()
-          .any(|ignore_directive| {
-            ignore_directive.maybe_ignore_diagnostic(&diagnostic)
-          })
-      })
-      .collect();
-
-    if self.lint_unused_ignore_directives || self.lint_unknown_rules {
-      for ignore_directive in ignore_directives.borrow().iter() {
-        for (code, used) in ignore_directive.used_codes.iter() {
-          if self.lint_unused_ignore_directives
-            && !used
-            && executed_rule_codes.contains(code)
-          {
-            let diagnostic = context.create_diagnostic(
-              ignore_directive.span,
-              "ban-unused-ignore",
-              format!("Ignore for code \"{}\" was not used.", code),
-              None,
-            );
-            filtered_diagnostics.push(diagnostic);
-          }
-
-          if self.lint_unknown_rules && !available_rule_codes.contains(code) {
-            filtered_diagnostics.push(context.create_diagnostic(
-              ignore_directive.span,
-              "ban-unknown-rule-code",
-              format!("Unknown rule for code \"{}\"", code),
-              None,
-            ))
-          }
+    for ignore_directive in context.ignore_directives.borrow().iter() {
+      for (code, used) in ignore_directive.used_codes.iter() {
+        if self.lint_unused_ignore_directives
+          && !used
+          && executed_rule_codes.contains(code)
+        {
+          sink(context.create_diagnostic(
+            ignore_directive.span,
+            "ban-unused-ignore",
+            format!("Ignore for code \"{}\" was not used.", code),
+            None,
+          ));
+        }
+
+        if self.lint_unknown_rules && !available_rule_codes.contains(code) {
+          sink(context.create_diagnostic(
+            ignore_directive.span,
+            "ban-unknown-rule-code",
+            format!("Unknown rule for code \"{}\"", code),
+            None,
+          ))
         }
       }
     }
 
-    filtered_diagnostics
-      .sort_by(|a, b| a.range.start.line.cmp(&b.range.start.line));
-
     let end = Instant::now();
-    debug!("Linter::filter_diagnostics took {:#?}", end - start);
-
-    filtered_diagnostics
+    debug!("Linter::report_ignore_directives took {:#?}", end - start);
   }
 
   fn lint_program(
     &self,
     file_name: String,
-    program: swc_ecmascript::ast::Program,
+    media_type: MediaType,
+    program: &Program,
     comments: SingleThreadedComments,
-  ) -> Vec<LintDiagnostic> {
+    rules: &[Box<dyn LintRule>],
+    sink: &mut dyn FnMut(LintDiagnostic),
+  ) {
     let start = Instant::now();
-    let file_ignore_directive =
+    let file_ignore_directive = if self.ignore_file_directives {
       comments.with_leading(program.span().lo(), |c| {
         c.iter().find_map(|comment| {
           parse_ignore_comment(
@@ -314,13 +584,16 @@ impl Linter {
             true,
           )
         })
-      });
+      })
+    } else {
+      None
+    };
 
     // If there's a file ignore directive that has no codes specified we must ignore
     // whole file and skip linting it.
     if let Some(ignore_directive) = &file_ignore_directive {
       if ignore_directive.codes.is_empty() {
-        return vec![];
+        return;
       }
     }
 
@@ -345,8 +618,8 @@ impl Linter {
       ignore_directives.insert(0, ignore_directive);
     }
 
-    let scope = Scope::analyze(&program);
-    let control_flow = ControlFlow::analyze(&program);
+    let scope = Scope::analyze(program);
+    let control_flow = ControlFlow::analyze(program);
     let top_level_ctxt = swc_common::GLOBALS
       .set(&self.ast_parser.globals, || {
         SyntaxContext::empty().apply_mark(self.ast_parser.top_level_mark)
@@ -354,6 +627,7 @@ impl Linter {
 
     let mut context = Context {
       file_name,
+      media_type,
       diagnostics: vec![],
       source_map: self.ast_parser.source_map.clone(),
       leading_comments: leading,
@@ -362,16 +636,103 @@ impl Linter {
       scope,
       control_flow,
       top_level_ctxt,
+      severities: self.rule_severities(rules),
     };
 
-    for rule in &self.rules {
-      rule.lint_program(&mut context, &program);
+    // Rules which only check TypeScript syntax are skipped for JavaScript.
+    let rules = rules
+      .iter()
+      .map(|rule| &**rule)
+      .filter(|rule| media_type.is_typescript() || !rule.is_typescript_only())
+      .collect::<Vec<_>>();
+
+    if self.single_pass {
+      let node_rules = rules.iter().filter_map(|rule| rule.node_rule());
+      CombinedVisitor::new(&mut context, node_rules).visit_program(program);
+      self.flush_diagnostics(&mut context, sink);
+    }
+
+    for rule in &rules {
+      if self.single_pass && rule.node_rule().is_some() {
+        continue;
+      }
+      rule.lint_program(&mut context, program);
+      self.flush_diagnostics(&mut context, sink);
     }
 
-    let d = self.filter_diagnostics(&mut context, &self.rules);
+    self.report_ignore_directives(&context, &rules, sink);
     let end = Instant::now();
     debug!("Linter::lint_module took {:#?}", end - start);
-
-    d
   }
 }
+
+/// Result of linting a single file with `lint_files`.
+pub type FileLintResult =
+  (String, Result<Vec<LintDiagnostic>, SwcDiagnosticBuffer>);
+
+/// Lints `files`, given as `(file_name, source_code)` pairs, in parallel with
+/// the recommended rules.
+///
+/// See `lint_files_with` for details.
+pub fn lint_files(files: Vec<(String, String)>) -> Vec<FileLintResult> {
+  lint_files_with(files, 0, || {
+    LinterBuilder::default()
+      .rules(get_recommended_rules())
+      .build()
+      .expect("Recommended rules have unique codes")
+  })
+}
+
+/// Lints `files`, given as `(file_name, source_code)` pairs, in parallel on
+/// `num_threads` threads, or as many threads as there are CPUs if it is `0`.
+///
+/// Every file is linted by a fresh `Linter` returned from `create_linter`, on
+/// the thread linting the file, so the `Context` and the rules are never
+/// shared between files or threads. Because of that rules don't have to be
+/// `Send` or `Sync`; only `create_linter` has to be `Sync`, as it's called
+/// from all threads.
+///
+/// If a thread pool with `num_threads` threads can't be created, files are
+/// linted on rayon's global thread pool instead.
+///
+/// Results are returned in the same order as `files`.
+pub fn lint_files_with<F>(
+  files: Vec<(String, String)>,
+  num_threads: usize,
+  create_linter: F,
+) -> Vec<FileLintResult>
+where
+  F: Fn() -> Linter + Sync,
+{
+  let start = Instant::now();
+  let lint_all = || -> Vec<FileLintResult> {
+    files
+      .into_par_iter()
+      .map(|(file_name, source_code)| {
+        let mut linter = create_linter();
+        let result = linter
+          .lint(file_name.clone(), source_code)
+          .map(|(_, diagnostics)| diagnostics);
+        (file_name, result)
+      })
+      .collect()
+  };
+
+  let results = match rayon::ThreadPoolBuilder::new()
+    .num_threads(num_threads)
+    .build()
+  {
+    Ok(pool) => pool.install(lint_all),
+    Err(err) => {
+      warn!(
+        "Failed to create thread pool, using the global one: {}",
+        err
+      );
+      lint_all()
+    }
+  };
+
+  let end = Instant::now();
+  debug!("lint_files took {:#?}", end - start);
+  results
+}
@@ -1,20 +1,37 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use crate::combined_visitor::NodeRule;
+use crate::diagnostic::Severity;
 use crate::linter::Context;
 use swc_ecmascript::ast::Program;
 
+pub mod accessor_pairs;
 pub mod adjacent_overload_signatures;
+pub mod array_type;
 pub mod ban_ts_comment;
 pub mod ban_types;
 pub mod ban_untagged_ignore;
 pub mod ban_untagged_todo;
+pub mod block_scoped_var;
 pub mod camelcase;
+pub mod complexity;
+pub mod consistent_type_definitions;
+pub mod consistent_type_imports;
 pub mod constructor_super;
+pub mod default_case;
+pub mod default_case_last;
 pub mod default_param_last;
 pub mod eqeqeq;
 pub mod explicit_function_return_type;
 pub mod explicit_module_boundary_types;
 pub mod for_direction;
 pub mod getter_return;
+pub mod grouped_accessor_pairs;
+pub mod max_depth;
+pub mod max_lines;
+pub mod max_lines_per_function;
+pub mod max_nested_callbacks;
+pub mod max_params;
+pub mod max_statements;
 pub mod no_array_constructor;
 pub mod no_async_promise_executor;
 pub mod no_await_in_loop;
@@ -22,6 +39,7 @@ pub mod no_case_declarations;
 pub mod no_class_assign;
 pub mod no_compare_neg_zero;
 pub mod no_cond_assign;
+pub mod no_confusing_non_null_assertion;
 pub mod no_const_assign;
 pub mod no_constant_condition;
 pub mod no_control_regex;
@@ -32,8 +50,10 @@ pub mod no_dupe_class_members;
 pub mod no_dupe_else_if;
 pub mod no_dupe_keys;
 pub mod no_duplicate_case;
+pub mod no_duplicate_enum_values;
 pub mod no_empty;
 pub mod no_empty_character_class;
+pub mod no_empty_function;
 pub mod no_empty_interface;
 pub mod no_empty_pattern;
 pub mod no_eval;
@@ -41,6 +61,7 @@ pub mod no_ex_assign;
 pub mod no_explicit_any;
 pub mod no_extra_boolean_cast;
 pub mod no_extra_non_null_assertion;
+pub mod no_extra_parens;
 pub mod no_extra_semi;
 pub mod no_fallthrough;
 pub mod no_func_assign;
@@ -49,8 +70,14 @@ pub mod no_import_assign;
 pub mod no_inferrable_types;
 pub mod no_inner_declarations;
 pub mod no_invalid_regexp;
+pub mod no_invalid_this;
 pub mod no_irregular_whitespace;
+pub mod no_lone_blocks;
+pub mod no_loop_func;
+pub mod no_magic_numbers;
 pub mod no_misused_new;
+pub mod no_mixed_enums;
+pub mod no_mixed_operators;
 pub mod no_mixed_spaces_and_tabs;
 pub mod no_namespace;
 pub mod no_new_symbol;
@@ -58,33 +85,75 @@ pub mod no_non_null_asserted_optional_chain;
 pub mod no_non_null_assertion;
 pub mod no_obj_calls;
 pub mod no_octal;
+pub mod no_promise_executor_return;
 pub mod no_prototype_builtins;
 pub mod no_redeclare;
 pub mod no_regex_spaces;
+pub mod no_require_imports;
+pub mod no_restricted_globals;
+pub mod no_restricted_imports;
+pub mod no_restricted_properties;
+pub mod no_restricted_syntax;
+pub mod no_return_await;
 pub mod no_self_assign;
 pub mod no_setter_return;
 pub mod no_shadow_restricted_names;
 pub mod no_sparse_arrays;
+pub mod no_ternary;
 pub mod no_this_alias;
 pub mod no_this_before_super;
 pub mod no_throw_literal;
 pub mod no_undef;
+pub mod no_unnecessary_boolean_literal_compare;
+pub mod no_unnecessary_type_assertion;
 pub mod no_unreachable;
 pub mod no_unsafe_finally;
 pub mod no_unsafe_negation;
+pub mod no_unsafe_optional_chaining;
 pub mod no_unused_labels;
 pub mod no_unused_vars;
+pub mod no_useless_computed_key;
+pub mod no_useless_constructor;
+pub mod no_useless_return;
 pub mod no_var;
+pub mod no_var_requires;
 pub mod no_with;
+pub mod operator_assignment;
+pub mod prefer_arrow_callback;
 pub mod prefer_as_const;
 pub mod prefer_const;
+pub mod prefer_enum_initializers;
+pub mod prefer_function_type;
 pub mod prefer_namespace_keyword;
+pub mod prefer_nullish_coalescing;
+pub mod prefer_object_spread;
+pub mod prefer_optional_chain;
+pub mod prefer_rest_params;
+pub mod prefer_spread;
+pub mod require_await;
 pub mod require_yield;
 pub mod single_var_declarator;
+pub mod sort_imports;
+pub mod sort_keys;
+pub mod switch_exhaustiveness_check;
+pub mod symbol_description;
 pub mod triple_slash_reference;
 pub mod use_isnan;
 pub mod valid_typeof;
+pub mod yoda;
 
+/// A lint rule, which reports problems in a program.
+///
+/// Rules can be defined outside of this crate and added to a linter with
+/// `LinterBuilder::rules` or `Linter::add_rule`. A rule must:
+/// - have a `code` which is unique among the rules of a linter; it identifies
+///   the rule in diagnostics, ignore directives and configuration,
+/// - report problems from `lint_program` only through
+///   `Context::add_diagnostic` or `Context::add_diagnostic_with_hint`, using
+///   its own code,
+/// - not keep state between calls to `lint_program`, which takes `&self`;
+///   state needed while linting a program belongs in a visitor created by
+///   `lint_program`.
 pub trait LintRule {
   fn new() -> Box<Self>
   where
@@ -97,23 +166,52 @@ pub trait LintRule {
   fn docs(&self) -> &'static str {
     ""
   }
+  /// Severity of the diagnostics of the rule, unless configured otherwise.
+  fn severity(&self) -> Severity {
+    Severity::Error
+  }
+  /// Returns the rule as a `NodeRule` if it can be run as part of a single
+  /// combined traversal of the program.
+  fn node_rule(&self) -> Option<&dyn NodeRule> {
+    None
+  }
+  /// Whether the rule only checks TypeScript syntax, in which case it's not
+  /// run on JavaScript files.
+  fn is_typescript_only(&self) -> bool {
+    false
+  }
 }
 
 pub fn get_all_rules() -> Vec<Box<dyn LintRule>> {
   vec![
+    accessor_pairs::AccessorPairs::new(),
     adjacent_overload_signatures::AdjacentOverloadSignatures::new(),
+    array_type::ArrayType::new(),
     ban_ts_comment::BanTsComment::new(),
     ban_types::BanTypes::new(),
     ban_untagged_ignore::BanUntaggedIgnore::new(),
     ban_untagged_todo::BanUntaggedTodo::new(),
+    block_scoped_var::BlockScopedVar::new(),
     camelcase::Camelcase::new(),
+    complexity::Complexity::new(),
+    consistent_type_definitions::ConsistentTypeDefinitions::new(),
+    consistent_type_imports::ConsistentTypeImports::new(),
     constructor_super::ConstructorSuper::new(),
+    default_case::DefaultCase::new(),
+    default_case_last::DefaultCaseLast::new(),
     default_param_last::DefaultParamLast::new(),
     eqeqeq::Eqeqeq::new(),
     explicit_function_return_type::ExplicitFunctionReturnType::new(),
     explicit_module_boundary_types::ExplicitModuleBoundaryTypes::new(),
     for_direction::ForDirection::new(),
     getter_return::GetterReturn::new(),
+    grouped_accessor_pairs::GroupedAccessorPairs::new(),
+    max_depth::MaxDepth::new(),
+    max_lines::MaxLines::new(),
+    max_lines_per_function::MaxLinesPerFunction::new(),
+    max_nested_callbacks::MaxNestedCallbacks::new(),
+    max_params::MaxParams::new(),
+    max_statements::MaxStatements::new(),
     no_array_constructor::NoArrayConstructor::new(),
     no_async_promise_executor::NoAsyncPromiseExecutor::new(),
     no_await_in_loop::NoAwaitInLoop::new(),
@@ -121,6 +219,7 @@ pub fn get_all_rules() -> Vec<Box<dyn LintRule>> {
     no_class_assign::NoClassAssign::new(),
     no_compare_neg_zero::NoCompareNegZero::new(),
     no_cond_assign::NoCondAssign::new(),
+    no_confusing_non_null_assertion::NoConfusingNonNullAssertion::new(),
     no_const_assign::NoConstAssign::new(),
     no_constant_condition::NoConstantCondition::new(),
     no_control_regex::NoControlRegex::new(),
@@ -131,8 +230,10 @@ pub fn get_all_rules() -> Vec<Box<dyn LintRule>> {
     no_dupe_else_if::NoDupeElseIf::new(),
     no_dupe_keys::NoDupeKeys::new(),
     no_duplicate_case::NoDuplicateCase::new(),
+    no_duplicate_enum_values::NoDuplicateEnumValues::new(),
     no_empty::NoEmpty::new(),
     no_empty_character_class::NoEmptyCharacterClass::new(),
+    no_empty_function::NoEmptyFunction::new(),
     no_empty_interface::NoEmptyInterface::new(),
     no_empty_pattern::NoEmptyPattern::new(),
     no_eval::NoEval::new(),
@@ -140,6 +241,7 @@ pub fn get_all_rules() -> Vec<Box<dyn LintRule>> {
     no_explicit_any::NoExplicitAny::new(),
     no_extra_boolean_cast::NoExtraBooleanCast::new(),
     no_extra_non_null_assertion::NoExtraNonNullAssertion::new(),
+    no_extra_parens::NoExtraParens::new(),
     no_extra_semi::NoExtraSemi::new(),
     no_fallthrough::NoFallthrough::new(),
     no_func_assign::NoFuncAssign::new(),
@@ -148,8 +250,14 @@ pub fn get_all_rules() -> Vec<Box<dyn LintRule>> {
     no_inferrable_types::NoInferrableTypes::new(),
     no_inner_declarations::NoInnerDeclarations::new(),
     no_invalid_regexp::NoInvalidRegexp::new(),
+    no_invalid_this::NoInvalidThis::new(),
     no_irregular_whitespace::NoIrregularWhitespace::new(),
+    no_lone_blocks::NoLoneBlocks::new(),
+    no_loop_func::NoLoopFunc::new(),
+    no_magic_numbers::NoMagicNumbers::new(),
     no_misused_new::NoMisusedNew::new(),
+    no_mixed_enums::NoMixedEnums::new(),
+    no_mixed_operators::NoMixedOperators::new(),
     no_mixed_spaces_and_tabs::NoMixedSpacesAndTabs::new(),
     no_namespace::NoNamespace::new(),
     no_new_symbol::NoNewSymbol::new(),
@@ -157,32 +265,62 @@ pub fn get_all_rules() -> Vec<Box<dyn LintRule>> {
     no_non_null_assertion::NoNonNullAssertion::new(),
     no_obj_calls::NoObjCalls::new(),
     no_octal::NoOctal::new(),
+    no_promise_executor_return::NoPromiseExecutorReturn::new(),
     no_prototype_builtins::NoPrototypeBuiltins::new(),
     no_redeclare::NoRedeclare::new(),
     no_regex_spaces::NoRegexSpaces::new(),
+    no_require_imports::NoRequireImports::new(),
+    no_restricted_globals::NoRestrictedGlobals::new(),
+    no_restricted_imports::NoRestrictedImports::new(),
+    no_restricted_properties::NoRestrictedProperties::new(),
+    no_restricted_syntax::NoRestrictedSyntax::new(),
+    no_return_await::NoReturnAwait::new(),
     no_self_assign::NoSelfAssign::new(),
     no_setter_return::NoSetterReturn::new(),
     no_shadow_restricted_names::NoShadowRestrictedNames::new(),
     no_sparse_arrays::NoSparseArrays::new(),
+    no_ternary::NoTernary::new(),
     no_this_alias::NoThisAlias::new(),
     no_this_before_super::NoThisBeforeSuper::new(),
     no_throw_literal::NoThrowLiteral::new(),
     no_undef::NoUndef::new(),
+    no_unnecessary_boolean_literal_compare::NoUnnecessaryBooleanLiteralCompare::new(),
+    no_unnecessary_type_assertion::NoUnnecessaryTypeAssertion::new(),
     no_unreachable::NoUnreachable::new(),
     no_unsafe_finally::NoUnsafeFinally::new(),
     no_unsafe_negation::NoUnsafeNegation::new(),
+    no_unsafe_optional_chaining::NoUnsafeOptionalChaining::new(),
     no_unused_labels::NoUnusedLabels::new(),
     no_unused_vars::NoUnusedVars::new(),
+    no_useless_computed_key::NoUselessComputedKey::new(),
+    no_useless_constructor::NoUselessConstructor::new(),
+    no_useless_return::NoUselessReturn::new(),
     no_var::NoVar::new(),
+    no_var_requires::NoVarRequires::new(),
     no_with::NoWith::new(),
+    operator_assignment::OperatorAssignment::new(),
+    prefer_arrow_callback::PreferArrowCallback::new(),
     prefer_as_const::PreferAsConst::new(),
     prefer_const::PreferConst::new(),
+    prefer_enum_initializers::PreferEnumInitializers::new(),
+    prefer_function_type::PreferFunctionType::new(),
     prefer_namespace_keyword::PreferNamespaceKeyword::new(),
+    prefer_nullish_coalescing::PreferNullishCoalescing::new(),
+    prefer_object_spread::PreferObjectSpread::new(),
+    prefer_optional_chain::PreferOptionalChain::new(),
+    prefer_rest_params::PreferRestParams::new(),
+    prefer_spread::PreferSpread::new(),
+    require_await::RequireAwait::new(),
     require_yield::RequireYield::new(),
     single_var_declarator::SingleVarDeclarator::new(),
+    sort_imports::SortImports::new(),
+    sort_keys::SortKeys::new(),
+    switch_exhaustiveness_check::SwitchExhaustivenessCheck::new(),
+    symbol_description::SymbolDescription::new(),
     triple_slash_reference::TripleSlashReference::new(),
     use_isnan::UseIsNaN::new(),
     valid_typeof::ValidTypeof::new(),
+    yoda::Yoda::new(),
   ]
 }
 
@@ -216,4 +354,14 @@ mod tests {
       assert_eq!(sorted.code(), unsorted.code());
     }
   }
+
+  #[test]
+  fn rules_have_unique_codes_and_docs() {
+    let mut codes = std::collections::HashSet::new();
+    for rule in get_all_rules() {
+      assert!(!rule.code().is_empty());
+      assert!(codes.insert(rule.code()), "Duplicate code {}", rule.code());
+      assert!(!rule.docs().is_empty(), "{} has no docs", rule.code());
+    }
+  }
 }
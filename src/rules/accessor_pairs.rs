@@ -0,0 +1,348 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::StringRepr;
+use serde::Deserialize;
+use std::collections::HashMap;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  Class, ClassMember, MethodKind, ObjectLit, Program, Prop, PropOrSpread,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct AccessorPairs {
+  options: AccessorPairsOptions,
+}
+
+const CODE: &str = "accessor-pairs";
+const MISSING_GETTER_HINT: &str =
+  "Add a getter for the property, so that its value can be read back";
+const MISSING_SETTER_HINT: &str =
+  "Add a setter for the property, or remove the getter if it isn't needed";
+
+fn missing_getter_message(key: &str) -> String {
+  format!("Getter is not present for setter `{}`", key)
+}
+
+fn missing_setter_message(key: &str) -> String {
+  format!("Setter is not present for getter `{}`", key)
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AccessorPairsOptions {
+  /// Whether to report setters without a getter.
+  pub set_without_get: bool,
+  /// Whether to report getters without a setter.
+  pub get_without_set: bool,
+  /// Whether to check accessors of classes, besides those of object
+  /// literals.
+  pub enforce_for_class_members: bool,
+}
+
+impl Default for AccessorPairsOptions {
+  fn default() -> Self {
+    Self {
+      set_without_get: true,
+      get_without_set: false,
+      enforce_for_class_members: true,
+    }
+  }
+}
+
+impl AccessorPairs {
+  pub fn with_options(options: AccessorPairsOptions) -> Box<Self> {
+    Box::new(AccessorPairs { options })
+  }
+}
+
+impl LintRule for AccessorPairs {
+  fn new() -> Box<Self> {
+    Self::with_options(AccessorPairsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = AccessorPairsVisitor {
+      context,
+      options: &self.options,
+    };
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires setters to have a corresponding getter
+
+A property with a setter but no getter can be written but not read back, which
+is rarely intended. Accessors are paired by their key, so getters and setters
+with computed keys are only paired if the keys are literals. Static and
+instance accessors of classes are paired separately.
+
+With the `getWithoutSet` option, getters without a setter are reported as well,
+and `setWithoutGet` can be set to `false` to allow setters without a getter.
+Accessors of classes are checked unless `enforceForClassMembers` is `false`.
+
+### Invalid:
+```typescript
+const a = {
+  set foo(value) {
+    this._foo = value;
+  },
+};
+
+class B {
+  set bar(value) {
+    this._bar = value;
+  }
+}
+```
+
+### Valid:
+```typescript
+const a = {
+  get foo() {
+    return this._foo;
+  },
+  set foo(value) {
+    this._foo = value;
+  },
+};
+
+class B {
+  get bar() {
+    return this._bar;
+  }
+  set bar(value) {
+    this._bar = value;
+  }
+}
+```
+"#
+  }
+}
+
+/// Spans of the getters and setters with the same key.
+#[derive(Default)]
+struct AccessorGroup {
+  getters: Vec<Span>,
+  setters: Vec<Span>,
+}
+
+struct AccessorPairsVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o AccessorPairsOptions,
+}
+
+impl<'c, 'o> AccessorPairsVisitor<'c, 'o> {
+  /// Reports unpaired accessors of `accessors`, which are `(key, kind,
+  /// span)` tuples in source order.
+  fn check_accessors(&mut self, accessors: Vec<(String, MethodKind, Span)>) {
+    let mut keys = vec![];
+    let mut groups: HashMap<String, AccessorGroup> = HashMap::new();
+    for (key, kind, span) in accessors {
+      let group = groups.entry(key.clone()).or_insert_with(|| {
+        keys.push(key);
+        AccessorGroup::default()
+      });
+      match kind {
+        MethodKind::Getter => group.getters.push(span),
+        MethodKind::Setter => group.setters.push(span),
+        MethodKind::Method => {}
+      }
+    }
+
+    for key in keys {
+      let group = &groups[&key];
+      if self.options.set_without_get && group.getters.is_empty() {
+        for span in &group.setters {
+          self.context.add_diagnostic_with_hint(
+            *span,
+            CODE,
+            missing_getter_message(&key),
+            MISSING_GETTER_HINT,
+          );
+        }
+      }
+      if self.options.get_without_set && group.setters.is_empty() {
+        for span in &group.getters {
+          self.context.add_diagnostic_with_hint(
+            *span,
+            CODE,
+            missing_setter_message(&key),
+            MISSING_SETTER_HINT,
+          );
+        }
+      }
+    }
+  }
+}
+
+impl<'c, 'o> VisitAll for AccessorPairsVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_object_lit(&mut self, object_lit: &ObjectLit, _parent: &dyn Node) {
+    let accessors = object_lit
+      .props
+      .iter()
+      .filter_map(|prop| match prop {
+        PropOrSpread::Prop(prop) => match &**prop {
+          Prop::Getter(getter) => getter
+            .key
+            .string_repr()
+            .map(|key| (key, MethodKind::Getter, getter.span)),
+          Prop::Setter(setter) => setter
+            .key
+            .string_repr()
+            .map(|key| (key, MethodKind::Setter, setter.span)),
+          _ => None,
+        },
+        PropOrSpread::Spread(_) => None,
+      })
+      .collect();
+    self.check_accessors(accessors);
+  }
+
+  fn visit_class(&mut self, class: &Class, _parent: &dyn Node) {
+    if !self.options.enforce_for_class_members {
+      return;
+    }
+    let accessors = class.body.iter().filter_map(class_method_key).collect();
+    self.check_accessors(accessors);
+  }
+}
+
+/// Returns the key, kind and span of a method of a class, if its key is
+/// known statically. Keys of static and private methods are prefixed with
+/// `static ` and `#`, so that accessors are only paired with accessors of the
+/// same kind.
+pub(crate) fn class_method_key(
+  member: &ClassMember,
+) -> Option<(String, MethodKind, Span)> {
+  let (key, kind, span, is_static) = match member {
+    ClassMember::Method(method) => (
+      method.key.string_repr()?,
+      method.kind,
+      method.span,
+      method.is_static,
+    ),
+    ClassMember::PrivateMethod(method) => (
+      format!("#{}", method.key.string_repr()?),
+      method.kind,
+      method.span,
+      method.is_static,
+    ),
+    _ => return None,
+  };
+  let key = if is_static {
+    format!("static {}", key)
+  } else {
+    key
+  };
+  Some((key, kind, span))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::{
+    assert_lint_err_on_line_n_with_rule, assert_lint_ok_with_rule, lint,
+  };
+
+  #[test]
+  fn accessor_pairs_valid() {
+    assert_lint_ok! {
+      AccessorPairs,
+      "const a = { get foo() { return 1; }, set foo(v) {} };",
+      "const a = { set foo(v) {}, get foo() { return 1; } };",
+      "const a = { get foo() { return 1; } };",
+      "const a = { foo: 1, bar() {} };",
+      "const a = { get ['foo']() { return 1; }, set foo(v) {} };",
+      "const a = { get [1]() { return 1; }, set [1](v) {} };",
+      "const a = { get ['foo']() { return 1; }, set [`foo`](v) {} };",
+      "const a = { set [foo](v) {} };",
+      "class A { get foo() { return 1; } set foo(v) {} }",
+      "class A { static get foo() { return 1; } static set foo(v) {} }",
+      "class A { get #foo() { return 1; } set #foo(v) {} }",
+      "class A { get foo() { return 1; } }",
+      "const A = class { set foo(v) {} get foo() { return 1; } };",
+    };
+  }
+
+  #[test]
+  fn accessor_pairs_invalid() {
+    assert_lint_err! {
+      AccessorPairs,
+      "const a = { set foo(v) {} };": [
+        { col: 12, message: missing_getter_message("foo"), hint: MISSING_GETTER_HINT },
+      ],
+      "const a = { set foo(v) {}, get bar() { return 1; } };": [
+        { col: 12, message: missing_getter_message("foo"), hint: MISSING_GETTER_HINT },
+      ],
+      "const a = { set ['foo'](v) {}, set [`foo`](v) {} };": [
+        { col: 12, message: missing_getter_message("foo"), hint: MISSING_GETTER_HINT },
+        { col: 31, message: missing_getter_message("foo"), hint: MISSING_GETTER_HINT },
+      ],
+      "const a = { foo() {}, set foo(v) {} };": [
+        { col: 22, message: missing_getter_message("foo"), hint: MISSING_GETTER_HINT },
+      ],
+      "const a = { b: { set foo(v) {} } };": [
+        { col: 17, message: missing_getter_message("foo"), hint: MISSING_GETTER_HINT },
+      ],
+      "class A { set foo(v) {} }": [
+        { col: 10, message: missing_getter_message("foo"), hint: MISSING_GETTER_HINT },
+      ],
+      "class A { static get foo() { return 1; } set foo(v) {} }": [
+        { col: 41, message: missing_getter_message("foo"), hint: MISSING_GETTER_HINT },
+      ],
+      "class A { get foo() { return 1; } static set foo(v) {} }": [
+        { col: 34, message: missing_getter_message("static foo"), hint: MISSING_GETTER_HINT },
+      ],
+      "class A { set #foo(v) {} get foo() { return 1; } }": [
+        { col: 10, message: missing_getter_message("#foo"), hint: MISSING_GETTER_HINT },
+      ],
+      "const A = class { set foo(v) {} };": [
+        { col: 18, message: missing_getter_message("foo"), hint: MISSING_GETTER_HINT },
+      ],
+    };
+  }
+
+  #[test]
+  fn accessor_pairs_options() {
+    let rule = |set_without_get, get_without_set, enforce_for_class_members| {
+      AccessorPairs::with_options(AccessorPairsOptions {
+        set_without_get,
+        get_without_set,
+        enforce_for_class_members,
+      })
+    };
+
+    let src = r#"const a = { set foo(v) {}, get bar() { return 1; } };
+class A { set foo(v) {} get bar() { return 1; } }"#;
+    assert_lint_err_on_line_n_with_rule(
+      rule(true, true, true),
+      src,
+      vec![(1, 12), (1, 27), (2, 10), (2, 24)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(false, true, true),
+      src,
+      vec![(1, 27), (2, 24)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(true, false, false),
+      src,
+      vec![(1, 12)],
+    );
+    assert_lint_ok_with_rule(rule(false, false, true), src);
+
+    let diagnostics = lint(rule(false, true, true), "({ get foo() {} });");
+    assert_eq!(diagnostics[0].message, missing_setter_message("foo"));
+    assert_eq!(diagnostics[0].hint.as_deref(), Some(MISSING_SETTER_HINT));
+  }
+}
@@ -6,8 +6,9 @@ use std::collections::HashSet;
 use swc_common::Span;
 use swc_common::Spanned;
 use swc_ecmascript::ast::{
-  Class, ClassMember, ClassMethod, Decl, ExportDecl, Expr, FnDecl, Ident, Lit,
-  Module, ModuleDecl, ModuleItem, Program, Script, Stmt, Str, TsInterfaceBody,
+  Class, ClassMember, ClassMethod, Decl, DefaultDecl, ExportDecl,
+  ExportDefaultDecl, Expr, FnDecl, Ident, Lit, Module, ModuleDecl, ModuleItem,
+  Number, PrivateMethod, Program, Script, Stmt, Str, TsInterfaceBody,
   TsMethodSignature, TsModuleBlock, TsTypeElement, TsTypeLit,
 };
 use swc_ecmascript::visit::VisitAllWith;
@@ -15,6 +16,9 @@ use swc_ecmascript::visit::{Node, VisitAll};
 
 pub struct AdjacentOverloadSignatures;
 
+const CODE: &str = "adjacent-overload-signatures";
+const HINT: &str = "Make sure all overloaded signatures are grouped together";
+
 impl LintRule for AdjacentOverloadSignatures {
   fn new() -> Box<Self> {
     Box::new(AdjacentOverloadSignatures)
@@ -25,7 +29,11 @@ impl LintRule for AdjacentOverloadSignatures {
   }
 
   fn code(&self) -> &'static str {
-    "adjacent-overload-signatures"
+    CODE
+  }
+
+  fn is_typescript_only(&self) -> bool {
+    true
   }
 
   fn lint_program(&self, context: &mut Context, program: &Program) {
@@ -117,9 +125,9 @@ impl<'c> AdjacentOverloadSignaturesVisitor<'c> {
   fn add_diagnostic(&mut self, span: Span, fn_name: &str) {
     self.context.add_diagnostic_with_hint(
       span,
-      "adjacent-overload-signatures",
+      CODE,
       format!("All '{}' signatures should be adjacent", fn_name),
-      "Make sure all overloaded signatures are grouped together",
+      HINT,
     );
   }
 
@@ -165,6 +173,15 @@ impl ExtractMethod for ExportDecl {
   }
 }
 
+impl ExtractMethod for ExportDefaultDecl {
+  fn get_method(&self) -> Option<Method> {
+    match &self.decl {
+      DefaultDecl::Fn(_) => Some(Method::Method("default".to_string())),
+      _ => None,
+    }
+  }
+}
+
 impl ExtractMethod for Stmt {
   fn get_method(&self) -> Option<Method> {
     let method_name = match self {
@@ -181,6 +198,9 @@ impl ExtractMethod for ModuleItem {
       ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
         export_decl.get_method()
       }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(
+        export_default_decl,
+      )) => export_default_decl.get_method(),
       ModuleItem::Stmt(stmt) => stmt.get_method(),
       _ => None,
     }
@@ -199,6 +219,16 @@ impl ExtractMethod for ClassMember {
           Method::Method(k)
         }
       }),
+      ClassMember::PrivateMethod(PrivateMethod {
+        ref key, is_static, ..
+      }) => {
+        let name = format!("#{}", key.id.sym);
+        Some(if *is_static {
+          Method::Static(name)
+        } else {
+          Method::Method(name)
+        })
+      }
       ClassMember::Constructor(_) => {
         Some(Method::Method("constructor".to_string()))
       }
@@ -219,6 +249,9 @@ impl ExtractMethod for TsTypeElement {
         Expr::Lit(Lit::Str(Str { ref value, .. })) => {
           Some(Method::Method(value.to_string()))
         }
+        Expr::Lit(Lit::Num(Number { value, .. })) => {
+          Some(Method::Method(value.to_string()))
+        }
         _ => None,
       },
       TsTypeElement::TsCallSignatureDecl(_) => Some(Method::CallSignature),
@@ -1098,4 +1131,76 @@ type Foo = {
           ]
     };
   }
+
+  #[test]
+  fn adjacent_overload_signatures_default_and_private() {
+    assert_lint_ok! {
+      AdjacentOverloadSignatures,
+      r#"
+export default function foo(s: string): void;
+export default function foo(n: number): void;
+export default function foo(sn: string | number) {}
+export function bar() {}
+      "#,
+      r#"
+class Foo {
+  #foo(s: string): void;
+  #foo(n: number): void;
+  #foo(sn: string | number) {}
+  foo() {}
+}
+      "#,
+      r#"
+interface Foo {
+  1(s: string): void;
+  1(n: number): void;
+  2(): void;
+}
+      "#,
+    };
+
+    assert_lint_err! {
+      AdjacentOverloadSignatures,
+      r#"
+export default function (s: string): void;
+export function bar() {}
+export default function (sn: string | number) {}
+      "#: [
+        {
+          line: 4,
+          col: 15,
+          message: "All 'default' signatures should be adjacent",
+          hint: HINT,
+        }
+      ],
+      r#"
+class Foo {
+  #foo(s: string): void;
+  bar() {}
+  #foo(sn: string | number) {}
+}
+      "#: [
+        {
+          line: 5,
+          col: 2,
+          message: "All '#foo' signatures should be adjacent",
+          hint: HINT,
+        }
+      ],
+      r#"
+interface Foo {
+  1(s: string): void;
+  2(): void;
+  1(n: number): void;
+}
+      "#: [
+        {
+          line: 5,
+          col: 2,
+          message: "All '1' signatures should be adjacent",
+          hint: HINT,
+        }
+      ],
+    };
+  }
 }
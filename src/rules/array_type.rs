@@ -0,0 +1,444 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{
+  Program, TsArrayType, TsEntityName, TsType, TsTypeOperator, TsTypeOperatorOp,
+  TsTypeRef,
+};
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct ArrayType {
+  options: ArrayTypeOptions,
+}
+
+const CODE: &str = "array-type";
+
+/// Syntax to use for array types.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ArrayTypeStyle {
+  /// `T[]`
+  Array,
+  /// `Array<T>`
+  Generic,
+  /// `T[]` for simple element types, `Array<T>` for the others.
+  ArraySimple,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ArrayTypeOptions {
+  /// Syntax of mutable array types.
+  pub default: ArrayTypeStyle,
+  /// Syntax of readonly array types, which defaults to the `default` option.
+  pub readonly: Option<ArrayTypeStyle>,
+}
+
+impl Default for ArrayTypeOptions {
+  fn default() -> Self {
+    Self {
+      default: ArrayTypeStyle::Array,
+      readonly: None,
+    }
+  }
+}
+
+impl ArrayType {
+  pub fn with_options(options: ArrayTypeOptions) -> Box<Self> {
+    Box::new(ArrayType { options })
+  }
+}
+
+impl LintRule for ArrayType {
+  fn new() -> Box<Self> {
+    Self::with_options(ArrayTypeOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = ArrayTypeVisitor::new(context, &self.options);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces consistent syntax for array types
+
+Array types can be written as `T[]` or as `Array<T>`, and readonly array types
+as `readonly T[]` or as `ReadonlyArray<T>`. This rule enforces one of the
+styles, set with the `default` option for mutable arrays and with the
+`readonly` option for readonly arrays:
+
+- `"array"` (default): always use `T[]`
+- `"generic"`: always use `Array<T>`
+- `"array-simple"`: use `T[]` for simple element types, such as keywords and
+  type references without type arguments, and `Array<T>` for all others
+
+### Invalid:
+```typescript
+// with `default` set to "array"
+let a: Array<string>;
+let b: ReadonlyArray<number>;
+
+// with `default` set to "generic"
+let c: string[];
+
+// with `default` set to "array-simple"
+let d: Array<string>;
+let e: (string | number)[];
+```
+
+### Valid:
+```typescript
+// with `default` set to "array"
+let a: string[];
+let b: readonly number[];
+
+// with `default` set to "generic"
+let c: Array<string>;
+
+// with `default` set to "array-simple"
+let d: string[];
+let e: Array<string | number>;
+```
+"#
+  }
+}
+
+struct ArrayTypeVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o ArrayTypeOptions,
+}
+
+impl<'c, 'o> ArrayTypeVisitor<'c, 'o> {
+  fn new(context: &'c mut Context, options: &'o ArrayTypeOptions) -> Self {
+    Self { context, options }
+  }
+
+  fn style(&self, readonly: bool) -> ArrayTypeStyle {
+    if readonly {
+      self.options.readonly.unwrap_or(self.options.default)
+    } else {
+      self.options.default
+    }
+  }
+
+  fn snippet(&self, ts_type: &TsType) -> String {
+    self.context.snippet(ts_type.span()).unwrap_or_default()
+  }
+
+  /// Checks an array type written as `T[]` or `readonly T[]`.
+  fn check_array(
+    &mut self,
+    span: Span,
+    array_type: &TsArrayType,
+    readonly: bool,
+  ) {
+    let elem_type = &*array_type.elem_type;
+    let is_valid = match self.style(readonly) {
+      ArrayTypeStyle::Array => true,
+      ArrayTypeStyle::Generic => false,
+      ArrayTypeStyle::ArraySimple => is_simple_type(elem_type),
+    };
+    if is_valid {
+      return;
+    }
+
+    let (prefix, class_name) = if readonly {
+      ("readonly ", "ReadonlyArray")
+    } else {
+      ("", "Array")
+    };
+    let elem_type = match elem_type {
+      TsType::TsParenthesizedType(paren) => &*paren.type_ann,
+      _ => elem_type,
+    };
+    self.context.add_diagnostic_with_hint(
+      span,
+      CODE,
+      format!(
+        "Array type using `{}T[]` is forbidden{}. Use `{}<T>` instead.",
+        prefix,
+        self.simple_suffix(readonly, "non-simple"),
+        class_name
+      ),
+      format!(
+        "Change the type to `{}<{}>`",
+        class_name,
+        self.snippet(elem_type)
+      ),
+    );
+  }
+
+  /// Checks an array type written as `Array<T>` or `ReadonlyArray<T>`.
+  fn check_generic(&mut self, type_ref: &TsTypeRef, readonly: bool) {
+    let elem_type = match &type_ref.type_params {
+      Some(type_params) if type_params.params.len() == 1 => {
+        &*type_params.params[0]
+      }
+      _ => return,
+    };
+    let is_valid = match self.style(readonly) {
+      ArrayTypeStyle::Array => false,
+      ArrayTypeStyle::Generic => true,
+      ArrayTypeStyle::ArraySimple => !is_simple_type(elem_type),
+    };
+    if is_valid {
+      return;
+    }
+
+    let (prefix, class_name) = if readonly {
+      ("readonly ", "ReadonlyArray")
+    } else {
+      ("", "Array")
+    };
+    let elem_snippet = self.snippet(elem_type);
+    let elem_snippet = if needs_parens(elem_type) {
+      format!("({})", elem_snippet)
+    } else {
+      elem_snippet
+    };
+    self.context.add_diagnostic_with_hint(
+      type_ref.span,
+      CODE,
+      format!(
+        "Array type using `{}<T>` is forbidden{}. Use `{}T[]` instead.",
+        class_name,
+        self.simple_suffix(readonly, "simple"),
+        prefix
+      ),
+      format!("Change the type to `{}{}[]`", prefix, elem_snippet),
+    );
+  }
+
+  fn simple_suffix(&self, readonly: bool, kind: &str) -> String {
+    if self.style(readonly) == ArrayTypeStyle::ArraySimple {
+      format!(" for {} types", kind)
+    } else {
+      String::new()
+    }
+  }
+}
+
+/// Checks if the type is simple enough to be written as the element type of
+/// `T[]` in the `array-simple` style.
+fn is_simple_type(ts_type: &TsType) -> bool {
+  match ts_type {
+    TsType::TsKeywordType(_) | TsType::TsThisType(_) => true,
+    TsType::TsArrayType(array_type) => is_simple_type(&array_type.elem_type),
+    TsType::TsTypeRef(type_ref) => match &type_ref.type_params {
+      None => true,
+      Some(type_params) => {
+        is_array_name(&type_ref.type_name) == Some(false)
+          && type_params.params.len() == 1
+          && is_simple_type(&type_params.params[0])
+      }
+    },
+    _ => false,
+  }
+}
+
+/// Returns `Some(readonly)` if the name refers to `Array` or `ReadonlyArray`.
+fn is_array_name(type_name: &TsEntityName) -> Option<bool> {
+  match type_name {
+    TsEntityName::Ident(ident) => match &*ident.sym {
+      "Array" => Some(false),
+      "ReadonlyArray" => Some(true),
+      _ => None,
+    },
+    TsEntityName::TsQualifiedName(_) => None,
+  }
+}
+
+/// Checks if the type has to be wrapped in parentheses to be the element type
+/// of `T[]`.
+fn needs_parens(ts_type: &TsType) -> bool {
+  matches!(
+    ts_type,
+    TsType::TsUnionOrIntersectionType(_)
+      | TsType::TsFnOrConstructorType(_)
+      | TsType::TsConditionalType(_)
+      | TsType::TsTypeOperator(_)
+      | TsType::TsInferType(_)
+  )
+}
+
+impl<'c, 'o> Visit for ArrayTypeVisitor<'c, 'o> {
+  fn visit_ts_array_type(
+    &mut self,
+    array_type: &TsArrayType,
+    _parent: &dyn Node,
+  ) {
+    self.check_array(array_type.span, array_type, false);
+    array_type.visit_children_with(self);
+  }
+
+  fn visit_ts_type_operator(
+    &mut self,
+    type_operator: &TsTypeOperator,
+    _parent: &dyn Node,
+  ) {
+    if type_operator.op == TsTypeOperatorOp::ReadOnly {
+      if let TsType::TsArrayType(array_type) = &*type_operator.type_ann {
+        self.check_array(type_operator.span, array_type, true);
+        array_type.visit_children_with(self);
+        return;
+      }
+    }
+    type_operator.visit_children_with(self);
+  }
+
+  fn visit_ts_type_ref(&mut self, type_ref: &TsTypeRef, _parent: &dyn Node) {
+    if let Some(readonly) = is_array_name(&type_ref.type_name) {
+      self.check_generic(type_ref, readonly);
+    }
+    type_ref.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  fn rule(
+    default: ArrayTypeStyle,
+    readonly: Option<ArrayTypeStyle>,
+  ) -> Box<ArrayType> {
+    ArrayType::with_options(ArrayTypeOptions { default, readonly })
+  }
+
+  #[test]
+  fn array_type_array_valid() {
+    assert_lint_ok! {
+      ArrayType,
+      "let a: string[] = [];",
+      "let a: readonly string[] = [];",
+      "let a: (string | number)[] = [];",
+      "let a: Array = [];",
+      "let a: Foo<string> = [];",
+      "let a: foo.Array<string> = [];",
+      "let a: Array<string, number> = [];",
+    };
+  }
+
+  #[test]
+  fn array_type_array_invalid() {
+    assert_lint_err! {
+      ArrayType,
+      "let a: Array<string> = [];": [
+        {
+          col: 7,
+          message: "Array type using `Array<T>` is forbidden. Use `T[]` instead.",
+          hint: "Change the type to `string[]`",
+        }
+      ],
+      "let a: ReadonlyArray<string> = [];": [
+        {
+          col: 7,
+          message: "Array type using `ReadonlyArray<T>` is forbidden. Use `readonly T[]` instead.",
+          hint: "Change the type to `readonly string[]`",
+        }
+      ],
+      "let a: Array<string | number> = [];": [
+        {
+          col: 7,
+          message: "Array type using `Array<T>` is forbidden. Use `T[]` instead.",
+          hint: "Change the type to `(string | number)[]`",
+        }
+      ],
+      "function foo(a: Array<() => void>) {}": [
+        {
+          col: 16,
+          message: "Array type using `Array<T>` is forbidden. Use `T[]` instead.",
+          hint: "Change the type to `(() => void)[]`",
+        }
+      ],
+    };
+
+    assert_lint_err_n::<ArrayType>(
+      "let a: Array<Array<string>> = [];",
+      vec![7, 13],
+    );
+  }
+
+  #[test]
+  fn array_type_generic() {
+    let generic = || rule(ArrayTypeStyle::Generic, None);
+    assert_lint_ok_with_rule(generic(), "let a: Array<string> = [];");
+    assert_lint_ok_with_rule(generic(), "let a: ReadonlyArray<string> = [];");
+    assert_lint_err_on_line_n_with_rule(
+      generic(),
+      "let a: string[] = [];\nlet b: readonly string[] = [];\nlet c: (string | number)[] = [];",
+      vec![(1, 7), (2, 7), (3, 7)],
+    );
+
+    let diagnostics = lint(generic(), "let a: (string | number)[] = [];");
+    assert_eq!(
+      diagnostics[0].message,
+      "Array type using `T[]` is forbidden. Use `Array<T>` instead."
+    );
+    assert_eq!(
+      diagnostics[0].hint.as_deref(),
+      Some("Change the type to `Array<string | number>`")
+    );
+    let diagnostics = lint(generic(), "let a: readonly Foo[] = [];");
+    assert_eq!(
+      diagnostics[0].hint.as_deref(),
+      Some("Change the type to `ReadonlyArray<Foo>`")
+    );
+  }
+
+  #[test]
+  fn array_type_array_simple() {
+    let simple = || rule(ArrayTypeStyle::ArraySimple, None);
+    assert_lint_ok_with_rule(
+      simple(),
+      "let a: string[] = [];\nlet b: Foo[] = [];\nlet c: this[] = [];\nlet d: string[][] = [];",
+    );
+    assert_lint_ok_with_rule(
+      simple(),
+      "let a: Array<string | number> = [];\nlet b: Array<Foo<string>> = [];\nlet c: ReadonlyArray<{ a: string }> = [];",
+    );
+    assert_lint_err_on_line_n_with_rule(
+      simple(),
+      "let a: Array<string> = [];\nlet b: (string | number)[] = [];\nlet c: readonly Foo<string>[] = [];\nlet d: ReadonlyArray<Foo> = [];",
+      vec![(1, 7), (2, 7), (3, 7), (4, 7)],
+    );
+
+    let diagnostics = lint(simple(), "let a: Array<string> = [];");
+    assert_eq!(
+      diagnostics[0].message,
+      "Array type using `Array<T>` is forbidden for simple types. Use `T[]` instead."
+    );
+    let diagnostics = lint(simple(), "let a: (string | number)[] = [];");
+    assert_eq!(
+      diagnostics[0].message,
+      "Array type using `T[]` is forbidden for non-simple types. Use `Array<T>` instead."
+    );
+  }
+
+  #[test]
+  fn array_type_readonly() {
+    let mixed = || rule(ArrayTypeStyle::Array, Some(ArrayTypeStyle::Generic));
+    assert_lint_ok_with_rule(
+      mixed(),
+      "let a: string[] = [];\nlet b: ReadonlyArray<string> = [];",
+    );
+    assert_lint_err_on_line_n_with_rule(
+      mixed(),
+      "let a: Array<string> = [];\nlet b: readonly string[] = [];",
+      vec![(1, 7), (2, 7)],
+    );
+  }
+}
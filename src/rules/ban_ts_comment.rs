@@ -4,6 +4,8 @@ use super::LintRule;
 
 use once_cell::sync::Lazy;
 use regex::Regex;
+use serde::Deserialize;
+use std::convert::TryFrom;
 use swc_common::comments::Comment;
 use swc_common::comments::CommentKind;
 use swc_common::Span;
@@ -18,22 +20,148 @@ use swc_common::Span;
 /// - ts-expect-error: allowed with comment
 /// - ts-ignore: allowed with comment
 /// - ts-nocheck: allowed with comment
-pub struct BanTsComment;
+pub struct BanTsComment {
+  options: BanTsCommentOptions,
+}
+
+const CODE: &str = "ban-ts-comment";
+const DESCRIPTION_MESSAGE: &str =
+  "ts directives are not allowed without comment";
+const DESCRIPTION_HINT: &str =
+  "Add an in-line comment explaining the reason for using this directive";
+const BANNED_HINT: &str = "Remove the directive";
+
+/// Whether a directive is allowed, configured as `true` (banned), `false`
+/// (allowed) or `"allow-with-description"`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "DirectiveConfigValue")]
+pub enum DirectiveConfig {
+  Allow,
+  Ban,
+  AllowWithDescription,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DirectiveConfigValue {
+  Bool(bool),
+  Str(String),
+}
+
+impl TryFrom<DirectiveConfigValue> for DirectiveConfig {
+  type Error = String;
+
+  fn try_from(value: DirectiveConfigValue) -> Result<Self, Self::Error> {
+    match value {
+      DirectiveConfigValue::Bool(true) => Ok(DirectiveConfig::Ban),
+      DirectiveConfigValue::Bool(false) => Ok(DirectiveConfig::Allow),
+      DirectiveConfigValue::Str(s) if s == "allow-with-description" => {
+        Ok(DirectiveConfig::AllowWithDescription)
+      }
+      DirectiveConfigValue::Str(s) => Err(format!(
+        "expected `true`, `false` or \"allow-with-description\", found \"{}\"",
+        s
+      )),
+    }
+  }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BanTsCommentOptions {
+  /// Configuration of `@ts-expect-error` directives.
+  #[serde(rename = "ts-expect-error")]
+  pub ts_expect_error: DirectiveConfig,
+  /// Configuration of `@ts-ignore` directives.
+  #[serde(rename = "ts-ignore")]
+  pub ts_ignore: DirectiveConfig,
+  /// Configuration of `@ts-nocheck` directives.
+  #[serde(rename = "ts-nocheck")]
+  pub ts_nocheck: DirectiveConfig,
+  /// Configuration of `@ts-check` directives.
+  #[serde(rename = "ts-check")]
+  pub ts_check: DirectiveConfig,
+  /// Minimum length of the description of directives configured as
+  /// `"allow-with-description"`.
+  pub minimum_description_length: usize,
+}
+
+impl Default for BanTsCommentOptions {
+  fn default() -> Self {
+    Self {
+      ts_expect_error: DirectiveConfig::AllowWithDescription,
+      ts_ignore: DirectiveConfig::AllowWithDescription,
+      ts_nocheck: DirectiveConfig::AllowWithDescription,
+      ts_check: DirectiveConfig::Allow,
+      minimum_description_length: 3,
+    }
+  }
+}
 
 impl BanTsComment {
-  fn report(&self, context: &mut Context, span: Span) {
-    context.add_diagnostic_with_hint(
-      span,
-      "ban-ts-comment",
-      "ts directives are not allowed without comment",
-      "Add an in-line comment explaining the reason for using this directive",
-    );
+  pub fn with_options(options: BanTsCommentOptions) -> Box<Self> {
+    Box::new(BanTsComment { options })
+  }
+
+  fn directive_config(&self, directive: &str) -> DirectiveConfig {
+    match directive {
+      "expect-error" => self.options.ts_expect_error,
+      "ignore" => self.options.ts_ignore,
+      "nocheck" => self.options.ts_nocheck,
+      "check" => self.options.ts_check,
+      _ => unreachable!(),
+    }
+  }
+
+  /// Returns the message and hint to report the comment with, if it is a
+  /// directive which isn't allowed.
+  fn check_comment(&self, comment: &Comment) -> Option<(String, &'static str)> {
+    if comment.kind != CommentKind::Line {
+      return None;
+    }
+
+    static BTC_REGEX: Lazy<Regex> = Lazy::new(|| {
+      Regex::new(r#"^/*\s*@ts-(expect-error|ignore|nocheck|check)\b(.*)$"#)
+        .unwrap()
+    });
+
+    let captures = BTC_REGEX.captures(&comment.text)?;
+    let directive = captures.get(1).unwrap().as_str();
+    match self.directive_config(directive) {
+      DirectiveConfig::Allow => None,
+      DirectiveConfig::Ban => Some((
+        format!("`@ts-{}` directives are not allowed", directive),
+        BANNED_HINT,
+      )),
+      DirectiveConfig::AllowWithDescription => {
+        let description = captures
+          .get(2)
+          .unwrap()
+          .as_str()
+          .trim_start_matches(|c: char| c == ':' || c.is_whitespace())
+          .trim_end();
+        let min_length = self.options.minimum_description_length;
+        if description.is_empty() {
+          Some((DESCRIPTION_MESSAGE.to_string(), DESCRIPTION_HINT))
+        } else if description.chars().count() < min_length {
+          Some((
+            format!(
+              "The description of ts directives must be at least {} characters long",
+              min_length
+            ),
+            DESCRIPTION_HINT,
+          ))
+        } else {
+          None
+        }
+      }
+    }
   }
 }
 
 impl LintRule for BanTsComment {
   fn new() -> Box<Self> {
-    Box::new(BanTsComment)
+    Self::with_options(BanTsCommentOptions::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -41,7 +169,7 @@ impl LintRule for BanTsComment {
   }
 
   fn code(&self) -> &'static str {
-    "ban-ts-comment"
+    CODE
   }
 
   fn lint_program(
@@ -49,27 +177,21 @@ impl LintRule for BanTsComment {
     context: &mut Context,
     _program: &swc_ecmascript::ast::Program,
   ) {
-    let mut violated_comment_spans = Vec::new();
+    let mut violations: Vec<(Span, String, &'static str)> = Vec::new();
 
-    violated_comment_spans.extend(
-      context.leading_comments.values().flatten().filter_map(|c| {
-        if check_comment(c) {
-          Some(c.span)
-        } else {
-          None
-        }
-      }),
-    );
-    violated_comment_spans.extend(
-      context
-        .trailing_comments
-        .values()
-        .flatten()
-        .filter_map(|c| if check_comment(c) { Some(c.span) } else { None }),
-    );
+    for comment in context
+      .leading_comments
+      .values()
+      .chain(context.trailing_comments.values())
+      .flatten()
+    {
+      if let Some((message, hint)) = self.check_comment(comment) {
+        violations.push((comment.span, message, hint));
+      }
+    }
 
-    for span in violated_comment_spans {
-      self.report(context, span);
+    for (span, message, hint) in violations {
+      context.add_diagnostic_with_hint(span, CODE, message, hint);
     }
   }
 
@@ -78,6 +200,12 @@ impl LintRule for BanTsComment {
 
 Typescript directives reduce the effectiveness of the compiler, something which should only be done in exceptional circumstances.  The reason why should be documented in a comment alongside the directive.
 
+Each of the `ts-expect-error`, `ts-ignore`, `ts-nocheck` and `ts-check` options
+can be set to `true` to ban the directive, `false` to allow it, or
+`"allow-with-description"` to allow it only with a description of at least
+`minimumDescriptionLength` characters (`3` by default). By default
+`@ts-check` is allowed and the other directives require a description.
+
 ### Invalid:
 ```typescript
 // @ts-expect-error
@@ -109,22 +237,10 @@ let a: number = "I am a string";
   }
 }
 
-/// Returns `true` if the comment should be reported.
-fn check_comment(comment: &Comment) -> bool {
-  if comment.kind != CommentKind::Line {
-    return false;
-  }
-
-  static BTC_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r#"^/*\s*@ts-(expect-error|ignore|nocheck)$"#).unwrap()
-  });
-
-  BTC_REGEX.is_match(&comment.text)
-}
-
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::test_util::*;
 
   #[test]
   fn ban_ts_comment_valid() {
@@ -200,4 +316,84 @@ console.log('hello');
           ]
     };
   }
+
+  #[test]
+  fn ban_ts_comment_description() {
+    assert_lint_ok! {
+      BanTsComment,
+      r#"// @ts-ignore: foo"#,
+      r#"// @ts-ignore foo"#,
+      r#"/// @ts-expect-error because of reasons"#,
+      r#"// @ts-check"#,
+      r#"// @ts-checked"#,
+    };
+
+    assert_lint_err! {
+      BanTsComment,
+      r#"// @ts-ignore:"#: [
+        { col: 0, message: DESCRIPTION_MESSAGE, hint: DESCRIPTION_HINT }
+      ],
+      r#"// @ts-ignore: ab"#: [
+        {
+          col: 0,
+          message: "The description of ts directives must be at least 3 characters long",
+          hint: DESCRIPTION_HINT,
+        }
+      ],
+      r#"let a = 1; // @ts-nocheck  "#: [
+        { col: 11, message: DESCRIPTION_MESSAGE, hint: DESCRIPTION_HINT }
+      ],
+    };
+
+    let rule = BanTsComment::with_options(BanTsCommentOptions {
+      minimum_description_length: 10,
+      ..Default::default()
+    });
+    assert_lint_err_on_line_n_with_rule(
+      rule,
+      "// @ts-expect-error: too short\n// @ts-ignore: long enough, really",
+      vec![(1, 0)],
+    );
+  }
+
+  #[test]
+  fn ban_ts_comment_options() {
+    let options: BanTsCommentOptions = serde_json::from_str(
+      r#"{
+        "ts-expect-error": "allow-with-description",
+        "ts-ignore": true,
+        "ts-nocheck": false,
+        "ts-check": true,
+        "minimumDescriptionLength": 5
+      }"#,
+    )
+    .unwrap();
+    assert_eq!(
+      options.ts_expect_error,
+      DirectiveConfig::AllowWithDescription
+    );
+    assert_eq!(options.ts_ignore, DirectiveConfig::Ban);
+    assert_eq!(options.ts_nocheck, DirectiveConfig::Allow);
+    assert_eq!(options.ts_check, DirectiveConfig::Ban);
+    assert_eq!(options.minimum_description_length, 5);
+    assert!(serde_json::from_str::<BanTsCommentOptions>(
+      r#"{ "ts-ignore": "sometimes" }"#
+    )
+    .is_err());
+
+    let rule = || BanTsComment::with_options(options.clone());
+    assert_lint_ok_with_rule(rule(), "// @ts-nocheck");
+    assert_lint_ok_with_rule(rule(), "// @ts-expect-error: a reason");
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "// @ts-ignore: with a description",
+      vec![(1, 0)],
+    );
+    assert_lint_err_on_line_n_with_rule(rule(), "// @ts-check", vec![(1, 0)]);
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "// @ts-expect-error: abc",
+      vec![(1, 0)],
+    );
+  }
 }
@@ -1,8 +1,9 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
-use once_cell::sync::Lazy;
+use serde::Deserialize;
 use std::collections::HashMap;
+use swc_common::Span;
 use swc_ecmascript::ast::{
   TsEntityName, TsKeywordType, TsKeywordTypeKind, TsTypeLit,
   TsTypeParamInstantiation, TsTypeRef,
@@ -10,11 +11,98 @@ use swc_ecmascript::ast::{
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
 
-pub struct BanTypes;
+pub struct BanTypes {
+  /// Banned types by name, with the hint to report them with.
+  types: HashMap<String, String>,
+}
+
+const CODE: &str = "ban-types";
+const OBJECT_HINT: &str = "if you want a type meaning `any object` use `Record<string, unknown>` instead,
+or if you want a type meaning `any value`, you probably want `unknown` instead.";
+
+const DEFAULT_BANNED_TYPES: &[(&str, &str)] = &[
+  ("String", "Use `string` instead"),
+  ("Boolean", "Use `boolean` instead"),
+  ("Number", "Use `number` instead"),
+  ("Symbol", "Use `symbol` instead"),
+  ("Function", "Define the function shape Explicitly."),
+  ("Object", OBJECT_HINT),
+  ("object", "Use `Record<string, unknown>` instead"),
+  ("{}", OBJECT_HINT),
+];
+
+/// How a type is banned, configured as `true` or `false`, a message, or an
+/// object with a `message` and a `fixWith` replacement.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum BannedTypeConfig {
+  Enabled(bool),
+  Message(String),
+  Detailed {
+    message: Option<String>,
+    #[serde(rename = "fixWith")]
+    fix_with: Option<String>,
+  },
+}
+
+impl BannedTypeConfig {
+  /// Returns the hint to report the type with, or `None` if it isn't banned.
+  fn hint(&self) -> Option<String> {
+    match self {
+      BannedTypeConfig::Enabled(false) => None,
+      BannedTypeConfig::Enabled(true) => Some("Avoid using this type".into()),
+      BannedTypeConfig::Message(message) => Some(message.clone()),
+      BannedTypeConfig::Detailed { message, fix_with } => {
+        Some(match (message, fix_with) {
+          (Some(message), _) => message.clone(),
+          (None, Some(fix_with)) => format!("Use `{}` instead", fix_with),
+          (None, None) => "Avoid using this type".into(),
+        })
+      }
+    }
+  }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BanTypesOptions {
+  /// Types to ban, by name. A `null` or `false` value allows a type which is
+  /// banned by default.
+  pub types: HashMap<String, Option<BannedTypeConfig>>,
+  /// Whether the configured types are added to the types banned by default.
+  pub extend_defaults: bool,
+}
+
+impl Default for BanTypesOptions {
+  fn default() -> Self {
+    Self {
+      types: HashMap::new(),
+      extend_defaults: true,
+    }
+  }
+}
+
+impl BanTypes {
+  pub fn with_options(options: BanTypesOptions) -> Box<Self> {
+    let mut types = HashMap::new();
+    if options.extend_defaults {
+      for (name, hint) in DEFAULT_BANNED_TYPES {
+        types.insert(name.to_string(), hint.to_string());
+      }
+    }
+    for (name, config) in options.types {
+      match config.as_ref().and_then(BannedTypeConfig::hint) {
+        Some(hint) => types.insert(name, hint),
+        None => types.remove(&name),
+      };
+    }
+    Box::new(BanTypes { types })
+  }
+}
 
 impl LintRule for BanTypes {
   fn new() -> Box<Self> {
-    Box::new(BanTypes)
+    Self::with_options(BanTypesOptions::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -22,7 +110,11 @@ impl LintRule for BanTypes {
   }
 
   fn code(&self) -> &'static str {
-    "ban-types"
+    CODE
+  }
+
+  fn is_typescript_only(&self) -> bool {
+    true
   }
 
   fn lint_program(
@@ -30,7 +122,7 @@ impl LintRule for BanTypes {
     context: &mut Context,
     program: &swc_ecmascript::ast::Program,
   ) {
-    let mut visitor = BanTypesVisitor::new(context);
+    let mut visitor = BanTypesVisitor::new(context, &self.types);
     visitor.visit_program(program, program);
   }
 
@@ -51,6 +143,12 @@ type safety with the function.
 Finally, `Object` means "any non-nullish value" rather than "any object type".
 `Record<string, unknown>` is a good choice for a meaning of "any object type".
 
+The banned types can be configured with the `types` option, which maps type
+names (including `"{}"` and qualified names like `"foo.Bar"`) to `true`, a
+message, or an object with a `message` and a `fixWith` replacement. A `null`
+or `false` value allows a type which is banned by default. With
+`extendDefaults` set to `false`, only the configured types are banned.
+
 ### Invalid:
 ```typescript
 let a: Boolean;
@@ -76,45 +174,40 @@ let f: Record<string, unknown>;
   }
 }
 
-struct BanTypesVisitor<'c> {
+struct BanTypesVisitor<'c, 't> {
   context: &'c mut Context,
+  types: &'t HashMap<String, String>,
 }
 
-impl<'c> BanTypesVisitor<'c> {
-  fn new(context: &'c mut Context) -> Self {
-    Self { context }
+impl<'c, 't> BanTypesVisitor<'c, 't> {
+  fn new(context: &'c mut Context, types: &'t HashMap<String, String>) -> Self {
+    Self { context, types }
   }
-}
 
-static BAN_TYPES_MESSAGE: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(
-  || {
-    let mut map = HashMap::new();
-    map.insert("String", "Use `string` instead");
-    map.insert("Boolean", "Use `boolean` instead");
-    map.insert("Number", "Use `number` instead");
-    map.insert("Symbol", "Use `symbol` instead");
-    map.insert("Function", "Define the function shape Explicitly.");
-    map.insert("Object",
-  "if you want a type meaning `any object` use `Record<string, unknown>` instead,
-or if you want a type meaning `any value`, you probably want `unknown` instead.");
-    map.insert("object", "Use `Record<string, unknown>` instead");
-    map
-  },
-);
+  fn check(&mut self, span: Span, name: &str) {
+    if let Some(hint) = self.types.get(name) {
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        format!("`{}` is not allowed as a type", name),
+        hint,
+      );
+    }
+  }
+}
 
-fn get_message(ident: impl AsRef<str>) -> Option<&'static str> {
-  BAN_TYPES_MESSAGE.get(ident.as_ref()).copied()
+fn entity_name(entity: &TsEntityName) -> String {
+  match entity {
+    TsEntityName::Ident(ident) => ident.sym.to_string(),
+    TsEntityName::TsQualifiedName(qualified) => {
+      format!("{}.{}", entity_name(&qualified.left), qualified.right.sym)
+    }
+  }
 }
 
-impl<'c> Visit for BanTypesVisitor<'c> {
+impl<'c, 't> Visit for BanTypesVisitor<'c, 't> {
   fn visit_ts_type_ref(&mut self, ts_type_ref: &TsTypeRef, _parent: &dyn Node) {
-    if let TsEntityName::Ident(ident) = &ts_type_ref.type_name {
-      if let Some(message) = get_message(&ident.sym) {
-        self
-          .context
-          .add_diagnostic(ts_type_ref.span, "ban-types", message);
-      }
-    }
+    self.check(ts_type_ref.span, &entity_name(&ts_type_ref.type_name));
     if let Some(type_param) = &ts_type_ref.type_params {
       self.visit_ts_type_param_instantiation(type_param, ts_type_ref);
     }
@@ -127,11 +220,7 @@ impl<'c> Visit for BanTypesVisitor<'c> {
       }
       return;
     }
-    self.context.add_diagnostic(
-      ts_type_lit.span,
-      "ban-types",
-      get_message("Object").unwrap(), // `BAN_TYPES_MESSAGE` absolutely has `Object` key
-    );
+    self.check(ts_type_lit.span, "{}");
   }
 
   fn visit_ts_keyword_type(
@@ -140,11 +229,7 @@ impl<'c> Visit for BanTypesVisitor<'c> {
     _parent: &dyn Node,
   ) {
     if TsKeywordTypeKind::TsObjectKeyword == ts_keyword_type.kind {
-      self.context.add_diagnostic(
-        ts_keyword_type.span,
-        "ban-types",
-        get_message("object").unwrap(), // `BAN_TYPES_MESSAGE` absolutely has `object` key
-      );
+      self.check(ts_keyword_type.span, "object");
     }
   }
 
@@ -162,6 +247,7 @@ impl<'c> Visit for BanTypesVisitor<'c> {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::test_util::*;
 
   #[test]
   fn ban_types_valid() {
@@ -178,8 +264,16 @@ mod tests {
 
   #[test]
   fn ban_types_invalid() {
-    fn message(ty: &str) -> &str {
-      get_message(ty).unwrap()
+    fn message(ty: &str) -> String {
+      format!("`{}` is not allowed as a type", ty)
+    }
+
+    fn hint(ty: &str) -> &str {
+      DEFAULT_BANNED_TYPES
+        .iter()
+        .find(|(name, _)| *name == ty)
+        .unwrap()
+        .1
     }
 
     assert_lint_err! {
@@ -188,110 +282,129 @@ mod tests {
         {
           col: 7,
           message: message("String"),
+          hint: hint("String"),
         }
       ],
       "let a: Object;": [
         {
           col: 7,
           message: message("Object"),
+          hint: hint("Object"),
         }
       ],
       "let a: Number;": [
         {
           col: 7,
           message: message("Number"),
+          hint: hint("Number"),
         }
       ],
       "let a: Function;": [
         {
           col: 7,
           message: message("Function"),
+          hint: hint("Function"),
         }
       ],
       "let a: object;": [
         {
           col: 7,
           message: message("object"),
+          hint: hint("object"),
         }
       ],
       "let a: {};": [
         {
           col: 7,
-          message: message("Object"),
+          message: message("{}"),
+          hint: hint("{}"),
         }
       ],
       "let a: { b: String };": [
         {
           col: 12,
           message: message("String"),
+          hint: hint("String"),
         }
       ],
       "let a: { b: Number };": [
         {
           col: 12,
           message: message("Number"),
+          hint: hint("Number"),
         }
       ],
       "let a: { b: object, c: Object };": [
         {
           col: 12,
           message: message("object"),
+          hint: hint("object"),
         },
         {
           col: 23,
           message: message("Object"),
+          hint: hint("Object"),
         }
       ],
       "let a: { b: { c : Function } };": [
         {
           col: 18,
           message: message("Function"),
+          hint: hint("Function"),
         }
       ],
       "let a: Array<String>": [
         {
           col: 13,
           message: message("String"),
+          hint: hint("String"),
         }
       ],
       "let a: Number<Function>": [
         {
           col: 7,
           message: message("Number"),
+          hint: hint("Number"),
         },
         {
           col: 14,
           message: message("Function"),
+          hint: hint("Function"),
         }
       ],
       "function foo(a: String) {}": [
         {
           col: 16,
           message: message("String"),
+          hint: hint("String"),
         }
       ],
       "function foo(): Number {}": [
         {
           col: 16,
           message: message("Number"),
+          hint: hint("Number"),
         }
       ],
       "let a: () => Number;": [
         {
           col: 13,
           message: message("Number"),
+          hint: hint("Number"),
         }
       ],
       "'a' as String;": [
         {
           col: 7,
           message: message("String"),
+          hint: hint("String"),
         }
       ],
       "1 as Number;": [
         {
           col: 5,
           message: message("Number"),
+          hint: hint("Number"),
         }
       ],
       "
@@ -306,43 +419,116 @@ class Foo<F = String> extends Bar<String> implements Baz<Object> {
           line: 2,
           col: 14,
           message: message("String"),
+          hint: hint("String"),
         },
         {
           line: 2,
           col: 34,
           message: message("String"),
+          hint: hint("String"),
         },
         {
           line: 2,
           col: 57,
           message: message("Object"),
+          hint: hint("Object"),
         },
         {
           line: 3,
           col: 19,
           message: message("String"),
+          hint: hint("String"),
         },
         {
           line: 3,
           col: 28,
           message: message("Object"),
+          hint: hint("Object"),
         },
         {
           line: 5,
           col: 16,
           message: message("String"),
+          hint: hint("String"),
         },
         {
           line: 6,
           col: 15,
           message: message("String"),
+          hint: hint("String"),
         },
         {
           line: 6,
           col: 29,
           message: message("String"),
+          hint: hint("String"),
         }
       ]
     };
   }
+
+  #[test]
+  fn ban_types_hint() {
+    assert_lint_err! {
+      BanTypes,
+      "let a: Object;": [
+        {
+          col: 7,
+          message: "`Object` is not allowed as a type",
+          hint: OBJECT_HINT,
+        }
+      ],
+      "let a: {};": [
+        {
+          col: 7,
+          message: "`{}` is not allowed as a type",
+          hint: OBJECT_HINT,
+        }
+      ],
+      "let a: Symbol;": [
+        {
+          col: 7,
+          message: "`Symbol` is not allowed as a type",
+          hint: "Use `symbol` instead",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn ban_types_options() {
+    let options: BanTypesOptions = serde_json::from_str(
+      r#"{
+        "types": {
+          "Object": "Use `Foo` instead of `Object`",
+          "{}": null,
+          "String": false,
+          "foo.Bar": { "fixWith": "Baz" },
+          "Qux": true
+        }
+      }"#,
+    )
+    .unwrap();
+    let rule = || BanTypes::with_options(options.clone());
+
+    assert_lint_ok_with_rule(rule(), "let a: {}; let b: String; let c: Bar;");
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "let a: Object;\nlet b: foo.Bar;\nlet c: Qux;\nlet d: Number;",
+      vec![(1, 7), (2, 7), (3, 7), (4, 7)],
+    );
+
+    let diagnostics = lint(rule(), "let a: Object; let b: foo.Bar;");
+    assert_eq!(
+      diagnostics[0].hint.as_deref(),
+      Some("Use `Foo` instead of `Object`")
+    );
+    assert_eq!(diagnostics[1].hint.as_deref(), Some("Use `Baz` instead"));
+
+    let only_configured = BanTypes::with_options(BanTypesOptions {
+      types: options.types.clone(),
+      extend_defaults: false,
+    });
+    assert_lint_ok_with_rule(only_configured, "let a: Number; let b: object;");
+  }
 }
@@ -0,0 +1,271 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use std::collections::HashMap;
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{
+  BlockStmt, Expr, ForInStmt, ForOfStmt, ForStmt, Ident, MemberExpr,
+  ObjectPatProp, Pat, Program, Prop, SwitchStmt, VarDecl, VarDeclKind,
+};
+use swc_ecmascript::utils::find_ids;
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::utils::Id;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct BlockScopedVar;
+
+const CODE: &str = "block-scoped-var";
+const HINT: &str =
+  "Declare the variable with `let` or `const` in the block where it is used";
+
+impl LintRule for BlockScopedVar {
+  fn new() -> Box<Self> {
+    Box::new(BlockScopedVar)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut collector = VarBlocksCollector::default();
+    program.visit_with(program, &mut collector);
+
+    let mut visitor = BlockScopedVarVisitor::new(context, collector.blocks);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows using `var` bindings outside of the block they are declared in
+
+Variables declared with `var` are hoisted to the enclosing function, so they
+are accessible outside of the block containing their declaration. Relying on
+this is confusing for readers used to block scoping, and is often a bug.
+
+### Invalid:
+```typescript
+function foo(a) {
+  if (a) {
+    var b = 1;
+  }
+  return b;
+}
+
+for (var i = 0; i < 10; i++) {}
+console.log(i);
+```
+
+### Valid:
+```typescript
+function foo(a) {
+  let b;
+  if (a) {
+    b = 1;
+  }
+  return b;
+}
+
+for (let i = 0; i < 10; i++) {
+  console.log(i);
+}
+```
+"#
+  }
+}
+
+/// Collects the spans of the blocks containing each `var` declaration.
+#[derive(Default)]
+struct VarBlocksCollector {
+  blocks: HashMap<Id, Vec<Span>>,
+  stack: Vec<Span>,
+}
+
+impl VarBlocksCollector {
+  fn with_block<N>(&mut self, span: Span, node: &N)
+  where
+    N: VisitWith<Self>,
+  {
+    self.stack.push(span);
+    node.visit_children_with(self);
+    self.stack.pop();
+  }
+}
+
+impl Visit for VarBlocksCollector {
+  noop_visit_type!();
+
+  fn visit_program(&mut self, program: &Program, _parent: &dyn Node) {
+    self.with_block(program.span(), program);
+  }
+
+  fn visit_block_stmt(&mut self, block_stmt: &BlockStmt, _parent: &dyn Node) {
+    self.with_block(block_stmt.span, block_stmt);
+  }
+
+  fn visit_switch_stmt(
+    &mut self,
+    switch_stmt: &SwitchStmt,
+    _parent: &dyn Node,
+  ) {
+    self.with_block(switch_stmt.span, switch_stmt);
+  }
+
+  // A `var` in the head of a `for` loop belongs to the loop.
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _parent: &dyn Node) {
+    self.with_block(for_stmt.span, for_stmt);
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, _parent: &dyn Node) {
+    self.with_block(for_in_stmt.span, for_in_stmt);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _parent: &dyn Node) {
+    self.with_block(for_of_stmt.span, for_of_stmt);
+  }
+
+  fn visit_var_decl(&mut self, var_decl: &VarDecl, _parent: &dyn Node) {
+    if var_decl.kind == VarDeclKind::Var {
+      let block = *self.stack.last().unwrap();
+      for decl in &var_decl.decls {
+        let ids: Vec<Id> = find_ids(&decl.name);
+        for id in ids {
+          self.blocks.entry(id).or_default().push(block);
+        }
+      }
+    }
+    var_decl.visit_children_with(self);
+  }
+}
+
+struct BlockScopedVarVisitor<'c> {
+  context: &'c mut Context,
+  blocks: HashMap<Id, Vec<Span>>,
+}
+
+impl<'c> BlockScopedVarVisitor<'c> {
+  fn new(context: &'c mut Context, blocks: HashMap<Id, Vec<Span>>) -> Self {
+    Self { context, blocks }
+  }
+
+  fn check_reference(&mut self, ident: &Ident) {
+    let blocks = match self.blocks.get(&ident.to_id()) {
+      Some(blocks) => blocks,
+      None => return,
+    };
+
+    if !blocks.iter().any(|block| block.contains(ident.span)) {
+      self.context.add_diagnostic_with_hint(
+        ident.span,
+        CODE,
+        format!(
+          "'{}' is used outside of the block it is declared in",
+          ident.sym
+        ),
+        HINT,
+      );
+    }
+  }
+}
+
+impl<'c> Visit for BlockScopedVarVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_expr(&mut self, expr: &Expr, _parent: &dyn Node) {
+    if let Expr::Ident(ident) = expr {
+      self.check_reference(ident);
+    }
+    expr.visit_children_with(self);
+  }
+
+  fn visit_pat(&mut self, pat: &Pat, _parent: &dyn Node) {
+    if let Pat::Ident(ident) = pat {
+      self.check_reference(ident);
+    }
+    pat.visit_children_with(self);
+  }
+
+  fn visit_object_pat_prop(
+    &mut self,
+    object_pat_prop: &ObjectPatProp,
+    _parent: &dyn Node,
+  ) {
+    if let ObjectPatProp::Assign(assign) = object_pat_prop {
+      self.check_reference(&assign.key);
+    }
+    object_pat_prop.visit_children_with(self);
+  }
+
+  fn visit_prop(&mut self, prop: &Prop, _parent: &dyn Node) {
+    if let Prop::Shorthand(ident) = prop {
+      self.check_reference(ident);
+    }
+    prop.visit_children_with(self);
+  }
+
+  fn visit_member_expr(
+    &mut self,
+    member_expr: &MemberExpr,
+    _parent: &dyn Node,
+  ) {
+    member_expr.obj.visit_with(member_expr, self);
+    if member_expr.computed {
+      member_expr.prop.visit_with(member_expr, self);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn block_scoped_var_valid() {
+    assert_lint_ok! {
+      BlockScopedVar,
+      "var a = 1; a = 2; function f() { return a; }",
+      "function f() { var a = 1; return a; }",
+      "if (x) { var a = 1; a++; foo(a); }",
+      "for (var i = 0; i < 10; i++) { foo(i); }",
+      "for (var k in obj) { foo(k); }",
+      "for (var v of xs) { foo(v); }",
+      "if (x) { var a = 1; } else { let a = 2; foo(a); }",
+      "if (x) { var a = 1; } if (y) { var a = 2; foo(a); }",
+      "if (x) { var a = 1; function f() { return a; } }",
+      "switch (x) { case 0: var a = 1; break; case 1: foo(a); }",
+      "if (x) { var a = 1; } foo.a;",
+      "if (x) { var a = 1; } foo({ a: 1 });",
+      "let a; if (x) { a = 1; } foo(a);",
+    };
+  }
+
+  #[test]
+  fn block_scoped_var_invalid() {
+    assert_lint_err! {
+      BlockScopedVar,
+      "if (x) { var a = 1; } foo(a);": [
+        { col: 26, message: "'a' is used outside of the block it is declared in", hint: HINT }
+      ],
+      "function f(x) { if (x) { var a = 1; } return a; }": [
+        { col: 45, message: "'a' is used outside of the block it is declared in", hint: HINT }
+      ],
+      "for (var i = 0; i < 10; i++) {} foo(i);": [
+        { col: 36, message: "'i' is used outside of the block it is declared in", hint: HINT }
+      ],
+      "a = 0; if (x) { var a = 1; }": [
+        { col: 0, message: "'a' is used outside of the block it is declared in", hint: HINT }
+      ],
+      "if (x) { var a = 1; } foo({ a });": [
+        { col: 28, message: "'a' is used outside of the block it is declared in", hint: HINT }
+      ],
+      "try { var a = 1; } catch (e) { a = 2; }": [
+        { col: 31, message: "'a' is used outside of the block it is declared in", hint: HINT }
+      ],
+      "if (x) { var { a } = obj; } [a] = [1];": [
+        { col: 29, message: "'a' is used outside of the block it is declared in", hint: HINT }
+      ],
+    };
+  }
+}
@@ -0,0 +1,334 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  ArrowExpr, BinExpr, BinaryOp, CatchClause, CondExpr, Constructor,
+  DoWhileStmt, ForInStmt, ForOfStmt, ForStmt, Function, IfStmt, OptChainExpr,
+  Program, SwitchCase, WhileStmt,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct Complexity {
+  options: ComplexityOptions,
+}
+
+const CODE: &str = "complexity";
+const HINT: &str =
+  "Split the function into smaller functions, or simplify its conditions";
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ComplexityOptions {
+  /// Maximum cyclomatic complexity of a function.
+  pub max: usize,
+}
+
+impl Default for ComplexityOptions {
+  fn default() -> Self {
+    Self { max: 20 }
+  }
+}
+
+impl Complexity {
+  pub fn with_options(options: ComplexityOptions) -> Box<Self> {
+    Box::new(Complexity { options })
+  }
+}
+
+impl LintRule for Complexity {
+  fn new() -> Box<Self> {
+    Self::with_options(ComplexityOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = ComplexityVisitor::new(context, self.options.max);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces a maximum cyclomatic complexity of functions
+
+Cyclomatic complexity is the number of independent paths through a function.
+Every function starts with a complexity of `1`, and each `if`, conditional
+expression, non-default `case`, loop, `catch` clause, `&&`, `||`, `??` and
+optional chain adds one. Nested functions are measured separately.
+
+Functions with a complexity higher than the `max` option, which defaults to
+`20`, are reported.
+
+### Invalid:
+```typescript
+// with `max` set to 2
+function foo(a, b) {
+  if (a) {
+    return 1;
+  }
+  return b ?? 2;
+}
+```
+
+### Valid:
+```typescript
+// with `max` set to 2
+function foo(a) {
+  if (a) {
+    return 1;
+  }
+  return 2;
+}
+```
+"#
+  }
+}
+
+struct ComplexityVisitor<'c> {
+  context: &'c mut Context,
+  max: usize,
+  /// Complexity of the enclosing functions, innermost last.
+  complexities: Vec<usize>,
+}
+
+impl<'c> ComplexityVisitor<'c> {
+  fn new(context: &'c mut Context, max: usize) -> Self {
+    Self {
+      context,
+      max,
+      complexities: vec![],
+    }
+  }
+
+  fn with_function<F>(&mut self, span: Span, op: F)
+  where
+    F: FnOnce(&mut Self),
+  {
+    self.complexities.push(1);
+    op(self);
+    let complexity = self.complexities.pop().unwrap();
+
+    if complexity > self.max {
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        format!(
+          "Function has a complexity of {}. Maximum allowed is {}.",
+          complexity, self.max
+        ),
+        HINT,
+      );
+    }
+  }
+
+  fn increase(&mut self) {
+    if let Some(complexity) = self.complexities.last_mut() {
+      *complexity += 1;
+    }
+  }
+}
+
+impl<'c> Visit for ComplexityVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
+    self.with_function(function.span, |v| function.visit_children_with(v));
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    self.with_function(arrow_expr.span, |v| arrow_expr.visit_children_with(v));
+  }
+
+  fn visit_constructor(
+    &mut self,
+    constructor: &Constructor,
+    _parent: &dyn Node,
+  ) {
+    self
+      .with_function(constructor.span, |v| constructor.visit_children_with(v));
+  }
+
+  fn visit_if_stmt(&mut self, if_stmt: &IfStmt, _parent: &dyn Node) {
+    self.increase();
+    if_stmt.visit_children_with(self);
+  }
+
+  fn visit_cond_expr(&mut self, cond_expr: &CondExpr, _parent: &dyn Node) {
+    self.increase();
+    cond_expr.visit_children_with(self);
+  }
+
+  fn visit_switch_case(
+    &mut self,
+    switch_case: &SwitchCase,
+    _parent: &dyn Node,
+  ) {
+    if switch_case.test.is_some() {
+      self.increase();
+    }
+    switch_case.visit_children_with(self);
+  }
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+    if matches!(
+      bin_expr.op,
+      BinaryOp::LogicalAnd | BinaryOp::LogicalOr | BinaryOp::NullishCoalescing
+    ) {
+      self.increase();
+    }
+    bin_expr.visit_children_with(self);
+  }
+
+  fn visit_opt_chain_expr(
+    &mut self,
+    opt_chain_expr: &OptChainExpr,
+    _parent: &dyn Node,
+  ) {
+    self.increase();
+    opt_chain_expr.visit_children_with(self);
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _parent: &dyn Node) {
+    self.increase();
+    for_stmt.visit_children_with(self);
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, _parent: &dyn Node) {
+    self.increase();
+    for_in_stmt.visit_children_with(self);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _parent: &dyn Node) {
+    self.increase();
+    for_of_stmt.visit_children_with(self);
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _parent: &dyn Node) {
+    self.increase();
+    while_stmt.visit_children_with(self);
+  }
+
+  fn visit_do_while_stmt(
+    &mut self,
+    do_while_stmt: &DoWhileStmt,
+    _parent: &dyn Node,
+  ) {
+    self.increase();
+    do_while_stmt.visit_children_with(self);
+  }
+
+  fn visit_catch_clause(
+    &mut self,
+    catch_clause: &CatchClause,
+    _parent: &dyn Node,
+  ) {
+    self.increase();
+    catch_clause.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  fn rule(max: usize) -> Box<Complexity> {
+    Complexity::with_options(ComplexityOptions { max })
+  }
+
+  #[test]
+  fn complexity_valid() {
+    assert_lint_ok! {
+      Complexity,
+      "function foo() {}",
+      "function foo(a) { if (a) {} else if (b) {} else {} }",
+      "if (a) {} if (b) {} while (c) {} for (;;) {}",
+    };
+
+    // right at the threshold
+    assert_lint_ok_with_rule(rule(1), "function foo() { bar(); }");
+    assert_lint_ok_with_rule(rule(2), "function foo(a) { if (a) {} }");
+    assert_lint_ok_with_rule(rule(2), "const foo = (a) => a ? 1 : 2;");
+    assert_lint_ok_with_rule(
+      rule(3),
+      "function foo(a) { switch (a) { case 1: break; case 2: break; default: break; } }",
+    );
+    assert_lint_ok_with_rule(
+      rule(2),
+      "function foo(a) { if (a) {} const bar = () => { if (b) {} }; }",
+    );
+    assert_lint_ok_with_rule(
+      rule(2),
+      "class A { constructor() { if (a) {} } foo() { for (x of y) {} } }",
+    );
+  }
+
+  #[test]
+  fn complexity_invalid() {
+    assert_lint_err_on_line_n_with_rule(
+      rule(1),
+      "function foo(a) { if (a) {} }",
+      vec![(1, 0)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(2),
+      "function foo(a) { if (a) {} else if (b) {} }",
+      vec![(1, 0)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(2),
+      "const foo = (a, b) => a && b || c;",
+      vec![(1, 12)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(2),
+      "const foo = function(a) { return a ?? b?.c; };",
+      vec![(1, 12)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(3),
+      "function foo(a) { switch (a) { case 1: break; case 2: break; case 3: break; } }",
+      vec![(1, 0)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(4),
+      "function foo() { for (;;) {} for (a in b) {} for (a of b) {} while (a) {} }",
+      vec![(1, 0)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(2),
+      "function foo() { do {} while (a); try {} catch {} }",
+      vec![(1, 0)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(1),
+      "class A {\n  constructor() { a?.b; }\n}",
+      vec![(2, 2)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(2),
+      "function foo() {\n  if (a) {}\n  function bar() { if (a) {} if (b) {} }\n}",
+      vec![(3, 2)],
+    );
+  }
+
+  #[test]
+  fn complexity_message() {
+    assert_lint_err! {
+      Complexity,
+      "function foo() { if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} }": [
+        {
+          col: 0,
+          message: "Function has a complexity of 21. Maximum allowed is 20.",
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,239 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_ecmascript::ast::{
+  Program, TsInterfaceDecl, TsModuleDecl, TsType, TsTypeAliasDecl,
+};
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct ConsistentTypeDefinitions {
+  options: ConsistentTypeDefinitionsOptions,
+}
+
+const CODE: &str = "consistent-type-definitions";
+const INTERFACE_MESSAGE: &str = "Use an `interface` instead of a `type`";
+const INTERFACE_HINT: &str =
+  "Convert the type alias to an interface declaration";
+const TYPE_MESSAGE: &str = "Use a `type` instead of an `interface`";
+const TYPE_HINT: &str =
+  "Convert the interface to a type alias of an object type";
+
+/// The preferred way to define object types.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TypeDefinitionStyle {
+  Interface,
+  Type,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ConsistentTypeDefinitionsOptions {
+  /// Whether object types are defined with `interface` or `type`.
+  pub style: TypeDefinitionStyle,
+}
+
+impl Default for ConsistentTypeDefinitionsOptions {
+  fn default() -> Self {
+    Self {
+      style: TypeDefinitionStyle::Interface,
+    }
+  }
+}
+
+impl ConsistentTypeDefinitions {
+  pub fn with_options(options: ConsistentTypeDefinitionsOptions) -> Box<Self> {
+    Box::new(ConsistentTypeDefinitions { options })
+  }
+}
+
+impl LintRule for ConsistentTypeDefinitions {
+  fn new() -> Box<Self> {
+    Self::with_options(ConsistentTypeDefinitionsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor =
+      ConsistentTypeDefinitionsVisitor::new(context, self.options.style);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces defining object types consistently with `interface` or `type`
+
+Object types can be defined both with an interface and with a type alias of an
+object type literal. Using one of the two consistently makes the code base
+easier to read.
+
+The preferred form is set with the `style` option, which is either
+`"interface"` (default) or `"type"`. Interfaces declared in `declare global`
+blocks are not reported, since they rely on declaration merging.
+
+### Invalid:
+```typescript
+// with `style` set to "interface"
+type Foo = { a: string };
+
+// with `style` set to "type"
+interface Bar {
+  b: number;
+}
+```
+
+### Valid:
+```typescript
+// with `style` set to "interface"
+interface Foo {
+  a: string;
+}
+type Bar = string | number;
+
+// with `style` set to "type"
+type Baz = { c: boolean };
+```
+"#
+  }
+}
+
+struct ConsistentTypeDefinitionsVisitor<'c> {
+  context: &'c mut Context,
+  style: TypeDefinitionStyle,
+  in_global: bool,
+}
+
+impl<'c> ConsistentTypeDefinitionsVisitor<'c> {
+  fn new(context: &'c mut Context, style: TypeDefinitionStyle) -> Self {
+    Self {
+      context,
+      style,
+      in_global: false,
+    }
+  }
+}
+
+fn is_type_lit(ts_type: &TsType) -> bool {
+  match ts_type {
+    TsType::TsTypeLit(_) => true,
+    TsType::TsParenthesizedType(paren) => is_type_lit(&paren.type_ann),
+    _ => false,
+  }
+}
+
+impl<'c> Visit for ConsistentTypeDefinitionsVisitor<'c> {
+  fn visit_ts_type_alias_decl(
+    &mut self,
+    type_alias: &TsTypeAliasDecl,
+    _parent: &dyn Node,
+  ) {
+    if self.style == TypeDefinitionStyle::Interface
+      && is_type_lit(&type_alias.type_ann)
+    {
+      self.context.add_diagnostic_with_hint(
+        type_alias.id.span,
+        CODE,
+        INTERFACE_MESSAGE,
+        INTERFACE_HINT,
+      );
+    }
+    type_alias.visit_children_with(self);
+  }
+
+  fn visit_ts_interface_decl(
+    &mut self,
+    interface_decl: &TsInterfaceDecl,
+    _parent: &dyn Node,
+  ) {
+    if self.style == TypeDefinitionStyle::Type && !self.in_global {
+      self.context.add_diagnostic_with_hint(
+        interface_decl.id.span,
+        CODE,
+        TYPE_MESSAGE,
+        TYPE_HINT,
+      );
+    }
+    interface_decl.visit_children_with(self);
+  }
+
+  fn visit_ts_module_decl(
+    &mut self,
+    module_decl: &TsModuleDecl,
+    _parent: &dyn Node,
+  ) {
+    let in_global = self.in_global;
+    self.in_global |= module_decl.global;
+    module_decl.visit_children_with(self);
+    self.in_global = in_global;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  fn type_style() -> Box<ConsistentTypeDefinitions> {
+    ConsistentTypeDefinitions::with_options(ConsistentTypeDefinitionsOptions {
+      style: TypeDefinitionStyle::Type,
+    })
+  }
+
+  #[test]
+  fn consistent_type_definitions_valid() {
+    assert_lint_ok! {
+      ConsistentTypeDefinitions,
+      "interface Foo { a: string }",
+      "interface Foo extends Bar { a: string }",
+      "type Foo = string;",
+      "type Foo = string | { a: string };",
+      "type Foo = { a: string } & Bar;",
+      "type Foo = Record<string, number>;",
+      "type Foo<T> = T extends string ? { a: T } : never;",
+    };
+
+    assert_lint_ok_with_rule(type_style(), "type Foo = { a: string };");
+    assert_lint_ok_with_rule(type_style(), "type Foo = string;");
+    assert_lint_ok_with_rule(
+      type_style(),
+      "declare global { interface Window { foo: string } }",
+    );
+  }
+
+  #[test]
+  fn consistent_type_definitions_invalid() {
+    assert_lint_err! {
+      ConsistentTypeDefinitions,
+      "type Foo = { a: string };": [
+        { col: 5, message: INTERFACE_MESSAGE, hint: INTERFACE_HINT }
+      ],
+      "type Foo = ({ a: string });": [
+        { col: 5, message: INTERFACE_MESSAGE, hint: INTERFACE_HINT }
+      ],
+      "export type Foo<T> = { a: T };": [
+        { col: 12, message: INTERFACE_MESSAGE, hint: INTERFACE_HINT }
+      ],
+      "namespace A { type Foo = {}; }": [
+        { col: 19, message: INTERFACE_MESSAGE, hint: INTERFACE_HINT }
+      ],
+      "function foo() { type Bar = { a: string }; }": [
+        { col: 22, message: INTERFACE_MESSAGE, hint: INTERFACE_HINT }
+      ],
+    };
+
+    assert_lint_err_on_line_n_with_rule(
+      type_style(),
+      "interface Foo { a: string }\nexport interface Bar extends Foo {}\nnamespace A { interface Baz {} }",
+      vec![(1, 10), (2, 17), (3, 24)],
+    );
+  }
+}
@@ -0,0 +1,350 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use swc_ecmascript::ast::{
+  Ident, ImportDecl, ImportSpecifier, ModuleDecl, ModuleItem, Program,
+  TsExprWithTypeArgs, TsType,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::utils::Id;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct ConsistentTypeImports {
+  options: ConsistentTypeImportsOptions,
+}
+
+const CODE: &str = "consistent-type-imports";
+const AVOID_IMPORT_TYPE_MESSAGE: &str =
+  "Use an `import` instead of an `import type`";
+const AVOID_IMPORT_TYPE_HINT: &str =
+  "Remove the `type` keyword from the import";
+
+/// Which kind of imports is expected for bindings only used as types.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TypeImportsPreference {
+  TypeImports,
+  NoTypeImports,
+}
+
+/// How type-only members of an import should be marked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TypeImportsFixStyle {
+  /// `import type { Foo } from "./foo.ts";`
+  SeparateTypeImports,
+  /// `import { type Foo } from "./foo.ts";`
+  InlineTypeImports,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ConsistentTypeImportsOptions {
+  /// Whether type-only imports are required or disallowed.
+  pub prefer: TypeImportsPreference,
+  /// How type-only members of an import should be marked.
+  pub fix_style: TypeImportsFixStyle,
+}
+
+impl Default for ConsistentTypeImportsOptions {
+  fn default() -> Self {
+    Self {
+      prefer: TypeImportsPreference::TypeImports,
+      fix_style: TypeImportsFixStyle::SeparateTypeImports,
+    }
+  }
+}
+
+impl ConsistentTypeImports {
+  pub fn with_options(options: ConsistentTypeImportsOptions) -> Box<Self> {
+    Box::new(ConsistentTypeImports { options })
+  }
+}
+
+impl LintRule for ConsistentTypeImports {
+  fn new() -> Box<Self> {
+    Self::with_options(ConsistentTypeImportsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let module = match program {
+      Program::Module(module) => module,
+      Program::Script(_) => return,
+    };
+    let imports: Vec<&ImportDecl> = module
+      .body
+      .iter()
+      .filter_map(|item| match item {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) => {
+          Some(import_decl)
+        }
+        _ => None,
+      })
+      .collect();
+
+    if self.options.prefer == TypeImportsPreference::NoTypeImports {
+      for import_decl in imports.iter().filter(|decl| decl.type_only) {
+        context.add_diagnostic_with_hint(
+          import_decl.span,
+          CODE,
+          AVOID_IMPORT_TYPE_MESSAGE,
+          AVOID_IMPORT_TYPE_HINT,
+        );
+      }
+      return;
+    }
+
+    let mut collector = ReferencesCollector {
+      imported: imports
+        .iter()
+        .filter(|decl| !decl.type_only)
+        .flat_map(|decl| decl.specifiers.iter())
+        .map(|specifier| local(specifier).to_id())
+        .collect(),
+      in_type: false,
+      used_as_value: HashMap::new(),
+    };
+    program.visit_with(program, &mut collector);
+
+    for import_decl in imports.iter().filter(|decl| !decl.type_only) {
+      let is_type_only = |specifier: &&ImportSpecifier| {
+        collector.used_as_value.get(&local(specifier).to_id()) == Some(&false)
+      };
+      let type_only: Vec<&ImportSpecifier> =
+        import_decl.specifiers.iter().filter(is_type_only).collect();
+      let all_type_only = type_only.len() == import_decl.specifiers.len();
+
+      for specifier in type_only {
+        let local = local(specifier);
+        let hint = if all_type_only {
+          "Change the declaration to an `import type`".to_string()
+        } else {
+          match self.options.fix_style {
+            TypeImportsFixStyle::SeparateTypeImports => format!(
+              "Move '{}' to a separate `import type` declaration",
+              local.sym
+            ),
+            TypeImportsFixStyle::InlineTypeImports => format!(
+              "Mark '{}' as a type, e.g. `import {{ type {} }}`",
+              local.sym, local.sym
+            ),
+          }
+        };
+        context.add_diagnostic_with_hint(
+          local.span,
+          CODE,
+          format!("Import '{}' is only used as a type", local.sym),
+          hint,
+        );
+      }
+    }
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires `import type` for imports which are only used as types
+
+Imports which are only used in type positions are removed when the code is
+compiled to JavaScript. Marking them with `import type` makes this explicit,
+avoids side effects of the imported module being accidentally dropped or kept,
+and is required with the `isolatedModules` compiler option.
+
+With the `prefer` option set to `"no-type-imports"`, `import type` is reported
+instead. The `fixStyle` option (`"separate-type-imports"` or
+`"inline-type-imports"`) controls how a declaration mixing values and types is
+expected to be split.
+
+### Invalid:
+```typescript
+import { Foo } from "./foo.ts";
+let foo: Foo;
+
+import { Bar, bar } from "./bar.ts";
+const a: Bar = bar();
+```
+
+### Valid:
+```typescript
+import type { Foo } from "./foo.ts";
+let foo: Foo;
+
+import type { Bar } from "./bar.ts";
+import { bar } from "./bar.ts";
+const a: Bar = bar();
+```
+"#
+  }
+}
+
+fn local(specifier: &ImportSpecifier) -> &Ident {
+  match specifier {
+    ImportSpecifier::Named(named) => &named.local,
+    ImportSpecifier::Default(default) => &default.local,
+    ImportSpecifier::Namespace(namespace) => &namespace.local,
+  }
+}
+
+/// Collects the references to imported bindings, split by whether they are in
+/// a type position.
+struct ReferencesCollector {
+  imported: HashSet<Id>,
+  in_type: bool,
+  /// Referenced imports, mapped to whether any of the references is a value.
+  used_as_value: HashMap<Id, bool>,
+}
+
+impl ReferencesCollector {
+  fn with_type<F>(&mut self, op: F)
+  where
+    F: FnOnce(&mut Self),
+  {
+    let in_type = std::mem::replace(&mut self.in_type, true);
+    op(self);
+    self.in_type = in_type;
+  }
+}
+
+impl Visit for ReferencesCollector {
+  fn visit_import_decl(
+    &mut self,
+    _import_decl: &ImportDecl,
+    _parent: &dyn Node,
+  ) {
+  }
+
+  fn visit_ts_type(&mut self, ts_type: &TsType, _parent: &dyn Node) {
+    self.with_type(|v| ts_type.visit_children_with(v));
+  }
+
+  fn visit_ts_expr_with_type_args(
+    &mut self,
+    ts_expr_with_type_args: &TsExprWithTypeArgs,
+    _parent: &dyn Node,
+  ) {
+    self.with_type(|v| ts_expr_with_type_args.visit_children_with(v));
+  }
+
+  fn visit_ident(&mut self, ident: &Ident, _parent: &dyn Node) {
+    let id = ident.to_id();
+    if self.imported.contains(&id) {
+      let used_as_value = self.used_as_value.entry(id).or_default();
+      *used_as_value |= !self.in_type;
+    }
+    // visits the type annotation of bindings
+    ident.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn consistent_type_imports_valid() {
+    assert_lint_ok! {
+      ConsistentTypeImports,
+      r#"import type { Foo } from "./foo.ts"; let foo: Foo;"#,
+      r#"import { foo } from "./foo.ts"; foo();"#,
+      r#"import { Foo } from "./foo.ts"; const foo: Foo = new Foo();"#,
+      r#"import { Foo } from "./foo.ts"; class Bar extends Foo {}"#,
+      r#"import Foo from "./foo.ts"; export default Foo;"#,
+      r#"import { Foo } from "./foo.ts"; export { Foo };"#,
+      r#"import { unused } from "./foo.ts";"#,
+      r#"import "./foo.ts";"#,
+      r#"import * as foo from "./foo.ts"; foo.bar();"#,
+      r#"import { Foo } from "./foo.ts"; function f() { const Foo = 1; return Foo; } const a: Foo = Foo;"#,
+      r#"import { foo } from "./foo.ts"; const a = { foo };"#,
+    };
+  }
+
+  #[test]
+  fn consistent_type_imports_invalid() {
+    assert_lint_err! {
+      ConsistentTypeImports,
+      r#"import { Foo } from "./foo.ts"; let foo: Foo;"#: [
+        {
+          col: 9,
+          message: "Import 'Foo' is only used as a type",
+          hint: "Change the declaration to an `import type`",
+        }
+      ],
+      r#"import Foo from "./foo.ts"; function f(a: Foo): Foo[] { return [a]; }"#: [
+        {
+          col: 7,
+          message: "Import 'Foo' is only used as a type",
+          hint: "Change the declaration to an `import type`",
+        }
+      ],
+      r#"import * as foo from "./foo.ts"; type A = foo.Bar;"#: [
+        {
+          col: 12,
+          message: "Import 'foo' is only used as a type",
+          hint: "Change the declaration to an `import type`",
+        }
+      ],
+      r#"import { Foo } from "./foo.ts"; class A implements Foo {} interface B extends Foo {}"#: [
+        {
+          col: 9,
+          message: "Import 'Foo' is only used as a type",
+          hint: "Change the declaration to an `import type`",
+        }
+      ],
+      r#"import { foo } from "./foo.ts"; type A = typeof foo;"#: [
+        {
+          col: 9,
+          message: "Import 'foo' is only used as a type",
+          hint: "Change the declaration to an `import type`",
+        }
+      ],
+      r#"import { Bar, bar } from "./bar.ts"; const a: Bar = bar();"#: [
+        {
+          col: 9,
+          message: "Import 'Bar' is only used as a type",
+          hint: "Move 'Bar' to a separate `import type` declaration",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn consistent_type_imports_options() {
+    let inline =
+      ConsistentTypeImports::with_options(ConsistentTypeImportsOptions {
+        fix_style: TypeImportsFixStyle::InlineTypeImports,
+        ..Default::default()
+      });
+    assert_lint_err_on_line_n_with_rule(
+      inline,
+      "import { Bar, bar } from \"./bar.ts\";\nconst a: Bar = bar();",
+      vec![(1, 9)],
+    );
+
+    let no_type_imports = || {
+      ConsistentTypeImports::with_options(ConsistentTypeImportsOptions {
+        prefer: TypeImportsPreference::NoTypeImports,
+        ..Default::default()
+      })
+    };
+    assert_lint_ok_with_rule(
+      no_type_imports(),
+      r#"import { Foo } from "./foo.ts"; let foo: Foo;"#,
+    );
+    assert_lint_err_on_line_n_with_rule(
+      no_type_imports(),
+      "import type { Foo } from \"./foo.ts\";\nlet foo: Foo;",
+      vec![(1, 0)],
+    );
+  }
+}
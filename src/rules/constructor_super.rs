@@ -1,17 +1,20 @@
 use super::Context;
 use super::LintRule;
+use swc_atoms::JsWord;
 use swc_common::Span;
 use swc_ecmascript::ast::{
-  Class, ClassMember, Constructor, Expr, ExprOrSuper, ReturnStmt, Stmt,
+  ArrowExpr, BinExpr, BinaryOp, BreakStmt, CallExpr, Class, ClassMember,
+  CondExpr, Constructor, ContinueStmt, DoWhileStmt, Expr, ExprOrSuper,
+  ForInStmt, ForOfStmt, ForStmt, Function, IfStmt, LabeledStmt, ReturnStmt,
+  Stmt, SwitchStmt, ThrowStmt, TryStmt, WhileStmt,
 };
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
 
 pub struct ConstructorSuper;
 
-// This rule currently differs from the ESlint implementation
-// as there is currently no way of handling code paths in dlint
 impl LintRule for ConstructorSuper {
   fn new() -> Box<Self> {
     Box::new(ConstructorSuper)
@@ -38,8 +41,8 @@ impl LintRule for ConstructorSuper {
     r#"Verifies the correct usage of constructors and calls to `super()`.
 
 Defined constructors of derived classes (e.g. `class A extends B`) must always call
-`super()`.  Classes which extend non-constructors (e.g. `class A extends null`) must
-not have a constructor.
+`super()` exactly once on every code path.  Classes which extend non-constructors
+(e.g. `class A extends null`) must not have a constructor.
 
 ### Invalid:
 ```typescript
@@ -50,7 +53,14 @@ class Z {
 
 class B extends Z {
   constructor() {} // missing super() call
-} 
+}
+class F extends Z {
+  constructor() {
+    if (a) {
+      super(); // missing super() call when `a` is falsy
+    }
+  }
+}
 class C {
   constructor() {
     super();  // Syntax error
@@ -76,6 +86,15 @@ class C extends A {
   }
 }
 class D extends null {}
+class E extends A {
+  constructor() {
+    if (a) {
+      super(1);
+    } else {
+      super(2);
+    }
+  }
+}
 ```
 "#
   }
@@ -84,6 +103,7 @@ class D extends null {}
 enum DiagnosticKind {
   TooManySuper,
   NoSuper,
+  NoSuperInSomePaths,
   UnnecessaryConstructor,
   UnnecessarySuper,
 }
@@ -98,6 +118,7 @@ impl DiagnosticKind {
     match *self {
       DiagnosticKind::TooManySuper => "Constructors of derived classes must call super() only once",
       DiagnosticKind::NoSuper => "Constructors of derived classes must call super()",
+      DiagnosticKind::NoSuperInSomePaths => "Constructors of derived classes must call super() in all code paths",
       DiagnosticKind::UnnecessaryConstructor => "Classes which inherit from a non constructor must not define a constructor",
       DiagnosticKind::UnnecessarySuper => "Constructors of non derived classes must not call super()",
     }
@@ -107,6 +128,9 @@ impl DiagnosticKind {
     match *self {
       DiagnosticKind::TooManySuper => "Remove extra calls to super()",
       DiagnosticKind::NoSuper => "Add call to super() in the constructor",
+      DiagnosticKind::NoSuperInSomePaths => {
+        "Make sure super() is called before every return from the constructor"
+      }
       DiagnosticKind::UnnecessaryConstructor => "Remove constructor",
       DiagnosticKind::UnnecessarySuper => "Remove call to super()",
     }
@@ -122,33 +146,19 @@ fn inherits_from_non_constructor(class: &Class) -> bool {
   false
 }
 
-fn super_call_spans(constructor: &Constructor) -> Vec<Span> {
-  if let Some(block_stmt) = &constructor.body {
-    block_stmt
-      .stmts
-      .iter()
-      .filter_map(|stmt| extract_super_span(stmt))
-      .collect()
-  } else {
-    vec![]
-  }
-}
-
-fn extract_super_span(stmt: &Stmt) -> Option<Span> {
+fn is_super_call(stmt: &Stmt) -> bool {
   if let Stmt::Expr(expr) = stmt {
     if let Expr::Call(call) = &*expr.expr {
-      if matches!(&call.callee, ExprOrSuper::Super(_)) {
-        return Some(call.span);
-      }
+      return matches!(&call.callee, ExprOrSuper::Super(_));
     }
   }
-  None
+  false
 }
 
 fn return_before_super(constructor: &Constructor) -> Option<&ReturnStmt> {
   if let Some(block_stmt) = &constructor.body {
     for stmt in &block_stmt.stmts {
-      if extract_super_span(stmt).is_some() {
+      if is_super_call(stmt) {
         return None;
       }
 
@@ -169,72 +179,382 @@ impl<'c> ConstructorSuperVisitor<'c> {
     Self { context }
   }
 
+  fn report(&mut self, span: Span, kind: DiagnosticKind) {
+    self.context.add_diagnostic_with_hint(
+      span,
+      "constructor-super",
+      kind.message(),
+      kind.hint(),
+    );
+  }
+
   fn check_constructor(&mut self, constructor: &Constructor, class: &Class) {
     // Declarations shouldn't be linted
-    if constructor.body.is_none() {
+    let body = match &constructor.body {
+      Some(body) => body,
+      None => return,
+    };
+
+    if inherits_from_non_constructor(class) {
+      // returning value is a substitute of 'super()'.
+      if return_before_super(constructor).is_none() {
+        self.report(constructor.span, DiagnosticKind::UnnecessaryConstructor);
+      }
       return;
     }
 
-    // returning value is a substitute of 'super()'.
-    if let Some(ret) = return_before_super(constructor) {
-      if ret.arg.is_none() && class.super_class.is_some() {
-        let kind = DiagnosticKind::NoSuper;
-        self.context.add_diagnostic_with_hint(
-          constructor.span,
-          "constructor-super",
-          kind.message(),
-          kind.hint(),
-        );
+    let mut paths = SuperCallPaths::default();
+    body.visit_with(constructor, &mut paths);
+    paths.exit();
+
+    if class.super_class.is_none() {
+      if let Some(span) = paths.calls.first() {
+        self.report(*span, DiagnosticKind::UnnecessarySuper);
       }
       return;
     }
 
-    if inherits_from_non_constructor(class) {
-      let kind = DiagnosticKind::UnnecessaryConstructor;
-      self.context.add_diagnostic_with_hint(
-        constructor.span,
-        "constructor-super",
-        kind.message(),
-        kind.hint(),
-      );
-      return;
+    for span in paths.duplicates {
+      self.report(span, DiagnosticKind::TooManySuper);
+    }
+
+    if paths.missing {
+      let kind = if paths.calls.is_empty() {
+        DiagnosticKind::NoSuper
+      } else {
+        DiagnosticKind::NoSuperInSomePaths
+      };
+      self.report(constructor.span, kind);
+    }
+  }
+}
+
+/// Range of how many times `super()` has been called along the code paths
+/// reaching a point. `2` stands for "more than once".
+#[derive(Clone, Copy, Debug)]
+struct Calls {
+  min: u8,
+  max: u8,
+}
+
+impl Calls {
+  const NONE: Calls = Calls { min: 0, max: 0 };
+
+  fn called(self) -> Self {
+    Calls {
+      min: (self.min + 1).min(2),
+      max: (self.max + 1).min(2),
+    }
+  }
+}
+
+/// Merges two code paths. `None` means the point is unreachable.
+fn join(a: Option<Calls>, b: Option<Calls>) -> Option<Calls> {
+  match (a, b) {
+    (Some(a), Some(b)) => Some(Calls {
+      min: a.min.min(b.min),
+      max: a.max.max(b.max),
+    }),
+    (a, None) => a,
+    (None, b) => b,
+  }
+}
+
+/// Walks a constructor body keeping track of `super()` calls on every code
+/// path. Nested functions and classes are not entered, since `super()` in
+/// them doesn't refer to this constructor.
+struct SuperCallPaths {
+  /// State of the current code path, `None` if it's unreachable.
+  state: Option<Calls>,
+  /// States at `break` statements of enclosing loops and switches.
+  breaks: Vec<Option<Calls>>,
+  /// States at `continue` statements of enclosing loops.
+  continues: Vec<Option<Calls>>,
+  /// Enclosing labeled statements.
+  labels: Vec<Label>,
+  /// Spans of reachable `super()` calls.
+  calls: Vec<Span>,
+  /// Spans of `super()` calls that may run after another one.
+  duplicates: Vec<Span>,
+  /// Whether there's a path leaving the constructor without `super()`.
+  missing: bool,
+}
+
+/// A labeled statement enclosing the current code path.
+struct Label {
+  name: JsWord,
+  /// States at `break` statements targeting the label.
+  breaks: Option<Calls>,
+  /// Index in `SuperCallPaths::continues` of the loop the label belongs to,
+  /// if the labeled statement is a loop.
+  continues: usize,
+}
+
+impl Default for SuperCallPaths {
+  fn default() -> Self {
+    Self {
+      state: Some(Calls::NONE),
+      breaks: vec![],
+      continues: vec![],
+      labels: vec![],
+      calls: vec![],
+      duplicates: vec![],
+      missing: false,
     }
+  }
+}
 
-    let super_calls = super_call_spans(constructor);
-
-    // in case where there are more than one `super()` calls.
-    for exceeded_super_span in super_calls.iter().skip(1) {
-      let kind = DiagnosticKind::TooManySuper;
-      self.context.add_diagnostic_with_hint(
-        *exceeded_super_span,
-        "constructor-super",
-        kind.message(),
-        kind.hint(),
-      );
+impl SuperCallPaths {
+  fn exit(&mut self) {
+    if let Some(calls) = self.state.take() {
+      if calls.min == 0 {
+        self.missing = true;
+      }
     }
+  }
+
+  /// Visits the body of a loop twice, so that calls which can happen again
+  /// on the next iteration are detected. Returns the state when leaving the
+  /// loop.
+  fn visit_loop<F>(&mut self, mut iteration: F) -> Option<Calls>
+  where
+    F: FnMut(&mut Self) -> Option<Calls>,
+  {
+    self.breaks.push(None);
+    let entry = self.state;
+    let first_exit = iteration(self);
+    self.state = join(entry, self.state);
+    let second_exit = iteration(self);
+    let breaks = self.breaks.pop().unwrap();
+    join(join(first_exit, second_exit), breaks)
+  }
+
+  /// Visits loop body, collecting states at `continue` statements.
+  fn visit_loop_body(&mut self, body: &Stmt, parent: &dyn Node) {
+    self.continues.push(None);
+    body.visit_with(parent, self);
+    let continues = self.continues.pop().unwrap();
+    self.state = join(self.state, continues);
+  }
+}
+
+impl Visit for SuperCallPaths {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, _function: &Function, _parent: &dyn Node) {}
+
+  fn visit_arrow_expr(&mut self, _arrow_expr: &ArrowExpr, _parent: &dyn Node) {}
+
+  fn visit_class(&mut self, _class: &Class, _parent: &dyn Node) {}
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    call_expr.visit_children_with(self);
 
-    match (super_calls.is_empty(), class.super_class.is_some()) {
-      (true, true) => {
-        let kind = DiagnosticKind::NoSuper;
-        self.context.add_diagnostic_with_hint(
-          constructor.span,
-          "constructor-super",
-          kind.message(),
-          kind.hint(),
-        );
+    if let ExprOrSuper::Super(_) = call_expr.callee {
+      if let Some(calls) = self.state {
+        self.calls.push(call_expr.span);
+        if calls.max > 0 && !self.duplicates.contains(&call_expr.span) {
+          self.duplicates.push(call_expr.span);
+        }
+        self.state = Some(calls.called());
       }
-      (false, false) => {
-        let kind = DiagnosticKind::UnnecessarySuper;
-        self.context.add_diagnostic_with_hint(
-          super_calls[0],
-          "constructor-super",
-          kind.message(),
-          kind.hint(),
-        );
+    }
+  }
+
+  fn visit_cond_expr(&mut self, cond_expr: &CondExpr, _parent: &dyn Node) {
+    cond_expr.test.visit_with(cond_expr, self);
+    let test = self.state;
+    cond_expr.cons.visit_with(cond_expr, self);
+    let cons = self.state;
+    self.state = test;
+    cond_expr.alt.visit_with(cond_expr, self);
+    self.state = join(cons, self.state);
+  }
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+    bin_expr.left.visit_with(bin_expr, self);
+    match bin_expr.op {
+      BinaryOp::LogicalAnd
+      | BinaryOp::LogicalOr
+      | BinaryOp::NullishCoalescing => {
+        let left = self.state;
+        bin_expr.right.visit_with(bin_expr, self);
+        self.state = join(left, self.state);
       }
-      _ => {}
+      _ => bin_expr.right.visit_with(bin_expr, self),
+    }
+  }
+
+  fn visit_if_stmt(&mut self, if_stmt: &IfStmt, _parent: &dyn Node) {
+    if_stmt.test.visit_with(if_stmt, self);
+    let test = self.state;
+    if_stmt.cons.visit_with(if_stmt, self);
+    let cons = self.state;
+    self.state = test;
+    if_stmt.alt.visit_with(if_stmt, self);
+    self.state = join(cons, self.state);
+  }
+
+  fn visit_return_stmt(
+    &mut self,
+    return_stmt: &ReturnStmt,
+    _parent: &dyn Node,
+  ) {
+    if let Some(arg) = &return_stmt.arg {
+      arg.visit_with(return_stmt, self);
+      // returning value is a substitute of 'super()'.
+      self.state = None;
+    } else {
+      self.exit();
     }
   }
+
+  fn visit_throw_stmt(&mut self, throw_stmt: &ThrowStmt, _parent: &dyn Node) {
+    throw_stmt.arg.visit_with(throw_stmt, self);
+    self.state = None;
+  }
+
+  fn visit_labeled_stmt(
+    &mut self,
+    labeled_stmt: &LabeledStmt,
+    _parent: &dyn Node,
+  ) {
+    self.labels.push(Label {
+      name: labeled_stmt.label.sym.clone(),
+      breaks: None,
+      continues: self.continues.len(),
+    });
+    labeled_stmt.body.visit_with(labeled_stmt, self);
+    let label = self.labels.pop().unwrap();
+    self.state = join(self.state, label.breaks);
+  }
+
+  fn visit_break_stmt(&mut self, break_stmt: &BreakStmt, _parent: &dyn Node) {
+    let breaks = match &break_stmt.label {
+      Some(name) => self
+        .labels
+        .iter_mut()
+        .rev()
+        .find(|label| label.name == name.sym)
+        .map(|label| &mut label.breaks),
+      None => self.breaks.last_mut(),
+    };
+    if let Some(breaks) = breaks {
+      *breaks = join(*breaks, self.state);
+    }
+    self.state = None;
+  }
+
+  fn visit_continue_stmt(
+    &mut self,
+    continue_stmt: &ContinueStmt,
+    _parent: &dyn Node,
+  ) {
+    let index = match &continue_stmt.label {
+      Some(name) => self
+        .labels
+        .iter()
+        .rev()
+        .find(|label| label.name == name.sym)
+        .map(|label| label.continues),
+      None => self.continues.len().checked_sub(1),
+    };
+    let state = self.state;
+    if let Some(continues) = index.and_then(|i| self.continues.get_mut(i)) {
+      *continues = join(*continues, state);
+    }
+    self.state = None;
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _parent: &dyn Node) {
+    self.state = self.visit_loop(|paths| {
+      while_stmt.test.visit_with(while_stmt, paths);
+      let exit = paths.state;
+      paths.visit_loop_body(&while_stmt.body, while_stmt);
+      exit
+    });
+  }
+
+  fn visit_do_while_stmt(
+    &mut self,
+    do_while_stmt: &DoWhileStmt,
+    _parent: &dyn Node,
+  ) {
+    self.state = self.visit_loop(|paths| {
+      paths.visit_loop_body(&do_while_stmt.body, do_while_stmt);
+      do_while_stmt.test.visit_with(do_while_stmt, paths);
+      paths.state
+    });
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _parent: &dyn Node) {
+    for_stmt.init.visit_with(for_stmt, self);
+    self.state = self.visit_loop(|paths| {
+      for_stmt.test.visit_with(for_stmt, paths);
+      // `for (;;)` can only be left with `break`.
+      let exit = for_stmt.test.as_ref().and(paths.state);
+      paths.visit_loop_body(&for_stmt.body, for_stmt);
+      for_stmt.update.visit_with(for_stmt, paths);
+      exit
+    });
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, _parent: &dyn Node) {
+    for_in_stmt.right.visit_with(for_in_stmt, self);
+    self.state = self.visit_loop(|paths| {
+      let exit = paths.state;
+      paths.visit_loop_body(&for_in_stmt.body, for_in_stmt);
+      exit
+    });
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _parent: &dyn Node) {
+    for_of_stmt.right.visit_with(for_of_stmt, self);
+    self.state = self.visit_loop(|paths| {
+      let exit = paths.state;
+      paths.visit_loop_body(&for_of_stmt.body, for_of_stmt);
+      exit
+    });
+  }
+
+  fn visit_switch_stmt(
+    &mut self,
+    switch_stmt: &SwitchStmt,
+    _parent: &dyn Node,
+  ) {
+    switch_stmt.discriminant.visit_with(switch_stmt, self);
+    let discriminant = self.state;
+    let has_default = switch_stmt.cases.iter().any(|case| case.test.is_none());
+
+    self.breaks.push(None);
+    self.state = None;
+    for case in &switch_stmt.cases {
+      // Each case can be entered either by falling through or by matching.
+      self.state = join(self.state, discriminant);
+      case.cons.visit_with(case, self);
+    }
+    let breaks = self.breaks.pop().unwrap();
+
+    self.state = join(self.state, breaks);
+    if !has_default {
+      self.state = join(self.state, discriminant);
+    }
+  }
+
+  fn visit_try_stmt(&mut self, try_stmt: &TryStmt, _parent: &dyn Node) {
+    let entry = self.state;
+    try_stmt.block.visit_with(try_stmt, self);
+
+    if let Some(handler) = &try_stmt.handler {
+      // An exception can be thrown at any point of the `try` block.
+      let block = self.state;
+      self.state = join(entry, block);
+      handler.visit_with(try_stmt, self);
+      self.state = join(block, self.state);
+    }
+
+    try_stmt.finalizer.visit_with(try_stmt, self);
+  }
 }
 
 impl<'c> Visit for ConstructorSuperVisitor<'c> {
@@ -272,13 +592,22 @@ mod tests {
       "class A extends B { }",
       "class A extends B { constructor() { super(); } }",
 
-      // TODO(magurotuna): control flow analysis is required to handle these cases
-      // "class A extends B { constructor() { if (true) { super(); } else { super(); } } }",
-      // "class A extends B { constructor() { a ? super() : super(); } }",
-      // "class A extends B { constructor() { if (a) super(); else super(); } }",
-      // "class A extends B { constructor() { switch (a) { case 0: super(); break; default: super(); } } }",
-      // "class A extends B { constructor() { try {} finally { super(); } } }",
-      // "class A extends B { constructor() { if (a) throw Error(); super(); } }",
+      // code paths.
+      "class A extends B { constructor() { if (true) { super(); } else { super(); } } }",
+      "class A extends B { constructor() { a ? super() : super(); } }",
+      "class A extends B { constructor() { if (a) super(); else super(); } }",
+      "class A extends B { constructor() { switch (a) { case 0: super(); break; default: super(); } } }",
+      "class A extends B { constructor() { switch (a) { case 0: case 1: super(); break; default: super(); } } }",
+      "class A extends B { constructor() { try {} finally { super(); } } }",
+      "class A extends B { constructor() { if (a) throw Error(); super(); } }",
+      "class A extends B { constructor() { if (a) return foo; super(); } }",
+      "class A extends B { constructor() { try { super(); } catch (e) { throw e; } } }",
+      "class A extends B { constructor() { while (a) { foo(); } super(); } }",
+      "class A extends B { constructor() { for (;;) { if (a) { super(); break; } } } }",
+      "class A extends B { constructor() { foo(a && b, super()); } }",
+      "class A extends B { constructor() { foo: { if (a) break foo; } super(); } }",
+      "class A extends B { constructor() { foo: for (;;) { for (;;) { super(); break foo; } } } }",
+      "class A extends B { constructor() { foo: while (a) { while (b) { continue foo; } } super(); } }",
 
       // derived classes.
       "class A extends (class B {}) { constructor() { super(); } }",
@@ -316,6 +645,8 @@ mod tests {
       DiagnosticKind::TooManySuper.message_and_hint();
     let (no_super_message, no_super_hint) =
       DiagnosticKind::NoSuper.message_and_hint();
+    let (no_super_in_some_paths_message, no_super_in_some_paths_hint) =
+      DiagnosticKind::NoSuperInSomePaths.message_and_hint();
     let (unnecessary_constructor_message, unnecessary_constructor_hint) =
       DiagnosticKind::UnnecessaryConstructor.message_and_hint();
     let (unnecessary_super_message, unnecessary_super_hint) =
@@ -414,6 +745,88 @@ mod tests {
           hint: too_many_super_hint,
         }
       ],
+      "class A extends B { constructor() { if (a) { super(); } } }": [
+        {
+          col: 20,
+          message: no_super_in_some_paths_message,
+          hint: no_super_in_some_paths_hint,
+        }
+      ],
+      "class A extends B { constructor() { a && super(); } }": [
+        {
+          col: 20,
+          message: no_super_in_some_paths_message,
+          hint: no_super_in_some_paths_hint,
+        }
+      ],
+      "class A extends B { constructor() { if (a) return; super(); } }": [
+        {
+          col: 20,
+          message: no_super_in_some_paths_message,
+          hint: no_super_in_some_paths_hint,
+        }
+      ],
+      "class A extends B { constructor() { switch (a) { case 0: super(); break; } } }": [
+        {
+          col: 20,
+          message: no_super_in_some_paths_message,
+          hint: no_super_in_some_paths_hint,
+        }
+      ],
+      "class A extends B { constructor() { try { super(); } catch (e) {} } }": [
+        {
+          col: 20,
+          message: no_super_in_some_paths_message,
+          hint: no_super_in_some_paths_hint,
+        }
+      ],
+      "class A extends B { constructor() { super(); if (a) { super(); } } }": [
+        {
+          col: 54,
+          message: too_many_super_message,
+          hint: too_many_super_hint,
+        }
+      ],
+      "class A extends B { constructor() { for (;;) { super(); } } }": [
+        {
+          col: 47,
+          message: too_many_super_message,
+          hint: too_many_super_hint,
+        }
+      ],
+      "class A extends B { constructor() { while (a) { super(); } } }": [
+        {
+          col: 48,
+          message: too_many_super_message,
+          hint: too_many_super_hint,
+        },
+        {
+          col: 20,
+          message: no_super_in_some_paths_message,
+          hint: no_super_in_some_paths_hint,
+        }
+      ],
+      "class A extends B { constructor() { switch (a) { case 0: super(); default: super(); } } }": [
+        {
+          col: 75,
+          message: too_many_super_message,
+          hint: too_many_super_hint,
+        }
+      ],
+      "class A extends B { constructor() { foo: { if (a) break foo; super(); } } }": [
+        {
+          col: 20,
+          message: no_super_in_some_paths_message,
+          hint: no_super_in_some_paths_hint,
+        }
+      ],
+      "class A extends B { constructor() { foo: for (;;) { for (;;) { break foo; } super(); } } }": [
+        {
+          col: 20,
+          message: no_super_message,
+          hint: no_super_hint,
+        }
+      ],
       "class A extends B { constructor() { return; super(); } }": [
         {
           col: 20,
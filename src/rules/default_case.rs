@@ -0,0 +1,216 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::{comment_pattern, comments_between};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Deserialize;
+use swc_common::comments::Comment;
+use swc_common::BytePos;
+use swc_common::Spanned;
+use swc_ecmascript::ast::{Program, SwitchStmt};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct DefaultCase {
+  comment_pattern: Regex,
+}
+
+const CODE: &str = "default-case";
+const MESSAGE: &str = "Expected a `default` case";
+const HINT: &str = "Add a `default` case, or a `// no default` comment after the last case if the cases are exhaustive";
+
+static DEFAULT_COMMENT_PATTERN: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"(?i)^no default$").unwrap());
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DefaultCaseOptions {
+  /// Regular expression matched against the last comment of a switch to
+  /// allow it to have no default case. Defaults to `^no default$`, matched
+  /// case-insensitively.
+  pub comment_pattern: Option<String>,
+}
+
+impl DefaultCase {
+  pub fn with_options(options: DefaultCaseOptions) -> Box<Self> {
+    let comment_pattern =
+      comment_pattern(CODE, options.comment_pattern, &DEFAULT_COMMENT_PATTERN);
+    Box::new(DefaultCase { comment_pattern })
+  }
+}
+
+impl LintRule for DefaultCase {
+  fn new() -> Box<Self> {
+    Self::with_options(DefaultCaseOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = DefaultCaseVisitor {
+      context,
+      comment_pattern: &self.comment_pattern,
+    };
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires `switch` statements to have a `default` case
+
+Without a `default` case it is not obvious whether the missing values were
+forgotten or are intentionally ignored. If the cases are exhaustive, a
+`// no default` comment after the last case states that explicitly.
+
+The comment can be customized with the `commentPattern` option, a regular
+expression that is matched against the text of the last comment in the switch.
+
+### Invalid:
+```typescript
+switch (foo) {
+  case 1:
+    doSomething();
+    break;
+}
+```
+
+### Valid:
+```typescript
+switch (foo) {
+  case 1:
+    doSomething();
+    break;
+  default:
+    break;
+}
+
+switch (foo) {
+  case 1:
+    doSomething();
+    break;
+  // no default
+}
+```
+"#
+  }
+}
+
+struct DefaultCaseVisitor<'c, 'r> {
+  context: &'c mut Context,
+  comment_pattern: &'r Regex,
+}
+
+impl<'c, 'r> DefaultCaseVisitor<'c, 'r> {
+  /// Returns the last comment between the end of the last case of `switch`
+  /// and its closing brace.
+  fn last_comment(&self, switch: &SwitchStmt) -> Option<&Comment> {
+    let lo = switch.cases.last()?.span.hi;
+    let hi = switch.span.hi - BytePos(1);
+    comments_between(self.context, lo, hi).last()
+  }
+}
+
+impl<'c, 'r> Visit for DefaultCaseVisitor<'c, 'r> {
+  noop_visit_type!();
+
+  fn visit_switch_stmt(&mut self, switch: &SwitchStmt, _parent: &dyn Node) {
+    switch.visit_children_with(self);
+
+    if switch.cases.is_empty()
+      || switch.cases.iter().any(|case| case.test.is_none())
+    {
+      return;
+    }
+
+    let exempt = self.last_comment(switch).map_or(false, |comment| {
+      self.comment_pattern.is_match(comment.text.trim())
+    });
+    if !exempt {
+      self
+        .context
+        .add_diagnostic_with_hint(switch.span(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::{
+    assert_lint_err_on_line_n_with_rule, assert_lint_ok_with_rule,
+  };
+
+  #[test]
+  fn default_case_valid() {
+    assert_lint_ok! {
+      DefaultCase,
+      "switch (a) {}",
+      "switch (a) { case 1: break; default: break; }",
+      "switch (a) { default: break; case 1: break; }",
+      "switch (a) { case 1: break; // no default\n }",
+      "switch (a) { case 1: break; /* no default */ }",
+      "switch (a) { case 1: break;\n  // No Default\n}",
+      "switch (a) {\n  case 1:\n    break;\n  // some comment\n  // no default\n}",
+    };
+  }
+
+  #[test]
+  fn default_case_invalid() {
+    assert_lint_err! {
+      DefaultCase,
+      "switch (a) { case 1: break; }": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "switch (a) { case 1: break; // no default here\n }": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "switch (a) {\n  // no default\n  case 1:\n    break;\n}": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "switch (a) {\n  case 1:\n    break;\n  // no default\n  // more\n}": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "switch (a) { case 1: switch (b) { case 2: break; } default: break; }": [
+        { col: 21, message: MESSAGE, hint: HINT },
+      ],
+      // the comment of a nested switch doesn't exempt the outer one
+      "switch (a) {\n  case 1:\n    switch (b) {\n      case 2:\n        break;\n      // no default\n    }\n}": [
+        { line: 1, col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "switch (a) { case 1: if (b) { break; // no default\n } }": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "function f() { switch (a) { case 1: return; } }": [
+        { col: 15, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+
+  #[test]
+  fn default_case_comment_pattern() {
+    let rule = |pattern: &str| {
+      DefaultCase::with_options(DefaultCaseOptions {
+        comment_pattern: Some(pattern.to_string()),
+      })
+    };
+
+    assert_lint_ok_with_rule(
+      rule("^skip\\s+default"),
+      "switch (a) { case 1: break; // skip default case\n }",
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule("^skip\\s+default"),
+      "switch (a) { case 1: break; // no default\n }",
+      vec![(1, 0)],
+    );
+    // An invalid pattern falls back to the default one.
+    assert_lint_ok_with_rule(
+      rule("("),
+      "switch (a) { case 1: break; // no default\n }",
+    );
+  }
+}
@@ -0,0 +1,123 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_ecmascript::ast::{Program, SwitchStmt};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct DefaultCaseLast;
+
+const CODE: &str = "default-case-last";
+const MESSAGE: &str = "The `default` case should be the last case";
+const HINT: &str = "Move the `default` case to the end of the `switch`";
+
+impl LintRule for DefaultCaseLast {
+  fn new() -> Box<Self> {
+    Box::new(DefaultCaseLast)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = DefaultCaseLastVisitor { context };
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires the `default` case of a `switch` to be the last case
+
+A `default` case can be placed anywhere in a `switch`, but it is still only
+run if none of the other cases match. Placing it before other cases is legal,
+but makes the flow of the `switch` harder to follow.
+
+### Invalid:
+```typescript
+switch (foo) {
+  default:
+    doSomethingElse();
+    break;
+  case 1:
+    doSomething();
+    break;
+}
+```
+
+### Valid:
+```typescript
+switch (foo) {
+  case 1:
+    doSomething();
+    break;
+  default:
+    doSomethingElse();
+    break;
+}
+```
+"#
+  }
+}
+
+struct DefaultCaseLastVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> Visit for DefaultCaseLastVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_switch_stmt(&mut self, switch: &SwitchStmt, _parent: &dyn Node) {
+    switch.visit_children_with(self);
+
+    let default_idx = switch.cases.iter().position(|case| case.test.is_none());
+    if let Some(idx) = default_idx {
+      if idx + 1 != switch.cases.len() {
+        self.context.add_diagnostic_with_hint(
+          switch.cases[idx].span,
+          CODE,
+          MESSAGE,
+          HINT,
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn default_case_last_valid() {
+    assert_lint_ok! {
+      DefaultCaseLast,
+      "switch (a) {}",
+      "switch (a) { case 1: break; }",
+      "switch (a) { default: break; }",
+      "switch (a) { case 1: break; default: break; }",
+      "switch (a) { case 1: case 2: default: break; }",
+      "switch (a) { case 1: switch (b) { case 2: break; default: break; } default: break; }",
+    };
+  }
+
+  #[test]
+  fn default_case_last_invalid() {
+    assert_lint_err! {
+      DefaultCaseLast,
+      "switch (a) { default: break; case 1: break; }": [
+        { col: 13, message: MESSAGE, hint: HINT },
+      ],
+      "switch (a) { case 1: break; default: break; case 2: break; }": [
+        { col: 28, message: MESSAGE, hint: HINT },
+      ],
+      "switch (a) { case 1: default: case 2: break; }": [
+        { col: 21, message: MESSAGE, hint: HINT },
+      ],
+      "switch (a) { case 1: switch (b) { default: break; case 2: break; } }": [
+        { col: 34, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
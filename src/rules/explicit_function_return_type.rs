@@ -16,6 +16,10 @@ impl LintRule for ExplicitFunctionReturnType {
     "explicit-function-return-type"
   }
 
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
   fn lint_program(
     &self,
     context: &mut Context,
@@ -22,6 +22,10 @@ impl LintRule for ExplicitModuleBoundaryTypes {
     "explicit-module-boundary-types"
   }
 
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
   fn lint_program(&self, context: &mut Context, program: &Program) {
     let mut visitor = ExplicitModuleBoundaryTypesVisitor::new(context);
     visitor.visit_program(program, program);
@@ -0,0 +1,355 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::accessor_pairs::class_method_key;
+use super::Context;
+use super::LintRule;
+use crate::swc_util::StringRepr;
+use serde::Deserialize;
+use std::collections::HashMap;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  Class, MethodKind, ObjectLit, Program, Prop, PropOrSpread,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct GroupedAccessorPairs {
+  options: GroupedAccessorPairsOptions,
+}
+
+const CODE: &str = "grouped-accessor-pairs";
+const NOT_GROUPED_HINT: &str =
+  "Move the accessor next to the other accessor of the property";
+const ORDER_HINT: &str = "Swap the getter and the setter";
+
+fn not_grouped_message(key: &str) -> String {
+  format!("Accessor pair `{}` should be grouped", key)
+}
+
+fn order_message(key: &str, order: AccessorPairOrder) -> String {
+  match order {
+    AccessorPairOrder::SetBeforeGet => {
+      format!("Expected setter `{}` to be before the getter", key)
+    }
+    _ => format!("Expected getter `{}` to be before the setter", key),
+  }
+}
+
+/// The required order of a getter and a setter of the same property.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AccessorPairOrder {
+  AnyOrder,
+  GetBeforeSet,
+  SetBeforeGet,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GroupedAccessorPairsOptions {
+  /// Whether the getter or the setter of a pair has to come first.
+  pub order: AccessorPairOrder,
+}
+
+impl Default for GroupedAccessorPairsOptions {
+  fn default() -> Self {
+    Self {
+      order: AccessorPairOrder::AnyOrder,
+    }
+  }
+}
+
+impl GroupedAccessorPairs {
+  pub fn with_options(options: GroupedAccessorPairsOptions) -> Box<Self> {
+    Box::new(GroupedAccessorPairs { options })
+  }
+}
+
+impl LintRule for GroupedAccessorPairs {
+  fn new() -> Box<Self> {
+    Self::with_options(GroupedAccessorPairsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = GroupedAccessorPairsVisitor {
+      context,
+      order: self.options.order,
+    };
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires the getter and the setter of a property to be defined next to each other
+
+Keeping the accessors of a property together makes it easier to see how the
+property behaves. Accessors are paired by their key, so getters and setters
+with computed keys are only paired if the keys are literals. Static and
+instance accessors of classes are paired separately.
+
+The `order` option can be set to `"getBeforeSet"` or `"setBeforeGet"` to also
+require the accessors of a pair to be defined in that order. It defaults to
+`"anyOrder"`.
+
+### Invalid:
+```typescript
+const a = {
+  get foo() {
+    return this._foo;
+  },
+  bar: 1,
+  set foo(value) {
+    this._foo = value;
+  },
+};
+
+class B {
+  set bar(value) {
+    this._bar = value;
+  }
+  baz() {}
+  get bar() {
+    return this._bar;
+  }
+}
+```
+
+### Valid:
+```typescript
+const a = {
+  get foo() {
+    return this._foo;
+  },
+  set foo(value) {
+    this._foo = value;
+  },
+  bar: 1,
+};
+
+class B {
+  set bar(value) {
+    this._bar = value;
+  }
+  get bar() {
+    return this._bar;
+  }
+  baz() {}
+}
+```
+"#
+  }
+}
+
+/// An accessor, with its position among the members of the object or class.
+struct Accessor {
+  key: String,
+  kind: MethodKind,
+  span: Span,
+  index: usize,
+}
+
+struct GroupedAccessorPairsVisitor<'c> {
+  context: &'c mut Context,
+  order: AccessorPairOrder,
+}
+
+impl<'c> GroupedAccessorPairsVisitor<'c> {
+  fn check_accessors(&mut self, accessors: Vec<Accessor>) {
+    // The first getter and the first setter seen for each key.
+    let mut pairs: HashMap<&str, (Option<&Accessor>, Option<&Accessor>)> =
+      HashMap::new();
+    for accessor in &accessors {
+      let pair = pairs.entry(&accessor.key).or_default();
+      match accessor.kind {
+        MethodKind::Getter if pair.0.is_none() => pair.0 = Some(accessor),
+        MethodKind::Setter if pair.1.is_none() => pair.1 = Some(accessor),
+        _ => {}
+      }
+    }
+
+    let mut pairs = pairs
+      .into_iter()
+      .filter_map(|(key, pair)| match pair {
+        (Some(getter), Some(setter)) => Some((key, getter, setter)),
+        _ => None,
+      })
+      .collect::<Vec<_>>();
+    pairs.sort_by_key(|(_, getter, setter)| getter.index.max(setter.index));
+
+    for (key, getter, setter) in pairs {
+      let later = if getter.index < setter.index {
+        setter
+      } else {
+        getter
+      };
+      if getter.index.max(setter.index) - getter.index.min(setter.index) != 1 {
+        self.context.add_diagnostic_with_hint(
+          later.span,
+          CODE,
+          not_grouped_message(key),
+          NOT_GROUPED_HINT,
+        );
+        continue;
+      }
+
+      let out_of_order = match self.order {
+        AccessorPairOrder::AnyOrder => false,
+        AccessorPairOrder::GetBeforeSet => setter.index < getter.index,
+        AccessorPairOrder::SetBeforeGet => getter.index < setter.index,
+      };
+      if out_of_order {
+        self.context.add_diagnostic_with_hint(
+          later.span,
+          CODE,
+          order_message(key, self.order),
+          ORDER_HINT,
+        );
+      }
+    }
+  }
+}
+
+impl<'c> VisitAll for GroupedAccessorPairsVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_object_lit(&mut self, object_lit: &ObjectLit, _parent: &dyn Node) {
+    let accessors = object_lit
+      .props
+      .iter()
+      .enumerate()
+      .filter_map(|(index, prop)| match prop {
+        PropOrSpread::Prop(prop) => match &**prop {
+          Prop::Getter(getter) => Some(Accessor {
+            key: getter.key.string_repr()?,
+            kind: MethodKind::Getter,
+            span: getter.span,
+            index,
+          }),
+          Prop::Setter(setter) => Some(Accessor {
+            key: setter.key.string_repr()?,
+            kind: MethodKind::Setter,
+            span: setter.span,
+            index,
+          }),
+          _ => None,
+        },
+        PropOrSpread::Spread(_) => None,
+      })
+      .collect();
+    self.check_accessors(accessors);
+  }
+
+  fn visit_class(&mut self, class: &Class, _parent: &dyn Node) {
+    let accessors = class
+      .body
+      .iter()
+      .enumerate()
+      .filter_map(|(index, member)| {
+        let (key, kind, span) = class_method_key(member)?;
+        Some(Accessor {
+          key,
+          kind,
+          span,
+          index,
+        })
+      })
+      .collect();
+    self.check_accessors(accessors);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::{
+    assert_lint_err_on_line_n_with_rule, assert_lint_ok_with_rule,
+  };
+
+  #[test]
+  fn grouped_accessor_pairs_valid() {
+    assert_lint_ok! {
+      GroupedAccessorPairs,
+      "const a = { get foo() { return 1; }, set foo(v) {}, bar: 1 };",
+      "const a = { set foo(v) {}, get foo() { return 1; } };",
+      "const a = { get foo() { return 1; }, bar: 1 };",
+      "const a = { set foo(v) {}, bar: 1 };",
+      "const a = { get ['foo']() { return 1; }, set foo(v) {} };",
+      "const a = { get [foo]() { return 1; }, bar: 1, set [foo](v) {} };",
+      "const a = { get foo() { return 1; }, b: { set foo(v) {} }, set bar(v) {} };",
+      "class A { get foo() { return 1; } set foo(v) {} bar() {} }",
+      "class A { static get foo() { return 1; } static set foo(v) {} set foo(v) {} }",
+      "class A { get #foo() { return 1; } set #foo(v) {} }",
+    };
+  }
+
+  #[test]
+  fn grouped_accessor_pairs_invalid() {
+    assert_lint_err! {
+      GroupedAccessorPairs,
+      "const a = { get foo() { return 1; }, bar: 1, set foo(v) {} };": [
+        { col: 45, message: not_grouped_message("foo"), hint: NOT_GROUPED_HINT },
+      ],
+      "const a = { set foo(v) {}, ...b, get foo() { return 1; } };": [
+        { col: 33, message: not_grouped_message("foo"), hint: NOT_GROUPED_HINT },
+      ],
+      "const a = { get foo() { return 1; }, get bar() { return 1; }, set foo(v) {}, set bar(v) {} };": [
+        { col: 62, message: not_grouped_message("foo"), hint: NOT_GROUPED_HINT },
+        { col: 77, message: not_grouped_message("bar"), hint: NOT_GROUPED_HINT },
+      ],
+      "const a = { get ['foo']() { return 1; }, bar: 1, set [`foo`](v) {} };": [
+        { col: 49, message: not_grouped_message("foo"), hint: NOT_GROUPED_HINT },
+      ],
+      "class A { set foo(v) {} bar() {} get foo() { return 1; } }": [
+        { col: 33, message: not_grouped_message("foo"), hint: NOT_GROUPED_HINT },
+      ],
+      "class A { static get foo() { return 1; } set foo(v) {} static set foo(v) {} }": [
+        { col: 55, message: not_grouped_message("static foo"), hint: NOT_GROUPED_HINT },
+      ],
+      "const A = class { get #foo() { return 1; } #bar = 1; set #foo(v) {} };": [
+        { col: 53, message: not_grouped_message("#foo"), hint: NOT_GROUPED_HINT },
+      ],
+    };
+  }
+
+  #[test]
+  fn grouped_accessor_pairs_order() {
+    let rule = |order| {
+      GroupedAccessorPairs::with_options(GroupedAccessorPairsOptions { order })
+    };
+
+    let src = r#"const a = { set foo(v) {}, get foo() { return 1; } };
+class A { get bar() { return 1; } set bar(v) {} }"#;
+    assert_lint_ok_with_rule(rule(AccessorPairOrder::AnyOrder), src);
+    assert_lint_err_on_line_n_with_rule(
+      rule(AccessorPairOrder::GetBeforeSet),
+      src,
+      vec![(1, 27)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(AccessorPairOrder::SetBeforeGet),
+      src,
+      vec![(2, 34)],
+    );
+
+    // Separated accessors are only reported once.
+    assert_lint_err_on_line_n_with_rule(
+      rule(AccessorPairOrder::GetBeforeSet),
+      "({ set foo(v) {}, bar: 1, get foo() { return 1; } });",
+      vec![(1, 26)],
+    );
+
+    let diagnostics = crate::test_util::lint(
+      rule(AccessorPairOrder::GetBeforeSet),
+      "({ set foo(v) {}, get foo() { return 1; } });",
+    );
+    assert_eq!(
+      diagnostics[0].message,
+      order_message("foo", AccessorPairOrder::GetBeforeSet)
+    );
+    assert_eq!(diagnostics[0].hint.as_deref(), Some(ORDER_HINT));
+  }
+}
@@ -0,0 +1,290 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  ArrowExpr, DoWhileStmt, ForInStmt, ForOfStmt, ForStmt, Function, IfStmt,
+  Program, Stmt, SwitchStmt, TryStmt, WhileStmt, WithStmt,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct MaxDepth {
+  options: MaxDepthOptions,
+}
+
+const CODE: &str = "max-depth";
+const HINT: &str =
+  "Extract the nested code into a function, or return early to reduce nesting";
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MaxDepthOptions {
+  /// Maximum depth of nested blocks within a function.
+  pub max: usize,
+}
+
+impl Default for MaxDepthOptions {
+  fn default() -> Self {
+    Self { max: 4 }
+  }
+}
+
+impl MaxDepth {
+  pub fn with_options(options: MaxDepthOptions) -> Box<Self> {
+    Box::new(MaxDepth { options })
+  }
+}
+
+impl LintRule for MaxDepth {
+  fn new() -> Box<Self> {
+    Self::with_options(MaxDepthOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = MaxDepthVisitor::new(context, self.options.max);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces a maximum depth of nested blocks
+
+Deeply nested code is hard to read and to follow. This rule counts nested
+`if`, `switch`, `try`, `with` statements and loops, and reports those nested
+deeper than the `max` option, which defaults to `4`. An `else if` doesn't
+increase the depth, and each function starts counting again from zero.
+
+### Invalid:
+```typescript
+function foo() {
+  for (;;) {
+    while (a) {
+      if (b) {
+        if (c) {
+          if (d) {
+          }
+        }
+      }
+    }
+  }
+}
+```
+
+### Valid:
+```typescript
+function foo() {
+  for (;;) {
+    while (a) {
+      if (b) {
+        bar();
+      } else if (c) {
+        baz();
+      }
+    }
+  }
+}
+```
+"#
+  }
+}
+
+struct MaxDepthVisitor<'c> {
+  context: &'c mut Context,
+  max: usize,
+  depth: usize,
+}
+
+impl<'c> MaxDepthVisitor<'c> {
+  fn new(context: &'c mut Context, max: usize) -> Self {
+    Self {
+      context,
+      max,
+      depth: 0,
+    }
+  }
+
+  fn with_block<F>(&mut self, span: Span, op: F)
+  where
+    F: FnOnce(&mut Self),
+  {
+    self.depth += 1;
+    if self.depth > self.max {
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        format!(
+          "Blocks are nested too deeply ({}). Maximum allowed is {}.",
+          self.depth, self.max
+        ),
+        HINT,
+      );
+    }
+    op(self);
+    self.depth -= 1;
+  }
+
+  fn with_function<F>(&mut self, op: F)
+  where
+    F: FnOnce(&mut Self),
+  {
+    let depth = std::mem::replace(&mut self.depth, 0);
+    op(self);
+    self.depth = depth;
+  }
+
+  /// Visits an `if` statement, keeping `else if` chains at the same depth.
+  fn visit_if_chain(&mut self, if_stmt: &IfStmt) {
+    if_stmt.test.visit_with(if_stmt, self);
+    if_stmt.cons.visit_with(if_stmt, self);
+    match if_stmt.alt.as_deref() {
+      Some(Stmt::If(else_if)) => self.visit_if_chain(else_if),
+      Some(alt) => alt.visit_with(if_stmt, self),
+      None => {}
+    }
+  }
+}
+
+impl<'c> Visit for MaxDepthVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
+    self.with_function(|v| function.visit_children_with(v));
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    self.with_function(|v| arrow_expr.visit_children_with(v));
+  }
+
+  fn visit_if_stmt(&mut self, if_stmt: &IfStmt, _parent: &dyn Node) {
+    self.with_block(if_stmt.span, |v| v.visit_if_chain(if_stmt));
+  }
+
+  fn visit_switch_stmt(
+    &mut self,
+    switch_stmt: &SwitchStmt,
+    _parent: &dyn Node,
+  ) {
+    self.with_block(switch_stmt.span, |v| switch_stmt.visit_children_with(v));
+  }
+
+  fn visit_try_stmt(&mut self, try_stmt: &TryStmt, _parent: &dyn Node) {
+    self.with_block(try_stmt.span, |v| try_stmt.visit_children_with(v));
+  }
+
+  fn visit_with_stmt(&mut self, with_stmt: &WithStmt, _parent: &dyn Node) {
+    self.with_block(with_stmt.span, |v| with_stmt.visit_children_with(v));
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _parent: &dyn Node) {
+    self.with_block(for_stmt.span, |v| for_stmt.visit_children_with(v));
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, _parent: &dyn Node) {
+    self.with_block(for_in_stmt.span, |v| for_in_stmt.visit_children_with(v));
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _parent: &dyn Node) {
+    self.with_block(for_of_stmt.span, |v| for_of_stmt.visit_children_with(v));
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _parent: &dyn Node) {
+    self.with_block(while_stmt.span, |v| while_stmt.visit_children_with(v));
+  }
+
+  fn visit_do_while_stmt(
+    &mut self,
+    do_while_stmt: &DoWhileStmt,
+    _parent: &dyn Node,
+  ) {
+    self
+      .with_block(do_while_stmt.span, |v| do_while_stmt.visit_children_with(v));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  fn message(depth: usize) -> String {
+    format!(
+      "Blocks are nested too deeply ({}). Maximum allowed is 4.",
+      depth
+    )
+  }
+
+  #[test]
+  fn max_depth_valid() {
+    assert_lint_ok! {
+      MaxDepth,
+      "if (a) { if (b) { if (c) { if (d) {} } } }",
+      "for (;;) { while (a) { switch (b) { case 0: try {} catch {} } } }",
+      "if (a) {} else if (b) {} else if (c) {} else if (d) {} else if (e) { if (f) { if (g) { if (h) {} } } }",
+      r#"
+function foo() {
+  if (a) {
+    if (b) {
+      if (c) {
+        if (d) {
+          function bar() {
+            if (e) { if (f) { if (g) { if (h) {} } } }
+          }
+        }
+      }
+    }
+  }
+}
+      "#,
+      r#"
+function foo() {
+  if (a) { if (b) { if (c) { if (d) {} } } }
+}
+function bar() {
+  if (a) { if (b) { if (c) { if (d) {} } } }
+}
+      "#,
+      "if (a) { if (b) { if (c) { if (d) { const f = () => { if (e) {} }; } } } }",
+    };
+  }
+
+  #[test]
+  fn max_depth_invalid() {
+    assert_lint_err! {
+      MaxDepth,
+      "if (a) { if (b) { if (c) { if (d) { if (e) {} } } } }": [
+        { col: 36, message: message(5), hint: HINT }
+      ],
+      "for (;;) { while (a) { do { for (x of y) { try {} catch {} } } while (b); } }": [
+        { col: 43, message: message(5), hint: HINT }
+      ],
+      "if (a) { if (b) { if (c) { if (d) { if (e) { switch (f) {} } } } } }": [
+        { col: 36, message: message(5), hint: HINT },
+        { col: 45, message: message(6), hint: HINT },
+      ],
+      "if (a) {} else if (b) { if (c) { if (d) { if (e) { if (f) {} } } } }": [
+        { col: 51, message: message(5), hint: HINT }
+      ],
+      "function foo() { if (a) { if (b) { if (c) { if (d) { for (;;) {} } } } } }": [
+        { col: 53, message: message(5), hint: HINT }
+      ],
+    };
+  }
+
+  #[test]
+  fn max_depth_options() {
+    let rule = || MaxDepth::with_options(MaxDepthOptions { max: 1 });
+    assert_lint_ok_with_rule(rule(), "if (a) {} function f() { if (b) {} }");
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "if (a) {\n  if (b) {}\n}",
+      vec![(2, 2)],
+    );
+  }
+}
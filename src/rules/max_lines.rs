@@ -0,0 +1,193 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::count_lines;
+use serde::Deserialize;
+use swc_common::{BytePos, FileName, Span, SyntaxContext};
+use swc_ecmascript::ast::Program;
+
+pub struct MaxLines {
+  options: MaxLinesOptions,
+}
+
+const CODE: &str = "max-lines";
+const HINT: &str = "Split the file into smaller modules";
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MaxLinesOptions {
+  /// Maximum number of lines in a file.
+  pub max: usize,
+  /// Whether lines containing only whitespace are left out of the count.
+  pub skip_blank_lines: bool,
+  /// Whether lines containing only comments are left out of the count.
+  pub skip_comments: bool,
+}
+
+impl Default for MaxLinesOptions {
+  fn default() -> Self {
+    Self {
+      max: 300,
+      skip_blank_lines: false,
+      skip_comments: false,
+    }
+  }
+}
+
+impl MaxLines {
+  pub fn with_options(options: MaxLinesOptions) -> Box<Self> {
+    Box::new(MaxLines { options })
+  }
+}
+
+impl LintRule for MaxLines {
+  fn new() -> Box<Self> {
+    Self::with_options(MaxLinesOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, _program: &Program) {
+    let file = match context
+      .source_map
+      .get_source_file(&FileName::Custom(context.file_name.clone()))
+    {
+      Some(file) => file,
+      None => return,
+    };
+
+    // A line break at the end of the file doesn't start another line.
+    let end_pos = if file.src.ends_with('\n') {
+      file.end_pos - BytePos(1)
+    } else {
+      file.end_pos
+    };
+    let count = count_lines(
+      context,
+      Span::new(file.start_pos, end_pos, SyntaxContext::empty()),
+      self.options.skip_blank_lines,
+      self.options.skip_comments,
+    );
+
+    if count > self.options.max {
+      let (first_line_start, first_line_end) = file.line_bounds(0);
+      context.add_diagnostic_with_hint(
+        Span::new(first_line_start, first_line_end, SyntaxContext::empty()),
+        CODE,
+        format!(
+          "File has too many lines ({}). Maximum allowed is {}.",
+          count, self.options.max
+        ),
+        HINT,
+      );
+    }
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces a maximum number of lines per file
+
+Large files tend to do too many things and are hard to navigate. This rule
+reports files with more lines than the `max` option, which defaults to `300`.
+
+Blank lines and lines containing only comments can be left out of the count
+with the `skipBlankLines` and `skipComments` options.
+
+### Invalid:
+```typescript
+// with `max` set to 2
+const a = 1;
+const b = 2;
+```
+
+### Valid:
+```typescript
+// with `max` set to 2 and `skipComments` enabled
+const a = 1;
+const b = 2;
+```
+"#
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  fn rule(
+    max: usize,
+    skip_blank_lines: bool,
+    skip_comments: bool,
+  ) -> Box<MaxLines> {
+    MaxLines::with_options(MaxLinesOptions {
+      max,
+      skip_blank_lines,
+      skip_comments,
+    })
+  }
+
+  #[test]
+  fn max_lines_valid() {
+    assert_lint_ok! {
+      MaxLines,
+      "",
+      "const a = 1;",
+      "const a = 1;\nconst b = 2;\n",
+    };
+
+    assert_lint_ok_with_rule(
+      rule(2, false, false),
+      "const a = 1;\nconst b = 2;",
+    );
+    assert_lint_ok_with_rule(
+      rule(2, false, false),
+      "const a = 1;\nconst b = 2;\n",
+    );
+    assert_lint_ok_with_rule(
+      rule(2, true, false),
+      "const a = 1;\n\n   \nconst b = 2;\n",
+    );
+    assert_lint_ok_with_rule(
+      rule(2, false, true),
+      "// comment\nconst a = 1;\n/*\n * block\n */\nconst b = 2; // trailing\n",
+    );
+    assert_lint_ok_with_rule(
+      rule(2, true, true),
+      "// comment\n\nconst a = 1;\n\n/* block */\nconst b = 2;\n",
+    );
+  }
+
+  #[test]
+  fn max_lines_invalid() {
+    assert_lint_err_on_line_n_with_rule(
+      rule(2, false, false),
+      "const a = 1;\nconst b = 2;\nconst c = 3;",
+      vec![(1, 0)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(2, false, false),
+      "const a = 1;\n\nconst b = 2;\n",
+      vec![(1, 0)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(2, false, true),
+      "const a = 1;\n\nconst b = 2;\n",
+      vec![(1, 0)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(2, true, false),
+      "// comment\nconst a = 1;\nconst b = 2;\n",
+      vec![(1, 0)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(2, true, true),
+      "/* a */ const a = 1;\n\nconst b = 2;\nconst c = /* c */ 3;\n",
+      vec![(1, 0)],
+    );
+
+    let source = "foo();\n".repeat(301);
+    assert_lint_err_on_line_n::<MaxLines>(&source, vec![(1, 0)]);
+  }
+}
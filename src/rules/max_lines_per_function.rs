@@ -0,0 +1,306 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::count_lines;
+use serde::Deserialize;
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{
+  ArrowExpr, CallExpr, Constructor, Expr, ExprOrSuper, Function, Program,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct MaxLinesPerFunction {
+  options: MaxLinesPerFunctionOptions,
+}
+
+const CODE: &str = "max-lines-per-function";
+const HINT: &str = "Split the function into smaller functions";
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MaxLinesPerFunctionOptions {
+  /// Maximum number of lines in a function body.
+  pub max: usize,
+  /// Whether lines containing only whitespace are left out of the count.
+  pub skip_blank_lines: bool,
+  /// Whether lines containing only comments are left out of the count.
+  pub skip_comments: bool,
+  /// Whether immediately invoked function expressions are checked.
+  #[serde(rename = "IIFEs")]
+  pub iifes: bool,
+}
+
+impl Default for MaxLinesPerFunctionOptions {
+  fn default() -> Self {
+    Self {
+      max: 50,
+      skip_blank_lines: false,
+      skip_comments: false,
+      iifes: false,
+    }
+  }
+}
+
+impl MaxLinesPerFunction {
+  pub fn with_options(options: MaxLinesPerFunctionOptions) -> Box<Self> {
+    Box::new(MaxLinesPerFunction { options })
+  }
+}
+
+impl LintRule for MaxLinesPerFunction {
+  fn new() -> Box<Self> {
+    Self::with_options(MaxLinesPerFunctionOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = MaxLinesPerFunctionVisitor::new(context, &self.options);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces a maximum number of lines per function
+
+Long functions are hard to understand and usually do more than one thing. This
+rule counts the lines from the opening to the closing brace of each function
+body and reports functions longer than the `max` option, which defaults to `50`.
+
+Blank lines and lines containing only comments can be left out of the count
+with the `skipBlankLines` and `skipComments` options. Immediately invoked
+function expressions are only checked when the `IIFEs` option is enabled.
+
+### Invalid:
+```typescript
+// with `max` set to 3
+function foo() {
+  const a = 1;
+  const b = 2;
+}
+```
+
+### Valid:
+```typescript
+// with `max` set to 3
+function foo() {
+  return 1;
+}
+
+(function () {
+  const a = 1;
+  const b = 2;
+})();
+```
+"#
+  }
+}
+
+struct MaxLinesPerFunctionVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o MaxLinesPerFunctionOptions,
+}
+
+impl<'c, 'o> MaxLinesPerFunctionVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o MaxLinesPerFunctionOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn check(&mut self, span: Span, body: Span) {
+    let count = count_lines(
+      self.context,
+      body,
+      self.options.skip_blank_lines,
+      self.options.skip_comments,
+    );
+    if count > self.options.max {
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        format!(
+          "Function has too many lines ({}). Maximum allowed is {}.",
+          count, self.options.max
+        ),
+        HINT,
+      );
+    }
+  }
+}
+
+fn unwrap_paren(expr: &Expr) -> &Expr {
+  match expr {
+    Expr::Paren(paren) => unwrap_paren(&paren.expr),
+    _ => expr,
+  }
+}
+
+impl<'c, 'o> Visit for MaxLinesPerFunctionVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
+    if let Some(body) = &function.body {
+      self.check(function.span, body.span);
+    }
+    function.visit_children_with(self);
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    self.check(arrow_expr.span, arrow_expr.body.span());
+    arrow_expr.visit_children_with(self);
+  }
+
+  fn visit_constructor(
+    &mut self,
+    constructor: &Constructor,
+    _parent: &dyn Node,
+  ) {
+    if let Some(body) = &constructor.body {
+      self.check(constructor.span, body.span);
+    }
+    constructor.visit_children_with(self);
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    if !self.options.iifes {
+      if let ExprOrSuper::Expr(callee) = &call_expr.callee {
+        // Visit the contents of an IIFE without checking the IIFE itself.
+        match unwrap_paren(callee) {
+          Expr::Fn(fn_expr) => {
+            fn_expr.function.visit_children_with(self);
+            call_expr.args.visit_with(call_expr, self);
+            return;
+          }
+          Expr::Arrow(arrow_expr) => {
+            arrow_expr.visit_children_with(self);
+            call_expr.args.visit_with(call_expr, self);
+            return;
+          }
+          _ => {}
+        }
+      }
+    }
+    call_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  fn options(max: usize) -> MaxLinesPerFunctionOptions {
+    MaxLinesPerFunctionOptions {
+      max,
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn max_lines_per_function_valid() {
+    assert_lint_ok! {
+      MaxLinesPerFunction,
+      "function foo() {}",
+      "function foo() {\n  return 1;\n}",
+      "const foo = () => 1;",
+    };
+
+    let with = MaxLinesPerFunction::with_options;
+    assert_lint_ok_with_rule(
+      with(options(3)),
+      "function foo() {\n  return 1;\n}",
+    );
+    assert_lint_ok_with_rule(
+      with(options(3)),
+      "function foo(\n  a,\n  b,\n) {\n  return 1;\n}",
+    );
+    assert_lint_ok_with_rule(
+      with(options(3)),
+      "class A {\n  foo() {\n    a();\n  }\n}",
+    );
+    assert_lint_ok_with_rule(
+      with(MaxLinesPerFunctionOptions {
+        skip_blank_lines: true,
+        ..options(3)
+      }),
+      "function foo() {\n\n  return 1;\n\n}",
+    );
+    assert_lint_ok_with_rule(
+      with(MaxLinesPerFunctionOptions {
+        skip_comments: true,
+        ..options(3)
+      }),
+      "function foo() {\n  // comment\n  /* block */\n  return 1; // trailing\n}",
+    );
+    assert_lint_ok_with_rule(
+      with(options(3)),
+      "(function() {\n  a();\n  b();\n})();\n(() => {\n  a();\n  b();\n})();",
+    );
+  }
+
+  #[test]
+  fn max_lines_per_function_invalid() {
+    let with = MaxLinesPerFunction::with_options;
+    assert_lint_err_on_line_n_with_rule(
+      with(options(3)),
+      "function foo() {\n  a();\n  b();\n}",
+      vec![(1, 0)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      with(options(3)),
+      "const foo = () => {\n  a();\n  b();\n};",
+      vec![(1, 12)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      with(options(3)),
+      "class A {\n  constructor() {\n    a();\n    b();\n  }\n}",
+      vec![(2, 2)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      with(options(3)),
+      "function foo() {\n\n  return 1;\n\n}",
+      vec![(1, 0)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      with(MaxLinesPerFunctionOptions {
+        skip_comments: true,
+        ..options(3)
+      }),
+      "function foo() {\n  a(); // comment\n  /* block */ b();\n}",
+      vec![(1, 0)],
+    );
+
+    // nested functions are counted independently
+    assert_lint_err_on_line_n_with_rule(
+      with(options(5)),
+      "function foo() {\n  a();\n  function bar() {\n    b();\n    c();\n    d();\n    e();\n  }\n}",
+      vec![(1, 0), (3, 2)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      with(options(4)),
+      "function foo() {\n  function bar() {\n    b();\n  }\n}",
+      vec![(1, 0)],
+    );
+
+    // IIFEs
+    assert_lint_err_on_line_n_with_rule(
+      with(MaxLinesPerFunctionOptions {
+        iifes: true,
+        ..options(3)
+      }),
+      "(function() {\n  a();\n  b();\n})();\n(() => {\n  a();\n  b();\n})();",
+      vec![(1, 1), (5, 1)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      with(options(3)),
+      "(function() {\n  function foo() {\n    a();\n    b();\n  }\n})();",
+      vec![(2, 2)],
+    );
+  }
+}
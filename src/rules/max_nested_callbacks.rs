@@ -0,0 +1,208 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_common::Spanned;
+use swc_ecmascript::ast::{CallExpr, Expr, Program};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct MaxNestedCallbacks {
+  options: MaxNestedCallbacksOptions,
+}
+
+const CODE: &str = "max-nested-callbacks";
+const HINT: &str =
+  "Flatten the callbacks with `async`/`await`, or extract them into named functions";
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MaxNestedCallbacksOptions {
+  /// Maximum depth of callbacks nested in each other.
+  pub max: usize,
+}
+
+impl Default for MaxNestedCallbacksOptions {
+  fn default() -> Self {
+    Self { max: 10 }
+  }
+}
+
+impl MaxNestedCallbacks {
+  pub fn with_options(options: MaxNestedCallbacksOptions) -> Box<Self> {
+    Box::new(MaxNestedCallbacks { options })
+  }
+}
+
+impl LintRule for MaxNestedCallbacks {
+  fn new() -> Box<Self> {
+    Self::with_options(MaxNestedCallbacksOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = MaxNestedCallbacksVisitor::new(context, self.options.max);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces a maximum depth of nested callbacks
+
+Callbacks nested in callbacks quickly become hard to follow ("callback hell").
+This rule reports function expressions and arrow functions passed as arguments
+which are nested deeper than the `max` option, which defaults to `10`.
+
+### Invalid:
+```typescript
+// with `max` set to 2
+foo(() => {
+  bar(() => {
+    baz(() => {});
+  });
+});
+```
+
+### Valid:
+```typescript
+// with `max` set to 2
+async function run() {
+  await foo();
+  await bar();
+  await baz();
+}
+```
+"#
+  }
+}
+
+struct MaxNestedCallbacksVisitor<'c> {
+  context: &'c mut Context,
+  max: usize,
+  depth: usize,
+}
+
+impl<'c> MaxNestedCallbacksVisitor<'c> {
+  fn new(context: &'c mut Context, max: usize) -> Self {
+    Self {
+      context,
+      max,
+      depth: 0,
+    }
+  }
+}
+
+fn is_function(expr: &Expr) -> bool {
+  match expr {
+    Expr::Fn(_) | Expr::Arrow(_) => true,
+    Expr::Paren(paren) => is_function(&paren.expr),
+    _ => false,
+  }
+}
+
+impl<'c> Visit for MaxNestedCallbacksVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    call_expr.callee.visit_with(call_expr, self);
+
+    for arg in &call_expr.args {
+      if !is_function(&arg.expr) {
+        arg.visit_with(call_expr, self);
+        continue;
+      }
+
+      self.depth += 1;
+      if self.depth > self.max {
+        self.context.add_diagnostic_with_hint(
+          arg.expr.span(),
+          CODE,
+          format!(
+            "Too many nested callbacks ({}). Maximum allowed is {}.",
+            self.depth, self.max
+          ),
+          HINT,
+        );
+      }
+      arg.visit_with(call_expr, self);
+      self.depth -= 1;
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  fn rule(max: usize) -> Box<MaxNestedCallbacks> {
+    MaxNestedCallbacks::with_options(MaxNestedCallbacksOptions { max })
+  }
+
+  #[test]
+  fn max_nested_callbacks_valid() {
+    assert_lint_ok! {
+      MaxNestedCallbacks,
+      "foo(() => { bar(() => { baz(() => {}); }); });",
+      "foo(function() { bar(function() { baz(function() {}); }); });",
+      "foo(a, b, c);",
+      "const f = () => () => () => () => () => () => () => () => () => () => () => 1;",
+    };
+
+    assert_lint_ok_with_rule(
+      rule(3),
+      "foo(() => { bar(() => { baz(() => {}); }); });",
+    );
+    assert_lint_ok_with_rule(
+      rule(2),
+      "foo(() => { bar(() => {}); });\nbaz(() => { qux(() => {}); });",
+    );
+    assert_lint_ok_with_rule(
+      rule(2),
+      "foo(() => { const f = function() { bar(() => {}); }; });",
+    );
+    assert_lint_ok_with_rule(rule(1), "foo(() => {}, () => {}, () => {});");
+  }
+
+  #[test]
+  fn max_nested_callbacks_invalid() {
+    assert_lint_err_on_line_n_with_rule(
+      rule(2),
+      "foo(() => {\n  bar(() => {\n    baz(() => {});\n  });\n});",
+      vec![(3, 8)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(2),
+      "foo(function() {\n  bar(function() {\n    baz((function() {}));\n  });\n});",
+      vec![(3, 8)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(1),
+      "foo(() => {\n  bar(() => {\n    baz(() => {});\n  });\n});",
+      vec![(2, 6), (3, 8)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(2),
+      "foo(() => bar(() => baz(() => {}), () => {}));",
+      vec![(1, 24)],
+    );
+  }
+
+  #[test]
+  fn max_nested_callbacks_message() {
+    assert_lint_err! {
+      MaxNestedCallbacks,
+      "a(() => b(() => c(() => d(() => e(() => f(() => g(() => h(() => i(() => j(() => k(() => {})))))))))));": [
+        {
+          col: 82,
+          message: "Too many nested callbacks (11). Maximum allowed is 10.",
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
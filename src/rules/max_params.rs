@@ -0,0 +1,188 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  ArrowExpr, Constructor, Function, Param, Pat, Program,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct MaxParams {
+  options: MaxParamsOptions,
+}
+
+const CODE: &str = "max-params";
+const HINT: &str = "Group related parameters into an options object";
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MaxParamsOptions {
+  /// Maximum number of parameters a function may declare.
+  pub max: usize,
+}
+
+impl Default for MaxParamsOptions {
+  fn default() -> Self {
+    Self { max: 3 }
+  }
+}
+
+impl MaxParams {
+  pub fn with_options(options: MaxParamsOptions) -> Box<Self> {
+    Box::new(MaxParams { options })
+  }
+}
+
+impl LintRule for MaxParams {
+  fn new() -> Box<Self> {
+    Self::with_options(MaxParamsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = MaxParamsVisitor::new(context, self.options.max);
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces a maximum number of parameters in function definitions
+
+Functions taking many parameters are hard to call correctly, since the order of
+the arguments has to be remembered. Passing an options object instead makes the
+call sites self-describing.
+
+The limit is set with the `max` option, which defaults to `3`. Rest parameters
+count as one parameter, and a TypeScript `this` parameter is not counted.
+
+### Invalid:
+```typescript
+function foo(a, b, c, d) {}
+const bar = (a, b, c, ...rest) => {};
+```
+
+### Valid:
+```typescript
+function foo(a, b, c) {}
+function bar({ a, b, c, d }) {}
+```
+"#
+  }
+}
+
+struct MaxParamsVisitor<'c> {
+  context: &'c mut Context,
+  max: usize,
+}
+
+impl<'c> MaxParamsVisitor<'c> {
+  fn new(context: &'c mut Context, max: usize) -> Self {
+    Self { context, max }
+  }
+
+  fn check(&mut self, span: Span, count: usize) {
+    if count > self.max {
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        format!(
+          "Function has too many parameters ({}). Maximum allowed is {}.",
+          count, self.max
+        ),
+        HINT,
+      );
+    }
+  }
+}
+
+/// A TypeScript `this` parameter only declares the type of `this`.
+fn is_this_param(pat: &Pat) -> bool {
+  matches!(pat, Pat::Ident(ident) if ident.sym == *"this")
+}
+
+fn count_params(params: &[Param]) -> usize {
+  params.iter().filter(|p| !is_this_param(&p.pat)).count()
+}
+
+impl<'c> VisitAll for MaxParamsVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
+    self.check(function.span, count_params(&function.params));
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    self.check(arrow_expr.span, arrow_expr.params.len());
+  }
+
+  fn visit_constructor(
+    &mut self,
+    constructor: &Constructor,
+    _parent: &dyn Node,
+  ) {
+    self.check(constructor.span, constructor.params.len());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  fn message(count: usize) -> String {
+    format!(
+      "Function has too many parameters ({}). Maximum allowed is 3.",
+      count
+    )
+  }
+
+  #[test]
+  fn max_params_valid() {
+    assert_lint_ok! {
+      MaxParams,
+      "function foo() {}",
+      "function foo(a, b, c) {}",
+      "const foo = (a, b, c) => {};",
+      "const foo = function(a, b, ...c) {};",
+      "function foo({ a, b, c, d }) {}",
+      "function foo(this: Foo, a, b, c) {}",
+      "class Foo { constructor(a, b, c) {} bar(a, b, c) {} }",
+      "class Foo { constructor(private a: string, b, c) {} }",
+      "function foo(a = 1, b = 2, c = 3) {}",
+    };
+  }
+
+  #[test]
+  fn max_params_invalid() {
+    assert_lint_err! {
+      MaxParams,
+      "function foo(a, b, c, d) {}": [{ col: 0, message: message(4), hint: HINT }],
+      "const foo = (a, b, c, d) => {};": [{ col: 12, message: message(4), hint: HINT }],
+      "const foo = function(a, b, c, ...d) {};": [{ col: 12, message: message(4), hint: HINT }],
+      "function foo(a, b, c = 1, d = 2, e = 3) {}": [{ col: 0, message: message(5), hint: HINT }],
+      "class Foo { bar(a, b, c, d) {} }": [{ col: 12, message: message(4), hint: HINT }],
+      "class Foo { constructor(a, b, c, private d: string) {} }": [{ col: 12, message: message(4), hint: HINT }],
+      "function foo(a, b, c, d) { return (e, f, g, h) => {}; }": [
+        { col: 0, message: message(4), hint: HINT },
+        { col: 34, message: message(4), hint: HINT },
+      ],
+    };
+  }
+
+  #[test]
+  fn max_params_options() {
+    let rule = || MaxParams::with_options(MaxParamsOptions { max: 1 });
+    assert_lint_ok_with_rule(rule(), "function foo(a) {}");
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "function foo(a, b) {}\nconst bar = (...args) => {};\nconst baz = (a, ...rest) => {};",
+      vec![(1, 0), (3, 12)],
+    );
+  }
+}
@@ -0,0 +1,261 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  ArrowExpr, BlockStmt, Constructor, Function, Program,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct MaxStatements {
+  options: MaxStatementsOptions,
+}
+
+const CODE: &str = "max-statements";
+const HINT: &str = "Split the function into smaller functions";
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MaxStatementsOptions {
+  /// Maximum number of statements in a function.
+  pub max: usize,
+  /// Whether functions which are not nested in another function are left
+  /// unchecked.
+  pub ignore_top_level_functions: bool,
+}
+
+impl Default for MaxStatementsOptions {
+  fn default() -> Self {
+    Self {
+      max: 10,
+      ignore_top_level_functions: false,
+    }
+  }
+}
+
+impl MaxStatements {
+  pub fn with_options(options: MaxStatementsOptions) -> Box<Self> {
+    Box::new(MaxStatements { options })
+  }
+}
+
+impl LintRule for MaxStatements {
+  fn new() -> Box<Self> {
+    Self::with_options(MaxStatementsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = MaxStatementsVisitor::new(context, &self.options);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces a maximum number of statements per function
+
+Functions with many statements usually do too many things. This rule counts
+the statements in the blocks of each function and reports functions with more
+than the `max` option, which defaults to `10`. Statements of nested functions
+are counted for the nested function only.
+
+With the `ignoreTopLevelFunctions` option enabled, functions which are not
+nested in another function are not checked.
+
+### Invalid:
+```typescript
+// with `max` set to 2
+function foo() {
+  const a = 1;
+  const b = 2;
+  return a + b;
+}
+```
+
+### Valid:
+```typescript
+// with `max` set to 2
+function foo() {
+  const a = 1;
+  return function () {
+    const b = 2;
+    return a + b;
+  };
+}
+```
+"#
+  }
+}
+
+struct MaxStatementsVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o MaxStatementsOptions,
+  /// Statement counts of the enclosing functions, innermost last.
+  counts: Vec<usize>,
+}
+
+impl<'c, 'o> MaxStatementsVisitor<'c, 'o> {
+  fn new(context: &'c mut Context, options: &'o MaxStatementsOptions) -> Self {
+    Self {
+      context,
+      options,
+      counts: vec![],
+    }
+  }
+
+  fn with_function<F>(&mut self, span: Span, op: F)
+  where
+    F: FnOnce(&mut Self),
+  {
+    let is_top_level = self.counts.is_empty();
+    self.counts.push(0);
+    op(self);
+    let count = self.counts.pop().unwrap();
+
+    if is_top_level && self.options.ignore_top_level_functions {
+      return;
+    }
+    if count > self.options.max {
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        format!(
+          "Function has too many statements ({}). Maximum allowed is {}.",
+          count, self.options.max
+        ),
+        HINT,
+      );
+    }
+  }
+}
+
+impl<'c, 'o> Visit for MaxStatementsVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
+    self.with_function(function.span, |v| function.visit_children_with(v));
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    self.with_function(arrow_expr.span, |v| arrow_expr.visit_children_with(v));
+  }
+
+  fn visit_constructor(
+    &mut self,
+    constructor: &Constructor,
+    _parent: &dyn Node,
+  ) {
+    self
+      .with_function(constructor.span, |v| constructor.visit_children_with(v));
+  }
+
+  fn visit_block_stmt(&mut self, block_stmt: &BlockStmt, _parent: &dyn Node) {
+    if let Some(count) = self.counts.last_mut() {
+      *count += block_stmt.stmts.len();
+    }
+    block_stmt.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  fn options(max: usize) -> MaxStatementsOptions {
+    MaxStatementsOptions {
+      max,
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn max_statements_valid() {
+    assert_lint_ok! {
+      MaxStatements,
+      "function foo() { a(); b(); c(); d(); e(); f(); g(); h(); i(); j(); }",
+      "a(); b(); c(); d(); e(); f(); g(); h(); i(); j(); k(); l();",
+      "const foo = () => a;",
+    };
+
+    let with = MaxStatements::with_options;
+    assert_lint_ok_with_rule(with(options(2)), "function foo() { a(); b(); }");
+    assert_lint_ok_with_rule(
+      with(options(2)),
+      "function foo() { a(); return () => { b(); c(); }; }",
+    );
+    assert_lint_ok_with_rule(
+      with(options(2)),
+      "function foo() { a(); function bar() { b(); c(); } }",
+    );
+    assert_lint_ok_with_rule(
+      with(options(2)),
+      "class A { foo() { a(); b(); } constructor() { c(); d(); } }",
+    );
+    assert_lint_ok_with_rule(
+      with(MaxStatementsOptions {
+        max: 2,
+        ignore_top_level_functions: true,
+      }),
+      "function foo() { a(); b(); c(); }\nconst bar = () => { a(); b(); c(); };",
+    );
+  }
+
+  #[test]
+  fn max_statements_invalid() {
+    let with = MaxStatements::with_options;
+    assert_lint_err_on_line_n_with_rule(
+      with(options(2)),
+      "function foo() { a(); b(); c(); }",
+      vec![(1, 0)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      with(options(2)),
+      "function foo() { if (a) { b(); c(); } }",
+      vec![(1, 0)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      with(options(2)),
+      "const foo = () => { a(); b(); c(); };",
+      vec![(1, 12)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      with(options(2)),
+      "class A { constructor() { a(); b(); c(); } }",
+      vec![(1, 10)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      with(options(2)),
+      "function foo() {\n  a();\n  function bar() { b(); c(); d(); }\n}",
+      vec![(3, 2)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      with(MaxStatementsOptions {
+        max: 2,
+        ignore_top_level_functions: true,
+      }),
+      "function foo() {\n  a();\n  function bar() { b(); c(); d(); }\n}",
+      vec![(3, 2)],
+    );
+  }
+
+  #[test]
+  fn max_statements_message() {
+    assert_lint_err! {
+      MaxStatements,
+      "function foo() { a(); b(); c(); d(); e(); f(); g(); h(); i(); j(); k(); }": [
+        {
+          col: 0,
+          message: "Function has too many statements (11). Maximum allowed is 10.",
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -1,6 +1,7 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
+use swc_common::{Span, Spanned};
 use swc_ecmascript::ast::{Expr, NewExpr, ParenExpr};
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
@@ -77,12 +78,13 @@ impl<'c> NoAsyncPromiseExecutorVisitor<'c> {
   }
 }
 
-fn is_async_function(expr: &Expr) -> bool {
+/// Returns the span of `expr` if it's an async function.
+fn async_function_span(expr: &Expr) -> Option<Span> {
   match expr {
-    Expr::Fn(fn_expr) => fn_expr.function.is_async,
-    Expr::Arrow(arrow_expr) => arrow_expr.is_async,
-    Expr::Paren(ParenExpr { ref expr, .. }) => is_async_function(&**expr),
-    _ => false,
+    Expr::Fn(fn_expr) if fn_expr.function.is_async => Some(fn_expr.span()),
+    Expr::Arrow(arrow_expr) if arrow_expr.is_async => Some(arrow_expr.span),
+    Expr::Paren(ParenExpr { ref expr, .. }) => async_function_span(&**expr),
+    _ => None,
   }
 }
 
@@ -98,13 +100,10 @@ impl<'c> VisitAll for NoAsyncPromiseExecutorVisitor<'c> {
 
       if let Some(args) = &new_expr.args {
         if let Some(first_arg) = args.get(0) {
-          if is_async_function(&*first_arg.expr) {
-            self.context.add_diagnostic_with_hint(
-              new_expr.span,
-              CODE,
-              MESSAGE,
-              HINT,
-            );
+          if let Some(span) = async_function_span(&*first_arg.expr) {
+            self
+              .context
+              .add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
           }
         }
       }
@@ -125,6 +124,8 @@ mod tests {
       "new Promise((resolve, reject) => {}, async function unrelated() {})",
       "new Foo(async (resolve, reject) => {})",
       "new class { foo() { new Promise(function(resolve, reject) {}); } }",
+      "new Promise((resolve) => { setTimeout(async () => resolve(await foo())); });",
+      "new Promise(resolve);",
     };
   }
 
@@ -132,10 +133,11 @@ mod tests {
   fn no_async_promise_executor_invalid() {
     assert_lint_err! {
       NoAsyncPromiseExecutor,
-      "new Promise(async function(resolve, reject) {});": [{ col: 0, message: MESSAGE, hint: HINT }],
-      "new Promise(async function foo(resolve, reject) {});": [{ col: 0, message: MESSAGE, hint: HINT }],
-      "new Promise(async (resolve, reject) => {});": [{ col: 0, message: MESSAGE, hint: HINT }],
-      "new Promise(((((async () => {})))));": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "new Promise(async function(resolve, reject) {});": [{ col: 12, message: MESSAGE, hint: HINT }],
+      "new Promise(async function foo(resolve, reject) {});": [{ col: 12, message: MESSAGE, hint: HINT }],
+      "new Promise(async (resolve, reject) => {});": [{ col: 12, message: MESSAGE, hint: HINT }],
+      "new Promise(((((async () => {})))));": [{ col: 16, message: MESSAGE, hint: HINT }],
+      "new Promise(async (resolve) => {\n  resolve(await foo());\n});": [{ col: 12, message: MESSAGE, hint: HINT }],
       // nested
       r#"
 const a = new class {
@@ -143,7 +145,7 @@ const a = new class {
     let b = new Promise(async function(resolve, reject) {});
   }
 }
-      "#: [{ line: 4, col: 12, message: MESSAGE, hint: HINT }],
+      "#: [{ line: 4, col: 24, message: MESSAGE, hint: HINT }],
     }
   }
 }
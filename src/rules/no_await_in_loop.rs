@@ -33,7 +33,10 @@ impl LintRule for NoAwaitInLoop {
   }
 
   fn docs(&self) -> &'static str {
-    r#"Requires `await` is not used in a for loop body
+    r#"Requires `await` is not used in a loop body
+
+This applies to `for`, `for-in`, `for-of`, `while` and `do-while` loops, but
+not to functions defined in the body of a loop, which may run concurrently.
 
 Async and await are used in Javascript to provide parallel execution.  If each
 element in the for loop is waited upon using `await`, then this negates the
@@ -136,36 +139,46 @@ impl<'c> Visit for NoAwaitInLoopVisitor<'c> {
   }
 }
 
+/// Visits a loop, reporting all awaits which are run on every iteration.
 struct LoopVisitor<'a, 'b> {
   root_visitor: &'b mut NoAwaitInLoopVisitor<'a>,
+  /// Whether the loop being visited is nested in the loop the visitor was
+  /// created for. The init of a `for` loop and the iterated object of a
+  /// `for-in` or `for-of` loop are only evaluated once, unless the loop is
+  /// nested.
+  in_loop: bool,
 }
 
 impl<'a, 'b> LoopVisitor<'a, 'b> {
   fn new(root_visitor: &'b mut NoAwaitInLoopVisitor<'a>) -> Self {
-    Self { root_visitor }
+    Self {
+      root_visitor,
+      in_loop: false,
+    }
   }
 }
 
 impl<'a, 'b> Visit for LoopVisitor<'a, 'b> {
+  noop_visit_type!();
+
   fn visit_function(&mut self, func: &Function, parent: &dyn Node) {
-    let mut func_visitor = if func.is_async {
-      FunctionVisitor::new(self.root_visitor, true)
-    } else {
-      FunctionVisitor::new(self.root_visitor, false)
-    };
+    let mut func_visitor =
+      FunctionVisitor::new(self.root_visitor, func.is_async);
     func_visitor.visit_function(func, parent);
   }
 
   fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, parent: &dyn Node) {
-    let mut func_visitor = if arrow_expr.is_async {
-      FunctionVisitor::new(self.root_visitor, true)
-    } else {
-      FunctionVisitor::new(self.root_visitor, false)
-    };
+    let mut func_visitor =
+      FunctionVisitor::new(self.root_visitor, arrow_expr.is_async);
     func_visitor.visit_arrow_expr(arrow_expr, parent);
   }
 
   fn visit_for_stmt(&mut self, for_stmt: &ForStmt, parent: &dyn Node) {
+    if self.in_loop {
+      swc_ecmascript::visit::visit_for_stmt(self, for_stmt, parent);
+      return;
+    }
+    self.in_loop = true;
     if let Some(test) = for_stmt.test.as_ref() {
       self.visit_expr(&**test, parent);
     }
@@ -177,15 +190,43 @@ impl<'a, 'b> Visit for LoopVisitor<'a, 'b> {
   }
 
   fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, parent: &dyn Node) {
+    if self.in_loop {
+      // Awaits each element on every iteration of the enclosing loop.
+      if for_of_stmt.await_token.is_some() {
+        self.root_visitor.add_diagnostic(for_of_stmt.span);
+      }
+      swc_ecmascript::visit::visit_for_of_stmt(self, for_of_stmt, parent);
+      return;
+    }
+    self.in_loop = true;
     let body = &*for_of_stmt.body;
     self.visit_stmt(body, parent);
   }
 
   fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, parent: &dyn Node) {
+    if self.in_loop {
+      swc_ecmascript::visit::visit_for_in_stmt(self, for_in_stmt, parent);
+      return;
+    }
+    self.in_loop = true;
     let body = &*for_in_stmt.body;
     self.visit_stmt(body, parent);
   }
 
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, parent: &dyn Node) {
+    self.in_loop = true;
+    swc_ecmascript::visit::visit_while_stmt(self, while_stmt, parent);
+  }
+
+  fn visit_do_while_stmt(
+    &mut self,
+    do_while_stmt: &DoWhileStmt,
+    parent: &dyn Node,
+  ) {
+    self.in_loop = true;
+    swc_ecmascript::visit::visit_do_while_stmt(self, do_while_stmt, parent);
+  }
+
   fn visit_await_expr(&mut self, await_expr: &AwaitExpr, parent: &dyn Node) {
     self.root_visitor.add_diagnostic(await_expr.span);
     swc_ecmascript::visit::visit_await_expr(self, await_expr, parent);
@@ -502,6 +543,37 @@ async function foo() {
   }
 }
       "#: [{ line: 6, col: 6, message: MESSAGE, hint: HINT }],
+      r#"
+async function foo() {
+  for (const thing of things) {
+    for (const x of await bar(thing)) {
+      baz(x);
+    }
+  }
+}
+      "#: [{ line: 4, col: 20, message: MESSAGE, hint: HINT }],
+      r#"
+async function foo() {
+  while (true) {
+    for (let i = await bar(); i < 42; i++) {}
+  }
+}
+      "#: [{ line: 4, col: 17, message: MESSAGE, hint: HINT }],
+      r#"
+async function foo() {
+  for (const thing of things) {
+    for await (const x of thing) {}
+  }
+}
+      "#: [{ line: 4, col: 4, message: MESSAGE, hint: HINT }],
+      r#"
+async function foo() {
+  for (const thing of things) {
+    results.push(await bar(thing));
+    const a = async () => await bar(thing);
+  }
+}
+      "#: [{ line: 4, col: 17, message: MESSAGE, hint: HINT }],
     }
   }
 }
@@ -1,14 +1,11 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
+use crate::combined_visitor::{run_node_rule, AstNode, NodeKind, NodeRule};
 use swc_ecmascript::ast::Decl;
 use swc_ecmascript::ast::Stmt;
 use swc_ecmascript::ast::SwitchCase;
 use swc_ecmascript::ast::VarDeclKind;
-use swc_ecmascript::visit::noop_visit_type;
-use swc_ecmascript::visit::Node;
-use swc_ecmascript::visit::VisitAll;
-use swc_ecmascript::visit::VisitAllWith;
 
 pub struct NoCaseDeclarations;
 
@@ -34,8 +31,11 @@ impl LintRule for NoCaseDeclarations {
     context: &mut Context,
     program: &swc_ecmascript::ast::Program,
   ) {
-    let mut visitor = NoCaseDeclarationsVisitor::new(context);
-    program.visit_all_with(program, &mut visitor);
+    run_node_rule(self, context, program);
+  }
+
+  fn node_rule(&self) -> Option<&dyn NodeRule> {
+    Some(self)
   }
 
   fn docs(&self) -> &'static str {
@@ -91,43 +91,32 @@ switch (choice) {
   }
 }
 
-struct NoCaseDeclarationsVisitor<'c> {
-  context: &'c mut Context,
-}
+impl NodeRule for NoCaseDeclarations {
+  fn node_kinds(&self) -> &'static [NodeKind] {
+    &[NodeKind::SwitchCase]
+  }
 
-impl<'c> NoCaseDeclarationsVisitor<'c> {
-  fn new(context: &'c mut Context) -> Self {
-    Self { context }
+  fn check_node(&self, context: &mut Context, node: AstNode) {
+    if let AstNode::SwitchCase(node) = node {
+      check_switch_case(context, node);
+    }
   }
 }
 
-impl<'c> VisitAll for NoCaseDeclarationsVisitor<'c> {
-  noop_visit_type!();
-
-  fn visit_switch_case(
-    &mut self,
-    switch_case: &SwitchCase,
-    _parent: &dyn Node,
-  ) {
-    for stmt in &switch_case.cons {
-      let is_lexical_decl = match stmt {
-        Stmt::Decl(decl) => match &decl {
-          Decl::Fn(_) => true,
-          Decl::Class(_) => true,
-          Decl::Var(var_decl) => var_decl.kind != VarDeclKind::Var,
-          _ => false,
-        },
+fn check_switch_case(context: &mut Context, switch_case: &SwitchCase) {
+  for stmt in &switch_case.cons {
+    let is_lexical_decl = match stmt {
+      Stmt::Decl(decl) => match &decl {
+        Decl::Fn(_) => true,
+        Decl::Class(_) => true,
+        Decl::Var(var_decl) => var_decl.kind != VarDeclKind::Var,
         _ => false,
-      };
+      },
+      _ => false,
+    };
 
-      if is_lexical_decl {
-        self.context.add_diagnostic_with_hint(
-          switch_case.span,
-          CODE,
-          MESSAGE,
-          HINT,
-        );
-      }
+    if is_lexical_decl {
+      context.add_diagnostic_with_hint(switch_case.span, CODE, MESSAGE, HINT);
     }
   }
 }
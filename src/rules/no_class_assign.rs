@@ -2,7 +2,10 @@
 use super::Context;
 use super::LintRule;
 use crate::{scopes::BindingKind, swc_util::find_lhs_ids};
-use swc_ecmascript::ast::AssignExpr;
+use swc_common::Span;
+use swc_ecmascript::ast::{AssignExpr, Expr, UpdateExpr};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::utils::Id;
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::VisitAll;
@@ -12,7 +15,8 @@ pub struct NoClassAssign;
 
 const CODE: &str = "no-class-assign";
 const MESSAGE: &str = "Reassigning class declaration is not allowed";
-const HINT: &str = "Do you have the right variable here?";
+const HINT: &str =
+  "Class bindings should be treated as constants, use a separate variable instead";
 
 impl LintRule for NoClassAssign {
   fn new() -> Box<Self> {
@@ -67,6 +71,17 @@ impl<'c> NoClassAssignVisitor<'c> {
   fn new(context: &'c mut Context) -> Self {
     Self { context }
   }
+
+  fn check(&mut self, span: Span, id: &Id) {
+    let var = self.context.scope.var(id);
+    if let Some(var) = var {
+      if let BindingKind::Class = var.kind() {
+        self
+          .context
+          .add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
+      }
+    }
+  }
 }
 
 impl<'c> VisitAll for NoClassAssignVisitor<'c> {
@@ -75,17 +90,13 @@ impl<'c> VisitAll for NoClassAssignVisitor<'c> {
   fn visit_assign_expr(&mut self, assign_expr: &AssignExpr, _node: &dyn Node) {
     let ids = find_lhs_ids(&assign_expr.left);
     for id in ids {
-      let var = self.context.scope.var(&id);
-      if let Some(var) = var {
-        if let BindingKind::Class = var.kind() {
-          self.context.add_diagnostic_with_hint(
-            assign_expr.span,
-            CODE,
-            MESSAGE,
-            HINT,
-          );
-        }
-      }
+      self.check(assign_expr.span, &id);
+    }
+  }
+
+  fn visit_update_expr(&mut self, update_expr: &UpdateExpr, _node: &dyn Node) {
+    if let Expr::Ident(ident) = &*update_expr.arg {
+      self.check(update_expr.span, &ident.to_id());
     }
   }
 }
@@ -140,6 +151,8 @@ A = class {
       r#"const x = 0;"#,
       r#"function x() {} x = 1;"#,
       r#"function foo(x) { x = 1; }"#,
+      r#"let x = 0; x++;"#,
+      r#"class A { foo(A) { A++; } }"#,
       r#"try {} catch (x) { x = 1; }"#,
     };
   }
@@ -253,6 +266,42 @@ A = class {
           message: MESSAGE,
           hint: HINT,
         }
+      ],
+      r#"
+class A {}
+A++;
+      "#: [
+        {
+          line: 3,
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      r#"
+class A {
+  static foo() {
+    --A;
+  }
+}
+      "#: [
+        {
+          line: 4,
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      r#"
+class A {}
+A += 1;
+      "#: [
+        {
+          line: 3,
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
       ]
     };
   }
@@ -1,5 +1,6 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule};
+use crate::combined_visitor::{run_node_rule, AstNode, NodeKind, NodeRule};
 use derive_more::Display;
 use swc_ecmascript::ast::BinaryOp::*;
 use swc_ecmascript::ast::Expr::{Lit, Unary};
@@ -7,7 +8,6 @@ use swc_ecmascript::ast::Lit::Num;
 use swc_ecmascript::ast::UnaryExpr;
 use swc_ecmascript::ast::UnaryOp::Minus;
 use swc_ecmascript::ast::{BinExpr, BinaryOp, Expr, Program};
-use swc_ecmascript::visit::{noop_visit_type, Node, VisitAll, VisitAllWith};
 
 pub struct NoCompareNegZero;
 
@@ -41,8 +41,11 @@ impl LintRule for NoCompareNegZero {
   }
 
   fn lint_program(&self, context: &mut Context, program: &Program) {
-    let mut visitor = NoCompareNegZeroVisitor::new(context);
-    program.visit_all_with(program, &mut visitor);
+    run_node_rule(self, context, program);
+  }
+
+  fn node_rule(&self) -> Option<&dyn NodeRule> {
+    Some(self)
   }
 
   fn docs(&self) -> &'static str {
@@ -64,32 +67,30 @@ if (Object.is(x, -0)) {}
   }
 }
 
-struct NoCompareNegZeroVisitor<'c> {
-  context: &'c mut Context,
-}
+impl NodeRule for NoCompareNegZero {
+  fn node_kinds(&self) -> &'static [NodeKind] {
+    &[NodeKind::BinExpr]
+  }
 
-impl<'c> NoCompareNegZeroVisitor<'c> {
-  fn new(context: &'c mut Context) -> Self {
-    Self { context }
+  fn check_node(&self, context: &mut Context, node: AstNode) {
+    if let AstNode::BinExpr(node) = node {
+      check_bin_expr(context, node);
+    }
   }
 }
 
-impl<'c> VisitAll for NoCompareNegZeroVisitor<'c> {
-  noop_visit_type!();
-
-  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
-    if !bin_expr.op.is_comparator() {
-      return;
-    }
+fn check_bin_expr(context: &mut Context, bin_expr: &BinExpr) {
+  if !bin_expr.op.is_comparator() {
+    return;
+  }
 
-    if bin_expr.left.is_neg_zero() || bin_expr.right.is_neg_zero() {
-      self.context.add_diagnostic_with_hint(
-        bin_expr.span,
-        CODE,
-        NoCompareNegZeroMessage::Unexpected,
-        NoCompareNegZeroHint::ObjectIs,
-      );
-    }
+  if bin_expr.left.is_neg_zero() || bin_expr.right.is_neg_zero() {
+    context.add_diagnostic_with_hint(
+      bin_expr.span,
+      CODE,
+      NoCompareNegZeroMessage::Unexpected,
+      NoCompareNegZeroHint::ObjectIs,
+    );
   }
 }
 
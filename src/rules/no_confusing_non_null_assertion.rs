@@ -0,0 +1,191 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  AssignExpr, AssignOp, BinExpr, BinaryOp, Expr, Pat, PatOrExpr, Program,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct NoConfusingNonNullAssertion;
+
+const CODE: &str = "no-confusing-non-null-assertion";
+
+fn message(op: &str) -> String {
+  let look_alike = if op == "=" { "!=" } else { "!==" };
+  format!(
+    "Non-null assertion followed by `{}` looks like the `{}` operator",
+    op, look_alike
+  )
+}
+
+fn hint(op: &str) -> String {
+  format!(
+    "Wrap the left side in parentheses, e.g. `(a!) {} b`, or remove the assertion",
+    op
+  )
+}
+
+impl LintRule for NoConfusingNonNullAssertion {
+  fn new() -> Box<Self> {
+    Box::new(NoConfusingNonNullAssertion)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = NoConfusingNonNullAssertionVisitor { context };
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows non-null assertions right before an equality or assignment operator
+
+A non-null assertion (`!`) at the end of the left side of `==`, `===` or `=`
+reads like part of a `!=` or `!==` operator, so `a! == b` is easily mistaken
+for `a !== b`.
+
+### Invalid:
+```typescript
+a! == b;
+a! === b;
+a.b! = c;
+```
+
+### Valid:
+```typescript
+(a!) == b;
+a == b!;
+a !== b;
+a! + b === c;
+```
+"#
+  }
+}
+
+struct NoConfusingNonNullAssertionVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoConfusingNonNullAssertionVisitor<'c> {
+  fn report(&mut self, span: Span, op: &str) {
+    self
+      .context
+      .add_diagnostic_with_hint(span, CODE, message(op), hint(op));
+  }
+}
+
+impl<'c> VisitAll for NoConfusingNonNullAssertionVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+    if matches!(bin_expr.op, BinaryOp::EqEq | BinaryOp::EqEqEq)
+      && matches!(&*bin_expr.left, Expr::TsNonNull(_))
+    {
+      self.report(bin_expr.span, bin_expr.op.as_str());
+    }
+  }
+
+  fn visit_assign_expr(
+    &mut self,
+    assign_expr: &AssignExpr,
+    _parent: &dyn Node,
+  ) {
+    if assign_expr.op != AssignOp::Assign {
+      return;
+    }
+    let left = match &assign_expr.left {
+      PatOrExpr::Expr(expr) => expr,
+      PatOrExpr::Pat(pat) => match &**pat {
+        Pat::Expr(expr) => expr,
+        _ => return,
+      },
+    };
+    if matches!(&**left, Expr::TsNonNull(_)) {
+      self.report(assign_expr.span, assign_expr.op.as_str());
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_confusing_non_null_assertion_valid() {
+    assert_lint_ok! {
+      NoConfusingNonNullAssertion,
+      "a == b;",
+      "a != b;",
+      "a !== b;",
+      "a!==b;",
+      "(a!) == b;",
+      "(a!) === b;",
+      "(a.b!) = c;",
+      "a == b!;",
+      "a === b!;",
+      "a! + b === c;",
+      "a!.b == c;",
+      "a! += b;",
+      "a! < b;",
+    };
+  }
+
+  #[test]
+  fn no_confusing_non_null_assertion_invalid() {
+    assert_lint_err! {
+      NoConfusingNonNullAssertion,
+      "a! == b;": [
+        {
+          col: 0,
+          message: message("=="),
+          hint: hint("=="),
+        }
+      ],
+      "a! === b;": [
+        {
+          col: 0,
+          message: message("==="),
+          hint: hint("==="),
+        }
+      ],
+      "a.b! == c;": [
+        {
+          col: 0,
+          message: message("=="),
+          hint: hint("=="),
+        }
+      ],
+      "a! = b;": [
+        {
+          col: 0,
+          message: message("="),
+          hint: hint("="),
+        }
+      ],
+      "a.b! = c;": [
+        {
+          col: 0,
+          message: message("="),
+          hint: hint("="),
+        }
+      ],
+      "if (a! == b) {}": [
+        {
+          col: 4,
+          message: message("=="),
+          hint: hint("=="),
+        }
+      ],
+    };
+  }
+}
@@ -9,8 +9,11 @@ use swc_ecmascript::ast::ObjectPatProp;
 use swc_ecmascript::ast::Pat;
 use swc_ecmascript::ast::PatOrExpr;
 use swc_ecmascript::ast::{Ident, UpdateExpr};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
-use swc_ecmascript::{utils::ident::IdentLike, visit::Visit};
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
 
 pub struct NoConstAssign;
 
@@ -29,7 +32,7 @@ impl LintRule for NoConstAssign {
     program: &swc_ecmascript::ast::Program,
   ) {
     let mut visitor = NoConstAssignVisitor::new(context);
-    visitor.visit_program(program, program);
+    program.visit_all_with(program, &mut visitor);
   }
 
   fn docs(&self) -> &'static str {
@@ -81,6 +84,9 @@ impl<'c> NoConstAssignVisitor<'c> {
       Pat::Object(object) => {
         self.check_obj_pat(object, span);
       }
+      Pat::Rest(rest) => {
+        self.check_pat(&rest.arg, span);
+      }
       _ => {}
     }
   }
@@ -96,6 +102,8 @@ impl<'c> NoConstAssignVisitor<'c> {
           self.check_scope_for_const(assign_prop.key.span, &assign_prop.key);
         } else if let ObjectPatProp::KeyValue(kv_prop) = prop {
           self.check_pat(&kv_prop.value, span);
+        } else if let ObjectPatProp::Rest(rest) = prop {
+          self.check_pat(&rest.arg, span);
         }
       }
     }
@@ -130,7 +138,9 @@ impl<'c> NoConstAssignVisitor<'c> {
   }
 }
 
-impl<'c> Visit for NoConstAssignVisitor<'c> {
+impl<'c> VisitAll for NoConstAssignVisitor<'c> {
+  noop_visit_type!();
+
   fn visit_assign_expr(&mut self, assign_expr: &AssignExpr, _node: &dyn Node) {
     match &assign_expr.left {
       PatOrExpr::Expr(pat_expr) => {
@@ -326,5 +336,23 @@ declare module "foo" {
       "const x = 0  ; x = 1; x = 2;",
       vec![15, 22],
     );
+    assert_lint_err::<NoConstAssign>("const x = 0; x--;", 13);
+    assert_lint_err::<NoConstAssign>("const x = 0; [x] = [1];", 13);
+    assert_lint_err::<NoConstAssign>("const x = 0; [, ...x] = [1];", 13);
+    assert_lint_err::<NoConstAssign>("const x = 0; ({...x} = {});", 14);
+    assert_lint_err::<NoConstAssign>("const x = 0; [{a: x}] = [{a: 1}];", 13);
+    assert_lint_err::<NoConstAssign>("let y; const x = 0; y = x = 1;", 24);
+    assert_lint_err::<NoConstAssign>(
+      "const x = 0; const inc = () => { x++; };",
+      33,
+    );
+    assert_lint_err::<NoConstAssign>(
+      "const x = 0; let f; f = () => { x = 1; };",
+      32,
+    );
+    assert_lint_err::<NoConstAssign>(
+      "const x = 0; [1].forEach(function() { x += 1; });",
+      38,
+    );
   }
 }
@@ -1,19 +1,22 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
-use std::cmp::Ordering;
-use std::collections::BTreeMap;
+use std::collections::HashMap;
 use swc_common::Span;
 use swc_ecmascript::ast::{
-  BigInt, Bool, Class, ClassMethod, ComputedPropName, Expr, Ident, Lit,
+  BigInt, Bool, Class, ClassMember, ComputedPropName, Expr, Ident, Lit,
   MethodKind, Null, Number, PropName, Str, Tpl,
 };
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
 
 pub struct NoDupeClassMembers;
 
+const CODE: &str = "no-dupe-class-members";
+const HINT: &str = "Rename or remove the member with the duplicated name";
+
 impl LintRule for NoDupeClassMembers {
   fn new() -> Box<Self> {
     Box::new(NoDupeClassMembers)
@@ -24,7 +27,7 @@ impl LintRule for NoDupeClassMembers {
   }
 
   fn code(&self) -> &'static str {
-    "no-dupe-class-members"
+    CODE
   }
 
   fn lint_program(
@@ -37,17 +40,26 @@ impl LintRule for NoDupeClassMembers {
   }
 
   fn docs(&self) -> &'static str {
-    r#"Disallows using a class member function name more than once
+    r#"Disallows using a class member name more than once
+
+Declaring a method or property of the same name twice in a class will cause the
+previous declaration(s) to be overwritten, causing unexpected behaviors.
+
+Static and instance members live on different objects, so they don't clash with
+each other. A single getter and a single setter of the same name are also
+allowed, since together they define one accessor property.
 
-Declaring a function of the same name twice in a class will cause the previous
-declaration(s) to be overwritten, causing unexpected behaviors.
-    
 ### Invalid:
 ```typescript
 class Foo {
   bar() {}
   bar() {}
 }
+
+class Baz {
+  qux = 1;
+  get qux() {}
+}
 ```
 
 ### Valid:
@@ -56,6 +68,12 @@ class Foo {
   bar() {}
   fizz() {}
 }
+
+class Baz {
+  static qux() {}
+  get qux() {}
+  set qux(value) {}
+}
 ```
 "#
   }
@@ -73,162 +91,191 @@ impl<'c> NoDupeClassMembersVisitor<'c> {
   fn add_diagnostic(&mut self, span: Span, name: &str) {
     self.context.add_diagnostic_with_hint(
       span,
-      "no-dupe-class-members",
+      CODE,
       format!("Duplicate name '{}'", name),
-      "Rename or remove the function with the duplicated name",
+      HINT,
     );
   }
+
+  fn check_members(&mut self, members: &[ClassMember]) {
+    let mut appeared: HashMap<MemberKey, AppearedKinds> = HashMap::new();
+
+    for member in members {
+      let (span, key, kind) = match member_to_check(member) {
+        Some(m) => m,
+        None => continue,
+      };
+
+      let name = key.display_name();
+      let appeared_kinds = appeared.entry(key).or_default();
+      if appeared_kinds.conflicts_with(kind) {
+        self.add_diagnostic(span, &name);
+      }
+      appeared_kinds.add(kind);
+    }
+  }
 }
 
 impl<'c> Visit for NoDupeClassMembersVisitor<'c> {
   noop_visit_type!();
 
-  fn visit_class(&mut self, class: &Class, parent: &dyn Node) {
-    let mut visitor = ClassVisitor::new(self);
-    visitor.visit_class(class, parent);
-    visitor.aggregate_dupes();
+  fn visit_class(&mut self, class: &Class, _parent: &dyn Node) {
+    self.check_members(&class.body);
+    class.visit_children_with(self);
   }
 }
 
-struct ClassVisitor<'a, 'b> {
-  root_visitor: &'b mut NoDupeClassMembersVisitor<'a>,
-  appeared_methods: BTreeMap<MethodToCheck, Vec<(Span, String)>>,
+/// Identifies class members that overwrite each other.
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct MemberKey {
+  normalized_name: String,
+  is_static: bool,
+  is_private: bool,
 }
 
-impl<'a, 'b> ClassVisitor<'a, 'b> {
-  fn new(root_visitor: &'b mut NoDupeClassMembersVisitor<'a>) -> Self {
-    Self {
-      root_visitor,
-      appeared_methods: BTreeMap::new(),
+impl MemberKey {
+  fn display_name(&self) -> String {
+    if self.is_private {
+      format!("#{}", self.normalized_name)
+    } else {
+      self.normalized_name.clone()
     }
   }
+}
 
-  fn aggregate_dupes(&mut self) {
-    let root_visitor = &mut self.root_visitor;
-
-    self
-      .appeared_methods
-      .values()
-      .filter(|m| m.len() >= 2)
-      .flatten()
-      .for_each(|(span, name)| {
-        root_visitor.add_diagnostic(*span, name);
-      });
-  }
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MemberKind {
+  Getter,
+  Setter,
+  /// Methods and properties.
+  Value,
 }
 
-impl<'a, 'b> Visit for ClassVisitor<'a, 'b> {
-  noop_visit_type!();
+#[derive(Default)]
+struct AppearedKinds {
+  getter: bool,
+  setter: bool,
+  value: bool,
+}
 
-  fn visit_class(&mut self, class: &Class, parent: &dyn Node) {
-    let mut visitor = ClassVisitor::new(self.root_visitor);
-    swc_ecmascript::visit::visit_class(&mut visitor, class, parent);
-    visitor.aggregate_dupes();
+impl AppearedKinds {
+  fn conflicts_with(&self, kind: MemberKind) -> bool {
+    match kind {
+      MemberKind::Getter => self.getter || self.value,
+      MemberKind::Setter => self.setter || self.value,
+      MemberKind::Value => self.getter || self.setter || self.value,
+    }
   }
 
-  fn visit_class_method(
-    &mut self,
-    class_method: &ClassMethod,
-    parent: &dyn Node,
-  ) {
-    if class_method.function.body.is_some() {
-      if let Some(m) = MethodToCheck::new(
-        &class_method.key,
-        class_method.kind,
-        class_method.is_static,
-      ) {
-        let name = m.normalized_name.clone();
-        self
-          .appeared_methods
-          .entry(m)
-          .or_insert_with(Vec::new)
-          .push((class_method.span, name));
-      }
+  fn add(&mut self, kind: MemberKind) {
+    match kind {
+      MemberKind::Getter => self.getter = true,
+      MemberKind::Setter => self.setter = true,
+      MemberKind::Value => self.value = true,
     }
-    swc_ecmascript::visit::visit_class_method(self, class_method, parent);
   }
 }
 
-fn normalize_prop_name(name: &PropName) -> Option<String> {
-  let normalized = match *name {
-    PropName::Ident(Ident { ref sym, .. }) => sym.to_string(),
-    PropName::Str(Str { ref value, .. }) => value.to_string(),
-    PropName::Num(Number { ref value, .. }) => value.to_string(),
-    PropName::Computed(ComputedPropName { ref expr, .. }) => match &**expr {
-      Expr::Lit(Lit::Str(Str { ref value, .. })) => value.to_string(),
-      Expr::Lit(Lit::Bool(Bool { ref value, .. })) => value.to_string(),
-      Expr::Lit(Lit::Null(Null { .. })) => "null".to_string(),
-      Expr::Lit(Lit::Num(Number { ref value, .. })) => value.to_string(),
-      Expr::Lit(Lit::BigInt(BigInt { ref value, .. })) => value.to_string(),
-      Expr::Tpl(Tpl {
-        ref quasis,
-        ref exprs,
-        ..
-      }) if exprs.is_empty() => {
-        quasis.iter().next().map(|q| q.raw.value.to_string())?
-      }
-      _ => return None,
-    },
-  };
-
-  Some(normalized)
+fn method_kind(kind: MethodKind) -> MemberKind {
+  match kind {
+    MethodKind::Getter => MemberKind::Getter,
+    MethodKind::Setter => MemberKind::Setter,
+    MethodKind::Method => MemberKind::Value,
+  }
 }
 
-struct MethodToCheck {
-  normalized_name: String,
-  kind: MethodKind,
-  is_static: bool,
-}
+/// Returns the span, key and kind of a member which can be duplicated, or
+/// `None` if the member's name can't be determined statically or it doesn't
+/// define anything at runtime (e.g. an overload signature).
+fn member_to_check(
+  member: &ClassMember,
+) -> Option<(Span, MemberKey, MemberKind)> {
+  let (span, normalized_name, is_static, is_private, kind) = match member {
+    ClassMember::Method(method) => {
+      method.function.body.as_ref()?;
+      (
+        method.span,
+        normalize_prop_name(&method.key)?,
+        method.is_static,
+        false,
+        method_kind(method.kind),
+      )
+    }
+    ClassMember::PrivateMethod(method) => {
+      method.function.body.as_ref()?;
+      (
+        method.span,
+        method.key.id.sym.to_string(),
+        method.is_static,
+        true,
+        method_kind(method.kind),
+      )
+    }
+    ClassMember::ClassProp(prop) => {
+      if prop.declare {
+        return None;
+      }
+      (
+        prop.span,
+        normalize_expr_key(&prop.key, prop.computed)?,
+        prop.is_static,
+        false,
+        MemberKind::Value,
+      )
+    }
+    ClassMember::PrivateProp(prop) => (
+      prop.span,
+      prop.key.id.sym.to_string(),
+      prop.is_static,
+      true,
+      MemberKind::Value,
+    ),
+    _ => return None,
+  };
 
-impl MethodToCheck {
-  fn new(name: &PropName, kind: MethodKind, is_static: bool) -> Option<Self> {
-    let normalized_name = normalize_prop_name(name)?;
-    Some(Self {
+  Some((
+    span,
+    MemberKey {
       normalized_name,
-      kind,
       is_static,
-    })
-  }
+      is_private,
+    },
+    kind,
+  ))
 }
 
-impl PartialEq for MethodToCheck {
-  fn eq(&self, other: &Self) -> bool {
-    if self.normalized_name != other.normalized_name {
-      return false;
-    }
-
-    if self.is_static != other.is_static {
-      return false;
+fn normalize_prop_name(name: &PropName) -> Option<String> {
+  let normalized = match *name {
+    PropName::Ident(Ident { ref sym, .. }) => sym.to_string(),
+    PropName::Str(Str { ref value, .. }) => value.to_string(),
+    PropName::Num(Number { ref value, .. }) => value.to_string(),
+    PropName::Computed(ComputedPropName { ref expr, .. }) => {
+      return normalize_expr_key(expr, true)
     }
+  };
 
-    !matches!(
-      (self.kind, other.kind),
-      (MethodKind::Getter, MethodKind::Setter)
-        | (MethodKind::Setter, MethodKind::Getter)
-    )
-  }
+  Some(normalized)
 }
 
-impl Eq for MethodToCheck {}
-
-impl PartialOrd for MethodToCheck {
-  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-    Some(self.cmp(other))
-  }
-}
+fn normalize_expr_key(expr: &Expr, computed: bool) -> Option<String> {
+  let normalized = match expr {
+    Expr::Ident(Ident { ref sym, .. }) if !computed => sym.to_string(),
+    Expr::Lit(Lit::Str(Str { ref value, .. })) => value.to_string(),
+    Expr::Lit(Lit::Bool(Bool { ref value, .. })) => value.to_string(),
+    Expr::Lit(Lit::Null(Null { .. })) => "null".to_string(),
+    Expr::Lit(Lit::Num(Number { ref value, .. })) => value.to_string(),
+    Expr::Lit(Lit::BigInt(BigInt { ref value, .. })) => value.to_string(),
+    Expr::Tpl(Tpl {
+      ref quasis,
+      ref exprs,
+      ..
+    }) if exprs.is_empty() => {
+      quasis.iter().next().map(|q| q.raw.value.to_string())?
+    }
+    _ => return None,
+  };
 
-impl Ord for MethodToCheck {
-  fn cmp(&self, other: &Self) -> Ordering {
-    self
-      .normalized_name
-      .cmp(&other.normalized_name)
-      .then(self.is_static.cmp(&other.is_static))
-      .then_with(|| match (self.kind, other.kind) {
-        (MethodKind::Getter, MethodKind::Setter) => Ordering::Less,
-        (MethodKind::Setter, MethodKind::Getter) => Ordering::Greater,
-        _ => Ordering::Equal,
-      })
-  }
+  Some(normalized)
 }
 
 #[cfg(test)]
@@ -415,6 +462,40 @@ class Foo {
   bar(v1: number | string, v2?: boolean): number | string {}
 }
       "#,
+      "class Foo { bar = 1; static bar = 2; }",
+      "class Foo { #bar = 1; bar = 2; }",
+      "class Foo { #bar() {} static #baz() {} baz() {} }",
+      "class Foo { get #bar() {} set #bar(v) {} }",
+      "class Foo { [bar] = 1; [bar] = 2; }",
+      "class Foo { declare bar: number; bar() {} }",
+    };
+  }
+
+  #[test]
+  fn no_dupe_class_members_properties() {
+    assert_lint_err! {
+      NoDupeClassMembers,
+      "class Foo { bar = 1; bar = 2; }": [
+        { col: 21, message: "Duplicate name 'bar'", hint: HINT }
+      ],
+      "class Foo { bar = 1; bar() {} }": [
+        { col: 21, message: "Duplicate name 'bar'", hint: HINT }
+      ],
+      "class Foo { static ['bar'] = 1; static bar() {} }": [
+        { col: 32, message: "Duplicate name 'bar'", hint: HINT }
+      ],
+      "class Foo { get bar() {} bar = 1; }": [
+        { col: 25, message: "Duplicate name 'bar'", hint: HINT }
+      ],
+      "class Foo { #bar = 1; #bar() {} }": [
+        { col: 22, message: "Duplicate name '#bar'", hint: HINT }
+      ],
+      "class Foo { get bar() {} set bar(v) {} get bar() {} }": [
+        { col: 39, message: "Duplicate name 'bar'", hint: HINT }
+      ],
+      "class Foo { static get bar() {} static set bar(v) {} static bar = 1; }": [
+        { col: 53, message: "Duplicate name 'bar'", hint: HINT }
+      ],
     };
   }
 
@@ -427,7 +508,7 @@ class Foo {
   bar() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -437,7 +518,7 @@ class Foo {
   bar() {}
 };
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -447,7 +528,7 @@ class Foo {
   'bar'() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -457,7 +538,7 @@ class Foo {
   1e1() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -467,7 +548,7 @@ class Foo {
   ['bar']() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -477,7 +558,7 @@ class Foo {
   static bar() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -487,7 +568,7 @@ class Foo {
   set ['bar'](val: number) {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -497,7 +578,7 @@ class Foo {
   ['']() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -507,7 +588,7 @@ class Foo {
   [`bar`]() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -517,7 +598,7 @@ class Foo {
   static get ['bar']() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -527,7 +608,7 @@ class Foo {
   [`bar`]() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -537,7 +618,7 @@ class Foo {
   'bar'() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -547,7 +628,7 @@ class Foo {
   static [`bar`]() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -557,7 +638,7 @@ class Foo {
   ['constructor']() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -567,7 +648,7 @@ class Foo {
   static ['constructor']() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -577,7 +658,7 @@ class Foo {
   [123]() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -587,7 +668,7 @@ class Foo {
   16() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -597,7 +678,7 @@ class Foo {
   [1e2]() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -607,7 +688,7 @@ class Foo {
   [`123`]() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -617,7 +698,7 @@ class Foo {
   static [0o101]() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -627,7 +708,7 @@ class Foo {
   123() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -637,7 +718,7 @@ class Foo {
   'null'() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -647,7 +728,7 @@ class Foo {
   get bar() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -658,7 +739,7 @@ class Foo {
   get bar() {}
 }
       "#,
-      vec![(3, 2), (4, 2), (5, 2)],
+      vec![(4, 2), (5, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -668,7 +749,7 @@ class Foo {
   static bar() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -678,7 +759,7 @@ class Foo {
   bar() {}
 }
       "#,
-      vec![(3, 2), (4, 2)],
+      vec![(4, 2)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -692,7 +773,7 @@ class Foo {
   }
 }
       "#,
-      vec![(5, 6), (6, 6)],
+      vec![(6, 6)],
     );
 
     assert_lint_err_on_line_n::<NoDupeClassMembers>(
@@ -704,7 +785,7 @@ class Foo {
   set bar(value: number) {}
 }
       "#,
-      vec![(5, 2), (6, 2)],
+      vec![(6, 2)],
     );
   }
 }
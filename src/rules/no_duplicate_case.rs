@@ -2,7 +2,7 @@
 use super::Context;
 use super::LintRule;
 use std::collections::HashSet;
-use swc_common::Spanned;
+use swc_common::{SourceFile, Span, Spanned};
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
@@ -88,13 +88,21 @@ impl<'c> Visit for NoDuplicateCaseVisitor<'c> {
     switch_stmt: &swc_ecmascript::ast::SwitchStmt,
     _parent: &dyn Node,
   ) {
+    let source_file = match self.context.source_file(switch_stmt.span) {
+      Some(source_file) => source_file,
+      None => return,
+    };
+
     // Works like in ESLint - by comparing text repr of case statement
-    let mut seen: HashSet<String> = HashSet::new();
+    let mut seen: HashSet<&str> = HashSet::new();
 
     for case in &switch_stmt.cases {
       if let Some(test) = &case.test {
         let span = test.span();
-        let test_txt = self.context.source_map.span_to_snippet(span).unwrap();
+        let test_txt = match snippet(&source_file, span) {
+          Some(test_txt) => test_txt,
+          None => continue,
+        };
         if !seen.insert(test_txt) {
           self.context.add_diagnostic_with_hint(
             span,
@@ -108,6 +116,14 @@ impl<'c> Visit for NoDuplicateCaseVisitor<'c> {
   }
 }
 
+/// Returns the source text of `span`, borrowed from `source_file`, or `None`
+/// if the span doesn't point into the file.
+fn snippet(source_file: &SourceFile, span: Span) -> Option<&str> {
+  let start = span.lo().0.checked_sub(source_file.start_pos.0)?;
+  let end = span.hi().0.checked_sub(source_file.start_pos.0)?;
+  source_file.src.get(start as usize..end as usize)
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -133,4 +149,63 @@ switch (someText) {
       9,
     );
   }
+
+  #[test]
+  fn no_duplicate_case_unresolved_span() {
+    use swc_common::BytePos;
+    use swc_ecmascript::ast::{Expr, Lit, ModuleItem, Program, Stmt};
+
+    // Moves the span of string literal case tests with the given value out
+    // of the source file.
+    struct BrokenSpans(&'static str);
+
+    impl LintRule for BrokenSpans {
+      fn new() -> Box<Self> {
+        unreachable!()
+      }
+
+      fn code(&self) -> &'static str {
+        "no-duplicate-case"
+      }
+
+      fn lint_program(&self, context: &mut Context, program: &Program) {
+        let mut program = program.clone();
+        let stmts: Vec<&mut Stmt> = match &mut program {
+          Program::Module(module) => module
+            .body
+            .iter_mut()
+            .filter_map(|item| match item {
+              ModuleItem::Stmt(stmt) => Some(stmt),
+              ModuleItem::ModuleDecl(_) => None,
+            })
+            .collect(),
+          Program::Script(script) => script.body.iter_mut().collect(),
+        };
+        for stmt in stmts {
+          if let Stmt::Switch(switch_stmt) = stmt {
+            for case in &mut switch_stmt.cases {
+              if let Some(Expr::Lit(Lit::Str(str_lit))) =
+                case.test.as_deref_mut()
+              {
+                if &*str_lit.value == self.0 {
+                  str_lit.span = str_lit
+                    .span
+                    .with_lo(BytePos(u32::MAX - 3))
+                    .with_hi(BytePos(u32::MAX));
+                }
+              }
+            }
+          }
+        }
+        NoDuplicateCase::new().lint_program(context, &program);
+      }
+    }
+
+    let diagnostics = lint(
+      Box::new(BrokenSpans("a")),
+      r#"switch (foo) { case "a": case "b": case "a": case "b": }"#,
+    );
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].range.start.col, 50);
+  }
 }
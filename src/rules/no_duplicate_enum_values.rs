@@ -0,0 +1,186 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use std::collections::HashSet;
+use swc_ecmascript::ast::{Expr, Lit, Program, TsEnumDecl, UnaryExpr, UnaryOp};
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct NoDuplicateEnumValues;
+
+const CODE: &str = "no-duplicate-enum-values";
+const HINT: &str = "Give the enum member a unique value";
+
+impl LintRule for NoDuplicateEnumValues {
+  fn new() -> Box<Self> {
+    Box::new(NoDuplicateEnumValues)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = NoDuplicateEnumValuesVisitor::new(context);
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows enum members with the same value
+
+Enum members which are initialized with the same literal value are
+indistinguishable at runtime, which is usually the result of copying a member
+and forgetting to change its value. Members without an initializer are not
+checked, since their values are assigned automatically.
+
+### Invalid:
+```typescript
+enum Foo {
+  A = 1,
+  B = 1,
+}
+
+enum Bar {
+  A = "a",
+  B = "a",
+}
+```
+
+### Valid:
+```typescript
+enum Foo {
+  A = 1,
+  B = 2,
+}
+
+enum Bar {
+  A = "a",
+  B = "b",
+}
+```
+"#
+  }
+}
+
+struct NoDuplicateEnumValuesVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoDuplicateEnumValuesVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+#[derive(PartialEq, Eq, Hash)]
+enum EnumValue {
+  /// The bits of the number, so that equal numbers compare equal.
+  Number(u64),
+  String(String),
+}
+
+/// Returns the literal value of an enum member initializer, along with how it
+/// is displayed.
+fn literal_value(expr: &Expr) -> Option<(EnumValue, String)> {
+  match expr {
+    Expr::Lit(Lit::Num(num)) => Some((
+      EnumValue::Number(num.value.to_bits()),
+      num.value.to_string(),
+    )),
+    Expr::Lit(Lit::Str(str_)) => Some((
+      EnumValue::String(str_.value.to_string()),
+      format!("\"{}\"", str_.value),
+    )),
+    Expr::Unary(UnaryExpr {
+      op: UnaryOp::Minus,
+      arg,
+      ..
+    }) => match &**arg {
+      Expr::Lit(Lit::Num(num)) => {
+        let value = -num.value;
+        Some((EnumValue::Number(value.to_bits()), value.to_string()))
+      }
+      _ => None,
+    },
+    Expr::Paren(paren) => literal_value(&paren.expr),
+    _ => None,
+  }
+}
+
+impl<'c> VisitAll for NoDuplicateEnumValuesVisitor<'c> {
+  fn visit_ts_enum_decl(&mut self, enum_decl: &TsEnumDecl, _parent: &dyn Node) {
+    let mut seen = HashSet::new();
+    for member in &enum_decl.members {
+      let (value, display) =
+        match member.init.as_ref().and_then(|init| literal_value(init)) {
+          Some(value) => value,
+          None => continue,
+        };
+      if !seen.insert(value) {
+        self.context.add_diagnostic_with_hint(
+          member.span,
+          CODE,
+          format!("Duplicate enum member value {}", display),
+          HINT,
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_duplicate_enum_values_valid() {
+    assert_lint_ok! {
+      NoDuplicateEnumValues,
+      "enum E { A, B, C }",
+      "enum E { A = 1, B }",
+      "enum E { A = 1, B = 2 }",
+      "enum E { A = 1, B = -1 }",
+      r#"enum E { A = "a", B = "b" }"#,
+      r#"enum E { A = 1, B = "1" }"#,
+      "enum E { A = 0, B }",
+      "enum E { A = foo, B = foo }",
+      "enum E { A = 1 << 0, B = 1 << 0 }",
+      "enum E { A = 1 } enum F { A = 1 }",
+      "const enum E { A = 1, B = 2 }",
+    };
+  }
+
+  #[test]
+  fn no_duplicate_enum_values_invalid() {
+    assert_lint_err! {
+      NoDuplicateEnumValues,
+      "enum E { A = 1, B = 1 }": [
+        { col: 16, message: "Duplicate enum member value 1", hint: HINT }
+      ],
+      "enum E { A = 1, B = 1.0, C = 1 }": [
+        { col: 16, message: "Duplicate enum member value 1", hint: HINT },
+        { col: 25, message: "Duplicate enum member value 1", hint: HINT }
+      ],
+      "enum E { A = 0x10, B = 16 }": [
+        { col: 19, message: "Duplicate enum member value 16", hint: HINT }
+      ],
+      "enum E { A = -1, B = (-1) }": [
+        { col: 17, message: "Duplicate enum member value -1", hint: HINT }
+      ],
+      r#"enum E { A = "a", B = "b", C = "a" }"#: [
+        { col: 27, message: "Duplicate enum member value \"a\"", hint: HINT }
+      ],
+      r#"enum E { A = 'a', B, C = "a" }"#: [
+        { col: 21, message: "Duplicate enum member value \"a\"", hint: HINT }
+      ],
+      "namespace N { export const enum E { A = 2, B = 2 } }": [
+        { col: 43, message: "Duplicate enum member value 2", hint: HINT }
+      ],
+    };
+  }
+}
@@ -1,8 +1,6 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule};
-use std::collections::HashMap;
-use swc_common::comments::Comment;
-use swc_common::BytePos;
+use crate::swc_util::ContainsComments;
 use swc_ecmascript::ast::{
   ArrowExpr, BlockStmt, BlockStmtOrExpr, Constructor, Function, Program,
   SwitchStmt,
@@ -153,23 +151,6 @@ impl<'c> Visit for NoEmptyVisitor<'c> {
   }
 }
 
-trait ContainsComments {
-  fn contains_comments(&self, context: &Context) -> bool;
-}
-
-impl ContainsComments for BlockStmt {
-  fn contains_comments(&self, context: &Context) -> bool {
-    let contains = |comments: &HashMap<BytePos, Vec<Comment>>| {
-      comments
-        .values()
-        .flatten()
-        .any(|comment| self.span.contains(comment.span))
-    };
-
-    contains(&context.leading_comments) || contains(&context.trailing_comments)
-  }
-}
-
 #[cfg(test)]
 mod tests {
   use super::*;
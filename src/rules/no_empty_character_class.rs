@@ -76,11 +76,10 @@ impl<'c> Visit for NoEmptyCharacterClassVisitor<'c> {
   noop_visit_type!();
 
   fn visit_regex(&mut self, regex: &Regex, _parent: &dyn Node) {
-    let raw_regex = self
-      .context
-      .source_map
-      .span_to_snippet(regex.span)
-      .expect("error in loading snippet");
+    let raw_regex = match self.context.snippet(regex.span) {
+      Some(raw_regex) => raw_regex,
+      None => return,
+    };
 
     static RULE_REGEX: Lazy<regex::Regex> = Lazy::new(|| {
       /* reference : [eslint no-empty-character-class](https://github.com/eslint/eslint/blob/master/lib/rules/no-empty-character-class.js#L13)
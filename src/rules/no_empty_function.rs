@@ -0,0 +1,359 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::ContainsComments;
+use serde::Deserialize;
+use swc_ecmascript::ast::{
+  Accessibility, ArrowExpr, BlockStmt, BlockStmtOrExpr, ClassMethod,
+  Constructor, FnDecl, FnExpr, Function, GetterProp, MethodKind, MethodProp,
+  ParamOrTsParamProp, PrivateMethod, Program, SetterProp,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoEmptyFunction {
+  options: NoEmptyFunctionOptions,
+}
+
+const CODE: &str = "no-empty-function";
+const MESSAGE: &str = "Empty function body";
+const HINT: &str =
+  "Add code to the function body, or a comment explaining why it is empty";
+
+/// Kinds of functions that can be allowed to have an empty body.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FunctionKind {
+  Functions,
+  ArrowFunctions,
+  GeneratorFunctions,
+  AsyncFunctions,
+  Methods,
+  GeneratorMethods,
+  AsyncMethods,
+  Getters,
+  Setters,
+  Constructors,
+  PrivateConstructors,
+  ProtectedConstructors,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoEmptyFunctionOptions {
+  /// Kinds of functions which are allowed to have an empty body.
+  pub allow: Vec<FunctionKind>,
+}
+
+impl NoEmptyFunction {
+  pub fn with_options(options: NoEmptyFunctionOptions) -> Box<Self> {
+    Box::new(NoEmptyFunction { options })
+  }
+}
+
+impl LintRule for NoEmptyFunction {
+  fn new() -> Box<Self> {
+    Self::with_options(NoEmptyFunctionOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = NoEmptyFunctionVisitor::new(context, &self.options);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows functions with an empty body
+
+An empty function is often the result of unfinished code. If a function is
+intentionally left empty, add a comment to its body explaining why.
+
+Empty bodies can be allowed per kind of function with the `allow` option, which
+accepts `"functions"`, `"arrowFunctions"`, `"generatorFunctions"`,
+`"asyncFunctions"`, `"methods"`, `"generatorMethods"`, `"asyncMethods"`,
+`"getters"`, `"setters"`, `"constructors"`, `"privateConstructors"` and
+`"protectedConstructors"`.
+
+Constructors with parameter properties are never reported, since the
+parameters alone make them meaningful.
+
+### Invalid:
+```typescript
+function foo() {}
+const bar = () => {};
+class Baz {
+  method() {}
+}
+```
+
+### Valid:
+```typescript
+function foo() {
+  // intentionally empty
+}
+const bar = () => {
+  /* noop */
+};
+class Baz {
+  constructor(private name: string) {}
+}
+```
+"#
+  }
+}
+
+struct NoEmptyFunctionVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o NoEmptyFunctionOptions,
+}
+
+impl<'c, 'o> NoEmptyFunctionVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o NoEmptyFunctionOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn check_body(&mut self, kind: FunctionKind, body: &Option<BlockStmt>) {
+    // Overload signatures and ambient declarations have no body.
+    let body = match body {
+      Some(body) => body,
+      None => return,
+    };
+
+    if body.stmts.is_empty()
+      && !body.contains_comments(self.context)
+      && !self.options.allow.contains(&kind)
+    {
+      self
+        .context
+        .add_diagnostic_with_hint(body.span, CODE, MESSAGE, HINT);
+    }
+  }
+
+  fn check_method(&mut self, kind: MethodKind, function: &Function) {
+    let kind = match kind {
+      MethodKind::Getter => FunctionKind::Getters,
+      MethodKind::Setter => FunctionKind::Setters,
+      MethodKind::Method if function.is_generator => {
+        FunctionKind::GeneratorMethods
+      }
+      MethodKind::Method if function.is_async => FunctionKind::AsyncMethods,
+      MethodKind::Method => FunctionKind::Methods,
+    };
+    self.check_body(kind, &function.body);
+  }
+
+  fn check_function(&mut self, function: &Function) {
+    let kind = if function.is_generator {
+      FunctionKind::GeneratorFunctions
+    } else if function.is_async {
+      FunctionKind::AsyncFunctions
+    } else {
+      FunctionKind::Functions
+    };
+    self.check_body(kind, &function.body);
+  }
+}
+
+impl<'c, 'o> Visit for NoEmptyFunctionVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_fn_decl(&mut self, fn_decl: &FnDecl, _parent: &dyn Node) {
+    self.check_function(&fn_decl.function);
+    fn_decl.visit_children_with(self);
+  }
+
+  fn visit_fn_expr(&mut self, fn_expr: &FnExpr, _parent: &dyn Node) {
+    self.check_function(&fn_expr.function);
+    fn_expr.visit_children_with(self);
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    if let BlockStmtOrExpr::BlockStmt(body) = &arrow_expr.body {
+      if body.stmts.is_empty()
+        && !body.contains_comments(self.context)
+        && !self.options.allow.contains(&FunctionKind::ArrowFunctions)
+      {
+        self
+          .context
+          .add_diagnostic_with_hint(body.span, CODE, MESSAGE, HINT);
+      }
+    }
+    arrow_expr.visit_children_with(self);
+  }
+
+  fn visit_class_method(
+    &mut self,
+    class_method: &ClassMethod,
+    _parent: &dyn Node,
+  ) {
+    self.check_method(class_method.kind, &class_method.function);
+    class_method.visit_children_with(self);
+  }
+
+  fn visit_private_method(
+    &mut self,
+    private_method: &PrivateMethod,
+    _parent: &dyn Node,
+  ) {
+    self.check_method(private_method.kind, &private_method.function);
+    private_method.visit_children_with(self);
+  }
+
+  fn visit_method_prop(
+    &mut self,
+    method_prop: &MethodProp,
+    _parent: &dyn Node,
+  ) {
+    self.check_method(MethodKind::Method, &method_prop.function);
+    method_prop.visit_children_with(self);
+  }
+
+  fn visit_getter_prop(
+    &mut self,
+    getter_prop: &GetterProp,
+    _parent: &dyn Node,
+  ) {
+    self.check_body(FunctionKind::Getters, &getter_prop.body);
+    getter_prop.visit_children_with(self);
+  }
+
+  fn visit_setter_prop(
+    &mut self,
+    setter_prop: &SetterProp,
+    _parent: &dyn Node,
+  ) {
+    self.check_body(FunctionKind::Setters, &setter_prop.body);
+    setter_prop.visit_children_with(self);
+  }
+
+  fn visit_constructor(
+    &mut self,
+    constructor: &Constructor,
+    _parent: &dyn Node,
+  ) {
+    let has_param_props = constructor
+      .params
+      .iter()
+      .any(|param| matches!(param, ParamOrTsParamProp::TsParamProp(_)));
+
+    if !has_param_props {
+      let kind = match constructor.accessibility {
+        Some(Accessibility::Private) => FunctionKind::PrivateConstructors,
+        Some(Accessibility::Protected) => FunctionKind::ProtectedConstructors,
+        _ => FunctionKind::Constructors,
+      };
+      if self.options.allow.contains(&FunctionKind::Constructors) {
+        self.check_body(FunctionKind::Constructors, &constructor.body);
+      } else {
+        self.check_body(kind, &constructor.body);
+      }
+    }
+    constructor.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  fn allowing(kinds: &[FunctionKind]) -> Box<NoEmptyFunction> {
+    NoEmptyFunction::with_options(NoEmptyFunctionOptions {
+      allow: kinds.to_vec(),
+    })
+  }
+
+  #[test]
+  fn no_empty_function_valid() {
+    assert_lint_ok! {
+      NoEmptyFunction,
+      "function foo() { bar(); }",
+      "function foo() { /* empty */ }",
+      "function foo() {\n  // empty\n}",
+      "const foo = () => { /* noop */ };",
+      "const foo = () => 1;",
+      "const foo = function() { /* noop */ };",
+      "class Foo { bar() { /* noop */ } }",
+      "class Foo { constructor(private bar: string) {} }",
+      "class Foo { constructor(readonly bar: string) {} }",
+      "const foo = { bar() { return 1; } };",
+      "function foo(): void;",
+      "declare function foo(): void;",
+      "class Foo { bar(): void; bar(a?: string) { baz(a); } }",
+      "abstract class Foo { abstract bar(): void; }",
+    };
+  }
+
+  #[test]
+  fn no_empty_function_invalid() {
+    assert_lint_err! {
+      NoEmptyFunction,
+      "function foo() {}": [{ col: 15, message: MESSAGE, hint: HINT }],
+      "function* foo() {}": [{ col: 16, message: MESSAGE, hint: HINT }],
+      "async function foo() {}": [{ col: 21, message: MESSAGE, hint: HINT }],
+      "const foo = function() {};": [{ col: 23, message: MESSAGE, hint: HINT }],
+      "const foo = () => {};": [{ col: 18, message: MESSAGE, hint: HINT }],
+      "class Foo { bar() {} }": [{ col: 18, message: MESSAGE, hint: HINT }],
+      "class Foo { #bar() {} }": [{ col: 19, message: MESSAGE, hint: HINT }],
+      "class Foo { constructor() {} }": [{ col: 26, message: MESSAGE, hint: HINT }],
+      "class Foo { get bar() {} }": [{ col: 22, message: MESSAGE, hint: HINT }],
+      "const foo = { bar() {} };": [{ col: 20, message: MESSAGE, hint: HINT }],
+      "const foo = { get bar() {} };": [{ col: 24, message: MESSAGE, hint: HINT }],
+      "const foo = { set bar(v) {} };": [{ col: 25, message: MESSAGE, hint: HINT }],
+      "// comment outside\nfunction foo() {}": [{ line: 2, col: 15, message: MESSAGE, hint: HINT }],
+      "function foo() { const bar = () => {}; }": [{ col: 35, message: MESSAGE, hint: HINT }],
+    };
+  }
+
+  #[test]
+  fn no_empty_function_allow() {
+    assert_lint_ok_with_rule(
+      allowing(&[FunctionKind::ArrowFunctions]),
+      "const foo = () => {};",
+    );
+    assert_lint_ok_with_rule(
+      allowing(&[FunctionKind::Functions]),
+      "function foo() {}\nconst bar = function() {};",
+    );
+    assert_lint_ok_with_rule(
+      allowing(&[FunctionKind::Methods]),
+      "class Foo { bar() {} }\nconst baz = { qux() {} };",
+    );
+    assert_lint_ok_with_rule(
+      allowing(&[FunctionKind::Constructors]),
+      "class Foo { private constructor() {} }",
+    );
+    assert_lint_ok_with_rule(
+      allowing(&[FunctionKind::PrivateConstructors]),
+      "class Foo { private constructor() {} }",
+    );
+    assert_lint_ok_with_rule(
+      allowing(&[FunctionKind::Getters, FunctionKind::Setters]),
+      "const foo = { get bar() {}, set bar(v) {} };",
+    );
+
+    assert_lint_err_on_line_n_with_rule(
+      allowing(&[FunctionKind::ArrowFunctions]),
+      "function foo() {}\nconst bar = () => {};",
+      vec![(1, 15)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      allowing(&[FunctionKind::Functions]),
+      "async function foo() {}\nfunction* bar() {}",
+      vec![(1, 21), (2, 16)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      allowing(&[FunctionKind::PrivateConstructors]),
+      "class Foo { protected constructor() {} }",
+      vec![(1, 36)],
+    );
+  }
+}
@@ -20,6 +20,10 @@ impl LintRule for NoEmptyInterface {
     "no-empty-interface"
   }
 
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
   fn lint_program(
     &self,
     context: &mut Context,
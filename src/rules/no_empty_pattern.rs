@@ -1,10 +1,11 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
-use swc_ecmascript::ast::{ArrayPat, ObjectPat, ObjectPatProp};
+use swc_ecmascript::ast::{ArrayPat, ObjectPat};
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
 
 pub struct NoEmptyPattern;
 
@@ -48,7 +49,7 @@ have no effect, most likely not what the author intended.
 const {} = someObj;
 const [] = someArray;
 const {a: {}} = someObj;
-const [a: []] = someArray;
+const [a, []] = someArray;
 function myFunc({}) {}
 function myFunc([]) {}
 ```
@@ -86,20 +87,6 @@ impl<'c> NoEmptyPatternVisitor<'c> {
 impl<'c> Visit for NoEmptyPatternVisitor<'c> {
   noop_visit_type!();
 
-  fn visit_object_pat_prop(
-    &mut self,
-    obj_pat_prop: &ObjectPatProp,
-    _parent: &dyn Node,
-  ) {
-    if let ObjectPatProp::KeyValue(kv_prop) = obj_pat_prop {
-      if let swc_ecmascript::ast::Pat::Object(obj_pat) = &*kv_prop.value {
-        self.visit_object_pat(obj_pat, _parent);
-      } else if let swc_ecmascript::ast::Pat::Array(arr_pat) = &*kv_prop.value {
-        self.visit_array_pat(arr_pat, _parent);
-      }
-    }
-  }
-
   fn visit_object_pat(&mut self, obj_pat: &ObjectPat, _parent: &dyn Node) {
     if obj_pat.props.is_empty() {
       if obj_pat.type_ann.is_none() {
@@ -108,9 +95,7 @@ impl<'c> Visit for NoEmptyPatternVisitor<'c> {
           .add_diagnostic_with_hint(obj_pat.span, CODE, MESSAGE, HINT)
       }
     } else {
-      for prop in &obj_pat.props {
-        self.visit_object_pat_prop(prop, _parent)
-      }
+      obj_pat.visit_children_with(self);
     }
   }
 
@@ -120,15 +105,7 @@ impl<'c> Visit for NoEmptyPatternVisitor<'c> {
         .context
         .add_diagnostic_with_hint(arr_pat.span, CODE, MESSAGE, HINT)
     } else {
-      for elem in &arr_pat.elems {
-        if let Some(element) = elem {
-          if let swc_ecmascript::ast::Pat::Object(obj_pat) = element {
-            self.visit_object_pat(&obj_pat, _parent);
-          } else if let swc_ecmascript::ast::Pat::Array(arr_pat) = element {
-            self.visit_array_pat(&arr_pat, _parent);
-          }
-        }
-      }
+      arr_pat.visit_children_with(self);
     }
   }
 }
@@ -148,6 +125,12 @@ mod tests {
       "function foo({a = []}) {}",
       "var [a] = foo",
       "async function startFileServerAsLibrary({}: FileServerCfg = {}): Promise<void>",
+      "const {a: {b} = {}} = foo;",
+      "const [[a] = []] = foo;",
+      "const [...rest] = foo;",
+      "({a} = foo);",
+      "const foo = ({a}) => a;",
+      "const foo = ([a = {}]) => a;",
     };
   }
 
@@ -200,6 +183,51 @@ mod tests {
         message: MESSAGE,
         hint: HINT,
       }],
+      "function foo({} = {}) {}": [{
+        col: 13,
+        message: MESSAGE,
+        hint: HINT,
+      }],
+      "const foo = ({}) => {};": [{
+        col: 13,
+        message: MESSAGE,
+        hint: HINT,
+      }],
+      "const foo = ([]) => {};": [{
+        col: 13,
+        message: MESSAGE,
+        hint: HINT,
+      }],
+      "const {a: {} = {}} = foo": [{
+        col: 10,
+        message: MESSAGE,
+        hint: HINT,
+      }],
+      "const [a, [] = []] = foo": [{
+        col: 10,
+        message: MESSAGE,
+        hint: HINT,
+      }],
+      "const [a, {}] = foo": [{
+        col: 10,
+        message: MESSAGE,
+        hint: HINT,
+      }],
+      "const [...[]] = foo": [{
+        col: 10,
+        message: MESSAGE,
+        hint: HINT,
+      }],
+      "({} = foo);": [{
+        col: 1,
+        message: MESSAGE,
+        hint: HINT,
+      }],
+      "for (const {} of foo) {}": [{
+        col: 11,
+        message: MESSAGE,
+        hint: HINT,
+      }],
     }
   }
 }
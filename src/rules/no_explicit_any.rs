@@ -24,6 +24,10 @@ impl LintRule for NoExplicitAny {
     CODE
   }
 
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
   fn lint_program(
     &self,
     context: &mut Context,
@@ -1,20 +1,39 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
-use swc_common::Span;
+use crate::diagnostic::Suggestion;
+use serde::Deserialize;
+use swc_common::{Span, Spanned};
 use swc_ecmascript::ast::{
-  CallExpr, CondExpr, DoWhileStmt, Expr, ExprOrSpread, ExprOrSuper, ForStmt,
-  Ident, IfStmt, NewExpr, ParenExpr, UnaryExpr, UnaryOp, WhileStmt,
+  BinExpr, BinaryOp, CallExpr, CondExpr, DoWhileStmt, Expr, ExprOrSpread,
+  ExprOrSuper, ForStmt, Ident, IfStmt, NewExpr, ParenExpr, UnaryExpr, UnaryOp,
+  WhileStmt,
 };
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
 
-pub struct NoExtraBooleanCast;
+pub struct NoExtraBooleanCast {
+  options: NoExtraBooleanCastOptions,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoExtraBooleanCastOptions {
+  /// Also checks the operands of `&&` and `||` expressions that are used in a
+  /// boolean context. Defaults to `false`.
+  pub enforce_for_logical_operands: bool,
+}
+
+impl NoExtraBooleanCast {
+  pub fn with_options(options: NoExtraBooleanCastOptions) -> Box<Self> {
+    Box::new(NoExtraBooleanCast { options })
+  }
+}
 
 impl LintRule for NoExtraBooleanCast {
   fn new() -> Box<Self> {
-    Box::new(NoExtraBooleanCast)
+    Self::with_options(NoExtraBooleanCastOptions::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -30,7 +49,10 @@ impl LintRule for NoExtraBooleanCast {
     context: &mut Context,
     program: &swc_ecmascript::ast::Program,
   ) {
-    let mut visitor = NoExtraBooleanCastVisitor::new(context);
+    let mut visitor = NoExtraBooleanCastVisitor::new(
+      context,
+      self.options.enforce_for_logical_operands,
+    );
     visitor.visit_program(program, program);
   }
 
@@ -56,44 +78,151 @@ if (foo) {}
 while(foo) {}
 for(;foo;) {}
 ```
+
+With the `enforceForLogicalOperands` option the operands of `&&` and `||`
+expressions in a boolean context are checked as well, so `if (!!foo || bar) {}`
+is reported too.
 "#
   }
 }
 
 struct NoExtraBooleanCastVisitor<'c> {
   context: &'c mut Context,
+  enforce_for_logical_operands: bool,
 }
 
 impl<'c> NoExtraBooleanCastVisitor<'c> {
-  fn new(context: &'c mut Context) -> Self {
-    Self { context }
+  fn new(context: &'c mut Context, enforce_for_logical_operands: bool) -> Self {
+    Self {
+      context,
+      enforce_for_logical_operands,
+    }
   }
 
-  fn unexpected_call(&mut self, span: Span) {
-    self.context.add_diagnostic_with_hint(
+  fn unexpected_call(&mut self, span: Span, new_text: Option<String>) {
+    let suggestions =
+      self.suggestion(span, new_text, "Remove the Boolean call");
+    self.context.add_diagnostic_with_suggestions(
       span,
       "no-extra-boolean-cast",
       "Redundant Boolean call.",
-      "Remove the Boolean call, it is unnecessary",
+      Some("Remove the Boolean call, it is unnecessary".to_string()),
+      suggestions,
     );
   }
 
-  fn unexpected_negation(&mut self, span: Span) {
-    self.context.add_diagnostic_with_hint(
+  fn unexpected_negation(&mut self, span: Span, new_text: Option<String>) {
+    let suggestions =
+      self.suggestion(span, new_text, "Remove the double negation");
+    self.context.add_diagnostic_with_suggestions(
       span,
       "no-extra-boolean-cast",
       "Redundant double negation.",
-      "Remove the double negation (`!!`), it is unnecessary",
+      Some("Remove the double negation (`!!`), it is unnecessary".to_string()),
+      suggestions,
     );
   }
 
+  fn suggestion(
+    &self,
+    span: Span,
+    new_text: Option<String>,
+    label: &str,
+  ) -> Vec<Suggestion> {
+    new_text
+      .map(|new_text| self.separate_from_preceding_word(span, new_text))
+      .and_then(|new_text| self.context.text_edit(span, new_text))
+      .map(|edit| Suggestion {
+        label: label.to_string(),
+        edit,
+      })
+      .into_iter()
+      .collect()
+  }
+
+  /// Adds a space before `new_text` if it would otherwise merge with a
+  /// preceding keyword, like `a` replacing `!!a` in `yield!!a`.
+  fn separate_from_preceding_word(
+    &self,
+    span: Span,
+    new_text: String,
+  ) -> String {
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    let preceding = self.context.source_file(span).and_then(|file| {
+      let offset = span.lo().0.checked_sub(file.start_pos.0)? as usize;
+      file.src.get(..offset)?.chars().next_back()
+    });
+    let starts_with_word = new_text.chars().next().map_or(false, is_word_char);
+    if starts_with_word && preceding.map_or(false, is_word_char) {
+      format!(" {}", new_text)
+    } else {
+      new_text
+    }
+  }
+
+  /// Returns the source text of `expr`, in parentheses if it's used as
+  /// `operand` and doesn't bind tightly enough, e.g. in `!(a || b)`.
+  fn operand_text(&self, expr: &Expr, is_operand: bool) -> Option<String> {
+    let text = self.context.snippet(expr.span())?;
+    let binds_tightly = matches!(
+      expr,
+      Expr::Ident(_)
+        | Expr::This(_)
+        | Expr::Lit(_)
+        | Expr::Array(_)
+        | Expr::Tpl(_)
+        | Expr::TaggedTpl(_)
+        | Expr::Member(_)
+        | Expr::Call(_)
+        | Expr::New(_)
+        | Expr::OptChain(_)
+        | Expr::Paren(_)
+        | Expr::Unary(_)
+        | Expr::Update(_)
+        | Expr::Await(_)
+    );
+    if is_operand && !binds_tightly {
+      Some(format!("({})", text))
+    } else {
+      Some(text)
+    }
+  }
+
+  /// Returns the replacement of a `Boolean()` call, negated with `!` if
+  /// `negated`.
+  fn call_replacement(
+    &self,
+    call_expr: &CallExpr,
+    is_operand: bool,
+    negated: bool,
+  ) -> Option<String> {
+    match call_expr.args.as_slice() {
+      [] => Some(if negated { "true" } else { "false" }.to_string()),
+      [ExprOrSpread { spread: None, expr }] => {
+        let text = self.operand_text(expr, is_operand || negated)?;
+        if negated {
+          Some(format!("!{}", text))
+        } else {
+          Some(text)
+        }
+      }
+      _ => None,
+    }
+  }
+
   fn check_condition(&mut self, expr: &Expr) {
+    self.check_boolean_context(expr, false);
+  }
+
+  /// Checks an expression whose value is coerced to a boolean. `is_operand`
+  /// tells whether it's an operand of another expression, like a logical
+  /// expression or a conditional expression.
+  fn check_boolean_context(&mut self, expr: &Expr, is_operand: bool) {
     match expr {
-      Expr::Call(CallExpr {
-        ref callee, span, ..
-      }) => {
-        if expr_or_super_callee_is_boolean(callee) {
-          self.unexpected_call(*span);
+      Expr::Call(call_expr) => {
+        if expr_or_super_callee_is_boolean(&call_expr.callee) {
+          let new_text = self.call_replacement(call_expr, is_operand, false);
+          self.unexpected_call(call_expr.span, new_text);
         }
       }
       Expr::Unary(UnaryExpr {
@@ -101,15 +230,26 @@ impl<'c> NoExtraBooleanCastVisitor<'c> {
         op: UnaryOp::Bang,
         ref arg,
       }) if has_n_bang(arg, 1) => {
-        self.unexpected_negation(*span);
+        let new_text = self.context.snippet(strip_bangs(expr, 2).span());
+        self.unexpected_negation(*span, new_text);
       }
       Expr::Paren(ParenExpr { ref expr, .. }) => {
-        self.check_condition(expr);
+        self.check_boolean_context(expr, false);
       }
+      Expr::Bin(bin_expr) => self.check_logical_operands(bin_expr),
       _ => (),
     }
   }
 
+  fn check_logical_operands(&mut self, bin_expr: &BinExpr) {
+    if self.enforce_for_logical_operands
+      && matches!(bin_expr.op, BinaryOp::LogicalAnd | BinaryOp::LogicalOr)
+    {
+      self.check_boolean_context(&bin_expr.left, true);
+      self.check_boolean_context(&bin_expr.right, true);
+    }
+  }
+
   fn check_unary_expr(&mut self, unary_expr: &UnaryExpr) {
     if unary_expr.op == UnaryOp::Bang {
       let expr = &*unary_expr.arg;
@@ -123,9 +263,10 @@ impl<'c> NoExtraBooleanCastVisitor<'c> {
     internal_expr: &Expr,
   ) {
     match internal_expr {
-      Expr::Call(CallExpr { ref callee, .. }) => {
-        if expr_or_super_callee_is_boolean(callee) {
-          self.unexpected_call(unary_expr_span);
+      Expr::Call(call_expr) => {
+        if expr_or_super_callee_is_boolean(&call_expr.callee) {
+          let new_text = self.call_replacement(call_expr, true, true);
+          self.unexpected_call(unary_expr_span, new_text);
         }
       }
       Expr::Unary(UnaryExpr {
@@ -133,11 +274,16 @@ impl<'c> NoExtraBooleanCastVisitor<'c> {
         ref arg,
         ..
       }) if has_n_bang(arg, 1) => {
-        self.unexpected_negation(unary_expr_span);
+        let new_text = self
+          .context
+          .snippet(strip_bangs(internal_expr, 2).span())
+          .map(|text| format!("!{}", text));
+        self.unexpected_negation(unary_expr_span, new_text);
       }
       Expr::Paren(ParenExpr { ref expr, .. }) => {
         self.check_unary_expr_internal(unary_expr_span, expr);
       }
+      Expr::Bin(bin_expr) => self.check_logical_operands(bin_expr),
       _ => (),
     }
   }
@@ -147,7 +293,7 @@ impl<'c> Visit for NoExtraBooleanCastVisitor<'c> {
   noop_visit_type!();
 
   fn visit_cond_expr(&mut self, cond_expr: &CondExpr, parent: &dyn Node) {
-    self.check_condition(&*cond_expr.test);
+    self.check_boolean_context(&*cond_expr.test, true);
     swc_ecmascript::visit::visit_cond_expr(self, cond_expr, parent);
   }
 
@@ -231,6 +377,25 @@ fn has_n_bang(expr: &Expr, n: usize) -> bool {
   }
 }
 
+/// Returns the operand of `n` continuous bang operators at the beginning of
+/// `expr`, ignoring parentheses around them. `expr` has to have them, which
+/// `has_n_bang` checks.
+fn strip_bangs(expr: &Expr, n: usize) -> &Expr {
+  if n == 0 {
+    return expr;
+  }
+
+  match expr {
+    Expr::Unary(UnaryExpr {
+      op: UnaryOp::Bang,
+      ref arg,
+      ..
+    }) => strip_bangs(arg, n - 1),
+    Expr::Paren(ParenExpr { ref expr, .. }) => strip_bangs(expr, n),
+    _ => expr,
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -253,11 +418,50 @@ mod tests {
       "for(;; Boolean(foo)) {}",
       "if (new Boolean(foo)) {}",
       "if (!!foo || bar) {}",
+      "!!x;",
+      "if (x) {}",
+      "var foo = !!bar || baz;",
     };
   }
 
+  #[test]
+  fn no_extra_boolean_cast_enforce_for_logical_operands() {
+    let rule = || {
+      NoExtraBooleanCast::with_options(
+        serde_json::from_str(r#"{ "enforceForLogicalOperands": true }"#)
+          .unwrap(),
+      )
+    };
+
+    assert_lint_ok_with_rule(rule(), "var foo = !!bar || baz;");
+    assert_lint_ok_with_rule(rule(), "if (foo || bar) {}");
+    assert_lint_ok_with_rule(rule(), "if (!!foo + bar) {}");
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "if (!!foo || bar) {}",
+      vec![(1, 4)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "while (a && Boolean(b)) {}",
+      vec![(1, 12)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "if ((a || !!b) && c) {}",
+      vec![(1, 10)],
+    );
+    assert_lint_err_on_line_n_with_rule(rule(), "!(!!a || b)", vec![(1, 2)]);
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "Boolean(a && !!b)",
+      vec![(1, 13)],
+    );
+  }
+
   #[test]
   fn no_extra_boolean_cast_invalid() {
+    assert_lint_err::<NoExtraBooleanCast>("if(!!x) {}", 3);
     assert_lint_err::<NoExtraBooleanCast>("if (!!foo) {}", 4);
     assert_lint_err::<NoExtraBooleanCast>("do {} while (!!foo)", 13);
     assert_lint_err::<NoExtraBooleanCast>("while (!!foo) {}", 7);
@@ -548,4 +752,50 @@ mod tests {
       vec![4, 18],
     );
   }
+
+  #[test]
+  fn no_extra_boolean_cast_suggestions() {
+    let cases = [
+      ("if (!!foo) {}", "if (foo) {}"),
+      ("if (!(!foo)) {}", "if (foo) {}"),
+      ("if (!!(a || b)) {}", "if ((a || b)) {}"),
+      ("!!!foo", "!foo"),
+      ("if (Boolean(foo)) {}", "if (foo) {}"),
+      ("if (Boolean(a || b)) {}", "if (a || b) {}"),
+      ("if (Boolean()) {}", "if (false) {}"),
+      ("!Boolean(foo)", "!foo"),
+      ("!Boolean(a || b)", "!(a || b)"),
+      ("!Boolean()", "true"),
+      ("x = Boolean(a = b) ? c : d", "x = (a = b) ? c : d"),
+      (
+        "function *foo() { yield!!a ? b : c }",
+        "function *foo() { yield a ? b : c }",
+      ),
+      ("void!Boolean()", "void true"),
+    ];
+    for (source, expected) in cases.iter() {
+      assert_eq!(
+        apply_suggestions(NoExtraBooleanCast::new(), source),
+        vec![*expected],
+        "{}",
+        source
+      );
+    }
+
+    // Other arguments would be dropped.
+    assert!(
+      apply_suggestions(NoExtraBooleanCast::new(), "!Boolean(a, b)").is_empty()
+    );
+    assert!(
+      apply_suggestions(NoExtraBooleanCast::new(), "!Boolean(...a)").is_empty()
+    );
+
+    let rule = NoExtraBooleanCast::with_options(
+      serde_json::from_str(r#"{ "enforceForLogicalOperands": true }"#).unwrap(),
+    );
+    assert_eq!(
+      apply_suggestions(rule, "if (a && Boolean(b || c)) {}"),
+      vec!["if (a && (b || c)) {}"]
+    );
+  }
 }
@@ -1,7 +1,8 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
-use swc_common::Span;
+use crate::diagnostic::Suggestion;
+use swc_common::{BytePos, Span};
 use swc_ecmascript::ast::Expr;
 use swc_ecmascript::ast::ExprOrSuper;
 use swc_ecmascript::ast::OptChainExpr;
@@ -24,6 +25,10 @@ impl LintRule for NoExtraNonNullAssertion {
     "no-extra-non-null-assertion"
   }
 
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
   fn lint_program(
     &self,
     context: &mut Context,
@@ -57,6 +62,9 @@ const bar = foo!.str;
 
 function myFunc(bar: undefined | string) { return bar!; }
 function anotherFunc(bar?: { str: string }) { return bar?.str; }
+
+// each assertion applies to a different value
+function nestedFunc(bar: { baz: string | null } | null) { return bar!.baz!; }
 ```
 "#
   }
@@ -72,35 +80,51 @@ impl<'c> NoExtraNonNullAssertionVisitor<'c> {
   }
 
   fn add_diagnostic(&mut self, span: Span) {
-    self.context.add_diagnostic_with_hint(
+    let suggestions = self
+      .context
+      .text_edit(span, "")
+      .map(|edit| Suggestion {
+        label: "Remove the extra non-null assertion".to_string(),
+        edit,
+      })
+      .into_iter()
+      .collect();
+    self.context.add_diagnostic_with_suggestions(
       span,
       "no-extra-non-null-assertion",
       "Extra non-null assertion is forbidden",
-      "Remove the extra non-null assertion operator (`!`)",
+      Some("Remove the extra non-null assertion operator (`!`)".to_string()),
+      suggestions,
     );
   }
+}
 
-  fn check_expr_for_nested_non_null_assert(&mut self, span: Span, expr: &Expr) {
-    match expr {
-      Expr::TsNonNull(_) => self.add_diagnostic(span),
-      Expr::Paren(paren_expr) => {
-        self.check_expr_for_nested_non_null_assert(span, &*paren_expr.expr)
-      }
-      _ => {}
-    }
+/// Returns the non-null assertion `expr` consists of, ignoring parentheses.
+fn nested_non_null_assert(expr: &Expr) -> Option<&TsNonNullExpr> {
+  match expr {
+    Expr::TsNonNull(ts_non_null_expr) => Some(ts_non_null_expr),
+    Expr::Paren(paren_expr) => nested_non_null_assert(&paren_expr.expr),
+    _ => None,
   }
 }
 
+/// Returns the span of the `!` of a non-null assertion, which is its last
+/// character.
+fn bang_span(ts_non_null_expr: &TsNonNullExpr) -> Span {
+  let span = ts_non_null_expr.span;
+  Span::new(span.hi - BytePos(1), span.hi, span.ctxt)
+}
+
 impl<'c> Visit for NoExtraNonNullAssertionVisitor<'c> {
   fn visit_ts_non_null_expr(
     &mut self,
     ts_non_null_expr: &TsNonNullExpr,
     parent: &dyn Node,
   ) {
-    self.check_expr_for_nested_non_null_assert(
-      ts_non_null_expr.span,
-      &*ts_non_null_expr.expr,
-    );
+    // The outer assertion is redundant, e.g. the second `!` of `foo!!`.
+    if nested_non_null_assert(&ts_non_null_expr.expr).is_some() {
+      self.add_diagnostic(bang_span(ts_non_null_expr));
+    }
     swc_ecmascript::visit::visit_ts_non_null_expr(
       self,
       ts_non_null_expr,
@@ -121,7 +145,11 @@ impl<'c> Visit for NoExtraNonNullAssertionVisitor<'c> {
 
     if let Some(expr_or_super) = maybe_expr_or_super {
       if let ExprOrSuper::Expr(expr) = &expr_or_super {
-        self.check_expr_for_nested_non_null_assert(opt_chain_expr.span, expr);
+        // The assertion is redundant with the optional chain, e.g. in
+        // `foo!?.bar`.
+        if let Some(nested) = nested_non_null_assert(expr) {
+          self.add_diagnostic(bang_span(nested));
+        }
       }
     }
 
@@ -142,6 +170,8 @@ mod tests {
       r#"function foo() { return "foo"; }"#,
       r#"function foo(bar: undefined | string) { return bar!; }"#,
       r#"function foo(bar?: { str: string }) { return bar?.str; }"#,
+      r#"function foo(bar: { baz: string | null } | null) { return bar!.baz!; }"#,
+      r#"function foo(bar?: { str: string }) { return bar?.str!; }"#,
     };
   }
 
@@ -149,31 +179,60 @@ mod tests {
   fn no_extra_non_null_assertion_invalid() {
     assert_lint_err::<NoExtraNonNullAssertion>(
       r#"const foo: { str: string } | null = null; const bar = foo!!.str;"#,
-      54,
+      58,
     );
     assert_lint_err::<NoExtraNonNullAssertion>(
       r#"function foo(bar: undefined | string) { return bar!!; }"#,
-      47,
+      51,
     );
     assert_lint_err::<NoExtraNonNullAssertion>(
       r#"function foo(bar?: { str: string }) { return bar!?.str; }"#,
-      45,
+      48,
     );
     assert_lint_err::<NoExtraNonNullAssertion>(
       r#"function foo(bar?: { str: string }) { return (bar!)!.str; }"#,
-      45,
+      51,
     );
     assert_lint_err::<NoExtraNonNullAssertion>(
       r#"function foo(bar?: { str: string }) { return (bar!)?.str; }"#,
-      45,
+      49,
     );
     assert_lint_err::<NoExtraNonNullAssertion>(
       r#"function foo(bar?: { str: string }) { return bar!?.(); }"#,
-      45,
+      48,
     );
     assert_lint_err::<NoExtraNonNullAssertion>(
       r#"function foo(bar?: { str: string }) { return (bar!)?.(); }"#,
-      45,
+      49,
+    );
+    assert_lint_err_n::<NoExtraNonNullAssertion>(
+      r#"function foo(bar?: string) { return bar!!!; }"#,
+      vec![41, 40],
+    );
+  }
+
+  #[test]
+  fn no_extra_non_null_assertion_suggestions() {
+    assert_eq!(
+      apply_suggestions(
+        NoExtraNonNullAssertion::new(),
+        "function foo(bar: undefined | string) { return bar!!; }"
+      ),
+      vec!["function foo(bar: undefined | string) { return bar!; }"]
+    );
+    assert_eq!(
+      apply_suggestions(
+        NoExtraNonNullAssertion::new(),
+        "function foo(bar?: { str: string }) { return bar!?.str; }"
+      ),
+      vec!["function foo(bar?: { str: string }) { return bar?.str; }"]
+    );
+    assert_eq!(
+      apply_suggestions(
+        NoExtraNonNullAssertion::new(),
+        "function foo(bar?: { str: string }) { return (bar!)?.str; }"
+      ),
+      vec!["function foo(bar?: { str: string }) { return (bar)?.str; }"]
     );
   }
 }
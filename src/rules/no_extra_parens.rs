@@ -0,0 +1,481 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use std::collections::HashSet;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  AssignExpr, DoWhileStmt, Expr, ExprOrSpread, ExprOrSuper, ExprStmt, IfStmt,
+  KeyValueProp, Lit, ParenExpr, Pat, PatOrExpr, Program, ReturnStmt,
+  SwitchStmt, ThrowStmt, VarDeclarator, WhileStmt,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoExtraParens {
+  options: NoExtraParensOptions,
+}
+
+const CODE: &str = "no-extra-parens";
+const MESSAGE: &str = "Unnecessary parentheses around expression";
+const HINT: &str = "Remove the parentheses";
+
+/// Which parenthesized expressions are checked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExtraParensMode {
+  /// Parentheses around any expression.
+  All,
+  /// Only parentheses around function expressions.
+  Functions,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoExtraParensOptions {
+  pub mode: ExtraParensMode,
+}
+
+impl Default for NoExtraParensOptions {
+  fn default() -> Self {
+    Self {
+      mode: ExtraParensMode::All,
+    }
+  }
+}
+
+impl NoExtraParens {
+  pub fn with_options(options: NoExtraParensOptions) -> Box<Self> {
+    Box::new(NoExtraParens { options })
+  }
+}
+
+impl LintRule for NoExtraParens {
+  fn new() -> Box<Self> {
+    Self::with_options(NoExtraParensOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = NoExtraParensVisitor {
+      context,
+      mode: self.options.mode,
+      reported: HashSet::new(),
+    };
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows unnecessary parentheses
+
+Parentheses are only needed where they change how an expression is grouped.
+This rule reports parentheses around a single identifier or literal, doubled
+parentheses, and parentheses around a whole expression in positions that take
+any expression, such as an expression statement, a variable initializer, a
+`return` argument or the condition of an `if` or `while` statement.
+
+With the `"functions"` mode only parentheses around function expressions are
+reported.
+
+### Invalid:
+```typescript
+const a = (b);
+const c = ((d + e)) * f;
+(foo + bar);
+if ((a === b)) {}
+const fn = (function () {});
+```
+
+### Valid:
+```typescript
+const a = b;
+const c = (d + e) * f;
+foo + bar;
+(function () {})();
+({}).toString();
+const d = (a, b);
+```
+"#
+  }
+}
+
+struct NoExtraParensVisitor<'c> {
+  context: &'c mut Context,
+  mode: ExtraParensMode,
+  reported: HashSet<Span>,
+}
+
+impl<'c> NoExtraParensVisitor<'c> {
+  fn report(&mut self, span: Span) {
+    if self.reported.insert(span) {
+      self
+        .context
+        .add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
+    }
+  }
+
+  fn is_checked(&self, expr: &Expr) -> bool {
+    match self.mode {
+      ExtraParensMode::All => true,
+      ExtraParensMode::Functions => matches!(unwrap_parens(expr), Expr::Fn(_)),
+    }
+  }
+
+  /// Checks an expression in a position where any expression is allowed
+  /// without parentheses. A sequence expression is only allowed there if
+  /// `allow_seq` is set.
+  fn check_free_position(&mut self, expr: &Expr, allow_seq: bool) {
+    if let Expr::Paren(ParenExpr { span, expr: inner }) = expr {
+      if (allow_seq || !matches!(&**inner, Expr::Seq(_)))
+        && self.is_checked(inner)
+      {
+        self.report(*span);
+      }
+    }
+  }
+}
+
+impl<'c> Visit for NoExtraParensVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_paren_expr(&mut self, paren_expr: &ParenExpr, _parent: &dyn Node) {
+    let redundant = match &*paren_expr.expr {
+      Expr::Paren(_) => true,
+      Expr::Ident(_) | Expr::This(_) | Expr::Array(_) | Expr::Tpl(_) => true,
+      Expr::Lit(lit) => !matches!(lit, Lit::Num(_)),
+      _ => false,
+    };
+    if redundant && self.is_checked(&paren_expr.expr) {
+      self.report(paren_expr.span);
+    }
+    paren_expr.visit_children_with(self);
+  }
+
+  fn visit_expr_stmt(&mut self, expr_stmt: &ExprStmt, _parent: &dyn Node) {
+    if let Expr::Paren(ParenExpr { expr, .. }) = &*expr_stmt.expr {
+      if !starts_with_ambiguous_token(expr) {
+        self.check_free_position(&expr_stmt.expr, true);
+      }
+    }
+    expr_stmt.visit_children_with(self);
+  }
+
+  fn visit_var_declarator(
+    &mut self,
+    var_declarator: &VarDeclarator,
+    _parent: &dyn Node,
+  ) {
+    if let Some(init) = &var_declarator.init {
+      self.check_free_position(init, false);
+    }
+    var_declarator.visit_children_with(self);
+  }
+
+  fn visit_return_stmt(
+    &mut self,
+    return_stmt: &ReturnStmt,
+    _parent: &dyn Node,
+  ) {
+    if let Some(arg) = &return_stmt.arg {
+      self.check_free_position(arg, true);
+    }
+    return_stmt.visit_children_with(self);
+  }
+
+  fn visit_throw_stmt(&mut self, throw_stmt: &ThrowStmt, _parent: &dyn Node) {
+    self.check_free_position(&throw_stmt.arg, true);
+    throw_stmt.visit_children_with(self);
+  }
+
+  fn visit_if_stmt(&mut self, if_stmt: &IfStmt, _parent: &dyn Node) {
+    self.check_free_position(&if_stmt.test, true);
+    if_stmt.visit_children_with(self);
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _parent: &dyn Node) {
+    self.check_free_position(&while_stmt.test, true);
+    while_stmt.visit_children_with(self);
+  }
+
+  fn visit_do_while_stmt(
+    &mut self,
+    do_while_stmt: &DoWhileStmt,
+    _parent: &dyn Node,
+  ) {
+    self.check_free_position(&do_while_stmt.test, true);
+    do_while_stmt.visit_children_with(self);
+  }
+
+  fn visit_switch_stmt(
+    &mut self,
+    switch_stmt: &SwitchStmt,
+    _parent: &dyn Node,
+  ) {
+    self.check_free_position(&switch_stmt.discriminant, true);
+    switch_stmt.visit_children_with(self);
+  }
+
+  fn visit_expr_or_spread(
+    &mut self,
+    expr_or_spread: &ExprOrSpread,
+    _parent: &dyn Node,
+  ) {
+    self.check_free_position(&expr_or_spread.expr, false);
+    expr_or_spread.visit_children_with(self);
+  }
+
+  fn visit_assign_expr(
+    &mut self,
+    assign_expr: &AssignExpr,
+    _parent: &dyn Node,
+  ) {
+    self.check_free_position(&assign_expr.right, false);
+    assign_expr.visit_children_with(self);
+  }
+
+  fn visit_key_value_prop(
+    &mut self,
+    key_value_prop: &KeyValueProp,
+    _parent: &dyn Node,
+  ) {
+    self.check_free_position(&key_value_prop.value, false);
+    key_value_prop.visit_children_with(self);
+  }
+}
+
+fn unwrap_parens(expr: &Expr) -> &Expr {
+  match expr {
+    Expr::Paren(ParenExpr { expr, .. }) => unwrap_parens(expr),
+    _ => expr,
+  }
+}
+
+/// Checks if `expr` starts with a token that would be parsed differently at
+/// the start of a statement, i.e. `{`, `function` or `class`.
+fn starts_with_ambiguous_token(expr: &Expr) -> bool {
+  match expr {
+    Expr::Object(_) | Expr::Fn(_) | Expr::Class(_) => true,
+    Expr::Bin(bin_expr) => starts_with_ambiguous_token(&bin_expr.left),
+    Expr::Cond(cond_expr) => starts_with_ambiguous_token(&cond_expr.test),
+    Expr::Seq(seq_expr) => seq_expr
+      .exprs
+      .first()
+      .map_or(false, |expr| starts_with_ambiguous_token(expr)),
+    Expr::Member(member_expr) => match &member_expr.obj {
+      ExprOrSuper::Expr(obj) => starts_with_ambiguous_token(obj),
+      ExprOrSuper::Super(_) => false,
+    },
+    Expr::Call(call_expr) => match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => starts_with_ambiguous_token(callee),
+      ExprOrSuper::Super(_) => false,
+    },
+    Expr::Update(update_expr) if !update_expr.prefix => {
+      starts_with_ambiguous_token(&update_expr.arg)
+    }
+    Expr::Assign(assign_expr) => match &assign_expr.left {
+      PatOrExpr::Expr(left) => starts_with_ambiguous_token(left),
+      PatOrExpr::Pat(pat) => match &**pat {
+        Pat::Object(_) => true,
+        Pat::Expr(left) => starts_with_ambiguous_token(left),
+        _ => false,
+      },
+    },
+    Expr::TaggedTpl(tagged_tpl) => starts_with_ambiguous_token(&tagged_tpl.tag),
+    Expr::OptChain(opt_chain) => starts_with_ambiguous_token(&opt_chain.expr),
+    Expr::TsAs(ts_as) => starts_with_ambiguous_token(&ts_as.expr),
+    Expr::TsNonNull(ts_non_null) => {
+      starts_with_ambiguous_token(&ts_non_null.expr)
+    }
+    _ => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn no_extra_parens_valid() {
+    assert_lint_ok! {
+      NoExtraParens,
+      "const a = b;",
+      "const a = (b + c) * d;",
+      "const a = -(b + c);",
+      "const a = (b, c);",
+      "foo((a, b));",
+      "const a = [(b, c)];",
+      "a = (b, c);",
+      "(function () {})();",
+      "(function () {}());",
+      "(class {}).name;",
+      "({}).toString();",
+      "({ a } = b);",
+      "({} + 1);",
+      "(1).toString();",
+      "(a?.b).c;",
+      "new (a.b())();",
+      "const f = () => ({});",
+      "for (const a of (b, c)) {}",
+      "(await a).b;",
+      "(a as any).b;",
+      "typeof (a + b);",
+    };
+  }
+
+  #[test]
+  fn no_extra_parens_invalid() {
+    assert_lint_err! {
+      NoExtraParens,
+      "const a = (b);": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "const a = ((b + c));": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "const a = ((b + c)) * d;": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "const a = (((b)));": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+        },
+        {
+          col: 11,
+          message: MESSAGE,
+          hint: HINT,
+        },
+        {
+          col: 12,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "(a + b);": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "(a, b);": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "(a)();": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "('a').length;": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "if ((a === b)) {}": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "while ((a, b)) {}": [
+        {
+          col: 7,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "function f() { return (a + b); }": [
+        {
+          col: 22,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "foo((a + b));": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "a = (b ? c : d);": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "const o = { a: (b || c) };": [
+        {
+          col: 15,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "const f = (function () {});": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_extra_parens_functions_mode() {
+    let rule = || {
+      NoExtraParens::with_options(
+        serde_json::from_str(r#"{ "mode": "functions" }"#).unwrap(),
+      )
+    };
+
+    assert_lint_ok_with_rule(rule(), "const a = (b);");
+    assert_lint_ok_with_rule(rule(), "const a = ((b + c));");
+    assert_lint_ok_with_rule(rule(), "(a + b);");
+    assert_lint_ok_with_rule(rule(), "(function () {})();");
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "const f = (function () {});",
+      vec![(1, 10)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "foo((function () {}));",
+      vec![(1, 4)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "((function () {}))();",
+      vec![(1, 0)],
+    );
+  }
+}
@@ -1,16 +1,44 @@
 use super::LintRule;
 use crate::linter::Context;
-use swc_common::{comments::Comment, Span, Spanned, DUMMY_SP};
+use crate::swc_util::{comment_pattern, comments_between};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Deserialize;
+use swc_common::{BytePos, Span, Spanned, DUMMY_SP};
 use swc_ecmascript::{
   ast::*,
   visit::{noop_visit_type, Node, Visit, VisitWith},
 };
 
-pub struct NoFallthrough;
+pub struct NoFallthrough {
+  comment_pattern: Regex,
+}
+
+static DEFAULT_COMMENT_PATTERN: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"(?i)falls?\s?through").unwrap());
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoFallthroughOptions {
+  /// Regular expression matched against comments marking a fallthrough as
+  /// intentional. Defaults to `falls?\s?through`, matched case-insensitively.
+  pub comment_pattern: Option<String>,
+}
+
+impl NoFallthrough {
+  pub fn with_options(options: NoFallthroughOptions) -> Box<Self> {
+    let comment_pattern = comment_pattern(
+      "no-fallthrough",
+      options.comment_pattern,
+      &DEFAULT_COMMENT_PATTERN,
+    );
+    Box::new(NoFallthrough { comment_pattern })
+  }
+}
 
 impl LintRule for NoFallthrough {
   fn new() -> Box<Self> {
-    Box::new(NoFallthrough)
+    Self::with_options(NoFallthroughOptions::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -26,7 +54,10 @@ impl LintRule for NoFallthrough {
     context: &mut Context,
     program: &swc_ecmascript::ast::Program,
   ) {
-    let mut visitor = NoFallthroughVisitor { context };
+    let mut visitor = NoFallthroughVisitor {
+      context,
+      comment_pattern: &self.comment_pattern,
+    };
     visitor.visit_program(program, program);
   }
 
@@ -39,7 +70,10 @@ is sometimes intentional, many times the developer has forgotten to add a break
 statement, intending only for a single case statement to be executed.  This
 rule enforces that you either end each case statement with a break statement or
 an explicit comment that fallthrough was intentional.  The fallthrough comment
-must contain one of `fallthrough`, `falls through` or `fall through`.
+must be placed between the last statement of the case and the next case, and
+must contain one of `fallthrough`, `falls through` or `fall through`.  A
+different comment can be required with the `commentPattern` option, a regular
+expression matched against the text of the comments.
     
 ### Invalid:
 ```typescript
@@ -80,61 +114,50 @@ switch(myVar) {
   }
 }
 
-struct NoFallthroughVisitor<'c> {
+struct NoFallthroughVisitor<'c, 'r> {
   context: &'c mut Context,
+  comment_pattern: &'r Regex,
 }
 
-impl<'c> Visit for NoFallthroughVisitor<'c> {
+impl<'c, 'r> NoFallthroughVisitor<'c, 'r> {
+  /// Returns whether a comment between `lo` and `hi` marks a fallthrough as
+  /// intentional.
+  fn has_fallthrough_comment(&self, lo: BytePos, hi: BytePos) -> bool {
+    comments_between(self.context, lo, hi)
+      .any(|comment| self.comment_pattern.is_match(&comment.text))
+  }
+}
+
+impl<'c, 'r> Visit for NoFallthroughVisitor<'c, 'r> {
   noop_visit_type!();
 
   fn visit_switch_cases(&mut self, cases: &[SwitchCase], parent: &dyn Node) {
     let mut should_emit_err = false;
     let mut prev_span = DUMMY_SP;
 
-    'cases: for (case_idx, case) in cases.iter().enumerate() {
+    for (case_idx, case) in cases.iter().enumerate() {
       case.visit_with(parent, self);
 
-      if should_emit_err {
-        let mut emit = true;
-        if let Some(comments) = self.context.leading_comments.get(&case.span.lo)
-        {
-          if allow_fall_through(&comments) {
-            emit = false;
-          }
-        }
-        if emit {
-          self.context.add_diagnostic_with_hint(
-            prev_span,
-            "no-fallthrough",
-            "Fallthrough is not allowed",
-            "Add `break` or comment `/* falls through */` to your case statement"
-          );
-        }
+      if should_emit_err
+        && !self.has_fallthrough_comment(prev_span.hi, case.span.lo)
+      {
+        self.context.add_diagnostic_with_hint(
+          prev_span,
+          "no-fallthrough",
+          "Fallthrough is not allowed",
+          "Add `break` or comment `/* falls through */` to your case statement",
+        );
       }
       should_emit_err = true;
       let mut stops_exec = false;
 
       // Handle return / throw / break / continue
-      for (idx, stmt) in case.cons.iter().enumerate() {
-        let last = idx + 1 == case.cons.len();
+      for stmt in &case.cons {
         let metadata = self.context.control_flow.meta(stmt.span().lo);
         stops_exec |= metadata.map(|v| v.stops_execution()).unwrap_or(false);
         if stops_exec {
           should_emit_err = false;
         }
-
-        if last {
-          if let Some(comments) =
-            self.context.trailing_comments.get(&stmt.span().hi)
-          {
-            if allow_fall_through(&comments) {
-              should_emit_err = false;
-              // User comment beats everything
-              prev_span = case.span;
-              continue 'cases;
-            }
-          }
-        }
       }
 
       let empty = case.cons.is_empty()
@@ -149,12 +172,14 @@ impl<'c> Visit for NoFallthroughVisitor<'c> {
           hi: cases[case_idx + 1].span.lo(),
           ctxt: case.span.ctxt,
         };
-        let span_lines = self.context.source_map.span_to_lines(span).unwrap();
         // When the case body contains only new lines `case.cons` will be empty.
         // This means there are no statements detected so we must detect case
         // bodies made up of only new lines by counting the total amount of new lines.
         // If there's more than 2 new lines and `case.cons` is empty this indicates the case body only contains new lines.
-        should_emit_err = span_lines.lines.len() > 2;
+        should_emit_err = match self.context.source_map.span_to_lines(span) {
+          Ok(span_lines) => span_lines.lines.len() > 2,
+          Err(_) => false,
+        };
       }
 
       prev_span = case.span;
@@ -162,19 +187,6 @@ impl<'c> Visit for NoFallthroughVisitor<'c> {
   }
 }
 
-fn allow_fall_through(comments: &[Comment]) -> bool {
-  for comment in comments {
-    let l = comment.text.to_ascii_lowercase();
-    if l.contains("fallthrough")
-      || l.contains("falls through")
-      || l.contains("fall through")
-    {
-      return true;
-    }
-  }
-  false
-}
-
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -217,9 +229,38 @@ mod tests {
       "switch('test') { case 'symbol':\n case 'function': default: b(); }",
       "switch('test') { case 'symbol':\n case 'function':\n default: b(); }",
       "switch('test') { case 'symbol': case 'function': default: b(); }",
+      "switch(foo) { case 0: a();\n  // falls through\n  case 1: b(); }",
+      "switch(foo) { case 0: a(); // falls through\n  case 1: b(); }",
+      "switch(foo) { case 0: a();\n  // some comment\n  // falls through\n  case 1: b(); }",
+      "switch(foo) { case 0: a();\n  /* falls through */\n\n  default: b(); }",
+      "switch(foo) { case 0: { a(); } // falls through\n  case 1: b(); }",
+      "switch(foo) { case 0:\n\n  // falls through\n  case 1: b(); }",
     };
   }
 
+  #[test]
+  fn no_fallthrough_comment_pattern() {
+    let rule = || {
+      NoFallthrough::with_options(NoFallthroughOptions {
+        comment_pattern: Some("break[\\s\\w]*omitted".to_string()),
+      })
+    };
+
+    assert_lint_ok_with_rule(
+      rule(),
+      "switch(foo) { case 0: a();\n  // break omitted\n  case 1: b(); }",
+    );
+    assert_lint_ok_with_rule(
+      rule(),
+      "switch(foo) { case 0: a(); /* break intentionally omitted */ case 1: b(); }",
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "switch(foo) { case 0: a();\n  // falls through\n  case 1: b(); }",
+      vec![(1, 14)],
+    );
+  }
+
   #[test]
   fn no_fallthrough_invalid() {
     assert_lint_err::<NoFallthrough>(
@@ -267,6 +308,14 @@ mod tests {
       "switch(foo) { case 0: a(); /* falling through */ default: b() }",
       14,
     );
+    assert_lint_err::<NoFallthrough>(
+      "switch(foo) { case 0: a(); case 1: b(); /* falls through */ }",
+      14,
+    );
+    assert_lint_err::<NoFallthrough>(
+      "switch(foo) { case 0: /* falls through */ a(); case 1: b(); }",
+      14,
+    );
   }
 
   #[test]
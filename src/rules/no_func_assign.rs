@@ -2,10 +2,14 @@
 use super::Context;
 use super::LintRule;
 use crate::{scopes::BindingKind, swc_util::find_lhs_ids};
-use swc_ecmascript::ast::AssignExpr;
+use swc_common::Span;
+use swc_ecmascript::ast::{AssignExpr, Expr, UpdateExpr};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::utils::Id;
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
-use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
 
 pub struct NoFuncAssign;
 
@@ -28,7 +32,7 @@ impl LintRule for NoFuncAssign {
     program: &swc_ecmascript::ast::Program,
   ) {
     let mut visitor = NoFuncAssignVisitor::new(context);
-    visitor.visit_program(program, program);
+    program.visit_all_with(program, &mut visitor);
   }
 
   fn docs(&self) -> &'static str {
@@ -77,26 +81,36 @@ impl<'c> NoFuncAssignVisitor<'c> {
   fn new(context: &'c mut Context) -> Self {
     Self { context }
   }
+
+  fn check(&mut self, span: Span, id: &Id) {
+    let var = self.context.scope.var(id);
+    if let Some(var) = var {
+      if let BindingKind::Function = var.kind() {
+        self.context.add_diagnostic_with_hint(
+          span,
+          "no-func-assign",
+          "Reassigning function declaration is not allowed",
+          "Remove or rework the reassignment of the existing function",
+        );
+      }
+    }
+  }
 }
 
-impl<'c> Visit for NoFuncAssignVisitor<'c> {
+impl<'c> VisitAll for NoFuncAssignVisitor<'c> {
   noop_visit_type!();
 
   fn visit_assign_expr(&mut self, assign_expr: &AssignExpr, _node: &dyn Node) {
     let ids = find_lhs_ids(&assign_expr.left);
 
     for id in ids {
-      let var = self.context.scope.var(&id);
-      if let Some(var) = var {
-        if let BindingKind::Function = var.kind() {
-          self.context.add_diagnostic_with_hint(
-            assign_expr.span,
-            "no-func-assign",
-            "Reassigning function declaration is not allowed",
-            "Remove or rework the reassignment of the existing function",
-          );
-        }
-      }
+      self.check(assign_expr.span, &id);
+    }
+  }
+
+  fn visit_update_expr(&mut self, update_expr: &UpdateExpr, _node: &dyn Node) {
+    if let Expr::Ident(ident) = &*update_expr.arg {
+      self.check(update_expr.span, &ident.to_id());
     }
   }
 }
@@ -106,6 +120,86 @@ mod tests {
   use super::*;
   use crate::test_util::assert_lint_err_on_line;
 
+  #[test]
+  fn no_func_assign_valid() {
+    assert_lint_ok! {
+      NoFuncAssign,
+      "function foo() { var foo = bar; }",
+      "function foo(foo) { foo = bar; }",
+      "function foo() { var foo; foo = bar; }",
+      "var foo = () => {}; foo = bar;",
+      "var foo = function() {}; foo = bar;",
+      "let foo = function() {}; foo = bar;",
+      "let foo = function bar() {}; foo = baz;",
+      "var foo = function() { foo = bar; };",
+      "const foo = function bar() { let bar; bar = 1; };",
+      "import bar from 'bar'; function foo() { var foo = bar; }",
+      "let foo = 0; foo++;",
+    };
+  }
+
+  #[test]
+  fn no_func_assign_invalid() {
+    assert_lint_err! {
+      NoFuncAssign,
+      "function foo() {}; foo = bar;": [{
+        col: 19,
+        message: "Reassigning function declaration is not allowed",
+        hint: "Remove or rework the reassignment of the existing function",
+      }],
+      "function foo() { foo = bar; }": [{
+        col: 17,
+        message: "Reassigning function declaration is not allowed",
+        hint: "Remove or rework the reassignment of the existing function",
+      }],
+      "foo = bar; function foo() { };": [{
+        col: 0,
+        message: "Reassigning function declaration is not allowed",
+        hint: "Remove or rework the reassignment of the existing function",
+      }],
+      "[foo] = bar; function foo() { };": [{
+        col: 0,
+        message: "Reassigning function declaration is not allowed",
+        hint: "Remove or rework the reassignment of the existing function",
+      }],
+      "({x: foo = 0} = bar); function foo() { };": [{
+        col: 1,
+        message: "Reassigning function declaration is not allowed",
+        hint: "Remove or rework the reassignment of the existing function",
+      }],
+      "function foo() { [foo] = bar; }": [{
+        col: 17,
+        message: "Reassigning function declaration is not allowed",
+        hint: "Remove or rework the reassignment of the existing function",
+      }],
+      "(function() { ({x: foo = 0} = bar); function foo() { }; })();": [{
+        col: 15,
+        message: "Reassigning function declaration is not allowed",
+        hint: "Remove or rework the reassignment of the existing function",
+      }],
+      "var a = function foo() { foo = 123; };": [{
+        col: 25,
+        message: "Reassigning function declaration is not allowed",
+        hint: "Remove or rework the reassignment of the existing function",
+      }],
+      "function foo() {}; foo++;": [{
+        col: 19,
+        message: "Reassigning function declaration is not allowed",
+        hint: "Remove or rework the reassignment of the existing function",
+      }],
+      "function foo() {}; bar(() => { foo = 1; });": [{
+        col: 31,
+        message: "Reassigning function declaration is not allowed",
+        hint: "Remove or rework the reassignment of the existing function",
+      }],
+      "function foo() {}; bar = foo = 1;": [{
+        col: 25,
+        message: "Reassigning function declaration is not allowed",
+        hint: "Remove or rework the reassignment of the existing function",
+      }],
+    };
+  }
+
   #[test]
   fn no_func_assign() {
     assert_lint_err_on_line::<NoFuncAssign>(
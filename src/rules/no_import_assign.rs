@@ -17,7 +17,8 @@ pub struct NoImportAssign;
 
 const CODE: &str = "no-import-assign";
 const MESSAGE: &str = "Assignment to import is not allowed";
-const HINT: &str = "Assign to another variable, this assignment is invalid";
+const HINT: &str =
+  "Imported bindings are read-only, assign the value to a local variable instead";
 
 impl LintRule for NoImportAssign {
   fn new() -> Box<Self> {
@@ -433,6 +434,10 @@ mod tests {
       "import * as mod from 'mod'; Object.seal(mod, obj)",
       "import * as mod from 'mod'; Object.preventExtensions(mod)",
       "import * as mod from 'mod'; Reflect.preventExtensions(mod)",
+      "import mod from 'mod'; function foo(mod) { mod = 1; }",
+      "import mod from 'mod'; const foo = (mod) => { mod++; };",
+      "import mod from 'mod'; try {} catch (mod) { mod = 1; }",
+      "import * as mod from 'mod'; function foo(mod) { mod.named = 1; }",
     };
   }
 
@@ -498,6 +503,12 @@ mod tests {
       "import * as mod from 'mod'; Object?.defineProperty(mod, key, d)": [{ col: 28, message: MESSAGE, hint: HINT }],
       "import * as mod from 'mod'; (Object?.defineProperty)(mod, key, d)": [{ col: 28, message: MESSAGE, hint: HINT }],
       "import * as mod from 'mod'; delete mod?.prop": [{ col: 28, message: MESSAGE, hint: HINT }],
+      "import * as mod from 'mod'; mod['named'] = 0": [{ col: 28, message: MESSAGE, hint: HINT }],
+      // assignments in nested scopes
+      "import mod from 'mod'; function foo() { mod = 1; }": [{ col: 40, message: MESSAGE, hint: HINT }],
+      "import mod from 'mod'; const foo = () => { mod++; };": [{ col: 43, message: MESSAGE, hint: HINT }],
+      "import {named} from 'mod'; class A { foo() { named = 1; } }": [{ col: 45, message: MESSAGE, hint: HINT }],
+      "import * as mod from 'mod'; function foo() { mod.named = 1; }": [{ col: 45, message: MESSAGE, hint: HINT }],
     }
   }
 }
@@ -1,18 +1,37 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
-
+use serde::Deserialize;
 use swc_ecmascript::ast::{
-  Expr, ExprOrSuper, Lit, TsKeywordType, TsType, TsTypeRef, VarDecl,
+  Expr, ExprOrSuper, Lit, Pat, TsKeywordType, TsType, TsTypeRef, VarDecl,
 };
+use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
 
-pub struct NoInferrableTypes;
+pub struct NoInferrableTypes {
+  options: NoInferrableTypesOptions,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoInferrableTypesOptions {
+  /// Whether parameters with a default value are left unchecked.
+  pub ignore_parameters: bool,
+  /// Whether class properties with an initializer are left unchecked.
+  pub ignore_properties: bool,
+}
+
+impl NoInferrableTypes {
+  pub fn with_options(options: NoInferrableTypesOptions) -> Box<Self> {
+    Box::new(NoInferrableTypes { options })
+  }
+}
 
 impl LintRule for NoInferrableTypes {
   fn new() -> Box<Self> {
-    Box::new(NoInferrableTypes)
+    Self::with_options(NoInferrableTypesOptions::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -23,12 +42,16 @@ impl LintRule for NoInferrableTypes {
     "no-inferrable-types"
   }
 
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
   fn lint_program(
     &self,
     context: &mut Context,
     program: &swc_ecmascript::ast::Program,
   ) {
-    let mut visitor = NoInferrableTypesVisitor::new(context);
+    let mut visitor = NoInferrableTypesVisitor::new(context, &self.options);
     visitor.visit_program(program, program);
   }
 
@@ -95,17 +118,24 @@ class Foo {
 
 function fn(s = 5, t = true) {}
 ```
+
+Parameters and class properties can be left unchecked with the
+`ignoreParameters` and `ignoreProperties` options.
 "#
   }
 }
 
-struct NoInferrableTypesVisitor<'c> {
+struct NoInferrableTypesVisitor<'c, 'o> {
   context: &'c mut Context,
+  options: &'o NoInferrableTypesOptions,
 }
 
-impl<'c> NoInferrableTypesVisitor<'c> {
-  fn new(context: &'c mut Context) -> Self {
-    Self { context }
+impl<'c, 'o> NoInferrableTypesVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o NoInferrableTypesOptions,
+  ) -> Self {
+    Self { context, options }
   }
 
   fn add_diagnostic_helper(&mut self, span: swc_common::Span) {
@@ -367,21 +397,26 @@ impl<'c> NoInferrableTypesVisitor<'c> {
   }
 }
 
-impl<'c> Visit for NoInferrableTypesVisitor<'c> {
+impl<'c, 'o> Visit for NoInferrableTypesVisitor<'c, 'o> {
+  noop_visit_type!();
+
   fn visit_function(
     &mut self,
     function: &swc_ecmascript::ast::Function,
     _parent: &dyn Node,
   ) {
-    for param in &function.params {
-      if let swc_ecmascript::ast::Pat::Assign(assign_pat) = &param.pat {
-        if let swc_ecmascript::ast::Pat::Ident(ident) = &*assign_pat.left {
-          if let Some(ident_type_ann) = &ident.type_ann {
-            self.check_ts_type(&assign_pat.right, ident_type_ann, param.span);
+    if !self.options.ignore_parameters {
+      for param in &function.params {
+        if let Pat::Assign(assign_pat) = &param.pat {
+          if let Pat::Ident(ident) = &*assign_pat.left {
+            if let Some(ident_type_ann) = &ident.type_ann {
+              self.check_ts_type(&assign_pat.right, ident_type_ann, param.span);
+            }
           }
         }
       }
     }
+    function.visit_children_with(self);
   }
 
   fn visit_arrow_expr(
@@ -389,19 +424,22 @@ impl<'c> Visit for NoInferrableTypesVisitor<'c> {
     arr_expr: &swc_ecmascript::ast::ArrowExpr,
     _parent: &dyn Node,
   ) {
-    for param in &arr_expr.params {
-      if let swc_ecmascript::ast::Pat::Assign(assign_pat) = &param {
-        if let swc_ecmascript::ast::Pat::Ident(ident) = &*assign_pat.left {
-          if let Some(ident_type_ann) = &ident.type_ann {
-            self.check_ts_type(
-              &assign_pat.right,
-              ident_type_ann,
-              assign_pat.span,
-            );
+    if !self.options.ignore_parameters {
+      for param in &arr_expr.params {
+        if let Pat::Assign(assign_pat) = &param {
+          if let Pat::Ident(ident) = &*assign_pat.left {
+            if let Some(ident_type_ann) = &ident.type_ann {
+              self.check_ts_type(
+                &assign_pat.right,
+                ident_type_ann,
+                assign_pat.span,
+              );
+            }
           }
         }
       }
     }
+    arr_expr.visit_children_with(self);
   }
 
   fn visit_class_prop(
@@ -409,35 +447,27 @@ impl<'c> Visit for NoInferrableTypesVisitor<'c> {
     prop: &swc_ecmascript::ast::ClassProp,
     _parent: &dyn Node,
   ) {
-    if prop.readonly || prop.is_optional {
-      return;
-    }
-    if let Some(init) = &prop.value {
-      if let Expr::Ident(_) = &*prop.key {
-        if let Some(ident_type_ann) = &prop.type_ann {
-          self.check_ts_type(init, ident_type_ann, prop.span);
+    if !self.options.ignore_properties && !prop.readonly && !prop.is_optional {
+      if let Some(init) = &prop.value {
+        if let Expr::Ident(_) = &*prop.key {
+          if let Some(ident_type_ann) = &prop.type_ann {
+            self.check_ts_type(init, ident_type_ann, prop.span);
+          }
         }
       }
     }
+    prop.visit_children_with(self);
   }
 
   fn visit_var_decl(&mut self, var_decl: &VarDecl, _parent: &dyn Node) {
-    if let Some(init) = &var_decl.decls[0].init {
-      if let Expr::Fn(fn_expr) = &**init {
-        if !fn_expr.function.params.is_empty() {
-          self.visit_function(&fn_expr.function, _parent);
-        }
-      } else if let Expr::Arrow(arr_expr) = &**init {
-        if !arr_expr.params.is_empty() {
-          self.visit_arrow_expr(&arr_expr, _parent);
-        }
-      }
-      if let swc_ecmascript::ast::Pat::Ident(ident) = &var_decl.decls[0].name {
+    for decl in &var_decl.decls {
+      if let (Some(init), Pat::Ident(ident)) = (&decl.init, &decl.name) {
         if let Some(ident_type_ann) = &ident.type_ann {
           self.check_ts_type(init, ident_type_ann, var_decl.span);
         }
       }
     }
+    var_decl.visit_children_with(self);
   }
 }
 
@@ -581,6 +611,50 @@ b: boolean = true;
 c: string = 'foo';
 }",
       vec![(2, 0), (3, 0), (4, 0)],
-    )
+    );
+
+    assert_lint_err_on_line_n::<NoInferrableTypes>(
+      "function foo() {\n  const a: number = 5, b: string = 'foo';\n  return (c: boolean = true) => {};\n}",
+      vec![(2, 2), (2, 2), (3, 10)],
+    );
+    assert_lint_err_on_line_n::<NoInferrableTypes>(
+      "class Foo {\n  bar(a: number = 5) {\n    let b: null = null;\n  }\n}",
+      vec![(2, 6), (3, 4)],
+    );
+  }
+
+  #[test]
+  fn no_inferrable_types_options() {
+    let ignore_parameters = || {
+      NoInferrableTypes::with_options(NoInferrableTypesOptions {
+        ignore_parameters: true,
+        ..Default::default()
+      })
+    };
+    assert_lint_ok_with_rule(
+      ignore_parameters(),
+      "function fn(a: number = 5, b: boolean = true) {}\nconst fn2 = (c: string = 'foo') => {};",
+    );
+    assert_lint_err_on_line_n_with_rule(
+      ignore_parameters(),
+      "class Foo {\n  a: number = 5;\n}",
+      vec![(2, 2)],
+    );
+
+    let ignore_properties = || {
+      NoInferrableTypes::with_options(NoInferrableTypesOptions {
+        ignore_properties: true,
+        ..Default::default()
+      })
+    };
+    assert_lint_ok_with_rule(
+      ignore_properties(),
+      "class Foo {\n  a: number = 5;\n  b: boolean = true;\n}",
+    );
+    assert_lint_err_on_line_n_with_rule(
+      ignore_properties(),
+      "function fn(a: number = 5) {}",
+      vec![(1, 12)],
+    );
   }
 }
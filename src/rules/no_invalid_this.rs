@@ -0,0 +1,477 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_ecmascript::ast::{
+  AssignExpr, CallExpr, ClassMethod, ClassProp, Constructor, Expr, ExprOrSuper,
+  FnDecl, FnExpr, Function, GetterProp, Ident, KeyValueProp, Lit, MethodProp,
+  Pat, PatOrExpr, PrivateMethod, PrivateProp, Program, SetterProp, ThisExpr,
+  VarDeclarator,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoInvalidThis {
+  options: NoInvalidThisOptions,
+}
+
+const CODE: &str = "no-invalid-this";
+const MESSAGE: &str = "Unexpected 'this' outside of a method or constructor";
+const HINT: &str = "Move the code into a class or object method, or use an arrow function to keep the enclosing 'this'";
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoInvalidThisOptions {
+  /// Whether functions whose name starts with an uppercase letter are
+  /// treated as constructors, so they may use `this`.
+  pub cap_is_constructor: bool,
+}
+
+impl Default for NoInvalidThisOptions {
+  fn default() -> Self {
+    Self {
+      cap_is_constructor: true,
+    }
+  }
+}
+
+impl NoInvalidThis {
+  pub fn with_options(options: NoInvalidThisOptions) -> Box<Self> {
+    Box::new(NoInvalidThis { options })
+  }
+}
+
+impl LintRule for NoInvalidThis {
+  fn new() -> Box<Self> {
+    Self::with_options(NoInvalidThisOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = NoInvalidThisVisitor::new(context, &self.options);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows `this` where its value is `undefined`
+
+In modules and strict mode functions, `this` is `undefined` at the top level
+and in plain function calls. Using it there is almost always a mistake.
+
+`this` is allowed in class members, object methods, functions assigned to a
+property, functions called with `.call()`, `.apply()` or `.bind()`, functions
+with an explicit `this` parameter, and arrow functions inside any of these.
+
+With the `capIsConstructor` option (enabled by default), functions whose name
+starts with an uppercase letter are considered constructors and may use `this`.
+
+### Invalid:
+```typescript
+this.a = 0;
+
+function foo() {
+  this.a = 0;
+}
+
+const bar = () => {
+  this.a = 0;
+};
+```
+
+### Valid:
+```typescript
+class A {
+  a = this.b;
+  foo() {
+    this.a = 0;
+  }
+}
+
+const obj = {
+  foo() {
+    this.a = 0;
+  },
+};
+
+function Foo() {
+  this.a = 0;
+}
+
+function bar(this: Bar) {
+  this.a = 0;
+}
+
+(function () {
+  this.a = 0;
+}).call(obj);
+```
+"#
+  }
+}
+
+struct NoInvalidThisVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o NoInvalidThisOptions,
+  /// Whether `this` is valid in each enclosing non-arrow function, innermost
+  /// last. Empty at the top level.
+  valid_this: Vec<bool>,
+}
+
+impl<'c, 'o> NoInvalidThisVisitor<'c, 'o> {
+  fn new(context: &'c mut Context, options: &'o NoInvalidThisOptions) -> Self {
+    Self {
+      context,
+      options,
+      valid_this: vec![],
+    }
+  }
+
+  fn with_this<F>(&mut self, valid: bool, f: F)
+  where
+    F: FnOnce(&mut Self),
+  {
+    self.valid_this.push(valid);
+    f(self);
+    self.valid_this.pop();
+  }
+
+  fn is_constructor_name(&self, ident: Option<&Ident>) -> bool {
+    self.options.cap_is_constructor
+      && matches!(
+        ident.and_then(|ident| ident.sym.chars().next()),
+        Some(c) if c.is_uppercase()
+      )
+  }
+
+  /// Visits a standalone function, in which `this` is valid only if it's
+  /// declared with a `this` parameter or it's named like a constructor.
+  fn visit_plain_function(
+    &mut self,
+    function: &Function,
+    name: Option<&Ident>,
+    parent: &dyn Node,
+  ) {
+    let valid = has_this_param(function) || self.is_constructor_name(name);
+    self.with_this(valid, |v| function.visit_with(parent, v));
+  }
+
+  /// Visits an expression which becomes a method of some object, e.g. the
+  /// value of an object property.
+  fn visit_method_value(&mut self, expr: &Expr, parent: &dyn Node) {
+    match unwrap_paren(expr) {
+      Expr::Fn(fn_expr) => {
+        fn_expr.ident.visit_with(fn_expr, self);
+        self.with_this(true, |v| fn_expr.function.visit_with(fn_expr, v));
+      }
+      _ => expr.visit_with(parent, self),
+    }
+  }
+}
+
+fn unwrap_paren(expr: &Expr) -> &Expr {
+  match expr {
+    Expr::Paren(paren) => unwrap_paren(&paren.expr),
+    _ => expr,
+  }
+}
+
+fn has_this_param(function: &Function) -> bool {
+  function.params.iter().any(
+    |param| matches!(&param.pat, Pat::Ident(ident) if ident.sym == *"this"),
+  )
+}
+
+fn is_member_target(left: &PatOrExpr) -> bool {
+  match left {
+    PatOrExpr::Expr(expr) => matches!(&**expr, Expr::Member(_)),
+    PatOrExpr::Pat(pat) => match &**pat {
+      Pat::Expr(expr) => matches!(&**expr, Expr::Member(_)),
+      _ => false,
+    },
+  }
+}
+
+fn assigned_ident(left: &PatOrExpr) -> Option<&Ident> {
+  match left {
+    PatOrExpr::Expr(expr) => match &**expr {
+      Expr::Ident(ident) => Some(ident),
+      _ => None,
+    },
+    PatOrExpr::Pat(pat) => match &**pat {
+      Pat::Ident(ident) => Some(ident),
+      _ => None,
+    },
+  }
+}
+
+/// Checks if the call binds `this` of a function expression explicitly, as
+/// in `(function() {}).call(obj)`, and returns the function.
+fn bound_function(call_expr: &CallExpr) -> Option<&FnExpr> {
+  let callee = match &call_expr.callee {
+    ExprOrSuper::Expr(callee) => callee,
+    ExprOrSuper::Super(_) => return None,
+  };
+  let member = match unwrap_paren(callee) {
+    Expr::Member(member) if !member.computed => member,
+    _ => return None,
+  };
+  match &*member.prop {
+    Expr::Ident(prop)
+      if prop.sym == *"call" || prop.sym == *"apply" || prop.sym == *"bind" => {
+    }
+    _ => return None,
+  }
+
+  // `this` stays `undefined` when binding `null` or `undefined`.
+  let this_arg = call_expr.args.first()?;
+  match unwrap_paren(&this_arg.expr) {
+    Expr::Lit(Lit::Null(_)) => return None,
+    Expr::Ident(ident) if ident.sym == *"undefined" => return None,
+    _ => {}
+  }
+
+  match &member.obj {
+    ExprOrSuper::Expr(obj) => match unwrap_paren(obj) {
+      Expr::Fn(fn_expr) => Some(fn_expr),
+      _ => None,
+    },
+    ExprOrSuper::Super(_) => None,
+  }
+}
+
+impl<'c, 'o> Visit for NoInvalidThisVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_this_expr(&mut self, this_expr: &ThisExpr, _parent: &dyn Node) {
+    if !self.valid_this.last().copied().unwrap_or(false) {
+      self.context.add_diagnostic_with_hint(
+        this_expr.span,
+        CODE,
+        MESSAGE,
+        HINT,
+      );
+    }
+  }
+
+  fn visit_fn_decl(&mut self, fn_decl: &FnDecl, _parent: &dyn Node) {
+    self.visit_plain_function(&fn_decl.function, Some(&fn_decl.ident), fn_decl);
+  }
+
+  fn visit_fn_expr(&mut self, fn_expr: &FnExpr, _parent: &dyn Node) {
+    self.visit_plain_function(
+      &fn_expr.function,
+      fn_expr.ident.as_ref(),
+      fn_expr,
+    );
+  }
+
+  fn visit_var_declarator(
+    &mut self,
+    var_declarator: &VarDeclarator,
+    _parent: &dyn Node,
+  ) {
+    var_declarator.name.visit_with(var_declarator, self);
+    if let (Pat::Ident(name), Some(init)) =
+      (&var_declarator.name, &var_declarator.init)
+    {
+      if let Expr::Fn(fn_expr) = unwrap_paren(init) {
+        let name = fn_expr.ident.as_ref().unwrap_or(name);
+        self.visit_plain_function(&fn_expr.function, Some(name), fn_expr);
+        return;
+      }
+    }
+    var_declarator.init.visit_with(var_declarator, self);
+  }
+
+  fn visit_assign_expr(
+    &mut self,
+    assign_expr: &AssignExpr,
+    _parent: &dyn Node,
+  ) {
+    assign_expr.left.visit_with(assign_expr, self);
+    if is_member_target(&assign_expr.left) {
+      self.visit_method_value(&assign_expr.right, assign_expr);
+      return;
+    }
+    if let Expr::Fn(fn_expr) = unwrap_paren(&assign_expr.right) {
+      let name = fn_expr
+        .ident
+        .as_ref()
+        .or_else(|| assigned_ident(&assign_expr.left));
+      self.visit_plain_function(&fn_expr.function, name, fn_expr);
+      return;
+    }
+    assign_expr.right.visit_with(assign_expr, self);
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    if let Some(fn_expr) = bound_function(call_expr) {
+      self.with_this(true, |v| fn_expr.function.visit_with(fn_expr, v));
+      call_expr.args.visit_with(call_expr, self);
+      call_expr.type_args.visit_with(call_expr, self);
+    } else {
+      call_expr.visit_children_with(self);
+    }
+  }
+
+  fn visit_key_value_prop(
+    &mut self,
+    key_value_prop: &KeyValueProp,
+    _parent: &dyn Node,
+  ) {
+    key_value_prop.key.visit_with(key_value_prop, self);
+    self.visit_method_value(&key_value_prop.value, key_value_prop);
+  }
+
+  fn visit_method_prop(
+    &mut self,
+    method_prop: &MethodProp,
+    _parent: &dyn Node,
+  ) {
+    method_prop.key.visit_with(method_prop, self);
+    self.with_this(true, |v| method_prop.function.visit_with(method_prop, v));
+  }
+
+  fn visit_getter_prop(
+    &mut self,
+    getter_prop: &GetterProp,
+    _parent: &dyn Node,
+  ) {
+    getter_prop.key.visit_with(getter_prop, self);
+    self.with_this(true, |v| getter_prop.body.visit_with(getter_prop, v));
+  }
+
+  fn visit_setter_prop(
+    &mut self,
+    setter_prop: &SetterProp,
+    _parent: &dyn Node,
+  ) {
+    setter_prop.key.visit_with(setter_prop, self);
+    self.with_this(true, |v| {
+      setter_prop.param.visit_with(setter_prop, v);
+      setter_prop.body.visit_with(setter_prop, v);
+    });
+  }
+
+  fn visit_constructor(
+    &mut self,
+    constructor: &Constructor,
+    _parent: &dyn Node,
+  ) {
+    constructor.key.visit_with(constructor, self);
+    self.with_this(true, |v| {
+      constructor.params.visit_with(constructor, v);
+      constructor.body.visit_with(constructor, v);
+    });
+  }
+
+  fn visit_class_method(
+    &mut self,
+    class_method: &ClassMethod,
+    _parent: &dyn Node,
+  ) {
+    class_method.key.visit_with(class_method, self);
+    self.with_this(true, |v| class_method.function.visit_with(class_method, v));
+  }
+
+  fn visit_private_method(
+    &mut self,
+    private_method: &PrivateMethod,
+    _parent: &dyn Node,
+  ) {
+    self.with_this(true, |v| {
+      private_method.function.visit_with(private_method, v)
+    });
+  }
+
+  fn visit_class_prop(&mut self, class_prop: &ClassProp, _parent: &dyn Node) {
+    class_prop.decorators.visit_with(class_prop, self);
+    class_prop.key.visit_with(class_prop, self);
+    self.with_this(true, |v| class_prop.value.visit_with(class_prop, v));
+  }
+
+  fn visit_private_prop(
+    &mut self,
+    private_prop: &PrivateProp,
+    _parent: &dyn Node,
+  ) {
+    private_prop.decorators.visit_with(private_prop, self);
+    self.with_this(true, |v| private_prop.value.visit_with(private_prop, v));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn no_invalid_this_valid() {
+    assert_lint_ok! {
+      NoInvalidThis,
+      "class A { foo() { this.a = 0; } }",
+      "class A { constructor() { this.a = 0; } }",
+      "class A { get foo() { return this.a; } set foo(v) { this.a = v; } }",
+      "class A { static foo() { return this.a; } }",
+      "class A { a = this.b; static c = this.d; #e = this.f; }",
+      "class A { #foo() { this.a = 0; } }",
+      "class A { foo() { const f = () => this.a; } }",
+      "const obj = { foo() { this.a = 0; } };",
+      "const obj = { foo: function() { this.a = 0; } };",
+      "const obj = { get foo() { return this.a; } };",
+      "const obj = { foo: () => 1, bar() { return () => this.a; } };",
+      "obj.foo = function() { this.a = 0; };",
+      "obj['foo'] = function() { this.a = 0; };",
+      "function Foo() { this.a = 0; }",
+      "const Foo = function() { this.a = 0; };",
+      "Foo = function() { this.a = 0; };",
+      "function foo(this: Foo) { this.a = 0; }",
+      "(function() { this.a = 0; }).call(obj);",
+      "(function() { this.a = 0; }).apply(obj, []);",
+      "const f = function() { this.a = 0; }.bind(obj);",
+    };
+  }
+
+  #[test]
+  fn no_invalid_this_invalid() {
+    assert_lint_err! {
+      NoInvalidThis,
+      "this.a = 0;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "function foo() { this.a = 0; }": [{ col: 17, message: MESSAGE, hint: HINT }],
+      "const foo = function() { this.a = 0; };": [{ col: 25, message: MESSAGE, hint: HINT }],
+      "const foo = () => { this.a = 0; };": [{ col: 20, message: MESSAGE, hint: HINT }],
+      "class A { foo() { function bar() { this.a = 0; } } }": [{ col: 35, message: MESSAGE, hint: HINT }],
+      "const obj = { foo() { return function() { return this.a; }; } };": [{ col: 49, message: MESSAGE, hint: HINT }],
+      "(function() { this.a = 0; }).call(null);": [{ col: 14, message: MESSAGE, hint: HINT }],
+      "(function() { this.a = 0; }).call(undefined);": [{ col: 14, message: MESSAGE, hint: HINT }],
+      "(function() { this.a = 0; })();": [{ col: 14, message: MESSAGE, hint: HINT }],
+      "class A { [this.a]() {} }": [{ col: 11, message: MESSAGE, hint: HINT }],
+      "foo(function() { this.a = 0; });": [{ col: 17, message: MESSAGE, hint: HINT }],
+    };
+  }
+
+  #[test]
+  fn no_invalid_this_cap_is_constructor() {
+    let rule = || {
+      NoInvalidThis::with_options(NoInvalidThisOptions {
+        cap_is_constructor: false,
+      })
+    };
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "function Foo() { this.a = 0; }",
+      vec![(1, 17)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "const Foo = function() { this.a = 0; };",
+      vec![(1, 25)],
+    );
+    assert_lint_ok_with_rule(rule(), "class Foo { bar() { this.a = 0; } }");
+  }
+}
@@ -52,12 +52,16 @@ impl LintRule for NoIrregularWhitespace {
 
     let excluded_ranges = visitor.ranges.iter();
 
-    let file_and_lines =
-      context.source_map.span_to_lines(program.span()).unwrap();
-    let file = file_and_lines.file;
+    let file = match context.source_file(program.span()) {
+      Some(file) => file,
+      None => return,
+    };
 
     for line_index in 0..file.count_lines() {
-      let line = file.get_line(line_index).unwrap();
+      let line = match file.get_line(line_index) {
+        Some(line) => line,
+        None => continue,
+      };
       let (byte_pos, _hi) = file.line_bounds(line_index);
 
       if let Some(whitespace_results) = test_for_whitespace(&line) {
@@ -83,6 +87,28 @@ impl LintRule for NoIrregularWhitespace {
       }
     }
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the use of irregular whitespace characters
+
+Irregular whitespace characters, like non-breaking spaces, zero width spaces or
+line and paragraph separators, are hard to spot and are often inserted by
+accident when copying code. They can break parsing in some environments and
+make code harder to debug. Irregular whitespace is allowed inside strings,
+template literals and regular expressions.
+
+### Invalid:
+```typescript
+// contains a non-breaking space (U+00A0) before `return`
+function foo() { return bar; }
+```
+
+### Valid:
+```typescript
+function foo() { return " bar"; }
+```
+"#
+  }
 }
 
 struct NoIrregularWhitespaceVisitor {
@@ -0,0 +1,170 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_ecmascript::ast::{
+  BlockStmt, Decl, ModuleItem, Program, Stmt, VarDeclKind,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoLoneBlocks;
+
+const CODE: &str = "no-lone-blocks";
+const MESSAGE: &str = "Block is redundant";
+const HINT: &str = "Remove the braces around the block";
+
+impl LintRule for NoLoneBlocks {
+  fn new() -> Box<Self> {
+    Box::new(NoLoneBlocks)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = NoLoneBlocksVisitor { context };
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows blocks which don't belong to a statement and declare nothing
+
+A block which isn't the body of a function, loop or other statement only
+limits the scope of the `let`, `const`, `class` and `function` declarations in
+it. Without such declarations, it has no effect and can be confused with an
+object literal or a missing statement.
+
+### Invalid:
+```typescript
+{
+  doSomething();
+}
+
+if (foo) {
+  bar();
+  {
+    baz();
+  }
+}
+```
+
+### Valid:
+```typescript
+{
+  let x = 1;
+  doSomething(x);
+}
+
+if (foo) {
+  bar();
+}
+
+label: {
+  bar();
+  break label;
+}
+```
+"#
+  }
+}
+
+struct NoLoneBlocksVisitor<'c> {
+  context: &'c mut Context,
+}
+
+/// Whether `block` declares bindings scoped to it.
+fn has_block_scoped_declaration(block: &BlockStmt) -> bool {
+  block.stmts.iter().any(|stmt| match stmt {
+    Stmt::Decl(Decl::Var(var_decl)) => var_decl.kind != VarDeclKind::Var,
+    Stmt::Decl(_) => true,
+    _ => false,
+  })
+}
+
+impl<'c> NoLoneBlocksVisitor<'c> {
+  /// Checks a statement of a statement list, where blocks are on their own.
+  fn check_stmt(&mut self, stmt: &Stmt) {
+    if let Stmt::Block(block) = stmt {
+      if !has_block_scoped_declaration(block) {
+        self
+          .context
+          .add_diagnostic_with_hint(block.span, CODE, MESSAGE, HINT);
+      }
+    }
+  }
+}
+
+impl<'c> Visit for NoLoneBlocksVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_module_items(&mut self, items: &[ModuleItem], parent: &dyn Node) {
+    for item in items {
+      if let ModuleItem::Stmt(stmt) = item {
+        self.check_stmt(stmt);
+      }
+      item.visit_with(parent, self);
+    }
+  }
+
+  fn visit_stmts(&mut self, stmts: &[Stmt], parent: &dyn Node) {
+    for stmt in stmts {
+      self.check_stmt(stmt);
+      stmt.visit_with(parent, self);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_lone_blocks_valid() {
+    assert_lint_ok! {
+      NoLoneBlocks,
+      "if (foo) { bar(); }",
+      "while (foo) { bar(); }",
+      "for (;;) { bar(); }",
+      "function foo() { bar(); }",
+      "const foo = () => { bar(); };",
+      "class A { m() { bar(); } }",
+      "try { bar(); } catch (e) { baz(); } finally { qux(); }",
+      "label: { bar(); break label; }",
+      "{ let x = 1; }",
+      "{ const x = 1; foo(x); }",
+      "{ class A {} }",
+      "{ function foo() {} }",
+      "if (foo) { { let x = 1; } }",
+      "switch (foo) { case 1: { const x = 1; break; } }",
+      "const a = {};",
+      "function foo() {}",
+    };
+  }
+
+  #[test]
+  fn no_lone_blocks_invalid() {
+    assert_lint_err! {
+      NoLoneBlocks,
+      "{}": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "{ foo(); }": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "{ var x = 1; }": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "if (foo) { bar(); { baz(); } }": [
+        { col: 18, message: MESSAGE, hint: HINT },
+      ],
+      "function foo() { {} bar(); }": [
+        { col: 17, message: MESSAGE, hint: HINT },
+      ],
+      "{ { let x = 1; } }": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "{ {} }": [
+        { col: 0, message: MESSAGE, hint: HINT },
+        { col: 2, message: MESSAGE, hint: HINT },
+      ],
+      "switch (foo) { case 1: { bar(); } }": [
+        { col: 23, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
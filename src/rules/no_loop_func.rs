@@ -0,0 +1,529 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::scopes::BindingKind;
+use crate::swc_util::find_lhs_ids;
+use std::collections::HashMap;
+use swc_common::{BytePos, Span};
+use swc_ecmascript::ast::{
+  ArrowExpr, AssignExpr, CatchClause, ClassDecl, DoWhileStmt, Expr, FnDecl,
+  FnExpr, ForInStmt, ForOfStmt, ForStmt, Ident, MemberExpr, Param, Pat,
+  Program, Prop, UpdateExpr, VarDeclOrPat, VarDeclarator, WhileStmt,
+};
+use swc_ecmascript::utils::find_ids;
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::utils::Id;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoLoopFunc;
+
+const CODE: &str = "no-loop-func";
+const HINT: &str = "Move the function out of the loop, or copy the variables into `const` or `let` bindings declared inside the loop";
+
+impl LintRule for NoLoopFunc {
+  fn new() -> Box<Self> {
+    Box::new(NoLoopFunc)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut bindings = BindingsCollector::default();
+    program.visit_with(program, &mut bindings);
+
+    let mut visitor = NoLoopFuncVisitor::new(context, &bindings);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows functions inside loops which refer to variables modified by the loop
+
+A function created inside a loop captures variables, not their values. If such
+a variable is shared by all iterations, every function sees its final value
+rather than the one at the time it was created.
+
+Referring to `const` bindings, to `let` bindings declared inside the loop (which
+are created anew for each iteration) and to variables which are not modified
+once the loop has started is safe.
+
+### Invalid:
+```typescript
+for (var i = 0; i < 10; i++) {
+  funcs.push(() => i);
+}
+
+let x = 0;
+while (x < 10) {
+  funcs.push(function () {
+    return x;
+  });
+  x++;
+}
+```
+
+### Valid:
+```typescript
+for (let i = 0; i < 10; i++) {
+  funcs.push(() => i);
+}
+
+const a = 1;
+for (const x of xs) {
+  funcs.push(() => x + a);
+}
+```
+"#
+  }
+}
+
+/// Records where bindings are declared and written to.
+#[derive(Default)]
+struct BindingsCollector {
+  declarations: HashMap<Id, BytePos>,
+  writes: HashMap<Id, Vec<BytePos>>,
+}
+
+impl BindingsCollector {
+  fn declare_pat(&mut self, pat: &Pat) {
+    let idents: Vec<Ident> = find_ids(pat);
+    for ident in idents {
+      self.declarations.insert(ident.to_id(), ident.span.lo());
+    }
+  }
+
+  fn write(&mut self, id: Id, pos: BytePos) {
+    self.writes.entry(id).or_default().push(pos);
+  }
+}
+
+impl Visit for BindingsCollector {
+  noop_visit_type!();
+
+  fn visit_var_declarator(
+    &mut self,
+    var_declarator: &VarDeclarator,
+    _parent: &dyn Node,
+  ) {
+    self.declare_pat(&var_declarator.name);
+    if var_declarator.init.is_some() {
+      let idents: Vec<Ident> = find_ids(&var_declarator.name);
+      for ident in idents {
+        self.write(ident.to_id(), ident.span.lo());
+      }
+    }
+    var_declarator.visit_children_with(self);
+  }
+
+  fn visit_param(&mut self, param: &Param, _parent: &dyn Node) {
+    self.declare_pat(&param.pat);
+    param.visit_children_with(self);
+  }
+
+  fn visit_fn_decl(&mut self, fn_decl: &FnDecl, _parent: &dyn Node) {
+    self
+      .declarations
+      .insert(fn_decl.ident.to_id(), fn_decl.ident.span.lo());
+    fn_decl.visit_children_with(self);
+  }
+
+  fn visit_class_decl(&mut self, class_decl: &ClassDecl, _parent: &dyn Node) {
+    self
+      .declarations
+      .insert(class_decl.ident.to_id(), class_decl.ident.span.lo());
+    class_decl.visit_children_with(self);
+  }
+
+  fn visit_catch_clause(
+    &mut self,
+    catch_clause: &CatchClause,
+    _parent: &dyn Node,
+  ) {
+    if let Some(param) = &catch_clause.param {
+      self.declare_pat(param);
+    }
+    catch_clause.visit_children_with(self);
+  }
+
+  fn visit_assign_expr(
+    &mut self,
+    assign_expr: &AssignExpr,
+    _parent: &dyn Node,
+  ) {
+    let idents: Vec<Ident> = find_lhs_ids(&assign_expr.left);
+    for ident in idents {
+      self.write(ident.to_id(), ident.span.lo());
+    }
+    assign_expr.visit_children_with(self);
+  }
+
+  fn visit_update_expr(
+    &mut self,
+    update_expr: &UpdateExpr,
+    _parent: &dyn Node,
+  ) {
+    if let Expr::Ident(ident) = &*update_expr.arg {
+      self.write(ident.to_id(), ident.span.lo());
+    }
+    update_expr.visit_children_with(self);
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, _parent: &dyn Node) {
+    self.write_loop_target(&for_in_stmt.left);
+    for_in_stmt.visit_children_with(self);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _parent: &dyn Node) {
+    self.write_loop_target(&for_of_stmt.left);
+    for_of_stmt.visit_children_with(self);
+  }
+}
+
+impl BindingsCollector {
+  /// `for-in` and `for-of` loops assign to their left side on each iteration.
+  fn write_loop_target(&mut self, left: &VarDeclOrPat) {
+    let idents: Vec<Ident> = match left {
+      VarDeclOrPat::VarDecl(var_decl) => var_decl
+        .decls
+        .iter()
+        .flat_map(|decl| find_ids::<_, Ident>(&decl.name))
+        .collect(),
+      VarDeclOrPat::Pat(pat) => find_ids(pat),
+    };
+    for ident in idents {
+      self.write(ident.to_id(), ident.span.lo());
+    }
+  }
+}
+
+/// Collects identifiers referenced in a function, in order of appearance.
+#[derive(Default)]
+struct ReferencesCollector {
+  references: Vec<Ident>,
+}
+
+impl Visit for ReferencesCollector {
+  noop_visit_type!();
+
+  fn visit_expr(&mut self, expr: &Expr, _parent: &dyn Node) {
+    if let Expr::Ident(ident) = expr {
+      self.references.push(ident.clone());
+    }
+    expr.visit_children_with(self);
+  }
+
+  fn visit_pat(&mut self, pat: &Pat, _parent: &dyn Node) {
+    if let Pat::Ident(ident) = pat {
+      self.references.push(ident.clone());
+    }
+    pat.visit_children_with(self);
+  }
+
+  fn visit_prop(&mut self, prop: &Prop, _parent: &dyn Node) {
+    if let Prop::Shorthand(ident) = prop {
+      self.references.push(ident.clone());
+    }
+    prop.visit_children_with(self);
+  }
+
+  fn visit_member_expr(
+    &mut self,
+    member_expr: &MemberExpr,
+    _parent: &dyn Node,
+  ) {
+    member_expr.obj.visit_with(member_expr, self);
+    if member_expr.computed {
+      member_expr.prop.visit_with(member_expr, self);
+    }
+  }
+}
+
+struct NoLoopFuncVisitor<'c, 'b> {
+  context: &'c mut Context,
+  bindings: &'b BindingsCollector,
+  /// Spans of the loops enclosing the current point within the current
+  /// function, innermost last.
+  loops: Vec<Span>,
+}
+
+impl<'c, 'b> NoLoopFuncVisitor<'c, 'b> {
+  fn new(context: &'c mut Context, bindings: &'b BindingsCollector) -> Self {
+    Self {
+      context,
+      bindings,
+      loops: vec![],
+    }
+  }
+
+  fn visit_loop_part<N>(&mut self, loop_span: Span, node: &N, parent: &dyn Node)
+  where
+    N: for<'x, 'y> VisitWith<NoLoopFuncVisitor<'x, 'y>> + ?Sized,
+  {
+    self.loops.push(loop_span);
+    node.visit_with(parent, self);
+    self.loops.pop();
+  }
+
+  /// Checks a function created at the current point and visits its body,
+  /// which doesn't belong to the enclosing loops anymore.
+  fn visit_function_like<N>(&mut self, span: Span, node: &N, parent: &dyn Node)
+  where
+    N: for<'x, 'y> VisitWith<NoLoopFuncVisitor<'x, 'y>>
+      + VisitWith<ReferencesCollector>
+      + ?Sized,
+  {
+    if !self.loops.is_empty() {
+      self.check_function(span, node, parent);
+    }
+
+    let loops = std::mem::take(&mut self.loops);
+    node.visit_children_with(self);
+    self.loops = loops;
+  }
+
+  fn check_function<N>(&mut self, span: Span, node: &N, parent: &dyn Node)
+  where
+    N: VisitWith<ReferencesCollector> + ?Sized,
+  {
+    let mut collector = ReferencesCollector::default();
+    node.visit_with(parent, &mut collector);
+
+    let mut unsafe_names: Vec<String> = vec![];
+    for ident in collector.references {
+      let id = ident.to_id();
+      let declared_at = match self.bindings.declarations.get(&id) {
+        Some(pos) => *pos,
+        None => continue,
+      };
+      // Bindings local to the function are fine.
+      if span.lo() <= declared_at && declared_at < span.hi() {
+        continue;
+      }
+      let name = ident.sym.to_string();
+      if unsafe_names.contains(&name) || self.is_safe(&id, declared_at) {
+        continue;
+      }
+      unsafe_names.push(name);
+    }
+
+    if !unsafe_names.is_empty() {
+      let names = unsafe_names
+        .iter()
+        .map(|name| format!("'{}'", name))
+        .collect::<Vec<_>>()
+        .join(", ");
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        format!(
+          "Function declared in a loop contains unsafe references to variable(s) {}",
+          names
+        ),
+        HINT,
+      );
+    }
+  }
+
+  fn is_safe(&self, id: &Id, declared_at: BytePos) -> bool {
+    let kind = match self.context.scope.var(id) {
+      Some(var) => var.kind(),
+      None => return true,
+    };
+
+    if kind == BindingKind::Const {
+      return true;
+    }
+
+    // `let` declared in the loop is created anew for each iteration.
+    let innermost_loop = self.loops.last().unwrap();
+    if kind == BindingKind::Let
+      && innermost_loop.lo() < declared_at
+      && declared_at < innermost_loop.hi()
+    {
+      return true;
+    }
+
+    // The variable is safe if it's not modified once the outermost loop
+    // that can run the function more than once has started. For `let`, only
+    // loops inside the binding's block count.
+    let border = if kind == BindingKind::Let {
+      self
+        .loops
+        .iter()
+        .rev()
+        .take_while(|span| span.lo() >= declared_at)
+        .last()
+        .unwrap_or(innermost_loop)
+        .lo()
+    } else {
+      self.loops[0].lo()
+    };
+
+    !self
+      .bindings
+      .writes
+      .get(id)
+      .into_iter()
+      .flatten()
+      .any(|pos| *pos >= border)
+  }
+}
+
+impl<'c, 'b> Visit for NoLoopFuncVisitor<'c, 'b> {
+  noop_visit_type!();
+
+  fn visit_fn_decl(&mut self, fn_decl: &FnDecl, _parent: &dyn Node) {
+    self.visit_function_like(fn_decl.function.span, fn_decl, fn_decl);
+  }
+
+  fn visit_fn_expr(&mut self, fn_expr: &FnExpr, _parent: &dyn Node) {
+    self.visit_function_like(fn_expr.function.span, fn_expr, fn_expr);
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    self.visit_function_like(arrow_expr.span, arrow_expr, arrow_expr);
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _parent: &dyn Node) {
+    // The initializer runs only once.
+    for_stmt.init.visit_with(for_stmt, self);
+    self.visit_loop_part(for_stmt.span, &for_stmt.test, for_stmt);
+    self.visit_loop_part(for_stmt.span, &for_stmt.update, for_stmt);
+    self.visit_loop_part(for_stmt.span, &*for_stmt.body, for_stmt);
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, _parent: &dyn Node) {
+    for_in_stmt.right.visit_with(for_in_stmt, self);
+    self.visit_loop_part(for_in_stmt.span, &for_in_stmt.left, for_in_stmt);
+    self.visit_loop_part(for_in_stmt.span, &*for_in_stmt.body, for_in_stmt);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _parent: &dyn Node) {
+    for_of_stmt.right.visit_with(for_of_stmt, self);
+    self.visit_loop_part(for_of_stmt.span, &for_of_stmt.left, for_of_stmt);
+    self.visit_loop_part(for_of_stmt.span, &*for_of_stmt.body, for_of_stmt);
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _parent: &dyn Node) {
+    self.visit_loop_part(while_stmt.span, &*while_stmt.test, while_stmt);
+    self.visit_loop_part(while_stmt.span, &*while_stmt.body, while_stmt);
+  }
+
+  fn visit_do_while_stmt(
+    &mut self,
+    do_while_stmt: &DoWhileStmt,
+    _parent: &dyn Node,
+  ) {
+    self.visit_loop_part(
+      do_while_stmt.span,
+      &*do_while_stmt.body,
+      do_while_stmt,
+    );
+    self.visit_loop_part(
+      do_while_stmt.span,
+      &*do_while_stmt.test,
+      do_while_stmt,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_loop_func_valid() {
+    assert_lint_ok! {
+      NoLoopFunc,
+      "for (let i = 0; i < 10; i++) { funcs.push(() => i); }",
+      "for (const x of xs) { funcs.push(function() { return x; }); }",
+      "for (const k in obj) { funcs.push(() => k); }",
+      "const a = 1; for (var i = 0; i < 10; i++) { funcs.push(() => a); }",
+      "let a = 1; for (var i = 0; i < 10; i++) { funcs.push(() => a); }",
+      "var a = 1; a = 2; for (var i = 0; i < 10; i++) { funcs.push(() => a); }",
+      "for (var i = 0; i < 10; i++) { funcs.push(() => { const j = 1; return j; }); }",
+      "for (var i = 0; i < 10; i++) { funcs.push((j) => j); }",
+      "while (a) { let x = foo(); funcs.push(() => x); }",
+      "function f() {} for (var i = 0; i < 10; i++) { funcs.push(() => f()); }",
+      "for (var i = 0; i < 10; i++) {} funcs.push(() => i);",
+      "for (var i = 0, f = () => i; i < 10; i++) {}",
+      "for (const x of [1, 2].map((y) => y * 2)) { }",
+      "for (var i = 0; i < 10; i++) { funcs.push(() => undeclared); }",
+      "while (a) { funcs.push(() => obj.i); }",
+    };
+  }
+
+  #[test]
+  fn no_loop_func_invalid() {
+    assert_lint_err! {
+      NoLoopFunc,
+      "for (var i = 0; i < 10; i++) { funcs.push(() => i); }": [
+        {
+          col: 42,
+          message: "Function declared in a loop contains unsafe references to variable(s) 'i'",
+          hint: HINT,
+        }
+      ],
+      "for (var i = 0; i < 10; i++) { funcs.push(function() { return i; }); }": [
+        {
+          col: 42,
+          message: "Function declared in a loop contains unsafe references to variable(s) 'i'",
+          hint: HINT,
+        }
+      ],
+      "for (var x of xs) { funcs.push(() => x); }": [
+        {
+          col: 31,
+          message: "Function declared in a loop contains unsafe references to variable(s) 'x'",
+          hint: HINT,
+        }
+      ],
+      "let x = 0; while (x < 10) { funcs.push(() => x); x++; }": [
+        {
+          col: 39,
+          message: "Function declared in a loop contains unsafe references to variable(s) 'x'",
+          hint: HINT,
+        }
+      ],
+      "let i; for (i = 0; i < 10; i++) { funcs.push(() => i); }": [
+        {
+          col: 45,
+          message: "Function declared in a loop contains unsafe references to variable(s) 'i'",
+          hint: HINT,
+        }
+      ],
+      "for (var i = 0; i < 10; i++) { var a = i; funcs.push(() => a + i + a); }": [
+        {
+          col: 53,
+          message: "Function declared in a loop contains unsafe references to variable(s) 'a', 'i'",
+          hint: HINT,
+        }
+      ],
+      "for (var i = 0; i < 10; i++) { function f() { return i; } }": [
+        {
+          col: 31,
+          message: "Function declared in a loop contains unsafe references to variable(s) 'i'",
+          hint: HINT,
+        }
+      ],
+      "for (var i = 0; i < 10; i++) { funcs.push(() => () => i); }": [
+        {
+          col: 42,
+          message: "Function declared in a loop contains unsafe references to variable(s) 'i'",
+          hint: HINT,
+        }
+      ],
+      "do { var x = foo(); funcs.push(() => x); } while (a);": [
+        {
+          col: 31,
+          message: "Function declared in a loop contains unsafe references to variable(s) 'x'",
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
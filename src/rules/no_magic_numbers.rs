@@ -0,0 +1,413 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{
+  AssignExpr, AssignPat, AssignPatProp, CallExpr, ClassProp, Expr, ExprOrSuper,
+  KeyValueProp, Lit, MemberExpr, Number, Pat, PatOrExpr, Program, UnaryExpr,
+  UnaryOp, VarDecl, VarDeclKind,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoMagicNumbers {
+  options: NoMagicNumbersOptions,
+}
+
+const CODE: &str = "no-magic-numbers";
+const CONST_MESSAGE: &str = "Number constants declarations must use 'const'";
+const HINT: &str = "Extract the number into a named constant";
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoMagicNumbersOptions {
+  /// Numbers which are allowed anywhere.
+  pub ignore: Vec<f64>,
+  /// Whether numbers used as array indexes are allowed.
+  pub ignore_array_indexes: bool,
+  /// Whether number constants must be declared with `const`.
+  pub enforce_const: bool,
+  /// Whether numbers assigned to object properties are checked.
+  pub detect_objects: bool,
+}
+
+impl NoMagicNumbers {
+  pub fn with_options(options: NoMagicNumbersOptions) -> Box<Self> {
+    Box::new(NoMagicNumbers { options })
+  }
+}
+
+impl LintRule for NoMagicNumbers {
+  fn new() -> Box<Self> {
+    Self::with_options(NoMagicNumbersOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = NoMagicNumbersVisitor::new(context, &self.options);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows magic numbers
+
+Numbers used directly in code don't explain what they stand for, and the same
+value repeated in several places is easy to update inconsistently. Declaring
+them as named constants documents their meaning.
+
+Numbers used to initialize a variable, as default values and as the radix of
+`parseInt` are allowed. Numbers assigned to object properties are allowed unless
+the `detectObjects` option is enabled.
+
+The rule can be configured with:
+- `ignore`: a list of numbers which are always allowed
+- `ignoreArrayIndexes`: allows numbers used as array indexes
+- `enforceConst`: requires number constants to be declared with `const`
+- `detectObjects`: reports numbers assigned to object properties
+
+### Invalid:
+```typescript
+const total = price * 1.25;
+
+for (let i = 0; i < 10; i++) {}
+```
+
+### Valid:
+```typescript
+const TAX = 1.25;
+const total = price * TAX;
+
+const MAX = 10;
+for (let i = 0; i < MAX; i++) {}
+```
+"#
+  }
+}
+
+struct NoMagicNumbersVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o NoMagicNumbersOptions,
+}
+
+impl<'c, 'o> NoMagicNumbersVisitor<'c, 'o> {
+  fn new(context: &'c mut Context, options: &'o NoMagicNumbersOptions) -> Self {
+    Self { context, options }
+  }
+
+  fn is_ignored(&self, value: f64) -> bool {
+    self.options.ignore.contains(&value)
+  }
+
+  fn report(&mut self, span: Span, value: f64) {
+    if self.is_ignored(value) {
+      return;
+    }
+    let raw = match self.context.snippet(span) {
+      Some(raw) => raw,
+      None => return,
+    };
+    self.context.add_diagnostic_with_hint(
+      span,
+      CODE,
+      format!("No magic number: {}", raw),
+      HINT,
+    );
+  }
+
+  /// Visits an expression in a position where a plain number is allowed.
+  fn visit_allowed(&mut self, expr: &Expr, parent: &dyn Node) {
+    if number_value(expr).is_none() {
+      expr.visit_with(parent, self);
+    }
+  }
+}
+
+/// Returns the value of a number literal, including negated ones.
+fn number_value(expr: &Expr) -> Option<f64> {
+  match expr {
+    Expr::Lit(Lit::Num(num)) => Some(num.value),
+    Expr::Unary(UnaryExpr {
+      op: UnaryOp::Minus,
+      arg,
+      ..
+    }) => match &**arg {
+      Expr::Lit(Lit::Num(num)) => Some(-num.value),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+fn is_array_index(value: f64) -> bool {
+  value >= 0.0 && value.fract() == 0.0 && value < u32::MAX as f64
+}
+
+fn is_ident(expr: &Expr, name: &str) -> bool {
+  matches!(expr, Expr::Ident(ident) if ident.sym == *name)
+}
+
+/// Checks if the callee is `parseInt` or `Number.parseInt`.
+fn is_parse_int(callee: &ExprOrSuper) -> bool {
+  let callee = match callee {
+    ExprOrSuper::Expr(expr) => &**expr,
+    ExprOrSuper::Super(_) => return false,
+  };
+  match callee {
+    Expr::Member(member) => {
+      let is_number = match &member.obj {
+        ExprOrSuper::Expr(obj) => is_ident(obj, "Number"),
+        ExprOrSuper::Super(_) => false,
+      };
+      is_number && !member.computed && is_ident(&member.prop, "parseInt")
+    }
+    _ => is_ident(callee, "parseInt"),
+  }
+}
+
+impl<'c, 'o> Visit for NoMagicNumbersVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_number(&mut self, num: &Number, _parent: &dyn Node) {
+    self.report(num.span, num.value);
+  }
+
+  fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr, _parent: &dyn Node) {
+    match (unary_expr.op, &*unary_expr.arg) {
+      (UnaryOp::Minus, Expr::Lit(Lit::Num(num))) => {
+        self.report(unary_expr.span, -num.value)
+      }
+      _ => unary_expr.visit_children_with(self),
+    }
+  }
+
+  fn visit_var_decl(&mut self, var_decl: &VarDecl, _parent: &dyn Node) {
+    for decl in &var_decl.decls {
+      decl.name.visit_with(decl, self);
+      if let Some(init) = &decl.init {
+        match number_value(init) {
+          Some(value) => {
+            if self.options.enforce_const
+              && var_decl.kind != VarDeclKind::Const
+              && !self.is_ignored(value)
+            {
+              self.context.add_diagnostic_with_hint(
+                init.span(),
+                CODE,
+                CONST_MESSAGE,
+                HINT,
+              );
+            }
+          }
+          None => init.visit_with(decl, self),
+        }
+      }
+    }
+  }
+
+  fn visit_member_expr(
+    &mut self,
+    member_expr: &MemberExpr,
+    _parent: &dyn Node,
+  ) {
+    member_expr.obj.visit_with(member_expr, self);
+    if !member_expr.computed {
+      return;
+    }
+    match number_value(&member_expr.prop) {
+      Some(value)
+        if self.options.ignore_array_indexes && is_array_index(value) => {}
+      _ => member_expr.prop.visit_with(member_expr, self),
+    }
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    call_expr.callee.visit_with(call_expr, self);
+    for (i, arg) in call_expr.args.iter().enumerate() {
+      if i == 1 && arg.spread.is_none() && is_parse_int(&call_expr.callee) {
+        self.visit_allowed(&arg.expr, call_expr);
+      } else {
+        arg.visit_with(call_expr, self);
+      }
+    }
+  }
+
+  fn visit_assign_pat(&mut self, assign_pat: &AssignPat, _parent: &dyn Node) {
+    assign_pat.left.visit_with(assign_pat, self);
+    self.visit_allowed(&assign_pat.right, assign_pat);
+  }
+
+  fn visit_assign_pat_prop(
+    &mut self,
+    assign_pat_prop: &AssignPatProp,
+    _parent: &dyn Node,
+  ) {
+    if let Some(value) = &assign_pat_prop.value {
+      self.visit_allowed(value, assign_pat_prop);
+    }
+  }
+
+  fn visit_key_value_prop(
+    &mut self,
+    key_value_prop: &KeyValueProp,
+    _parent: &dyn Node,
+  ) {
+    key_value_prop.key.visit_with(key_value_prop, self);
+    if self.options.detect_objects {
+      key_value_prop.value.visit_with(key_value_prop, self);
+    } else {
+      self.visit_allowed(&key_value_prop.value, key_value_prop);
+    }
+  }
+
+  fn visit_class_prop(&mut self, class_prop: &ClassProp, _parent: &dyn Node) {
+    class_prop.key.visit_with(class_prop, self);
+    class_prop.decorators.visit_with(class_prop, self);
+    if let Some(value) = &class_prop.value {
+      if self.options.detect_objects {
+        value.visit_with(class_prop, self);
+      } else {
+        self.visit_allowed(value, class_prop);
+      }
+    }
+  }
+
+  fn visit_assign_expr(
+    &mut self,
+    assign_expr: &AssignExpr,
+    _parent: &dyn Node,
+  ) {
+    assign_expr.left.visit_with(assign_expr, self);
+    let assigns_ident = match &assign_expr.left {
+      PatOrExpr::Pat(pat) => match &**pat {
+        Pat::Ident(_) => true,
+        Pat::Expr(expr) => matches!(&**expr, Expr::Ident(_)),
+        _ => false,
+      },
+      PatOrExpr::Expr(expr) => matches!(&**expr, Expr::Ident(_)),
+    };
+    if assigns_ident || self.options.detect_objects {
+      assign_expr.right.visit_with(assign_expr, self);
+    } else {
+      self.visit_allowed(&assign_expr.right, assign_expr);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn no_magic_numbers_valid() {
+    assert_lint_ok! {
+      NoMagicNumbers,
+      "const FOO = 42;",
+      "let foo = -1;",
+      "var foo = 0, bar = 1;",
+      "const TAX = 0.25; const total = price * TAX;",
+      "function foo(a = 1) {}",
+      "const { a = 1 } = foo;",
+      "const [a = 1] = foo;",
+      "const foo = { bar: 10 };",
+      "foo.bar = 10;",
+      "class A { foo = 10; }",
+      "parseInt(foo, 10);",
+      "Number.parseInt(foo, 16);",
+      "enum A { B = 1, C = 2 }",
+      "type A = 1 | 2;",
+      "const a: 1 = 1;",
+    };
+  }
+
+  #[test]
+  fn no_magic_numbers_invalid() {
+    assert_lint_err! {
+      NoMagicNumbers,
+      "const total = price * 1.25;": [
+        { col: 22, message: "No magic number: 1.25", hint: HINT }
+      ],
+      "foo(-1);": [{ col: 4, message: "No magic number: -1", hint: HINT }],
+      "for (let i = 0; i < 10; i++) {}": [
+        { col: 20, message: "No magic number: 10", hint: HINT }
+      ],
+      "foo = 5;": [{ col: 6, message: "No magic number: 5", hint: HINT }],
+      "const a = foo[2];": [{ col: 14, message: "No magic number: 2", hint: HINT }],
+      "parseInt(10, 16);": [{ col: 9, message: "No magic number: 10", hint: HINT }],
+      "const a = [1, 2];": [
+        { col: 11, message: "No magic number: 1", hint: HINT },
+        { col: 14, message: "No magic number: 2", hint: HINT },
+      ],
+    };
+  }
+
+  #[test]
+  fn no_magic_numbers_ignore() {
+    let rule = || {
+      NoMagicNumbers::with_options(NoMagicNumbersOptions {
+        ignore: vec![0.0, 1.0, -1.0],
+        ..Default::default()
+      })
+    };
+    assert_lint_ok_with_rule(rule(), "foo(0); foo(1); foo(-1); a[0];");
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "foo(0);\nfoo(2);\nfoo(-2);",
+      vec![(2, 4), (3, 4)],
+    );
+  }
+
+  #[test]
+  fn no_magic_numbers_ignore_array_indexes() {
+    let rule = || {
+      NoMagicNumbers::with_options(NoMagicNumbersOptions {
+        ignore_array_indexes: true,
+        ..Default::default()
+      })
+    };
+    assert_lint_ok_with_rule(rule(), "foo[0]; foo[1]; foo[100] = bar;");
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "foo[-1];\nfoo[1.5];\nfoo[1 + 2];",
+      vec![(1, 4), (2, 4), (3, 4), (3, 8)],
+    );
+  }
+
+  #[test]
+  fn no_magic_numbers_enforce_const() {
+    let rule = || {
+      NoMagicNumbers::with_options(NoMagicNumbersOptions {
+        enforce_const: true,
+        ..Default::default()
+      })
+    };
+    assert_lint_ok_with_rule(rule(), "const FOO = 42;");
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "let foo = 42;\nvar bar = -1;",
+      vec![(1, 10), (2, 10)],
+    );
+  }
+
+  #[test]
+  fn no_magic_numbers_detect_objects() {
+    let rule = || {
+      NoMagicNumbers::with_options(NoMagicNumbersOptions {
+        detect_objects: true,
+        ..Default::default()
+      })
+    };
+    assert_lint_ok_with_rule(rule(), "const FOO = 42;");
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "const foo = { bar: 10 };\nfoo.bar = 10;\nclass A { foo = 10; }",
+      vec![(1, 19), (2, 10), (3, 16)],
+    );
+  }
+}
@@ -2,8 +2,8 @@
 use super::Context;
 use super::LintRule;
 use swc_ecmascript::ast::{
-  ClassDecl, ClassMember, Expr, Ident, Program, PropName, TsEntityName,
-  TsInterfaceDecl, TsType, TsTypeAliasDecl, TsTypeAnn,
+  Class, ClassDecl, ClassExpr, ClassMember, Expr, Ident, Program, PropName,
+  TsEntityName, TsInterfaceDecl, TsType, TsTypeAliasDecl, TsTypeAnn,
   TsTypeElement::{TsConstructSignatureDecl, TsMethodSignature},
 };
 use swc_ecmascript::visit::Node;
@@ -11,6 +11,19 @@ use swc_ecmascript::visit::Visit;
 
 pub struct NoMisusedNew;
 
+const CODE: &str = "no-misused-new";
+const TYPE_ALIAS_MESSAGE: &str =
+  "Type aliases cannot be constructed, only classes";
+const INTERFACE_MESSAGE: &str =
+  "Interfaces cannot be constructed, only classes";
+const CLASS_MESSAGE: &str = "Class cannot have method named `new`.";
+const CONSTRUCT_SIGNATURE_HINT: &str =
+  "Use `new (): Type` to declare a construct signature";
+const INTERFACE_NEW_HINT: &str =
+  "Declare a class with a `constructor` instead, or make the construct signature return another type";
+const CLASS_HINT: &str =
+  "Use `constructor` to declare the constructor of the class";
+
 impl LintRule for NoMisusedNew {
   fn new() -> Box<Self> {
     Box::new(NoMisusedNew)
@@ -26,7 +39,54 @@ impl LintRule for NoMisusedNew {
   }
 
   fn code(&self) -> &'static str {
-    "no-misused-new"
+    CODE
+  }
+
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows defining constructors for interfaces or `new` for classes
+
+Only classes have constructors, which are declared with `constructor`. A
+`constructor` member of an interface or a type alias is a method named
+"constructor", and a `new` method of a class is a method named "new", neither
+of which is likely intended. An interface can describe the constructor of a
+class with a construct signature, `new (): Type`, but a construct signature
+returning the interface itself most likely was meant to be a class.
+
+### Invalid:
+```typescript
+class C {
+  new(): C;
+}
+
+interface I {
+  new (): I;
+  constructor(): void;
+}
+
+type T = {
+  constructor(): void;
+};
+```
+
+### Valid:
+```typescript
+class C {
+  constructor() {}
+}
+
+interface I {
+  new (): C;
+}
+
+type T = {
+  new (): C;
+};
+```
+"#
   }
 }
 
@@ -52,6 +112,37 @@ impl<'c> NoMisusedNewVisitor<'c> {
   fn is_constructor_keyword(&self, ident: &Ident) -> bool {
     *"constructor" == ident.sym
   }
+
+  fn check_class(&mut self, name: &Ident, class: &Class) {
+    for member in &class.body {
+      if let ClassMember::Method(method) = member {
+        let method_name = match &method.key {
+          PropName::Ident(ident) => ident.sym.as_ref(),
+          PropName::Str(str_) => str_.value.as_ref(),
+          _ => continue,
+        };
+
+        if method_name != "new" {
+          continue;
+        }
+
+        if method.function.return_type.is_some()
+          && self.match_parent_type(
+            name,
+            &method.function.return_type.as_ref().unwrap(),
+          )
+        {
+          // new
+          self.context.add_diagnostic_with_hint(
+            method.span,
+            CODE,
+            CLASS_MESSAGE,
+            CLASS_HINT,
+          );
+        }
+      }
+    }
+  }
 }
 
 impl<'c> Visit for NoMisusedNewVisitor<'c> {
@@ -65,10 +156,11 @@ impl<'c> Visit for NoMisusedNewVisitor<'c> {
         if let TsMethodSignature(signature) = &member {
           if let Expr::Ident(ident) = &*signature.key {
             if self.is_constructor_keyword(&ident) {
-              self.context.add_diagnostic(
+              self.context.add_diagnostic_with_hint(
                 ident.span,
-                "no-misused-new",
-                "Type aliases cannot be constructed, only classes",
+                CODE,
+                TYPE_ALIAS_MESSAGE,
+                CONSTRUCT_SIGNATURE_HINT,
               );
             }
           }
@@ -88,10 +180,11 @@ impl<'c> Visit for NoMisusedNewVisitor<'c> {
           if let Expr::Ident(ident) = &*signature.key {
             if self.is_constructor_keyword(&ident) {
               // constructor
-              self.context.add_diagnostic(
+              self.context.add_diagnostic_with_hint(
                 signature.span,
-                "no-misused-new",
-                "Interfaces cannot be constructed, only classes",
+                CODE,
+                INTERFACE_MESSAGE,
+                CONSTRUCT_SIGNATURE_HINT,
               );
             }
           }
@@ -101,10 +194,11 @@ impl<'c> Visit for NoMisusedNewVisitor<'c> {
             && self
               .match_parent_type(&n.id, &signature.type_ann.as_ref().unwrap())
           {
-            self.context.add_diagnostic(
+            self.context.add_diagnostic_with_hint(
               signature.span,
-              "no-misused-new",
-              "Interfaces cannot be constructed, only classes",
+              CODE,
+              INTERFACE_MESSAGE,
+              INTERFACE_NEW_HINT,
             );
           }
         }
@@ -116,35 +210,15 @@ impl<'c> Visit for NoMisusedNewVisitor<'c> {
   }
 
   fn visit_class_decl(&mut self, expr: &ClassDecl, parent: &dyn Node) {
-    for member in &expr.class.body {
-      if let ClassMember::Method(method) = member {
-        let method_name = match &method.key {
-          PropName::Ident(ident) => ident.sym.as_ref(),
-          PropName::Str(str_) => str_.value.as_ref(),
-          _ => continue,
-        };
-
-        if method_name != "new" {
-          continue;
-        }
+    self.check_class(&expr.ident, &expr.class);
+    swc_ecmascript::visit::visit_class_decl(self, expr, parent);
+  }
 
-        if method.function.return_type.is_some()
-          && self.match_parent_type(
-            &expr.ident,
-            &method.function.return_type.as_ref().unwrap(),
-          )
-        {
-          // new
-          self.context.add_diagnostic(
-            method.span,
-            "no-misused-new",
-            "Class cannot have method named `new`.",
-          );
-        }
-      }
+  fn visit_class_expr(&mut self, expr: &ClassExpr, parent: &dyn Node) {
+    if let Some(ident) = &expr.ident {
+      self.check_class(ident, &expr.class);
     }
-
-    swc_ecmascript::visit::visit_class_decl(self, expr, parent);
+    swc_ecmascript::visit::visit_class_expr(self, expr, parent);
   }
 }
 
@@ -162,6 +236,9 @@ mod tests {
       "class C { new(): {} }",
       "class C { constructor(); }",
       "class C { constructor() {} }",
+      "const C = class { new(): C {} }",
+      "interface I { new(): C }",
+      "interface I { constructor: string }",
       r#"
     export class Fnv32a extends Fnv32Base<Fnv32a> {
       write(data: Uint8Array): Fnv32a {
@@ -260,4 +337,26 @@ declare abstract class C {
       4,
     )
   }
+
+  #[test]
+  fn no_misused_new_hint() {
+    assert_lint_err! {
+      NoMisusedNew,
+      "interface I { new(): I; }": [
+        { col: 14, message: INTERFACE_MESSAGE, hint: INTERFACE_NEW_HINT }
+      ],
+      "interface I { constructor(): void; }": [
+        { col: 14, message: INTERFACE_MESSAGE, hint: CONSTRUCT_SIGNATURE_HINT }
+      ],
+      "type T = { constructor(): void; };": [
+        { col: 11, message: TYPE_ALIAS_MESSAGE, hint: CONSTRUCT_SIGNATURE_HINT }
+      ],
+      "class C { new(): C; }": [
+        { col: 10, message: CLASS_MESSAGE, hint: CLASS_HINT }
+      ],
+      "const A = class C { 'new'(): C; }": [
+        { col: 20, message: CLASS_MESSAGE, hint: CLASS_HINT }
+      ],
+    };
+  }
 }
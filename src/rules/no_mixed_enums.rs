@@ -0,0 +1,181 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_ecmascript::ast::{
+  Expr, Lit, Program, TsEnumDecl, TsEnumMember, UnaryExpr, UnaryOp,
+};
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct NoMixedEnums;
+
+const CODE: &str = "no-mixed-enums";
+const MESSAGE: &str = "Mixing number and string enum members can be confusing";
+const HINT: &str = "Use only number or only string values in the enum";
+
+impl LintRule for NoMixedEnums {
+  fn new() -> Box<Self> {
+    Box::new(NoMixedEnums)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = NoMixedEnumsVisitor::new(context);
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows enums which mix number and string members
+
+Number and string enum members behave differently: only number enums have a
+reverse mapping from values to names, and numbers are assignable to number
+enums while strings are not assignable to string enums. Mixing both kinds in
+one enum makes it unclear how the enum can be used.
+
+Members without an initializer have a number value. Members initialized with
+other expressions are not checked.
+
+### Invalid:
+```typescript
+enum Foo {
+  A = 0,
+  B = "b",
+}
+
+enum Bar {
+  A = "a",
+  B = 1,
+}
+```
+
+### Valid:
+```typescript
+enum Foo {
+  A,
+  B = 1,
+}
+
+enum Bar {
+  A = "a",
+  B = "b",
+}
+```
+"#
+  }
+}
+
+struct NoMixedEnumsVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoMixedEnumsVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MemberKind {
+  Number,
+  String,
+}
+
+/// Returns the kind of value of the enum member, if it is known.
+fn member_kind(member: &TsEnumMember) -> Option<MemberKind> {
+  match &member.init {
+    None => Some(MemberKind::Number),
+    Some(init) => expr_kind(init),
+  }
+}
+
+fn expr_kind(expr: &Expr) -> Option<MemberKind> {
+  match expr {
+    Expr::Lit(Lit::Num(_)) => Some(MemberKind::Number),
+    Expr::Lit(Lit::Str(_)) => Some(MemberKind::String),
+    Expr::Tpl(tpl) if tpl.exprs.is_empty() => Some(MemberKind::String),
+    Expr::Unary(UnaryExpr {
+      op: UnaryOp::Minus,
+      arg,
+      ..
+    }) if matches!(&**arg, Expr::Lit(Lit::Num(_))) => Some(MemberKind::Number),
+    Expr::Paren(paren) => expr_kind(&paren.expr),
+    _ => None,
+  }
+}
+
+impl<'c> VisitAll for NoMixedEnumsVisitor<'c> {
+  fn visit_ts_enum_decl(&mut self, enum_decl: &TsEnumDecl, _parent: &dyn Node) {
+    let mut expected_kind = None;
+    for member in &enum_decl.members {
+      let kind = match member_kind(member) {
+        Some(kind) => kind,
+        None => continue,
+      };
+      match expected_kind {
+        None => expected_kind = Some(kind),
+        Some(expected_kind) if expected_kind != kind => {
+          self.context.add_diagnostic_with_hint(
+            member.span,
+            CODE,
+            MESSAGE,
+            HINT,
+          );
+        }
+        Some(_) => {}
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_mixed_enums_valid() {
+    assert_lint_ok! {
+      NoMixedEnums,
+      "enum E {}",
+      "enum E { A, B, C }",
+      "enum E { A = 0, B = 1, C = -1 }",
+      "enum E { A, B = 5, C }",
+      r#"enum E { A = "a", B = "b" }"#,
+      "enum E { A = `a`, B = 'b' }",
+      r#"enum E { A = "a", B = foo, C = A.length }"#,
+      "enum E { A = 1 << 0, B = `b${c}` }",
+      r#"enum E { A = 0 } enum F { A = "a" }"#,
+    };
+  }
+
+  #[test]
+  fn no_mixed_enums_invalid() {
+    assert_lint_err! {
+      NoMixedEnums,
+      r#"enum E { A = 0, B = "b" }"#: [
+        { col: 16, message: MESSAGE, hint: HINT }
+      ],
+      r#"enum E { A, B = "b", C = "c" }"#: [
+        { col: 12, message: MESSAGE, hint: HINT },
+        { col: 21, message: MESSAGE, hint: HINT }
+      ],
+      r#"enum E { A = "a", B = 1, C = -1 }"#: [
+        { col: 18, message: MESSAGE, hint: HINT },
+        { col: 25, message: MESSAGE, hint: HINT }
+      ],
+      "enum E { A = foo, B = `b`, C = 2 }": [
+        { col: 27, message: MESSAGE, hint: HINT }
+      ],
+      r#"namespace N { const enum E { A = "a", B = 0 } }"#: [
+        { col: 38, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+}
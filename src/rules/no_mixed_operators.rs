@@ -0,0 +1,310 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_ecmascript::ast::{BinExpr, CondExpr, Expr, Program};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct NoMixedOperators {
+  options: NoMixedOperatorsOptions,
+}
+
+const CODE: &str = "no-mixed-operators";
+const HINT: &str =
+  "Add parentheses to make the intended order of operations explicit";
+const TERNARY_OPERATOR: &str = "?:";
+
+fn message(child_op: &str, parent_op: &str) -> String {
+  format!("Unexpected mix of `{}` and `{}`", child_op, parent_op)
+}
+
+const DEFAULT_GROUPS: &[&[&str]] = &[
+  &["+", "-", "*", "/", "%", "**"],
+  &["&", "|", "^", "~", "<<", ">>", ">>>"],
+  &["==", "!=", "===", "!==", ">", ">=", "<", "<="],
+  &["&&", "||"],
+  &["in", "instanceof"],
+];
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoMixedOperatorsOptions {
+  /// Groups of operators which may not be mixed with each other. `?:` stands
+  /// for the conditional operator. Defaults to the arithmetic, bitwise,
+  /// comparison, logical and relational operators, each in its own group.
+  pub groups: Option<Vec<Vec<String>>>,
+  /// Allows mixing operators of the same precedence, like `a + b - c`.
+  /// Defaults to `true`.
+  pub allow_same_precedence: bool,
+}
+
+impl Default for NoMixedOperatorsOptions {
+  fn default() -> Self {
+    Self {
+      groups: None,
+      allow_same_precedence: true,
+    }
+  }
+}
+
+impl NoMixedOperators {
+  pub fn with_options(options: NoMixedOperatorsOptions) -> Box<Self> {
+    Box::new(NoMixedOperators { options })
+  }
+}
+
+impl LintRule for NoMixedOperators {
+  fn new() -> Box<Self> {
+    Self::with_options(NoMixedOperatorsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let groups = match &self.options.groups {
+      Some(groups) => groups
+        .iter()
+        .map(|group| group.iter().map(String::as_str).collect())
+        .collect(),
+      None => DEFAULT_GROUPS.iter().map(|group| group.to_vec()).collect(),
+    };
+    let mut visitor = NoMixedOperatorsVisitor {
+      context,
+      groups,
+      allow_same_precedence: self.options.allow_same_precedence,
+    };
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows mixing different operators without parentheses
+
+Mixing operators such as `&&` and `||` or `+` and `*` in one expression relies
+on the reader knowing their precedence. Parentheses make the intended order
+explicit.
+
+Only operators in the same group are checked. The groups can be configured with
+the `groups` option, and mixing operators of the same precedence, like `+` and
+`-`, is allowed unless `allowSamePrecedence` is set to `false`.
+
+### Invalid:
+```typescript
+const a = b && c || d;
+const e = f + g * h;
+const i = j ** k / l;
+```
+
+### Valid:
+```typescript
+const a = (b && c) || d;
+const e = f + (g * h);
+const i = a + b - c;
+const j = a + b + c;
+```
+"#
+  }
+}
+
+struct NoMixedOperatorsVisitor<'c, 'o> {
+  context: &'c mut Context,
+  groups: Vec<Vec<&'o str>>,
+  allow_same_precedence: bool,
+}
+
+impl<'c, 'o> NoMixedOperatorsVisitor<'c, 'o> {
+  fn in_same_group(&self, a: &str, b: &str) -> bool {
+    self
+      .groups
+      .iter()
+      .any(|group| group.contains(&a) && group.contains(&b))
+  }
+
+  /// Checks an operand of a binary or conditional expression. Parenthesized
+  /// operands are `Expr::Paren` and therefore never match.
+  fn check_operand(
+    &mut self,
+    operand: &Expr,
+    parent_op: &str,
+    parent_precedence: u8,
+  ) {
+    if let Expr::Bin(child) = operand {
+      let child_op = child.op.as_str();
+      if child_op == parent_op || !self.in_same_group(child_op, parent_op) {
+        return;
+      }
+      if self.allow_same_precedence
+        && child.op.precedence() == parent_precedence
+      {
+        return;
+      }
+      self.context.add_diagnostic_with_hint(
+        child.span,
+        CODE,
+        message(child_op, parent_op),
+        HINT,
+      );
+    }
+  }
+}
+
+impl<'c, 'o> VisitAll for NoMixedOperatorsVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+    let op = bin_expr.op.as_str();
+    let precedence = bin_expr.op.precedence();
+    self.check_operand(&bin_expr.left, op, precedence);
+    self.check_operand(&bin_expr.right, op, precedence);
+  }
+
+  fn visit_cond_expr(&mut self, cond_expr: &CondExpr, _parent: &dyn Node) {
+    // The conditional operator binds looser than any binary operator.
+    for operand in &[&cond_expr.test, &cond_expr.cons, &cond_expr.alt] {
+      self.check_operand(operand, TERNARY_OPERATOR, 0);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn no_mixed_operators_valid() {
+    assert_lint_ok! {
+      NoMixedOperators,
+      "a && b && c;",
+      "a || b || c;",
+      "(a && b) || c;",
+      "a && (b || c);",
+      "a + b + c;",
+      "a + b - c;",
+      "a * b / c;",
+      "a + (b * c);",
+      "(a + b) * c;",
+      "a + b && c;",
+      "a === b && c;",
+      "a ? b : c + d;",
+      "a & b && c;",
+    };
+  }
+
+  #[test]
+  fn no_mixed_operators_invalid() {
+    assert_lint_err! {
+      NoMixedOperators,
+      "a && b || c;": [
+        {
+          col: 0,
+          message: message("&&", "||"),
+          hint: HINT,
+        }
+      ],
+      "a || b && c;": [
+        {
+          col: 5,
+          message: message("&&", "||"),
+          hint: HINT,
+        }
+      ],
+      "a + b * c;": [
+        {
+          col: 4,
+          message: message("*", "+"),
+          hint: HINT,
+        }
+      ],
+      "a * b + c;": [
+        {
+          col: 0,
+          message: message("*", "+"),
+          hint: HINT,
+        }
+      ],
+      "a ** b / c;": [
+        {
+          col: 0,
+          message: message("**", "/"),
+          hint: HINT,
+        }
+      ],
+      "a & b | c;": [
+        {
+          col: 0,
+          message: message("&", "|"),
+          hint: HINT,
+        }
+      ],
+      "a < b === c;": [
+        {
+          col: 0,
+          message: message("<", "==="),
+          hint: HINT,
+        }
+      ],
+      "a < b == c;": [
+        {
+          col: 0,
+          message: message("<", "=="),
+          hint: HINT,
+        }
+      ],
+      "a + b * c - d / e;": [
+        {
+          col: 12,
+          message: message("/", "-"),
+          hint: HINT,
+        },
+        {
+          col: 4,
+          message: message("*", "+"),
+          hint: HINT,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_mixed_operators_options() {
+    let rule = |options: &str| {
+      NoMixedOperators::with_options(serde_json::from_str(options).unwrap())
+    };
+
+    let same_precedence = r#"{ "allowSamePrecedence": false }"#;
+    assert_lint_ok_with_rule(rule(same_precedence), "a + b + c;");
+    assert_lint_err_on_line_n_with_rule(
+      rule(same_precedence),
+      "a + b - c;",
+      vec![(1, 0)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(same_precedence),
+      "a * b % c;",
+      vec![(1, 0)],
+    );
+
+    let groups = r#"{ "groups": [["&&", "||", "?:"], ["+", "&&"]] }"#;
+    assert_lint_ok_with_rule(rule(groups), "a + b * c;");
+    assert_lint_err_on_line_n_with_rule(
+      rule(groups),
+      "a + b && c;",
+      vec![(1, 0)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(groups),
+      "a && b ? c : d;",
+      vec![(1, 0)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(groups),
+      "a && b || c;",
+      vec![(1, 0)],
+    );
+  }
+}
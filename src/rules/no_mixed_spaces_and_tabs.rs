@@ -39,19 +39,19 @@ impl LintRule for NoMixedSpacesAndTabs {
     let mut visitor = NoMixedSpacesAndTabsVisitor::default();
     visitor.visit_program(program, program);
 
-    let file_and_lines =
-      context.source_map.span_to_lines(program.span()).unwrap();
-    let file = file_and_lines.file;
+    let file = match context.source_file(program.span()) {
+      Some(file) => file,
+      None => return,
+    };
 
     let mut excluded_ranges = visitor.ranges;
 
     context.leading_comments.values().for_each(|comments| {
       for comment in comments {
-        let lines = context
-          .source_map
-          .span_to_lines(comment.span)
-          .unwrap()
-          .lines;
+        let lines = match context.source_map.span_to_lines(comment.span) {
+          Ok(file_lines) => file_lines.lines,
+          Err(_) => continue,
+        };
         for line in lines.iter().skip(1) {
           let (lo, hi) = file.line_bounds(line.line_index as usize);
           excluded_ranges.push(Span::new(lo, hi, SyntaxContext::empty()));
@@ -60,11 +60,10 @@ impl LintRule for NoMixedSpacesAndTabs {
     });
     context.trailing_comments.values().for_each(|comments| {
       for comment in comments {
-        let lines = context
-          .source_map
-          .span_to_lines(comment.span)
-          .unwrap()
-          .lines;
+        let lines = match context.source_map.span_to_lines(comment.span) {
+          Ok(file_lines) => file_lines.lines,
+          Err(_) => continue,
+        };
         for line in lines.iter().skip(1) {
           let (lo, hi) = file.line_bounds(line.line_index as usize);
           excluded_ranges.push(Span::new(lo, hi, SyntaxContext::empty()));
@@ -96,6 +95,29 @@ impl LintRule for NoMixedSpacesAndTabs {
       }
     }
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows mixing spaces and tabs for indentation
+
+Indenting lines with a mix of spaces and tabs makes the indentation depend on
+the tab width of the editor, so code can look misaligned for other readers.
+Use either spaces or tabs for indentation, but not both on the same line.
+
+### Invalid:
+```typescript
+function add(x, y) {
+	  return x + y; // indented with a tab followed by spaces
+}
+```
+
+### Valid:
+```typescript
+function add(x, y) {
+  return x + y;
+}
+```
+"#
+  }
 }
 
 struct NoMixedSpacesAndTabsVisitor {
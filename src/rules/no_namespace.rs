@@ -1,15 +1,46 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
+use serde::Deserialize;
 use swc_ecmascript::ast::{TsModuleDecl, TsModuleName};
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
 
-pub struct NoNamespace;
+pub struct NoNamespace {
+  options: NoNamespaceOptions,
+}
+
+const CODE: &str = "no-namespace";
+const MESSAGE: &str = "custom typescript modules are outdated";
+const HINT: &str = "Use ES module syntax (`import` and `export`) instead";
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoNamespaceOptions {
+  /// Whether `declare namespace` and `declare module` are allowed.
+  pub allow_declarations: bool,
+  /// Whether namespaces are allowed in `.d.ts` files.
+  pub allow_definition_files: bool,
+}
+
+impl Default for NoNamespaceOptions {
+  fn default() -> Self {
+    Self {
+      allow_declarations: true,
+      allow_definition_files: true,
+    }
+  }
+}
+
+impl NoNamespace {
+  pub fn with_options(options: NoNamespaceOptions) -> Box<Self> {
+    Box::new(NoNamespace { options })
+  }
+}
 
 impl LintRule for NoNamespace {
   fn new() -> Box<Self> {
-    Box::new(NoNamespace)
+    Self::with_options(NoNamespaceOptions::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -17,7 +48,11 @@ impl LintRule for NoNamespace {
   }
 
   fn code(&self) -> &'static str {
-    "no-namespace"
+    CODE
+  }
+
+  fn is_typescript_only(&self) -> bool {
+    true
   }
 
   fn lint_program(
@@ -25,18 +60,58 @@ impl LintRule for NoNamespace {
     context: &mut Context,
     program: &swc_ecmascript::ast::Program,
   ) {
-    let mut visitor = NoNamespaceVisitor::new(context);
+    if self.options.allow_definition_files
+      && context.file_name.ends_with(".d.ts")
+    {
+      return;
+    }
+    let mut visitor =
+      NoNamespaceVisitor::new(context, self.options.allow_declarations);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the use of `namespace` and `module` keywords in TypeScript code
+
+Namespaces and custom modules are an outdated way to organize code. ES
+modules, with `import` and `export`, should be used instead.
+
+Ambient declarations, `declare namespace` and `declare module`, are allowed
+by default, as well as namespaces in `.d.ts` files. Set the
+`allowDeclarations` and `allowDefinitionFiles` options to `false` to report
+them as well. `declare global` and `declare module "name"` are always allowed.
+
+### Invalid:
+```typescript
+namespace Foo {}
+module Bar {}
+```
+
+### Valid:
+```typescript
+export const foo = 1;
+
+declare global {}
+declare module "bar" {}
+declare namespace Baz {}
+```
+"#
+  }
 }
 
 struct NoNamespaceVisitor<'c> {
   context: &'c mut Context,
+  allow_declarations: bool,
+  in_declaration: bool,
 }
 
 impl<'c> NoNamespaceVisitor<'c> {
-  fn new(context: &'c mut Context) -> Self {
-    Self { context }
+  fn new(context: &'c mut Context, allow_declarations: bool) -> Self {
+    Self {
+      context,
+      allow_declarations,
+      in_declaration: false,
+    }
   }
 }
 
@@ -46,18 +121,23 @@ impl<'c> Visit for NoNamespaceVisitor<'c> {
     mod_decl: &TsModuleDecl,
     parent: &dyn Node,
   ) {
-    if !mod_decl.global && !mod_decl.declare {
+    let in_declaration = self.in_declaration;
+    self.in_declaration |= mod_decl.declare;
+    let allowed = self.in_declaration && self.allow_declarations;
+    if !mod_decl.global && !allowed {
       if let TsModuleName::Ident(_) = mod_decl.id {
-        self.context.add_diagnostic(
+        self.context.add_diagnostic_with_hint(
           mod_decl.span,
-          "no-namespace",
-          "custom typescript modules are outdated",
+          CODE,
+          MESSAGE,
+          HINT,
         );
       }
     }
     for stmt in &mod_decl.body {
       self.visit_ts_namespace_body(stmt, parent);
     }
+    self.in_declaration = in_declaration;
   }
 }
 
@@ -86,4 +166,41 @@ mod tests {
       vec![0, 20],
     );
   }
+
+  #[test]
+  fn no_namespace_options() {
+    let rule = |allow_declarations, allow_definition_files| {
+      NoNamespace::with_options(NoNamespaceOptions {
+        allow_declarations,
+        allow_definition_files,
+      })
+    };
+
+    assert_lint_ok_with_rule(
+      rule(true, true),
+      "declare namespace Bar { namespace Baz {} }",
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(false, true),
+      "declare namespace Bar {}\ndeclare module Baz { namespace Qux {} }",
+      vec![(1, 0), (2, 0), (2, 21)],
+    );
+    assert_lint_ok_with_rule(
+      rule(false, false),
+      "declare global {}\ndeclare module 'foo' {}",
+    );
+
+    let source = "namespace Foo {}\ndeclare namespace Bar {}";
+    assert!(lint_file(rule(false, true), "foo.d.ts", source).is_empty());
+    assert_eq!(lint_file(rule(false, false), "foo.d.ts", source).len(), 2);
+    assert_eq!(lint_file(rule(false, true), "foo.ts", source).len(), 2);
+  }
+
+  #[test]
+  fn no_namespace_hint() {
+    assert_lint_err! {
+      NoNamespace,
+      "namespace Foo {}": [{ col: 0, message: MESSAGE, hint: HINT }],
+    };
+  }
 }
@@ -2,12 +2,18 @@
 use super::Context;
 use super::LintRule;
 use swc_ecmascript::ast::{Expr, NewExpr};
+use swc_ecmascript::utils::ident::IdentLike;
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
 
 pub struct NoNewSymbol;
 
+const CODE: &str = "no-new-symbol";
+const MESSAGE: &str = "`Symbol` cannot be called as a constructor.";
+const HINT: &str = "Call `Symbol()` without the `new` keyword";
+
 impl LintRule for NoNewSymbol {
   fn new() -> Box<Self> {
     Box::new(NoNewSymbol)
@@ -18,7 +24,7 @@ impl LintRule for NoNewSymbol {
   }
 
   fn code(&self) -> &'static str {
-    "no-new-symbol"
+    CODE
   }
 
   fn lint_program(
@@ -29,6 +35,29 @@ impl LintRule for NoNewSymbol {
     let mut visitor = NoNewSymbolVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the use of `new` operators with built-in `Symbol`s
+
+`Symbol`s are created by invoking as a function, and calling it with the `new`
+operator throws a `TypeError`. A local binding named `Symbol` is not reported.
+
+### Invalid:
+```typescript
+const foo = new Symbol("foo");
+```
+
+### Valid:
+```typescript
+const foo = Symbol("foo");
+
+function func(Symbol: typeof SomeClass) {
+  // This `Symbol` is not built-in one
+  const bar = new Symbol();
+}
+```
+"#
+  }
 }
 
 struct NoNewSymbolVisitor<'c> {
@@ -46,21 +75,24 @@ impl<'c> Visit for NoNewSymbolVisitor<'c> {
 
   fn visit_new_expr(&mut self, new_expr: &NewExpr, _parent: &dyn Node) {
     if let Expr::Ident(ident) = &*new_expr.callee {
-      if ident.sym == *"Symbol" {
-        self.context.add_diagnostic(
+      if ident.sym == *"Symbol"
+        && self.context.scope.var(&ident.to_id()).is_none()
+      {
+        self.context.add_diagnostic_with_hint(
           new_expr.span,
-          "no-new-symbol",
-          "`Symbol` cannot be called as a constructor.",
+          CODE,
+          MESSAGE,
+          HINT,
         );
       }
     }
+    new_expr.visit_children_with(self);
   }
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::test_util::*;
 
   #[test]
   fn no_new_symbol_valid() {
@@ -68,11 +100,22 @@ mod tests {
       NoNewSymbol,
       "new Class()",
       "Symbol()",
+      "const foo = Symbol('foo');",
+      "function func(Symbol: typeof SomeClass) { const bar = new Symbol(); }",
+      "const Symbol = () => {}; new Symbol();",
+      "class Symbol {} new Symbol();",
+      "import { Symbol } from './foo.ts'; new Symbol();",
     };
   }
 
   #[test]
   fn no_new_symbol_invalid() {
-    assert_lint_err::<NoNewSymbol>("new Symbol()", 0);
+    assert_lint_err! {
+      NoNewSymbol,
+      "new Symbol()": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "const foo = new Symbol('foo');": [{ col: 12, message: MESSAGE, hint: HINT }],
+      "function f(Symbol) {} new Symbol();": [{ col: 22, message: MESSAGE, hint: HINT }],
+      "new Foo(new Symbol());": [{ col: 8, message: MESSAGE, hint: HINT }],
+    };
   }
 }
@@ -18,6 +18,10 @@ impl LintRule for NoNonNullAssertedOptionalChain {
     "no-non-null-asserted-optional-chain"
   }
 
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
   fn lint_program(
     &self,
     context: &mut Context,
@@ -26,6 +30,28 @@ impl LintRule for NoNonNullAssertedOptionalChain {
     let mut visitor = NoNonNullAssertedOptionalChainVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows non-null assertions after an optional chain expression
+
+Optional chains evaluate to `undefined` when the object they access is `null`
+or `undefined`. Asserting that the result is non-null with `!` contradicts the
+purpose of the optional chain and hides potential `undefined` values from the
+type checker.
+
+### Invalid:
+```typescript
+foo?.bar!;
+foo?.bar()!;
+```
+
+### Valid:
+```typescript
+foo?.bar;
+foo?.bar();
+```
+"#
+  }
 }
 
 struct NoNonNullAssertedOptionalChainVisitor<'c> {
@@ -1,28 +1,59 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
+use swc_ecmascript::ast::{Expr, Program, TsNonNullExpr};
+use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
 
 pub struct NoNonNullAssertion;
 
+const CODE: &str = "no-non-null-assertion";
+const MESSAGE: &str = "Do not use non-null assertion";
+const HINT: &str = "Check for `null` and `undefined` explicitly, or use optional chaining (`?.`) instead";
+
 impl LintRule for NoNonNullAssertion {
   fn new() -> Box<Self> {
     Box::new(NoNonNullAssertion)
   }
 
   fn code(&self) -> &'static str {
-    "no-non-null-assertion"
+    CODE
   }
 
-  fn lint_program(
-    &self,
-    context: &mut Context,
-    program: &swc_ecmascript::ast::Program,
-  ) {
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
     let mut visitor = NoNonNullAssertionVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows non-null assertions using the `!` postfix operator
+
+A non-null assertion tells the compiler that a value is neither `null` nor
+`undefined` without checking it, so a wrong assertion is only noticed at
+runtime. Checking the value, or using optional chaining, keeps the code type
+safe. Chained assertions like `foo!!` are reported once.
+
+### Invalid:
+```typescript
+const length = foo!.bar.length;
+const first = arr![0];
+```
+
+### Valid:
+```typescript
+const length = foo?.bar.length;
+if (arr) {
+  const first = arr[0];
+}
+```
+"#
+  }
 }
 
 struct NoNonNullAssertionVisitor<'c> {
@@ -36,16 +67,27 @@ impl<'c> NoNonNullAssertionVisitor<'c> {
 }
 
 impl<'c> Visit for NoNonNullAssertionVisitor<'c> {
+  noop_visit_type!();
+
   fn visit_ts_non_null_expr(
     &mut self,
-    non_null_expr: &swc_ecmascript::ast::TsNonNullExpr,
+    non_null_expr: &TsNonNullExpr,
     _parent: &dyn Node,
   ) {
-    self.context.add_diagnostic(
+    self.context.add_diagnostic_with_hint(
       non_null_expr.span,
-      "no-non-null-assertion",
-      "do not use non-null assertion",
+      CODE,
+      MESSAGE,
+      HINT,
     );
+
+    // `foo!!` is reported once, but assertions nested deeper are reported on
+    // their own.
+    let mut expr = &*non_null_expr.expr;
+    while let Expr::TsNonNull(inner) = expr {
+      expr = &*inner.expr;
+    }
+    expr.visit_with(non_null_expr, self);
   }
 }
 
@@ -90,4 +132,26 @@ mod tests {
     assert_lint_err::<NoNonNullAssertion>("x!.y;", 0);
     assert_lint_err::<NoNonNullAssertion>("x!;", 0);
   }
+
+  #[test]
+  fn no_non_null_assertion_nested() {
+    assert_lint_err! {
+      NoNonNullAssertion,
+      "foo!.bar;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "arr![0];": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "foo(a!)!;": [
+        { col: 0, message: MESSAGE, hint: HINT },
+        { col: 4, message: MESSAGE, hint: HINT },
+      ],
+      "arr![idx!];": [
+        { col: 0, message: MESSAGE, hint: HINT },
+        { col: 5, message: MESSAGE, hint: HINT },
+      ],
+      "(a!.b + c!)!;": [
+        { col: 0, message: MESSAGE, hint: HINT },
+        { col: 1, message: MESSAGE, hint: HINT },
+        { col: 8, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
 }
@@ -5,14 +5,17 @@ use swc_common::Span;
 use swc_ecmascript::ast::CallExpr;
 use swc_ecmascript::ast::Expr;
 use swc_ecmascript::ast::ExprOrSuper;
+use swc_ecmascript::ast::Ident;
 use swc_ecmascript::ast::NewExpr;
+use swc_ecmascript::utils::ident::IdentLike;
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
-use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::{Visit, VisitWith};
 
 pub struct NoObjCalls;
 
 const CODE: &str = "no-obj-calls";
+const HINT: &str = "This is a namespace of static functions and values, not a function, use one of its members instead";
 
 fn get_message(callee_name: &str) -> String {
   format!("`{}` call as function is not allowed", callee_name)
@@ -39,6 +42,32 @@ impl LintRule for NoObjCalls {
     let mut visitor = NoObjCallsVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows calling built-in global objects like functions
+
+`Math`, `JSON`, `Reflect`, `Atomics` and `Intl` are namespaces of static
+functions and values, not constructors or functions. Calling them, with or
+without `new`, throws a `TypeError` at runtime. Local variables shadowing these
+globals can be called.
+
+### Invalid:
+```typescript
+const math = Math();
+const newMath = new Math();
+const json = JSON();
+const reflect = Reflect();
+const atomics = new Atomics();
+```
+
+### Valid:
+```typescript
+const area = Math.PI * 2;
+const parsed = JSON.parse("{}");
+const keys = Reflect.ownKeys({});
+```
+"#
+  }
 }
 
 struct NoObjCallsVisitor<'c> {
@@ -50,14 +79,18 @@ impl<'c> NoObjCallsVisitor<'c> {
     Self { context }
   }
 
-  fn check_callee(&mut self, callee_name: impl AsRef<str>, span: Span) {
-    let callee_name = callee_name.as_ref();
+  fn check_callee(&mut self, callee: &Ident, span: Span) {
+    let callee_name = callee.sym.as_ref();
     match callee_name {
-      "Math" | "JSON" | "Reflect" | "Atomics" => {
-        self.context.add_diagnostic(
+      "Math" | "JSON" | "Reflect" | "Atomics" | "Intl" => {
+        if self.context.scope.var(&callee.to_id()).is_some() {
+          return;
+        }
+        self.context.add_diagnostic_with_hint(
           span,
-          "no-obj-calls",
+          CODE,
           get_message(callee_name),
+          HINT,
         );
       }
       _ => {}
@@ -71,15 +104,17 @@ impl<'c> Visit for NoObjCallsVisitor<'c> {
   fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
     if let ExprOrSuper::Expr(expr) = &call_expr.callee {
       if let Expr::Ident(ident) = expr.as_ref() {
-        self.check_callee(&ident.sym, call_expr.span);
+        self.check_callee(ident, call_expr.span);
       }
     }
+    call_expr.visit_children_with(self);
   }
 
   fn visit_new_expr(&mut self, new_expr: &NewExpr, _parent: &dyn Node) {
     if let Expr::Ident(ident) = &*new_expr.callee {
-      self.check_callee(&ident.sym, new_expr.span);
+      self.check_callee(ident, new_expr.span);
     }
+    new_expr.visit_children_with(self);
   }
 }
 
@@ -95,6 +130,15 @@ mod tests {
       "JSON.parse(\"{}\");",
       "Reflect.get({ x: 1, y: 2 }, \"x\");",
       "Atomics.load(foo, 0);",
+      "new Intl.DateTimeFormat();",
+      "foo(Math.max(1, 2));",
+
+      // shadowed globals
+      "const Math = () => 1; Math();",
+      "function foo(JSON) { return JSON(); }",
+      "import Reflect from './reflect.ts'; new Reflect();",
+      "function bar() { class Atomics {} return new Atomics(); }",
+      "function baz(Intl) { Intl(); }",
     };
   }
 
@@ -102,14 +146,20 @@ mod tests {
   fn no_obj_calls_invalid() {
     assert_lint_err! {
       NoObjCalls,
-      "Math();": [{col: 0, message: get_message("Math")}],
-      "new Math();": [{col: 0, message: get_message("Math")}],
-      "JSON();": [{col: 0, message: get_message("JSON")}],
-      "new JSON();": [{col: 0, message: get_message("JSON")}],
-      "Reflect();": [{col: 0, message: get_message("Reflect")}],
-      "new Reflect();": [{col: 0, message: get_message("Reflect")}],
-      "Atomics();": [{col: 0, message: get_message("Atomics")}],
-      "new Atomics();": [{col: 0, message: get_message("Atomics")}],
+      "Math();": [{col: 0, message: get_message("Math"), hint: HINT}],
+      "new Math();": [{col: 0, message: get_message("Math"), hint: HINT}],
+      "JSON();": [{col: 0, message: get_message("JSON"), hint: HINT}],
+      "new JSON();": [{col: 0, message: get_message("JSON"), hint: HINT}],
+      "Reflect();": [{col: 0, message: get_message("Reflect"), hint: HINT}],
+      "new Reflect();": [{col: 0, message: get_message("Reflect"), hint: HINT}],
+      "Atomics();": [{col: 0, message: get_message("Atomics"), hint: HINT}],
+      "new Atomics();": [{col: 0, message: get_message("Atomics"), hint: HINT}],
+      "Intl();": [{col: 0, message: get_message("Intl"), hint: HINT}],
+      "new Intl();": [{col: 0, message: get_message("Intl"), hint: HINT}],
+      "foo(Math());": [{col: 4, message: get_message("Math"), hint: HINT}],
+      "const a = () => JSON();": [{col: 16, message: get_message("JSON"), hint: HINT}],
+      "function foo(JSON) {} Reflect();": [{col: 22, message: get_message("Reflect"), hint: HINT}],
+      "{ const Math = 1; } Math();": [{col: 20, message: get_message("Math"), hint: HINT}],
     }
   }
 }
@@ -33,6 +33,28 @@ impl LintRule for NoOctal {
     let mut visitor = NoOctalVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows legacy octal literals
+
+Number literals with a leading zero, like `071`, are interpreted as octal
+numbers in sloppy mode and are a syntax error in strict mode and ES modules.
+Because they are easily mistaken for decimal numbers, use the `0o` prefix for
+octal numbers instead.
+
+### Invalid:
+```typescript
+const num = 071;
+const result = 5 + 07;
+```
+
+### Valid:
+```typescript
+const num = 0o71;
+const result = 5 + 7;
+```
+"#
+  }
 }
 
 struct NoOctalVisitor<'c> {
@@ -49,11 +71,10 @@ impl<'c> Visit for NoOctalVisitor<'c> {
   fn visit_number(&mut self, literal_num: &Number, _parent: &dyn Node) {
     static OCTAL: Lazy<Regex> = Lazy::new(|| Regex::new(r"^0[0-9]").unwrap());
 
-    let raw_number = self
-      .context
-      .source_map
-      .span_to_snippet(literal_num.span)
-      .expect("error in loading snippet");
+    let raw_number = match self.context.snippet(literal_num.span) {
+      Some(raw_number) => raw_number,
+      None => return,
+    };
 
     if OCTAL.is_match(&raw_number) {
       self.context.add_diagnostic(literal_num.span, CODE, MESSAGE);
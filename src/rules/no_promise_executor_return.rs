@@ -0,0 +1,268 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_common::Spanned;
+use swc_ecmascript::ast::{
+  ArrowExpr, BlockStmt, BlockStmtOrExpr, Constructor, Expr, Function,
+  GetterProp, NewExpr, Program, ReturnStmt, SetterProp,
+};
+use swc_ecmascript::visit::{noop_visit_type, Node, Visit, VisitWith};
+
+pub struct NoPromiseExecutorReturn;
+
+const CODE: &str = "no-promise-executor-return";
+const MESSAGE: &str =
+  "Return values from promise executor functions are ignored";
+const HINT: &str = "Call `resolve` with the value instead, or use `return;` to stop the executor";
+
+impl LintRule for NoPromiseExecutorReturn {
+  fn new() -> Box<Self> {
+    Box::new(NoPromiseExecutorReturn)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = NoPromiseExecutorReturnVisitor { context };
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows returning values from promise executor functions
+
+The value returned from the executor function passed to `new Promise()` is
+ignored, so returning a value is most likely a mistake, like trying to resolve
+the promise with it. This includes arrow functions without braces, which return
+the value of their body. A `return` statement without a value can still be used
+to stop the executor early.
+
+### Invalid:
+```typescript
+new Promise((resolve) => {
+  return 1;
+});
+
+new Promise((resolve) => resolve(1));
+
+new Promise(function (resolve, reject) {
+  if (error) {
+    return reject(error);
+  }
+  resolve(value);
+});
+```
+
+### Valid:
+```typescript
+new Promise((resolve) => {
+  resolve(1);
+});
+
+new Promise(function (resolve, reject) {
+  if (error) {
+    reject(error);
+    return;
+  }
+  resolve(value);
+});
+```
+"#
+  }
+}
+
+struct NoPromiseExecutorReturnVisitor<'c> {
+  context: &'c mut Context,
+}
+
+/// Returns the body of the executor of `new Promise(executor)`, if the
+/// executor is a function.
+fn executor_body(new_expr: &NewExpr) -> Option<ExecutorBody<'_>> {
+  match &*new_expr.callee {
+    Expr::Ident(ident) if &*ident.sym == "Promise" => {}
+    _ => return None,
+  }
+  let mut executor = &*new_expr.args.as_ref()?.first()?.expr;
+  while let Expr::Paren(paren_expr) = executor {
+    executor = &*paren_expr.expr;
+  }
+  match executor {
+    Expr::Fn(fn_expr) => {
+      fn_expr.function.body.as_ref().map(ExecutorBody::Block)
+    }
+    Expr::Arrow(arrow_expr) => match &arrow_expr.body {
+      BlockStmtOrExpr::BlockStmt(body) => Some(ExecutorBody::Block(body)),
+      BlockStmtOrExpr::Expr(expr) => Some(ExecutorBody::Expr(expr)),
+    },
+    _ => None,
+  }
+}
+
+enum ExecutorBody<'a> {
+  Block(&'a BlockStmt),
+  /// The body of an arrow function without braces, which is implicitly
+  /// returned.
+  Expr(&'a Expr),
+}
+
+impl<'c> Visit for NoPromiseExecutorReturnVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_new_expr(&mut self, new_expr: &NewExpr, _parent: &dyn Node) {
+    match executor_body(new_expr) {
+      Some(ExecutorBody::Block(body)) => {
+        let mut return_visitor = ReturnVisitor {
+          context: self.context,
+        };
+        body.visit_children_with(&mut return_visitor);
+      }
+      Some(ExecutorBody::Expr(expr)) => {
+        self
+          .context
+          .add_diagnostic_with_hint(expr.span(), CODE, MESSAGE, HINT);
+      }
+      None => {}
+    }
+    new_expr.visit_children_with(self);
+  }
+}
+
+/// Reports return statements with a value, except for those of nested
+/// functions.
+struct ReturnVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> Visit for ReturnVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, _function: &Function, _parent: &dyn Node) {}
+
+  fn visit_arrow_expr(&mut self, _arrow_expr: &ArrowExpr, _parent: &dyn Node) {}
+
+  // Accessors of object literals and constructors aren't `Function`s.
+  fn visit_getter_prop(
+    &mut self,
+    _getter_prop: &GetterProp,
+    _parent: &dyn Node,
+  ) {
+  }
+
+  fn visit_setter_prop(
+    &mut self,
+    _setter_prop: &SetterProp,
+    _parent: &dyn Node,
+  ) {
+  }
+
+  fn visit_constructor(
+    &mut self,
+    _constructor: &Constructor,
+    _parent: &dyn Node,
+  ) {
+  }
+
+  fn visit_return_stmt(
+    &mut self,
+    return_stmt: &ReturnStmt,
+    _parent: &dyn Node,
+  ) {
+    if return_stmt.arg.is_some() {
+      self.context.add_diagnostic_with_hint(
+        return_stmt.span,
+        CODE,
+        MESSAGE,
+        HINT,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_promise_executor_return_valid() {
+    assert_lint_ok! {
+      NoPromiseExecutorReturn,
+      "new Promise((resolve) => { resolve(1); });",
+      "new Promise(function (resolve) { resolve(1); });",
+      "new Promise((resolve) => { if (a) { return; } resolve(1); });",
+      "new Promise(function (resolve) { return; });",
+      "new Promise(executor);",
+      "new Foo((resolve) => { return 1; });",
+      "function foo() { return 1; }",
+
+      // returns of nested functions
+      "new Promise((resolve) => { setTimeout(() => { return resolve(1); }); });",
+      "new Promise((resolve) => { function foo() { return 1; } resolve(foo()); });",
+      "new Promise((resolve) => { const a = { foo() { return 1; } }; });",
+      "new Promise((resolve) => { class A { foo() { return 1; } } });",
+      "new Promise((resolve) => { class A { constructor() { return {}; } } });",
+      "new Promise((resolve) => { const a = { get foo() { return 1; } }; });",
+      "new Promise((resolve) => { const a = { set foo(x) { return x; } }; });",
+    };
+  }
+
+  #[test]
+  fn no_promise_executor_return_invalid() {
+    assert_lint_err! {
+      NoPromiseExecutorReturn,
+      "new Promise((resolve) => { return 1; });": [
+        { col: 27, message: MESSAGE, hint: HINT },
+      ],
+      "new Promise(function (resolve) { return 1; });": [
+        { col: 33, message: MESSAGE, hint: HINT },
+      ],
+      "new Promise(async function (resolve) { return await foo(); });": [
+        { col: 39, message: MESSAGE, hint: HINT },
+      ],
+      "new Promise(((resolve, reject) => { return reject(); }));": [
+        { col: 36, message: MESSAGE, hint: HINT },
+      ],
+      "new Promise((resolve) => resolve(1));": [
+        { col: 25, message: MESSAGE, hint: HINT },
+      ],
+      "new Promise(r => void r(1));": [
+        { col: 17, message: MESSAGE, hint: HINT },
+      ],
+      // executors nested in accessors and constructors
+      "class A { constructor() { new Promise((r) => r(1)); } }": [
+        { col: 45, message: MESSAGE, hint: HINT },
+      ],
+      "const a = { set foo(v) { new Promise((r) => r(v)); } };": [
+        { col: 44, message: MESSAGE, hint: HINT },
+      ],
+      "const a = { get foo() { return new Promise((r) => { return 1; }); } };": [
+        { col: 52, message: MESSAGE, hint: HINT },
+      ],
+      r#"
+new Promise((resolve, reject) => {
+  if (error) {
+    return reject(error);
+  }
+  try {
+    return resolve(1);
+  } catch (e) {
+    return;
+  }
+});
+      "#: [
+        { line: 4, col: 4, message: MESSAGE, hint: HINT },
+        { line: 7, col: 4, message: MESSAGE, hint: HINT },
+      ],
+      // nested promises
+      r#"
+function foo() {
+  return new Promise((resolve) => {
+    resolve(new Promise((resolve) => { return 1; }));
+  });
+}
+      "#: [
+        { line: 4, col: 39, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
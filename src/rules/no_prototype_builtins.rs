@@ -5,16 +5,19 @@ use super::LintRule;
 use swc_ecmascript::ast::CallExpr;
 use swc_ecmascript::ast::Expr;
 use swc_ecmascript::ast::ExprOrSuper;
+use swc_ecmascript::ast::Lit;
+use swc_ecmascript::ast::MemberExpr;
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
-use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::{Visit, VisitWith};
 
 const BANNED_PROPERTIES: &[&str] =
-  &["hasOwnProperty", "isPrototypeOf", "propertyIsEnumberable"];
+  &["hasOwnProperty", "isPrototypeOf", "propertyIsEnumerable"];
 
 pub struct NoPrototypeBuiltins;
 
 const CODE: &str = "no-prototype-builtins";
+const HINT: &str = "Call the method from `Object.prototype` instead, like `Object.prototype.hasOwnProperty.call(obj, key)`, or use `Object.hasOwn(obj, key)`";
 
 fn get_message(prop: &str) -> String {
   format!(
@@ -44,6 +47,29 @@ impl LintRule for NoPrototypeBuiltins {
     let mut visitor = NoPrototypeBuiltinsVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows calling `Object.prototype` methods directly on objects
+
+Objects can shadow the methods of `Object.prototype`, like `hasOwnProperty`,
+and objects created with `Object.create(null)` don't inherit them at all.
+Calling these methods directly on an object can therefore fail or return
+incorrect results, so call them on `Object.prototype` instead.
+
+### Invalid:
+```typescript
+const a = foo.hasOwnProperty("bar");
+const b = foo.isPrototypeOf(bar);
+```
+
+### Valid:
+```typescript
+const a = Object.prototype.hasOwnProperty.call(foo, "bar");
+const b = Object.prototype.isPrototypeOf.call(foo, bar);
+const c = Object.hasOwn(foo, "bar");
+```
+"#
+  }
 }
 
 struct NoPrototypeBuiltinsVisitor<'c> {
@@ -56,30 +82,65 @@ impl<'c> NoPrototypeBuiltinsVisitor<'c> {
   }
 }
 
+/// Returns the name of the property accessed by `member_expr`, for both
+/// `obj.prop` and `obj["prop"]`.
+fn property_name(member_expr: &MemberExpr) -> Option<&str> {
+  match &*member_expr.prop {
+    Expr::Ident(ident) if !member_expr.computed => Some(ident.sym.as_ref()),
+    Expr::Lit(Lit::Str(str_)) if member_expr.computed => {
+      Some(str_.value.as_ref())
+    }
+    _ => None,
+  }
+}
+
+/// Returns true for `Object.prototype`, on which calling the methods is fine.
+fn is_object_prototype(expr: &Expr) -> bool {
+  match expr {
+    Expr::Member(member_expr) => {
+      let is_object = match &member_expr.obj {
+        ExprOrSuper::Expr(obj) => {
+          matches!(&**obj, Expr::Ident(ident) if &*ident.sym == "Object")
+        }
+        ExprOrSuper::Super(_) => false,
+      };
+      is_object && property_name(member_expr) == Some("prototype")
+    }
+    _ => false,
+  }
+}
+
 impl<'c> Visit for NoPrototypeBuiltinsVisitor<'c> {
   noop_visit_type!();
 
   fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
-    let member_expr = match &call_expr.callee {
-      ExprOrSuper::Expr(boxed_expr) => match &**boxed_expr {
-        Expr::Member(member_expr) => {
-          if member_expr.computed {
-            return;
-          }
-          member_expr
-        }
-        _ => return,
-      },
+    call_expr.visit_children_with(self);
+
+    let mut callee = match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => &**callee,
       ExprOrSuper::Super(_) => return,
     };
+    // `foo?.hasOwnProperty("bar")`
+    if let Expr::OptChain(opt_chain) = callee {
+      callee = &*opt_chain.expr;
+    }
+    let member_expr = match callee {
+      Expr::Member(member_expr) => member_expr,
+      _ => return,
+    };
+    if let ExprOrSuper::Expr(obj) = &member_expr.obj {
+      if is_object_prototype(obj) {
+        return;
+      }
+    }
 
-    if let Expr::Ident(ident) = &*member_expr.prop {
-      let prop_name = ident.sym.as_ref();
+    if let Some(prop_name) = property_name(member_expr) {
       if BANNED_PROPERTIES.contains(&prop_name) {
-        self.context.add_diagnostic(
+        self.context.add_diagnostic_with_hint(
           call_expr.span,
           CODE,
           get_message(prop_name),
+          HINT,
         );
       }
     }
@@ -97,20 +158,25 @@ mod tests {
       r#"
   Object.prototype.hasOwnProperty.call(foo, "bar");
   Object.prototype.isPrototypeOf.call(foo, "bar");
-  Object.prototype.propertyIsEnumberable.call(foo, "bar");
+  Object.prototype.propertyIsEnumerable.call(foo, "bar");
   Object.prototype.hasOwnProperty.apply(foo, ["bar"]);
   Object.prototype.isPrototypeOf.apply(foo, ["bar"]);
-  Object.prototype.propertyIsEnumberable.apply(foo, ["bar"]);
+  Object.prototype.propertyIsEnumerable.apply(foo, ["bar"]);
   hasOwnProperty(foo, "bar");
   isPrototypeOf(foo, "bar");
-  propertyIsEnumberable(foo, "bar");
+  propertyIsEnumerable(foo, "bar");
   ({}.hasOwnProperty.call(foo, "bar"));
   ({}.isPrototypeOf.call(foo, "bar"));
-  ({}.propertyIsEnumberable.call(foo, "bar"));
+  ({}.propertyIsEnumerable.call(foo, "bar"));
   ({}.hasOwnProperty.apply(foo, ["bar"]));
   ({}.isPrototypeOf.apply(foo, ["bar"]));
-  ({}.propertyIsEnumberable.apply(foo, ["bar"]));
+  ({}.propertyIsEnumerable.apply(foo, ["bar"]));
       "#,
+      "Object.prototype.hasOwnProperty(foo);",
+      "Object['prototype'].isPrototypeOf(foo);",
+      "foo.propertyIsEnumberable('bar');",
+      "foo[hasOwnProperty]('bar');",
+      "Object.hasOwn(foo, 'bar');",
     };
   }
 
@@ -118,10 +184,15 @@ mod tests {
   fn no_prototype_builtins_invalid() {
     assert_lint_err! {
       NoPrototypeBuiltins,
-      "foo.hasOwnProperty('bar');": [{col: 0, message: get_message("hasOwnProperty")}],
-      "foo.isPrototypeOf('bar');": [{col: 0, message: get_message("isPrototypeOf")}],
-      "foo.propertyIsEnumberable('bar');": [{col: 0, message: get_message("propertyIsEnumberable")}],
-      "foo.bar.baz.hasOwnProperty('bar');": [{col: 0, message: get_message("hasOwnProperty")}],
+      "foo.hasOwnProperty('bar');": [{col: 0, message: get_message("hasOwnProperty"), hint: HINT}],
+      "foo.isPrototypeOf('bar');": [{col: 0, message: get_message("isPrototypeOf"), hint: HINT}],
+      "foo.propertyIsEnumerable('bar');": [{col: 0, message: get_message("propertyIsEnumerable"), hint: HINT}],
+      "foo.bar.baz.hasOwnProperty('bar');": [{col: 0, message: get_message("hasOwnProperty"), hint: HINT}],
+      "foo['hasOwnProperty']('bar');": [{col: 0, message: get_message("hasOwnProperty"), hint: HINT}],
+      "foo?.hasOwnProperty('bar');": [{col: 0, message: get_message("hasOwnProperty"), hint: HINT}],
+      "if (foo(bar.hasOwnProperty('baz'))) {}": [{col: 8, message: get_message("hasOwnProperty"), hint: HINT}],
+      "const a = () => foo.isPrototypeOf(bar);": [{col: 16, message: get_message("isPrototypeOf"), hint: HINT}],
+      "Object.hasOwnProperty('bar');": [{col: 0, message: get_message("hasOwnProperty"), hint: HINT}],
     }
   }
 }
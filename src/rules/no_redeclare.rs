@@ -1,6 +1,8 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
+use crate::globals::GLOBALS;
+use serde::Deserialize;
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::{
   ast::*, utils::find_ids, utils::ident::IdentLike, utils::Id, visit::Node,
@@ -9,14 +11,32 @@ use swc_ecmascript::{
 
 use std::collections::HashSet;
 
-pub struct NoRedeclare;
+pub struct NoRedeclare {
+  options: NoRedeclareOptions,
+}
 
 const CODE: &str = "no-redeclare";
 const MESSAGE: &str = "Redeclaration is not allowed";
+const HINT: &str = "Remove the duplicate declaration or rename the variable";
+const BUILTIN_HINT: &str =
+  "Rename the variable so it doesn't shadow the built-in global";
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoRedeclareOptions {
+  /// Also report top-level declarations of built-in globals like `Object`.
+  pub builtin_globals: bool,
+}
+
+impl NoRedeclare {
+  pub fn with_options(options: NoRedeclareOptions) -> Box<Self> {
+    Box::new(NoRedeclare { options })
+  }
+}
 
 impl LintRule for NoRedeclare {
   fn new() -> Box<Self> {
-    Box::new(NoRedeclare)
+    Self::with_options(NoRedeclareOptions::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -30,29 +50,78 @@ impl LintRule for NoRedeclare {
   fn lint_program(&self, context: &mut Context, program: &Program) {
     let mut visitor = NoRedeclareVisitor {
       context,
+      options: &self.options,
       bindings: Default::default(),
     };
     program.visit_with(program, &mut visitor);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows redeclaration of variables, functions and parameters
+
+Declaring the same name twice in one scope, for example with two `var`
+declarations or a `function` and a `var` of the same name, silently overwrites
+the first binding and is almost always a mistake.
+
+With the `builtinGlobals` option enabled, top-level declarations that shadow a
+built-in global such as `Object` are reported as well.
+
+### Invalid:
+```typescript
+var a = 3;
+var a = 10;
+
+function b() {}
+var b;
+
+function c(d) {
+  var d;
+}
+```
+
+### Valid:
+```typescript
+var a = 3;
+a = 10;
+
+function b() {
+  var a = 4;
+}
+```
+"#
+  }
 }
 
-struct NoRedeclareVisitor<'c> {
+struct NoRedeclareVisitor<'c, 'o> {
   context: &'c mut Context,
+  options: &'o NoRedeclareOptions,
   /// TODO(kdy1): Change this to HashMap<Id, Vec<Span>> and use those spans to point previous bindings/
   bindings: HashSet<Id>,
 }
 
-impl<'c> NoRedeclareVisitor<'c> {
+impl<'c, 'o> NoRedeclareVisitor<'c, 'o> {
   fn declare(&mut self, i: &Ident) {
     let id = i.to_id();
 
     if !self.bindings.insert(id) {
-      self.context.add_diagnostic(i.span, CODE, MESSAGE);
+      self
+        .context
+        .add_diagnostic_with_hint(i.span, CODE, MESSAGE, HINT);
+    } else if self.options.builtin_globals
+      && i.span.ctxt == self.context.top_level_ctxt
+      && GLOBALS.iter().any(|(name, _)| name == &&*i.sym)
+    {
+      self.context.add_diagnostic_with_hint(
+        i.span,
+        CODE,
+        format!("'{}' is already defined as a built-in global", i.sym),
+        BUILTIN_HINT,
+      );
     }
   }
 }
 
-impl<'c> Visit for NoRedeclareVisitor<'c> {
+impl<'c, 'o> Visit for NoRedeclareVisitor<'c, 'o> {
   noop_visit_type!();
 
   fn visit_fn_decl(&mut self, f: &FnDecl, _: &dyn Node) {
@@ -93,6 +162,7 @@ impl<'c> Visit for NoRedeclareVisitor<'c> {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::test_util::*;
 
   #[test]
   fn no_redeclare_valid() {
@@ -114,33 +184,66 @@ mod tests {
   fn no_redeclare_invalid() {
     assert_lint_err! {
       NoRedeclare,
-      "var a = 3; var a = 10;": [{col: 15, message: MESSAGE}],
-      "switch(foo) { case a: var b = 3;\ncase b: var b = 4}": [{col: 12, line: 2, message: MESSAGE}],
-      "var a = 3; var a = 10;": [{col: 15, message: MESSAGE}],
-      "var a = {}; var a = [];": [{col: 16, message: MESSAGE}],
-      "var a; function a() {}": [{col: 16, message: MESSAGE}],
-      "function a() {} function a() {}": [{col: 25, message: MESSAGE}],
-      "var a = function() { }; var a = function() { }": [{col: 28, message: MESSAGE}],
-      "var a = function() { }; var a = new Date();": [{col: 28, message: MESSAGE}],
-      "var a; var a;": [{col: 11, message: MESSAGE}],
-      "export var a; var a;": [{col: 18, message: MESSAGE}],
-      "function f() { var a; var a; }": [{col: 26, message: MESSAGE}],
-      "function f(a) { var a; }": [{col: 20, message: MESSAGE}],
-      "function f() { var a; if (test) { var a; } }": [{col: 38, message: MESSAGE}],
-      "for (var a, a;;);": [{col: 12, message: MESSAGE}],
-      "let a; let a;": [{col: 11, message: MESSAGE}],
-      "let a; const a = 0;": [{col: 13, message: MESSAGE}],
-      "const a = 0; const a = 0;": [{col: 19, message: MESSAGE}],
-      "if (test) { let a; let a; }": [{col: 23, message: MESSAGE}],
-      "switch (test) { case 0: let a; let a; }": [{col: 35, message: MESSAGE}],
-      "for (let a, a;;);": [{col: 12, message: MESSAGE}],
-      "for (let [a, a] in xs);": [{col: 13, message: MESSAGE}],
-      "function f() { let a; let a; }": [{col: 26, message: MESSAGE}],
-      "function f(a) { let a; }": [{col: 20, message: MESSAGE}],
-      "function f() { if (test) { let a; let a; } }": [{col: 38, message: MESSAGE}],
-      "var a = 3; var a = 10; var a = 15;": [{col: 15, message: MESSAGE}, {col: 27, message: MESSAGE}],
-      "var a; var {a = 0, b: Object = 0} = {};": [{line: 1, col: 12, message: MESSAGE}],
-      "var a; var {a = 0, b: globalThis = 0} = {};": [{line: 1, col: 12, message: MESSAGE}],
+      "var a = 3; var a = 10;": [{col: 15, message: MESSAGE, hint: HINT}],
+      "switch(foo) { case a: var b = 3;\ncase b: var b = 4}": [{col: 12, line: 2, message: MESSAGE, hint: HINT}],
+      "var a = 3; var a = 10;": [{col: 15, message: MESSAGE, hint: HINT}],
+      "var a = {}; var a = [];": [{col: 16, message: MESSAGE, hint: HINT}],
+      "var a; function a() {}": [{col: 16, message: MESSAGE, hint: HINT}],
+      "function a() {} function a() {}": [{col: 25, message: MESSAGE, hint: HINT}],
+      "var a = function() { }; var a = function() { }": [{col: 28, message: MESSAGE, hint: HINT}],
+      "var a = function() { }; var a = new Date();": [{col: 28, message: MESSAGE, hint: HINT}],
+      "var a; var a;": [{col: 11, message: MESSAGE, hint: HINT}],
+      "export var a; var a;": [{col: 18, message: MESSAGE, hint: HINT}],
+      "function f() { var a; var a; }": [{col: 26, message: MESSAGE, hint: HINT}],
+      "function f(a) { var a; }": [{col: 20, message: MESSAGE, hint: HINT}],
+      "function f() { var a; if (test) { var a; } }": [{col: 38, message: MESSAGE, hint: HINT}],
+      "for (var a, a;;);": [{col: 12, message: MESSAGE, hint: HINT}],
+      "let a; let a;": [{col: 11, message: MESSAGE, hint: HINT}],
+      "let a; const a = 0;": [{col: 13, message: MESSAGE, hint: HINT}],
+      "const a = 0; const a = 0;": [{col: 19, message: MESSAGE, hint: HINT}],
+      "if (test) { let a; let a; }": [{col: 23, message: MESSAGE, hint: HINT}],
+      "switch (test) { case 0: let a; let a; }": [{col: 35, message: MESSAGE, hint: HINT}],
+      "for (let a, a;;);": [{col: 12, message: MESSAGE, hint: HINT}],
+      "for (let [a, a] in xs);": [{col: 13, message: MESSAGE, hint: HINT}],
+      "function f() { let a; let a; }": [{col: 26, message: MESSAGE, hint: HINT}],
+      "function f(a) { let a; }": [{col: 20, message: MESSAGE, hint: HINT}],
+      "function f() { if (test) { let a; let a; } }": [{col: 38, message: MESSAGE, hint: HINT}],
+      "var a = 3; var a = 10; var a = 15;": [{col: 15, message: MESSAGE, hint: HINT}, {col: 27, message: MESSAGE, hint: HINT}],
+      "var a; var {a = 0, b: Object = 0} = {};": [{line: 1, col: 12, message: MESSAGE, hint: HINT}],
+      "var a; var {a = 0, b: globalThis = 0} = {};": [{line: 1, col: 12, message: MESSAGE, hint: HINT}],
     }
   }
+
+  #[test]
+  fn no_redeclare_builtin_globals() {
+    let rule = || {
+      NoRedeclare::with_options(NoRedeclareOptions {
+        builtin_globals: true,
+      })
+    };
+
+    assert_lint_ok! {
+      NoRedeclare,
+      "var Object = 0;",
+      "function Array() {}",
+    };
+    assert_lint_ok_with_rule(rule(), "function f() { var Object = 0; }");
+    assert_lint_ok_with_rule(rule(), "var foo = 0;");
+
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "var Object = 0;",
+      vec![(1, 4)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "let a; var {a = 0, b: Object = 0} = {};",
+      vec![(1, 12), (1, 22)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "function Array() {}",
+      vec![(1, 9)],
+    );
+  }
 }
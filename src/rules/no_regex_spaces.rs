@@ -32,6 +32,27 @@ impl LintRule for NoRegexSpaces {
     let mut visitor = NoRegexSpacesVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows multiple consecutive spaces in regular expressions
+
+Several spaces in a row are hard to count in a regular expression. Use a single
+space with a quantifier, like ` {3}`, instead so the number of spaces is
+explicit.
+
+### Invalid:
+```typescript
+const re = /foo   bar/;
+const re2 = new RegExp("foo   bar");
+```
+
+### Valid:
+```typescript
+const re = /foo {3}bar/;
+const re2 = new RegExp("foo {3}bar");
+```
+"#
+  }
 }
 
 struct NoRegexSpacesVisitor<'c> {
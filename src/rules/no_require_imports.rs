@@ -0,0 +1,155 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_ecmascript::ast::{
+  CallExpr, Expr, ExprOrSuper, Program, TsImportEqualsDecl, TsModuleRef,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct NoRequireImports;
+
+const CODE: &str = "no-require-imports";
+const MESSAGE: &str = "A `require()` style import is forbidden";
+const HINT: &str = "Use an ES module `import` instead";
+
+impl LintRule for NoRequireImports {
+  fn new() -> Box<Self> {
+    Box::new(NoRequireImports)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = NoRequireImportsVisitor::new(context);
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows CommonJS `require()` imports
+
+ES modules are the standard way to import other modules, and unlike
+`require()` calls they can be statically analyzed. This rule reports calls of
+the global `require` function as well as TypeScript's
+`import foo = require("foo")` syntax.
+
+### Invalid:
+```typescript
+const foo = require("./foo.js");
+require("./polyfill.js");
+import bar = require("./bar.js");
+```
+
+### Valid:
+```typescript
+import foo from "./foo.js";
+import "./polyfill.js";
+import * as bar from "./bar.js";
+```
+"#
+  }
+}
+
+struct NoRequireImportsVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoRequireImportsVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+/// Checks if the call calls the global `require` function.
+pub(crate) fn is_require_call(context: &Context, call_expr: &CallExpr) -> bool {
+  match &call_expr.callee {
+    ExprOrSuper::Expr(callee) => match &**callee {
+      Expr::Ident(ident) => {
+        ident.sym == *"require" && context.scope.var(&ident.to_id()).is_none()
+      }
+      _ => false,
+    },
+    ExprOrSuper::Super(_) => false,
+  }
+}
+
+impl<'c> VisitAll for NoRequireImportsVisitor<'c> {
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    if is_require_call(self.context, call_expr) {
+      self.context.add_diagnostic_with_hint(
+        call_expr.span,
+        CODE,
+        MESSAGE,
+        HINT,
+      );
+    }
+  }
+
+  fn visit_ts_import_equals_decl(
+    &mut self,
+    import_equals: &TsImportEqualsDecl,
+    _parent: &dyn Node,
+  ) {
+    if let TsModuleRef::TsExternalModuleRef(_) = import_equals.module_ref {
+      self.context.add_diagnostic_with_hint(
+        import_equals.span,
+        CODE,
+        MESSAGE,
+        HINT,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_require_imports_valid() {
+    assert_lint_ok! {
+      NoRequireImports,
+      r#"import foo from "foo";"#,
+      r#"import * as foo from "foo";"#,
+      r#"import "foo";"#,
+      r#"const foo = await import("foo");"#,
+      "import Foo = Bar.Baz;",
+      r#"foo.require("foo");"#,
+      r#"require.resolve("foo");"#,
+      r#"function foo(require) { require("foo"); }"#,
+      r#"const require = createRequire(import.meta.url); require("foo");"#,
+      r#"import { createRequire as require } from "module"; require("foo");"#,
+    };
+  }
+
+  #[test]
+  fn no_require_imports_invalid() {
+    assert_lint_err! {
+      NoRequireImports,
+      r#"const foo = require("foo");"#: [
+        { col: 12, message: MESSAGE, hint: HINT }
+      ],
+      r#"require("foo");"#: [{ col: 0, message: MESSAGE, hint: HINT }],
+      r#"const { bar } = require("foo");"#: [
+        { col: 16, message: MESSAGE, hint: HINT }
+      ],
+      r#"foo(require("foo").bar);"#: [{ col: 4, message: MESSAGE, hint: HINT }],
+      r#"function foo() { return require("foo"); }"#: [
+        { col: 24, message: MESSAGE, hint: HINT }
+      ],
+      r#"import foo = require("foo");"#: [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      r#"export import foo = require("foo");"#: [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      r#"namespace N { import foo = require("foo"); }"#: [
+        { col: 14, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+}
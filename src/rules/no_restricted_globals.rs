@@ -0,0 +1,258 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use std::collections::HashMap;
+use swc_ecmascript::ast::{Expr, Ident, MemberExpr, Pat, Program, Prop};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoRestrictedGlobals {
+  /// Restricted globals by name, with their custom message.
+  globals: HashMap<String, Option<String>>,
+}
+
+const CODE: &str = "no-restricted-globals";
+const HINT: &str =
+  "This global is restricted in this project, use a local binding instead";
+
+fn message(name: &str) -> String {
+  format!("Unexpected use of the global `{}`", name)
+}
+
+/// A restricted global, configured as its name, or as an object with a
+/// `name` and a `message`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum RestrictedGlobalConfig {
+  Name(String),
+  Detailed {
+    name: String,
+    message: Option<String>,
+  },
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoRestrictedGlobalsOptions {
+  /// Global variables which can't be used.
+  pub globals: Vec<RestrictedGlobalConfig>,
+}
+
+impl NoRestrictedGlobals {
+  pub fn with_options(options: NoRestrictedGlobalsOptions) -> Box<Self> {
+    let globals = options
+      .globals
+      .into_iter()
+      .map(|config| match config {
+        RestrictedGlobalConfig::Name(name) => (name, None),
+        RestrictedGlobalConfig::Detailed { name, message } => (name, message),
+      })
+      .collect();
+    Box::new(NoRestrictedGlobals { globals })
+  }
+}
+
+impl LintRule for NoRestrictedGlobals {
+  fn new() -> Box<Self> {
+    Self::with_options(NoRestrictedGlobalsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    if self.globals.is_empty() {
+      return;
+    }
+    let mut visitor = NoRestrictedGlobalsVisitor {
+      context,
+      globals: &self.globals,
+    };
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the use of the global variables configured in the `globals` option
+
+Some globals are easily used by mistake, like the browser's `event` global
+inside of an event handler which forgot to declare its parameter. This rule
+reports references to the globals listed in the `globals` option, either by
+name or as an object with a `name` and a `message` to report as the hint.
+Variables declared in the file with the same name are not reported.
+
+### Invalid:
+```typescript
+// With `{ "globals": [{ "name": "event", "message": "Use the handler parameter instead" }] }`
+button.addEventListener("click", () => {
+  console.log(event.target);
+});
+```
+
+### Valid:
+```typescript
+// With `{ "globals": [{ "name": "event", "message": "Use the handler parameter instead" }] }`
+button.addEventListener("click", (event) => {
+  console.log(event.target);
+});
+```
+"#
+  }
+}
+
+struct NoRestrictedGlobalsVisitor<'c, 'g> {
+  context: &'c mut Context,
+  globals: &'g HashMap<String, Option<String>>,
+}
+
+impl<'c, 'g> NoRestrictedGlobalsVisitor<'c, 'g> {
+  fn check(&mut self, ident: &Ident) {
+    if let Some(custom_message) = self.globals.get(&*ident.sym) {
+      if self.context.scope.var(&ident.to_id()).is_some() {
+        return;
+      }
+      let hint = custom_message.as_deref().unwrap_or(HINT);
+      self.context.add_diagnostic_with_hint(
+        ident.span,
+        CODE,
+        message(&ident.sym),
+        hint,
+      );
+    }
+  }
+}
+
+impl<'c, 'g> Visit for NoRestrictedGlobalsVisitor<'c, 'g> {
+  noop_visit_type!();
+
+  fn visit_member_expr(&mut self, member_expr: &MemberExpr, _: &dyn Node) {
+    member_expr.obj.visit_with(member_expr, self);
+    if member_expr.computed {
+      member_expr.prop.visit_with(member_expr, self);
+    }
+  }
+
+  fn visit_expr(&mut self, expr: &Expr, _: &dyn Node) {
+    if let Expr::Ident(ident) = expr {
+      self.check(ident);
+    } else {
+      expr.visit_children_with(self);
+    }
+  }
+
+  fn visit_prop(&mut self, prop: &Prop, _: &dyn Node) {
+    if let Prop::Shorthand(ident) = prop {
+      self.check(ident);
+    } else {
+      prop.visit_children_with(self);
+    }
+  }
+
+  fn visit_pat(&mut self, pat: &Pat, _: &dyn Node) {
+    if let Pat::Ident(ident) = pat {
+      self.check(ident);
+    } else {
+      pat.visit_children_with(self);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::lint;
+
+  fn rule() -> Box<NoRestrictedGlobals> {
+    NoRestrictedGlobals::with_options(
+      serde_json::from_str(
+        r#"{ "globals": ["fdescribe", { "name": "event", "message": "Use the handler parameter" }] }"#,
+      )
+      .unwrap(),
+    )
+  }
+
+  #[test]
+  fn no_restricted_globals_default() {
+    assert_lint_ok! {
+      NoRestrictedGlobals,
+      "event.target;",
+      "fdescribe();",
+    };
+  }
+
+  #[test]
+  fn no_restricted_globals_valid() {
+    for src in &[
+      "function onClick(event) { event.target; }",
+      "const f = (event) => event.target;",
+      "let event; event = 1;",
+      "import { event } from './event.ts'; event();",
+      "function fdescribe() {} fdescribe();",
+      "try {} catch (event) { event; }",
+      "window.event; a.fdescribe();",
+      "const a = { event: 1 }; a.event;",
+      "class A { event() {} }",
+      "describe(); xdescribe();",
+    ] {
+      assert!(lint(rule(), src).is_empty(), "{} should be valid", src);
+    }
+  }
+
+  #[test]
+  fn no_restricted_globals_invalid() {
+    let diagnostics = lint(
+      rule(),
+      r#"fdescribe("a", () => {});
+button.onclick = () => event.target;
+const a = { event };
+window[event];
+event = null;
+function f() { return typeof event; }"#,
+    );
+    let reported = diagnostics
+      .iter()
+      .map(|d| {
+        (
+          d.range.start.line,
+          d.range.start.col,
+          d.message.clone(),
+          d.hint.clone().unwrap(),
+        )
+      })
+      .collect::<Vec<_>>();
+    let event = |line, col| {
+      (
+        line,
+        col,
+        message("event"),
+        "Use the handler parameter".to_string(),
+      )
+    };
+    assert_eq!(
+      reported,
+      vec![
+        (1, 0, message("fdescribe"), HINT.to_string()),
+        event(2, 23),
+        event(3, 12),
+        event(4, 7),
+        event(5, 0),
+        event(6, 29),
+      ]
+    );
+  }
+
+  #[test]
+  fn no_restricted_globals_shadowed_in_other_scope() {
+    let diagnostics = lint(
+      rule(),
+      "function f(event) { return event; }\nfunction g() { return event; }",
+    );
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].range.start.line, 2);
+    assert_eq!(diagnostics[0].range.start.col, 22);
+  }
+}
@@ -0,0 +1,443 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use regex::Regex;
+use serde::Deserialize;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  ExportAll, ExportSpecifier, ImportDecl, ImportSpecifier, NamedExport,
+  Program, Str,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+
+pub struct NoRestrictedImports {
+  paths: Vec<RestrictedPath>,
+  /// Compiled patterns, with whether they are negated.
+  patterns: Vec<(Regex, bool)>,
+}
+
+const CODE: &str = "no-restricted-imports";
+const HINT: &str =
+  "This module is restricted in this project, import from a different module";
+
+fn path_message(src: &str) -> String {
+  format!("`{}` import is restricted from being used", src)
+}
+
+fn import_name_message(name: &str, src: &str) -> String {
+  format!(
+    "`{}` import from `{}` is restricted from being used",
+    name, src
+  )
+}
+
+fn pattern_message(src: &str) -> String {
+  format!(
+    "`{}` import is restricted from being used by a pattern",
+    src
+  )
+}
+
+/// A restricted module, configured as its specifier, or as an object with
+/// the specifier as `name`, a `message` and the restricted `importNames`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum RestrictedPathConfig {
+  Name(String),
+  Detailed {
+    name: String,
+    message: Option<String>,
+    #[serde(rename = "importNames")]
+    import_names: Option<Vec<String>>,
+  },
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoRestrictedImportsOptions {
+  /// Module specifiers which can't be imported from.
+  pub paths: Vec<RestrictedPathConfig>,
+  /// Glob patterns of module specifiers which can't be imported from. A
+  /// pattern starting with `!` allows specifiers matched by earlier patterns.
+  pub patterns: Vec<String>,
+}
+
+struct RestrictedPath {
+  name: String,
+  message: Option<String>,
+  /// Restricted exported names of the module, or `None` if the whole module
+  /// is restricted.
+  import_names: Option<Vec<String>>,
+}
+
+/// Converts a glob pattern to a regular expression matching the specifier.
+/// `**` matches any characters, `*` and `?` match any characters and any
+/// single character except `/`. Like in `.gitignore`, a pattern matching a
+/// leading part of the specifier also matches all of its subpaths.
+fn glob_to_regex(glob: &str) -> Option<Regex> {
+  let mut re = String::from("^");
+  let mut chars = glob.chars().peekable();
+  while let Some(c) = chars.next() {
+    match c {
+      '*' if chars.peek() == Some(&'*') => {
+        chars.next();
+        re.push_str(".*");
+      }
+      '*' => re.push_str("[^/]*"),
+      '?' => re.push_str("[^/]"),
+      c => re.push_str(&regex::escape(&c.to_string())),
+    }
+  }
+  re.push_str("(/.*)?$");
+  Regex::new(&re).ok()
+}
+
+impl NoRestrictedImports {
+  pub fn with_options(options: NoRestrictedImportsOptions) -> Box<Self> {
+    let paths = options
+      .paths
+      .into_iter()
+      .map(|config| match config {
+        RestrictedPathConfig::Name(name) => RestrictedPath {
+          name,
+          message: None,
+          import_names: None,
+        },
+        RestrictedPathConfig::Detailed {
+          name,
+          message,
+          import_names,
+        } => RestrictedPath {
+          name,
+          message,
+          import_names,
+        },
+      })
+      .collect();
+    let patterns = options
+      .patterns
+      .iter()
+      .filter_map(|pattern| match pattern.strip_prefix('!') {
+        Some(pattern) => glob_to_regex(pattern).map(|re| (re, true)),
+        None => glob_to_regex(pattern).map(|re| (re, false)),
+      })
+      .collect();
+    Box::new(NoRestrictedImports { paths, patterns })
+  }
+
+  fn is_restricted_by_pattern(&self, src: &str) -> bool {
+    self
+      .patterns
+      .iter()
+      .fold(false, |restricted, (re, negated)| {
+        if re.is_match(src) {
+          !negated
+        } else {
+          restricted
+        }
+      })
+  }
+}
+
+impl LintRule for NoRestrictedImports {
+  fn new() -> Box<Self> {
+    Self::with_options(NoRestrictedImportsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    if self.paths.is_empty() && self.patterns.is_empty() {
+      return;
+    }
+    let mut visitor = NoRestrictedImportsVisitor {
+      context,
+      rule: self,
+    };
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows imports from the modules configured in the `paths` and `patterns` options
+
+Projects can use this rule to ban modules which shouldn't be depended on, or
+to point to a replacement for them.
+
+Modules are listed in the `paths` option, either by their specifier or as an
+object with the specifier as `name`, a `message` to report as the hint, and
+`importNames` to only restrict some of the names exported by the module. The
+`patterns` option lists glob patterns of specifiers, where `*` matches within a
+path segment and `**` matches across segments. A pattern also restricts all
+subpaths of the specifiers it matches, so `internal/*` restricts
+`internal/a/b` as well. A pattern starting with `!` allows specifiers matched
+by earlier patterns. Re-exports from the modules are
+reported as well.
+
+### Invalid:
+```typescript
+// With `{ "paths": [{ "name": "lodash", "message": "Use lodash-es instead" }], "patterns": ["internal/*"] }`
+import { map } from "lodash";
+import { helper } from "internal/helpers";
+export * from "lodash";
+```
+
+### Valid:
+```typescript
+// With `{ "paths": [{ "name": "lodash", "message": "Use lodash-es instead" }], "patterns": ["internal/*"] }`
+import { map } from "lodash-es";
+import { helper } from "./internal/helpers";
+```
+"#
+  }
+}
+
+struct NoRestrictedImportsVisitor<'c, 'r> {
+  context: &'c mut Context,
+  rule: &'r NoRestrictedImports,
+}
+
+impl<'c, 'r> NoRestrictedImportsVisitor<'c, 'r> {
+  /// Checks an import or re-export of `src`. `names` are the imported names
+  /// with their spans, where `None` stands for all names of the module.
+  fn check(&mut self, span: Span, src: &Str, names: Vec<(Option<&str>, Span)>) {
+    let src = &*src.value;
+    for path in self.rule.paths.iter().filter(|path| path.name == src) {
+      let hint = path.message.as_deref().unwrap_or(HINT);
+      match &path.import_names {
+        None => {
+          self.context.add_diagnostic_with_hint(
+            span,
+            CODE,
+            path_message(src),
+            hint,
+          );
+        }
+        Some(import_names) => {
+          for (name, span) in &names {
+            match name {
+              Some(name) if !import_names.iter().any(|n| n == name) => {}
+              Some(name) => self.context.add_diagnostic_with_hint(
+                *span,
+                CODE,
+                import_name_message(name, src),
+                hint,
+              ),
+              None => self.context.add_diagnostic_with_hint(
+                *span,
+                CODE,
+                import_name_message(&import_names.join("`, `"), src),
+                hint,
+              ),
+            }
+          }
+        }
+      }
+    }
+
+    if self.rule.is_restricted_by_pattern(src) {
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        pattern_message(src),
+        HINT,
+      );
+    }
+  }
+}
+
+impl<'c, 'r> Visit for NoRestrictedImportsVisitor<'c, 'r> {
+  noop_visit_type!();
+
+  fn visit_import_decl(
+    &mut self,
+    import_decl: &ImportDecl,
+    _parent: &dyn Node,
+  ) {
+    let names = import_decl
+      .specifiers
+      .iter()
+      .map(|specifier| match specifier {
+        ImportSpecifier::Named(named) => (
+          Some(&*named.imported.as_ref().unwrap_or(&named.local).sym),
+          named.span,
+        ),
+        ImportSpecifier::Default(default) => (Some("default"), default.span),
+        ImportSpecifier::Namespace(namespace) => (None, namespace.span),
+      })
+      .collect();
+    self.check(import_decl.span, &import_decl.src, names);
+  }
+
+  fn visit_named_export(
+    &mut self,
+    named_export: &NamedExport,
+    _parent: &dyn Node,
+  ) {
+    if let Some(src) = &named_export.src {
+      let names = named_export
+        .specifiers
+        .iter()
+        .map(|specifier| match specifier {
+          ExportSpecifier::Named(named) => (Some(&*named.orig.sym), named.span),
+          ExportSpecifier::Default(default) => {
+            (Some("default"), default.exported.span)
+          }
+          ExportSpecifier::Namespace(namespace) => (None, namespace.span),
+        })
+        .collect();
+      self.check(named_export.span, src, names);
+    }
+  }
+
+  fn visit_export_all(&mut self, export_all: &ExportAll, _parent: &dyn Node) {
+    self.check(
+      export_all.span,
+      &export_all.src,
+      vec![(None, export_all.span)],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::lint;
+
+  fn rule(options: &str) -> Box<NoRestrictedImports> {
+    NoRestrictedImports::with_options(serde_json::from_str(options).unwrap())
+  }
+
+  #[test]
+  fn no_restricted_imports_default() {
+    assert_lint_ok! {
+      NoRestrictedImports,
+      "import { map } from 'lodash';",
+      "export * from 'internal/foo';",
+    };
+  }
+
+  #[test]
+  fn no_restricted_imports_paths() {
+    let rule = || {
+      rule(
+        r#"{ "paths": ["fs", { "name": "lodash", "message": "Use lodash-es instead" }] }"#,
+      )
+    };
+
+    let diagnostics = lint(
+      rule(),
+      r#"import { map } from "lodash";
+import * as fs from "fs";
+export { map } from "lodash";
+export * from "lodash";
+import "fs";"#,
+    );
+    let reported = diagnostics
+      .iter()
+      .map(|d| (d.range.start.line, d.message.clone(), d.hint.clone()))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      reported,
+      vec![
+        (
+          1,
+          path_message("lodash"),
+          Some("Use lodash-es instead".to_string())
+        ),
+        (2, path_message("fs"), Some(HINT.to_string())),
+        (
+          3,
+          path_message("lodash"),
+          Some("Use lodash-es instead".to_string())
+        ),
+        (
+          4,
+          path_message("lodash"),
+          Some("Use lodash-es instead".to_string())
+        ),
+        (5, path_message("fs"), Some(HINT.to_string())),
+      ]
+    );
+
+    assert!(lint(
+      rule(),
+      r#"import { map } from "lodash-es";
+import fs from "./fs";
+import { lodash } from "lodash/fp";"#
+    )
+    .is_empty());
+  }
+
+  #[test]
+  fn no_restricted_imports_import_names() {
+    let rule = rule(
+      r#"{ "paths": [{ "name": "mod", "importNames": ["foo", "default"], "message": "Don't" }] }"#,
+    );
+
+    let diagnostics = lint(
+      rule,
+      r#"import { foo, bar } from "mod";
+import { baz as foo } from "mod";
+import mod, { foo as qux } from "mod";
+import * as all from "mod";
+export { foo } from "mod";"#,
+    );
+    let reported = diagnostics
+      .iter()
+      .map(|d| (d.range.start.line, d.range.start.col, d.message.clone()))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      reported,
+      vec![
+        (1, 9, import_name_message("foo", "mod")),
+        (3, 7, import_name_message("default", "mod")),
+        (3, 14, import_name_message("foo", "mod")),
+        (4, 7, import_name_message("foo`, `default", "mod")),
+        (5, 9, import_name_message("foo", "mod")),
+      ]
+    );
+    assert_eq!(diagnostics[0].hint.as_deref(), Some("Don't"));
+  }
+
+  #[test]
+  fn no_restricted_imports_patterns() {
+    let rule =
+      || rule(r#"{ "patterns": ["internal/*", "lib/**", "!lib/public/**"] }"#);
+
+    let diagnostics = lint(
+      rule(),
+      r#"import a from "internal/a";
+import b from "lib/b/c";
+export * from "internal/b";
+import c from "internal/a/b";"#,
+    );
+    let reported = diagnostics
+      .iter()
+      .map(|d| (d.range.start.line, d.message.clone()))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      reported,
+      vec![
+        (1, pattern_message("internal/a")),
+        (2, pattern_message("lib/b/c")),
+        (3, pattern_message("internal/b")),
+        (4, pattern_message("internal/a/b")),
+      ]
+    );
+    assert_eq!(diagnostics[0].hint.as_deref(), Some(HINT));
+
+    assert!(lint(
+      rule(),
+      r#"import a from "./internal/a";
+import b from "lib/public/c";
+import c from "lib/public/c/d";
+import d from "internal";
+import e from "internals/a";"#
+    )
+    .is_empty());
+  }
+}
@@ -0,0 +1,246 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_ecmascript::ast::{Expr, ExprOrSuper, Lit, MemberExpr, Program};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct NoRestrictedProperties {
+  properties: Vec<RestrictedProperty>,
+}
+
+const CODE: &str = "no-restricted-properties";
+const HINT: &str = "This property is restricted in this project";
+
+fn message(object: Option<&str>, property: &str) -> String {
+  match object {
+    Some(object) => {
+      format!("`{}.{}` is restricted from being used", object, property)
+    }
+    None => format!("`{}` is restricted from being used", property),
+  }
+}
+
+/// A restricted property. A missing `object` matches properties of any
+/// object, and a missing `property` matches all properties of the object.
+#[derive(Clone, Debug, Deserialize)]
+pub struct RestrictedProperty {
+  pub object: Option<String>,
+  pub property: Option<String>,
+  pub message: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoRestrictedPropertiesOptions {
+  /// Properties which can't be accessed.
+  pub properties: Vec<RestrictedProperty>,
+}
+
+impl NoRestrictedProperties {
+  pub fn with_options(options: NoRestrictedPropertiesOptions) -> Box<Self> {
+    let properties = options
+      .properties
+      .into_iter()
+      .filter(|p| p.object.is_some() || p.property.is_some())
+      .collect();
+    Box::new(NoRestrictedProperties { properties })
+  }
+}
+
+impl LintRule for NoRestrictedProperties {
+  fn new() -> Box<Self> {
+    Self::with_options(NoRestrictedPropertiesOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    if self.properties.is_empty() {
+      return;
+    }
+    let mut visitor = NoRestrictedPropertiesVisitor {
+      context,
+      properties: &self.properties,
+    };
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows accessing the properties configured in the `properties` option
+
+Projects can use this rule to ban properties which are deprecated or easy to
+misuse. Each entry of the `properties` option has an `object` name, a
+`property` name and an optional `message` to report as the hint. Either the
+`object` or the `property` can be left out to match any object or any property.
+
+### Invalid:
+```typescript
+// With `{ "properties": [{ "object": "window", "property": "location" }, { "property": "__proto__" }] }`
+window.location.reload();
+const proto = foo.__proto__;
+const bar = foo["__proto__"];
+```
+
+### Valid:
+```typescript
+// With `{ "properties": [{ "object": "window", "property": "location" }, { "property": "__proto__" }] }`
+location.reload();
+const proto = Object.getPrototypeOf(foo);
+```
+"#
+  }
+}
+
+struct NoRestrictedPropertiesVisitor<'c, 'p> {
+  context: &'c mut Context,
+  properties: &'p [RestrictedProperty],
+}
+
+/// Returns the name of the accessed property, if it's statically known.
+fn property_name(member_expr: &MemberExpr) -> Option<&str> {
+  match &*member_expr.prop {
+    Expr::Ident(ident) if !member_expr.computed => Some(&ident.sym),
+    Expr::Lit(Lit::Str(s)) if member_expr.computed => Some(&s.value),
+    _ => None,
+  }
+}
+
+impl<'c, 'p> VisitAll for NoRestrictedPropertiesVisitor<'c, 'p> {
+  noop_visit_type!();
+
+  fn visit_member_expr(&mut self, member_expr: &MemberExpr, _: &dyn Node) {
+    let property = match property_name(member_expr) {
+      Some(property) => property,
+      None => return,
+    };
+    let object = match &member_expr.obj {
+      ExprOrSuper::Expr(expr) => match &**expr {
+        Expr::Ident(ident) => Some(&*ident.sym),
+        _ => None,
+      },
+      ExprOrSuper::Super(_) => None,
+    };
+
+    let restricted = self.properties.iter().find(|restricted| {
+      restricted
+        .object
+        .as_deref()
+        .map_or(true, |o| Some(o) == object)
+        && restricted
+          .property
+          .as_deref()
+          .map_or(true, |p| p == property)
+    });
+    if let Some(restricted) = restricted {
+      let object = restricted.object.as_ref().and(object);
+      let hint = restricted.message.as_deref().unwrap_or(HINT);
+      self.context.add_diagnostic_with_hint(
+        member_expr.span,
+        CODE,
+        message(object, property),
+        hint,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::lint;
+
+  fn rule() -> Box<NoRestrictedProperties> {
+    NoRestrictedProperties::with_options(
+      serde_json::from_str(
+        r#"{ "properties": [
+          { "object": "window", "property": "location", "message": "Use the router" },
+          { "property": "__proto__" },
+          { "object": "require" }
+        ] }"#,
+      )
+      .unwrap(),
+    )
+  }
+
+  #[test]
+  fn no_restricted_properties_default() {
+    assert_lint_ok! {
+      NoRestrictedProperties,
+      "window.location;",
+      "foo.__proto__;",
+    };
+  }
+
+  #[test]
+  fn no_restricted_properties_valid() {
+    for src in &[
+      "location.reload();",
+      "self.location;",
+      "window.locations;",
+      "window[location];",
+      "foo.proto;",
+      "foo[__proto__];",
+      "require('foo');",
+      "window.foo.location;",
+      "const location = { window: 1 }; location.window;",
+    ] {
+      assert!(lint(rule(), src).is_empty(), "{} should be valid", src);
+    }
+  }
+
+  #[test]
+  fn no_restricted_properties_invalid() {
+    let diagnostics = lint(
+      rule(),
+      r#"window.location.reload();
+const a = window["location"];
+foo.__proto__;
+foo.bar["__proto__"];
+require.resolve("a");
+window?.location;"#,
+    );
+    let reported = diagnostics
+      .iter()
+      .map(|d| {
+        (
+          d.range.start.line,
+          d.range.start.col,
+          d.message.clone(),
+          d.hint.clone().unwrap(),
+        )
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(
+      reported,
+      vec![
+        (
+          1,
+          0,
+          message(Some("window"), "location"),
+          "Use the router".to_string()
+        ),
+        (
+          2,
+          10,
+          message(Some("window"), "location"),
+          "Use the router".to_string()
+        ),
+        (3, 0, message(None, "__proto__"), HINT.to_string()),
+        (4, 0, message(None, "__proto__"), HINT.to_string()),
+        (5, 0, message(Some("require"), "resolve"), HINT.to_string()),
+        (
+          6,
+          0,
+          message(Some("window"), "location"),
+          "Use the router".to_string()
+        ),
+      ]
+    );
+  }
+}
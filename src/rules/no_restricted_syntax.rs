@@ -0,0 +1,340 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use std::collections::HashMap;
+use swc_common::Span;
+use swc_common::Spanned;
+use swc_ecmascript::ast::*;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct NoRestrictedSyntax {
+  /// Restricted node types by ESTree name, with their custom message.
+  selectors: HashMap<String, Option<String>>,
+}
+
+const CODE: &str = "no-restricted-syntax";
+const HINT: &str =
+  "This syntax is restricted in this project, rewrite the code without it";
+
+fn message(node_type: &str) -> String {
+  format!("Using `{}` is not allowed", node_type)
+}
+
+/// A restricted node type, configured as its ESTree name, or as an object
+/// with a `selector` and a custom `message`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum RestrictedSyntaxConfig {
+  Selector(String),
+  Detailed {
+    selector: String,
+    message: Option<String>,
+  },
+}
+
+/// Configured like in ESLint, as a bare array of restricted node types.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct NoRestrictedSyntaxOptions {
+  /// Node types to restrict. Only bare ESTree node type names, like
+  /// `"WithStatement"`, are supported as selectors.
+  pub selectors: Vec<RestrictedSyntaxConfig>,
+}
+
+impl NoRestrictedSyntax {
+  /// Selectors other than the supported node type names are ignored, with a
+  /// warning.
+  pub fn with_options(options: NoRestrictedSyntaxOptions) -> Box<Self> {
+    let selectors = options
+      .selectors
+      .into_iter()
+      .map(|config| match config {
+        RestrictedSyntaxConfig::Selector(selector) => (selector, None),
+        RestrictedSyntaxConfig::Detailed { selector, message } => {
+          (selector, message)
+        }
+      })
+      .filter(|(selector, _)| {
+        let is_supported = NODE_TYPES.contains(&selector.as_str());
+        if !is_supported {
+          warn!("Ignoring unsupported selector \"{}\" of {}", selector, CODE);
+        }
+        is_supported
+      })
+      .collect();
+    Box::new(NoRestrictedSyntax { selectors })
+  }
+}
+
+impl LintRule for NoRestrictedSyntax {
+  fn new() -> Box<Self> {
+    Self::with_options(NoRestrictedSyntaxOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    if self.selectors.is_empty() {
+      return;
+    }
+    let mut visitor = NoRestrictedSyntaxVisitor {
+      context,
+      selectors: &self.selectors,
+    };
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the configured syntax
+
+Some projects want to ban specific language features, like `with` statements
+or labels. This rule reports every node of the types listed in its options.
+Node types are given by their ESTree name, like `"WithStatement"` or
+`"ForInStatement"`, optionally as an object with a `selector` and a custom
+`message` to report instead of the default one.
+
+Only bare node type names are supported, other ESLint selectors are ignored
+with a warning.
+
+### Invalid:
+```typescript
+// With `["WithStatement", "LabeledStatement"]`
+with (foo) {
+  bar();
+}
+
+outer: for (const a of b) {
+  break outer;
+}
+```
+
+### Valid:
+```typescript
+// With `["WithStatement", "LabeledStatement"]`
+foo.bar();
+
+for (const a of b) {
+  break;
+}
+```
+"#
+  }
+}
+
+struct NoRestrictedSyntaxVisitor<'c, 's> {
+  context: &'c mut Context,
+  selectors: &'s HashMap<String, Option<String>>,
+}
+
+impl<'c, 's> NoRestrictedSyntaxVisitor<'c, 's> {
+  fn check(&mut self, node_type: &str, span: Span) {
+    if let Some(custom_message) = self.selectors.get(node_type) {
+      let message =
+        custom_message.clone().unwrap_or_else(|| message(node_type));
+      self
+        .context
+        .add_diagnostic_with_hint(span, CODE, message, HINT);
+    }
+  }
+}
+
+macro_rules! restricted_node_types {
+  ($($node:ident => $visit:ident => $node_type:literal,)*) => {
+    /// ESTree names of the node types which can be restricted.
+    const NODE_TYPES: &[&str] = &[
+      $($node_type,)*
+      "BinaryExpression",
+      "LogicalExpression",
+    ];
+
+    impl<'c, 's> VisitAll for NoRestrictedSyntaxVisitor<'c, 's> {
+      $(
+        fn $visit(&mut self, node: &$node, _parent: &dyn Node) {
+          self.check($node_type, node.span());
+        }
+      )*
+
+      fn visit_bin_expr(&mut self, node: &BinExpr, _parent: &dyn Node) {
+        let node_type = match node.op {
+          BinaryOp::LogicalAnd
+          | BinaryOp::LogicalOr
+          | BinaryOp::NullishCoalescing => "LogicalExpression",
+          _ => "BinaryExpression",
+        };
+        self.check(node_type, node.span);
+      }
+
+      fn visit_export_default_expr(
+        &mut self,
+        node: &ExportDefaultExpr,
+        _parent: &dyn Node,
+      ) {
+        self.check("ExportDefaultDeclaration", node.span);
+      }
+
+      fn visit_named_export(&mut self, node: &NamedExport, _parent: &dyn Node) {
+        self.check("ExportNamedDeclaration", node.span);
+      }
+    }
+  };
+}
+
+restricted_node_types! {
+  ArrayLit => visit_array_lit => "ArrayExpression",
+  ArrowExpr => visit_arrow_expr => "ArrowFunctionExpression",
+  AssignExpr => visit_assign_expr => "AssignmentExpression",
+  AwaitExpr => visit_await_expr => "AwaitExpression",
+  BlockStmt => visit_block_stmt => "BlockStatement",
+  BreakStmt => visit_break_stmt => "BreakStatement",
+  CallExpr => visit_call_expr => "CallExpression",
+  ClassDecl => visit_class_decl => "ClassDeclaration",
+  ClassExpr => visit_class_expr => "ClassExpression",
+  CondExpr => visit_cond_expr => "ConditionalExpression",
+  ContinueStmt => visit_continue_stmt => "ContinueStatement",
+  DebuggerStmt => visit_debugger_stmt => "DebuggerStatement",
+  DoWhileStmt => visit_do_while_stmt => "DoWhileStatement",
+  EmptyStmt => visit_empty_stmt => "EmptyStatement",
+  ExportAll => visit_export_all => "ExportAllDeclaration",
+  ExportDecl => visit_export_decl => "ExportNamedDeclaration",
+  ExportDefaultDecl => visit_export_default_decl => "ExportDefaultDeclaration",
+  ExprStmt => visit_expr_stmt => "ExpressionStatement",
+  FnDecl => visit_fn_decl => "FunctionDeclaration",
+  FnExpr => visit_fn_expr => "FunctionExpression",
+  ForInStmt => visit_for_in_stmt => "ForInStatement",
+  ForOfStmt => visit_for_of_stmt => "ForOfStatement",
+  ForStmt => visit_for_stmt => "ForStatement",
+  IfStmt => visit_if_stmt => "IfStatement",
+  ImportDecl => visit_import_decl => "ImportDeclaration",
+  LabeledStmt => visit_labeled_stmt => "LabeledStatement",
+  MemberExpr => visit_member_expr => "MemberExpression",
+  NewExpr => visit_new_expr => "NewExpression",
+  ObjectLit => visit_object_lit => "ObjectExpression",
+  OptChainExpr => visit_opt_chain_expr => "ChainExpression",
+  ReturnStmt => visit_return_stmt => "ReturnStatement",
+  SeqExpr => visit_seq_expr => "SequenceExpression",
+  SwitchStmt => visit_switch_stmt => "SwitchStatement",
+  TaggedTpl => visit_tagged_tpl => "TaggedTemplateExpression",
+  ThisExpr => visit_this_expr => "ThisExpression",
+  ThrowStmt => visit_throw_stmt => "ThrowStatement",
+  Tpl => visit_tpl => "TemplateLiteral",
+  TryStmt => visit_try_stmt => "TryStatement",
+  TsAsExpr => visit_ts_as_expr => "TSAsExpression",
+  TsEnumDecl => visit_ts_enum_decl => "TSEnumDeclaration",
+  TsInterfaceDecl => visit_ts_interface_decl => "TSInterfaceDeclaration",
+  TsModuleDecl => visit_ts_module_decl => "TSModuleDeclaration",
+  TsNonNullExpr => visit_ts_non_null_expr => "TSNonNullExpression",
+  TsTypeAliasDecl => visit_ts_type_alias_decl => "TSTypeAliasDeclaration",
+  UnaryExpr => visit_unary_expr => "UnaryExpression",
+  UpdateExpr => visit_update_expr => "UpdateExpression",
+  VarDecl => visit_var_decl => "VariableDeclaration",
+  WhileStmt => visit_while_stmt => "WhileStatement",
+  WithStmt => visit_with_stmt => "WithStatement",
+  YieldExpr => visit_yield_expr => "YieldExpression",
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::lint;
+
+  fn rule(selectors: Vec<RestrictedSyntaxConfig>) -> Box<NoRestrictedSyntax> {
+    NoRestrictedSyntax::with_options(NoRestrictedSyntaxOptions { selectors })
+  }
+
+  fn selector(selector: &str) -> RestrictedSyntaxConfig {
+    RestrictedSyntaxConfig::Selector(selector.to_string())
+  }
+
+  #[test]
+  fn no_restricted_syntax_default() {
+    assert_lint_ok! {
+      NoRestrictedSyntax,
+      "with (a) { b(); }",
+      "a: for (;;) { break a; }",
+    };
+  }
+
+  #[test]
+  fn no_restricted_syntax_with_statement() {
+    let rule = || rule(vec![selector("WithStatement")]);
+
+    let diagnostics = lint(rule(), "foo();\nwith (a) { with (b) {} }");
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].range.start.line, 2);
+    assert_eq!(diagnostics[0].range.start.col, 0);
+    assert_eq!(diagnostics[0].message, message("WithStatement"));
+    assert_eq!(diagnostics[0].hint.as_deref(), Some(HINT));
+    assert_eq!(diagnostics[1].range.start.col, 11);
+
+    assert!(lint(rule(), "a.b(); for (const x in y) {}").is_empty());
+  }
+
+  #[test]
+  fn no_restricted_syntax_labeled_statement() {
+    let rule = rule(vec![RestrictedSyntaxConfig::Detailed {
+      selector: "LabeledStatement".to_string(),
+      message: Some("Labels are not allowed".to_string()),
+    }]);
+
+    let diagnostics = lint(
+      rule,
+      "outer: for (;;) { inner: while (a) { break outer; } }",
+    );
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].message, "Labels are not allowed");
+    assert_eq!(diagnostics[0].range.start.col, 0);
+    assert_eq!(diagnostics[1].range.start.col, 18);
+  }
+
+  #[test]
+  fn no_restricted_syntax_node_types() {
+    let rule = || {
+      rule(vec![
+        selector("LogicalExpression"),
+        selector("ForInStatement"),
+        selector("TSEnumDeclaration"),
+        selector("WithStatement :has(Identifier)"),
+      ])
+    };
+
+    let diagnostics =
+      lint(rule(), "a && b; a + b; for (const x in y) {} enum E {}");
+    let messages = diagnostics
+      .iter()
+      .map(|diagnostic| diagnostic.message.as_str())
+      .collect::<Vec<_>>();
+    assert_eq!(
+      messages,
+      vec![
+        "Using `LogicalExpression` is not allowed",
+        "Using `ForInStatement` is not allowed",
+        "Using `TSEnumDeclaration` is not allowed",
+      ]
+    );
+
+    assert!(lint(rule(), "with (a) {}").is_empty());
+    assert!(!rule()
+      .selectors
+      .contains_key("WithStatement :has(Identifier)"));
+  }
+
+  #[test]
+  fn no_restricted_syntax_options() {
+    let options: NoRestrictedSyntaxOptions = serde_json::from_str(
+      r#"["WithStatement", { "selector": "DebuggerStatement", "message": "No debugging" }]"#,
+    )
+    .unwrap();
+    let diagnostics = lint(
+      NoRestrictedSyntax::with_options(options),
+      "debugger; with (a) {}",
+    );
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].message, "No debugging");
+    assert_eq!(diagnostics[1].message, message("WithStatement"));
+  }
+}
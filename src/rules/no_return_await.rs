@@ -0,0 +1,264 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_ecmascript::ast::{
+  ArrowExpr, BlockStmtOrExpr, Expr, Function, Program, ReturnStmt, TryStmt,
+};
+use swc_ecmascript::visit::{noop_visit_type, Node, Visit, VisitWith};
+
+pub struct NoReturnAwait;
+
+const CODE: &str = "no-return-await";
+const MESSAGE: &str = "Redundant use of `await` on a return value";
+const HINT: &str = "Remove `await`";
+
+impl LintRule for NoReturnAwait {
+  fn new() -> Box<Self> {
+    Box::new(NoReturnAwait)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = NoReturnAwaitVisitor::new(context);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows unnecessary `return await`
+
+The return value of an async function is always wrapped in a promise, which the
+caller has to await anyway, so awaiting a promise just to return its value only
+delays the function. Inside a `try` block, and inside a `catch` block followed
+by `finally`, `return await` is allowed, because there it changes which errors
+are caught and when the `finally` block is run.
+
+### Invalid:
+```typescript
+async function foo() {
+  return await bar();
+}
+
+const baz = async () => await bar();
+```
+
+### Valid:
+```typescript
+async function foo() {
+  return bar();
+}
+
+async function baz() {
+  try {
+    return await bar();
+  } catch (e) {
+    handleError(e);
+  }
+}
+
+async function qux() {
+  const x = await bar();
+  return x;
+}
+```
+"#
+  }
+}
+
+struct NoReturnAwaitVisitor<'c> {
+  context: &'c mut Context,
+  /// Whether errors thrown by the returned promise are handled in the
+  /// innermost enclosing function, by a `try` or `finally` block.
+  in_try: bool,
+}
+
+impl<'c> NoReturnAwaitVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self {
+      context,
+      in_try: false,
+    }
+  }
+
+  fn with_in_try(&mut self, in_try: bool, visit: impl FnOnce(&mut Self)) {
+    let outer_in_try = self.in_try;
+    self.in_try = in_try;
+    visit(self);
+    self.in_try = outer_in_try;
+  }
+
+  /// Reports awaits whose value is returned directly by `expr`.
+  fn check_returned_expr(&mut self, expr: &Expr) {
+    if self.in_try {
+      return;
+    }
+    match expr {
+      Expr::Await(await_expr) => {
+        self.context.add_diagnostic_with_hint(
+          await_expr.span,
+          CODE,
+          MESSAGE,
+          HINT,
+        );
+      }
+      Expr::Paren(paren_expr) => self.check_returned_expr(&paren_expr.expr),
+      Expr::Cond(cond_expr) => {
+        self.check_returned_expr(&cond_expr.cons);
+        self.check_returned_expr(&cond_expr.alt);
+      }
+      Expr::Seq(seq_expr) => {
+        if let Some(last) = seq_expr.exprs.last() {
+          self.check_returned_expr(last);
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+impl<'c> Visit for NoReturnAwaitVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
+    self.with_in_try(false, |visitor| {
+      function.visit_children_with(visitor);
+    });
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    self.with_in_try(false, |visitor| {
+      if let BlockStmtOrExpr::Expr(expr) = &arrow_expr.body {
+        visitor.check_returned_expr(expr);
+      }
+      arrow_expr.visit_children_with(visitor);
+    });
+  }
+
+  fn visit_try_stmt(&mut self, try_stmt: &TryStmt, _parent: &dyn Node) {
+    self.with_in_try(true, |visitor| {
+      try_stmt.block.visit_with(try_stmt, visitor);
+    });
+    if let Some(handler) = &try_stmt.handler {
+      // Errors from a `catch` block still have to be thrown before the
+      // `finally` block runs.
+      let in_try = self.in_try || try_stmt.finalizer.is_some();
+      self.with_in_try(in_try, |visitor| {
+        handler.visit_with(try_stmt, visitor);
+      });
+    }
+    if let Some(finalizer) = &try_stmt.finalizer {
+      finalizer.visit_with(try_stmt, self);
+    }
+  }
+
+  fn visit_return_stmt(
+    &mut self,
+    return_stmt: &ReturnStmt,
+    _parent: &dyn Node,
+  ) {
+    if let Some(arg) = &return_stmt.arg {
+      self.check_returned_expr(arg);
+    }
+    return_stmt.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_return_await_valid() {
+    assert_lint_ok! {
+      NoReturnAwait,
+      "async function foo() { return bar(); }",
+      "async function foo() { const x = await bar(); return x; }",
+      "async function foo() { await bar(); return; }",
+      "async function foo() { return bar(await baz()); }",
+      "async function foo() { return (await bar()).baz; }",
+      "async function foo() { return await bar() + 1; }",
+      "const foo = async () => bar();",
+      "const foo = async () => (await bar()).baz;",
+      "const foo = async () => { await bar(); };",
+
+      // errors are handled in the function
+      "async function foo() { try { return await bar(); } catch (e) {} }",
+      "async function foo() { try { return await bar(); } finally {} }",
+      "async function foo() { try {} catch (e) { return await bar(); } finally {} }",
+      r#"
+async function foo() {
+  try {
+    if (a) {
+      return await bar();
+    }
+  } catch (e) {
+    handle(e);
+  }
+}
+      "#,
+      r#"
+async function foo() {
+  try {
+    try {} finally {
+      return await bar();
+    }
+  } catch (e) {}
+}
+      "#,
+    };
+  }
+
+  #[test]
+  fn no_return_await_invalid() {
+    assert_lint_err! {
+      NoReturnAwait,
+      "async function foo() { return await bar(); }": [
+        { col: 30, message: MESSAGE, hint: HINT },
+      ],
+      "async function foo() { return (await bar()); }": [
+        { col: 31, message: MESSAGE, hint: HINT },
+      ],
+      "async function foo() { return a ? await b() : await c(); }": [
+        { col: 34, message: MESSAGE, hint: HINT },
+        { col: 46, message: MESSAGE, hint: HINT },
+      ],
+      "async function foo() { return a(), await b(); }": [
+        { col: 35, message: MESSAGE, hint: HINT },
+      ],
+      "const foo = async () => await bar();": [
+        { col: 24, message: MESSAGE, hint: HINT },
+      ],
+      "class A { async foo() { return await bar(); } }": [
+        { col: 31, message: MESSAGE, hint: HINT },
+      ],
+
+      // only the try block handles errors of the returned promise
+      "async function foo() { try {} catch (e) { return await bar(); } }": [
+        { col: 49, message: MESSAGE, hint: HINT },
+      ],
+      "async function foo() { try {} finally { return await bar(); } }": [
+        { col: 47, message: MESSAGE, hint: HINT },
+      ],
+      "async function foo() { try { bar(); } catch (e) {} return await baz(); }": [
+        { col: 58, message: MESSAGE, hint: HINT },
+      ],
+      // a nested function returns its own promise
+      "async function foo() { try { return async () => await bar(); } catch (e) {} }": [
+        { col: 48, message: MESSAGE, hint: HINT },
+      ],
+      r#"
+async function foo() {
+  try {
+    async function bar() {
+      return await baz();
+    }
+  } catch (e) {}
+}
+      "#: [
+        { line: 5, col: 13, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
@@ -43,6 +43,28 @@ impl LintRule for NoSelfAssign {
     let mut visitor = NoSelfAssignVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows assigning a variable to itself
+
+Self assignments have no effect and are usually left over from refactoring or
+the result of a typo.
+
+### Invalid:
+```typescript
+a = a;
+[a, b] = [a, b];
+({ a } = { a });
+```
+
+### Valid:
+```typescript
+a = b;
+[a, b] = [b, a];
+({ a } = { a: b });
+```
+"#
+  }
 }
 
 struct NoSelfAssignVisitor<'c> {
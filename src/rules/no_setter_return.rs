@@ -1,18 +1,23 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
-use swc_ecmascript::ast::BlockStmt;
-use swc_ecmascript::ast::Class;
-use swc_ecmascript::ast::ClassMember;
-use swc_ecmascript::ast::MethodKind;
-use swc_ecmascript::ast::SetterProp;
-use swc_ecmascript::ast::Stmt;
+use swc_common::Spanned;
+use swc_ecmascript::ast::{
+  ArrowExpr, BlockStmtOrExpr, CallExpr, ClassMethod, Constructor, Expr,
+  ExprOrSpread, ExprOrSuper, Function, GetterProp, MethodKind, PrivateMethod,
+  Prop, PropName, PropOrSpread, ReturnStmt, SetterProp,
+};
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
-use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::{Visit, VisitWith};
 
 pub struct NoSetterReturn;
 
+const CODE: &str = "no-setter-return";
+const MESSAGE: &str = "Setter cannot return a value";
+const HINT: &str =
+  "Remove the returned value, as the return value of a setter is ignored";
+
 impl LintRule for NoSetterReturn {
   fn new() -> Box<Self> {
     Box::new(NoSetterReturn)
@@ -23,7 +28,7 @@ impl LintRule for NoSetterReturn {
   }
 
   fn code(&self) -> &'static str {
-    "no-setter-return"
+    CODE
   }
 
   fn lint_program(
@@ -34,6 +39,50 @@ impl LintRule for NoSetterReturn {
     let mut visitor = NoSetterReturnVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows returning values from setters
+
+The value returned from a setter is ignored, so returning a value from a setter
+is either useless or a mistake. A bare `return;` to exit the setter early is
+allowed. Setters of object literals, classes and property descriptors passed to
+`Object.defineProperty`, `Object.defineProperties`, `Object.create` and
+`Reflect.defineProperty` are checked.
+
+### Invalid:
+```typescript
+const a = {
+  set foo(x) {
+    return "something";
+  },
+};
+
+class B {
+  set foo(x) {
+    return 42;
+  }
+}
+
+Object.defineProperty(c, "foo", {
+  set(x) {
+    return x;
+  },
+});
+```
+
+### Valid:
+```typescript
+const a = {
+  set foo(x) {
+    if (!x) {
+      return;
+    }
+    this._foo = x;
+  },
+};
+```
+"#
+  }
 }
 
 struct NoSetterReturnVisitor<'c> {
@@ -45,44 +94,135 @@ impl<'c> NoSetterReturnVisitor<'c> {
     Self { context }
   }
 
-  fn check_block_stmt(&mut self, block_stmt: &BlockStmt) {
-    for stmt in &block_stmt.stmts {
-      if let Stmt::Return(return_stmt) = stmt {
-        if return_stmt.arg.is_some() {
-          self.context.add_diagnostic(
-            return_stmt.span,
-            "no-setter-return",
-            "Setter cannot return a value",
-          );
+  fn check_setter(&mut self, function: &Function) {
+    if let Some(body) = &function.body {
+      body.visit_children_with(&mut ReturnVisitor {
+        context: self.context,
+      });
+    }
+  }
+
+  /// Checks the `set` function of a property descriptor like
+  /// `{ set(value) {} }`.
+  fn check_descriptor(&mut self, expr: &Expr) {
+    let object_lit = match expr {
+      Expr::Object(object_lit) => object_lit,
+      _ => return,
+    };
+    for prop in &object_lit.props {
+      let prop = match prop {
+        PropOrSpread::Prop(prop) => &**prop,
+        PropOrSpread::Spread(_) => continue,
+      };
+      match prop {
+        Prop::Method(method_prop) if is_set_key(&method_prop.key) => {
+          self.check_setter(&method_prop.function);
+        }
+        Prop::KeyValue(key_value) if is_set_key(&key_value.key) => {
+          match &*key_value.value {
+            Expr::Fn(fn_expr) => self.check_setter(&fn_expr.function),
+            Expr::Arrow(arrow_expr) => self.check_arrow_setter(arrow_expr),
+            _ => {}
+          }
         }
+        _ => {}
       }
     }
   }
-}
 
-impl<'c> Visit for NoSetterReturnVisitor<'c> {
-  noop_visit_type!();
+  fn check_arrow_setter(&mut self, arrow_expr: &ArrowExpr) {
+    match &arrow_expr.body {
+      BlockStmtOrExpr::BlockStmt(body) => {
+        body.visit_children_with(&mut ReturnVisitor {
+          context: self.context,
+        });
+      }
+      // `set: (value) => value` implicitly returns the value.
+      BlockStmtOrExpr::Expr(expr) => {
+        self
+          .context
+          .add_diagnostic_with_hint(expr.span(), CODE, MESSAGE, HINT);
+      }
+    }
+  }
 
-  fn visit_class(&mut self, class: &Class, _parent: &dyn Node) {
-    for member in &class.body {
-      match member {
-        ClassMember::Method(class_method) => {
-          if class_method.kind == MethodKind::Setter {
-            if let Some(block_stmt) = &class_method.function.body {
-              self.check_block_stmt(block_stmt);
-            }
-          }
+  /// Checks the descriptors of calls like `Object.defineProperty(obj, key,
+  /// descriptor)` or `Object.defineProperties(obj, descriptors)`.
+  fn check_call(&mut self, call_expr: &CallExpr) {
+    let member_expr = match &call_expr.callee {
+      ExprOrSuper::Expr(expr) => match &**expr {
+        Expr::Member(member_expr) if !member_expr.computed => member_expr,
+        _ => return,
+      },
+      ExprOrSuper::Super(_) => return,
+    };
+    let (obj, prop) = match (&member_expr.obj, &*member_expr.prop) {
+      (ExprOrSuper::Expr(obj), Expr::Ident(prop)) => match &**obj {
+        Expr::Ident(obj) => (obj.sym.as_ref(), prop.sym.as_ref()),
+        _ => return,
+      },
+      _ => return,
+    };
+    let arg = |index: usize| {
+      call_expr
+        .args
+        .get(index)
+        .filter(|arg| arg.spread.is_none())
+        .map(|arg: &ExprOrSpread| &*arg.expr)
+    };
+    match (obj, prop) {
+      ("Object", "defineProperty") | ("Reflect", "defineProperty") => {
+        if let Some(descriptor) = arg(2) {
+          self.check_descriptor(descriptor);
         }
-        ClassMember::PrivateMethod(private_method) => {
-          if private_method.kind == MethodKind::Setter {
-            if let Some(block_stmt) = &private_method.function.body {
-              self.check_block_stmt(block_stmt);
+      }
+      ("Object", "defineProperties") | ("Object", "create") => {
+        if let Some(Expr::Object(descriptors)) = arg(1) {
+          for prop in &descriptors.props {
+            if let PropOrSpread::Prop(prop) = prop {
+              if let Prop::KeyValue(key_value) = &**prop {
+                self.check_descriptor(&key_value.value);
+              }
             }
           }
         }
-        _ => {}
       }
+      _ => {}
+    }
+  }
+}
+
+fn is_set_key(key: &PropName) -> bool {
+  match key {
+    PropName::Ident(ident) => &*ident.sym == "set",
+    PropName::Str(str_) => &*str_.value == "set",
+    _ => false,
+  }
+}
+
+impl<'c> Visit for NoSetterReturnVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_class_method(
+    &mut self,
+    class_method: &ClassMethod,
+    _parent: &dyn Node,
+  ) {
+    if class_method.kind == MethodKind::Setter {
+      self.check_setter(&class_method.function);
+    }
+    class_method.visit_children_with(self);
+  }
+
+  fn visit_private_method(
+    &mut self,
+    private_method: &PrivateMethod,
+    _parent: &dyn Node,
+  ) {
+    if private_method.kind == MethodKind::Setter {
+      self.check_setter(&private_method.function);
     }
+    private_method.visit_children_with(self);
   }
 
   fn visit_setter_prop(
@@ -90,8 +230,67 @@ impl<'c> Visit for NoSetterReturnVisitor<'c> {
     setter_prop: &SetterProp,
     _parent: &dyn Node,
   ) {
-    if let Some(block_stmt) = &setter_prop.body {
-      self.check_block_stmt(block_stmt);
+    if let Some(body) = &setter_prop.body {
+      body.visit_children_with(&mut ReturnVisitor {
+        context: self.context,
+      });
+    }
+    setter_prop.visit_children_with(self);
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    self.check_call(call_expr);
+    call_expr.visit_children_with(self);
+  }
+}
+
+/// Reports return statements with a value, except for those of nested
+/// functions.
+struct ReturnVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> Visit for ReturnVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, _function: &Function, _parent: &dyn Node) {}
+
+  fn visit_arrow_expr(&mut self, _arrow_expr: &ArrowExpr, _parent: &dyn Node) {}
+
+  // Accessors of object literals and constructors aren't `Function`s.
+  fn visit_getter_prop(
+    &mut self,
+    _getter_prop: &GetterProp,
+    _parent: &dyn Node,
+  ) {
+  }
+
+  fn visit_setter_prop(
+    &mut self,
+    _setter_prop: &SetterProp,
+    _parent: &dyn Node,
+  ) {
+  }
+
+  fn visit_constructor(
+    &mut self,
+    _constructor: &Constructor,
+    _parent: &dyn Node,
+  ) {
+  }
+
+  fn visit_return_stmt(
+    &mut self,
+    return_stmt: &ReturnStmt,
+    _parent: &dyn Node,
+  ) {
+    if return_stmt.arg.is_some() {
+      self.context.add_diagnostic_with_hint(
+        return_stmt.span,
+        CODE,
+        MESSAGE,
+        HINT,
+      );
     }
   }
 }
@@ -101,6 +300,88 @@ mod tests {
   use super::*;
   use crate::test_util::*;
 
+  #[test]
+  fn no_setter_return_valid() {
+    assert_lint_ok! {
+      NoSetterReturn,
+      "const a = { set foo(x) { this._foo = x; } };",
+      "const a = { set foo(x) { if (!x) { return; } this._foo = x; } };",
+      "const a = { get foo() { return 1; } };",
+      "const a = { foo(x) { return x; } };",
+      "class A { set foo(x) { this._foo = x; } }",
+      "class A { get foo() { return 1; } foo() { return 1; } }",
+      "class A { static set foo(x) { return; } }",
+      // nested functions
+      "const a = { set foo(x) { const f = () => { return x; }; } };",
+      "class A { set foo(x) { this.bar(function() { return x; }); } }",
+      "class A { set foo(x) { const b = { bar() { return x; } }; } }",
+      "class A { set foo(x) { const b = { get bar() { return x; } }; } }",
+      "class A { set foo(x) { class B { constructor() { return {}; } } } }",
+      "class A { set foo(x) { this.bar((y) => y); } }",
+      // property descriptors
+      "Object.defineProperty(a, 'foo', { set(x) { this._foo = x; } });",
+      "Object.defineProperty(a, 'foo', { get() { return 1; } });",
+      "Object.defineProperty(a, 'foo', { set: (x) => { a._foo = x; } });",
+      "Object.defineProperty(a, 'foo', { value(x) { return x; } });",
+      "foo.defineProperty(a, 'foo', { set(x) { return x; } });",
+      "Object.defineProperties(a, { foo: { set(x) { return; } } });",
+    };
+  }
+
+  #[test]
+  fn no_setter_return_invalid_nested() {
+    assert_lint_err! {
+      NoSetterReturn,
+      "const a = { set foo(x) { if (x) { return x; } } };": [
+        { col: 34, message: MESSAGE, hint: HINT },
+      ],
+      "class A { set foo(x) { for (;;) { return 1; } } }": [
+        { col: 34, message: MESSAGE, hint: HINT },
+      ],
+      "class A { static set foo(x) { return x; } }": [
+        { col: 30, message: MESSAGE, hint: HINT },
+      ],
+      "class A { set #foo(x) { return x; } }": [
+        { col: 24, message: MESSAGE, hint: HINT },
+      ],
+      "const a = class { set foo(x) { return x; } };": [
+        { col: 31, message: MESSAGE, hint: HINT },
+      ],
+      // setters nested in setters and other functions
+      "const a = { set foo(x) { const b = { set bar(y) { return y; } }; } };": [
+        { col: 50, message: MESSAGE, hint: HINT },
+      ],
+      "function foo() { class A { set bar(x) { return x; } } }": [
+        { col: 40, message: MESSAGE, hint: HINT },
+      ],
+      "class A { foo() { return { set bar(x) { return x; } }; } }": [
+        { col: 40, message: MESSAGE, hint: HINT },
+      ],
+      // property descriptors
+      "Object.defineProperty(a, 'foo', { set(x) { return x; } });": [
+        { col: 43, message: MESSAGE, hint: HINT },
+      ],
+      "Object.defineProperty(a, 'foo', { set: function(x) { return x; } });": [
+        { col: 53, message: MESSAGE, hint: HINT },
+      ],
+      "Object.defineProperty(a, 'foo', { set: (x) => { return x; } });": [
+        { col: 48, message: MESSAGE, hint: HINT },
+      ],
+      "Object.defineProperty(a, 'foo', { set: (x) => x });": [
+        { col: 46, message: MESSAGE, hint: HINT },
+      ],
+      "Reflect.defineProperty(a, 'foo', { 'set'(x) { return x; } });": [
+        { col: 46, message: MESSAGE, hint: HINT },
+      ],
+      "Object.defineProperties(a, { foo: { set(x) { return x; } } });": [
+        { col: 45, message: MESSAGE, hint: HINT },
+      ],
+      "Object.create(null, { foo: { set(x) { return x; } } });": [
+        { col: 38, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+
   #[test]
   fn no_setter_return_invalid() {
     assert_lint_err::<NoSetterReturn>(
@@ -29,6 +29,31 @@ impl LintRule for NoShadowRestrictedNames {
   fn code(&self) -> &'static str {
     "no-shadow-restricted-names"
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows shadowing of restricted names
+
+`undefined`, `NaN`, `Infinity`, `arguments` and `eval` are global values or
+keywords which code expects to have their usual meaning. Declaring variables,
+functions, parameters or catch clause bindings with these names shadows them,
+which is confusing at best.
+
+### Invalid:
+```typescript
+const undefined = "foo";
+function NaN() {}
+function foo(Infinity) {}
+try {} catch (eval) {}
+```
+
+### Valid:
+```typescript
+let foo = undefined;
+function bar(baz) {}
+try {} catch (e) {}
+```
+"#
+  }
 }
 
 struct NoShadowRestrictedNamesVisitor<'c> {
@@ -24,6 +24,27 @@ impl LintRule for NoSparseArrays {
     let mut visitor = NoSparseArraysVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows sparse arrays
+
+Array literals with empty slots, like `[1, , 3]`, create sparse arrays whose
+holes behave differently from `undefined` elements in many array methods. The
+extra commas are also easily overlooked or mistaken for a typo.
+
+### Invalid:
+```typescript
+const items = ["red", , "blue"];
+const colors = [, "green"];
+```
+
+### Valid:
+```typescript
+const items = ["red", "blue"];
+const colors = [undefined, "green"];
+```
+"#
+  }
 }
 
 struct NoSparseArraysVisitor<'c> {
@@ -0,0 +1,103 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::combined_visitor::{run_node_rule, AstNode, NodeKind, NodeRule};
+use swc_ecmascript::ast::{CondExpr, Program};
+
+pub struct NoTernary;
+
+const CODE: &str = "no-ternary";
+const MESSAGE: &str = "Ternary expressions are not allowed";
+const HINT: &str = "Use an `if`/`else` statement instead";
+
+impl LintRule for NoTernary {
+  fn new() -> Box<Self> {
+    Box::new(NoTernary)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    run_node_rule(self, context, program);
+  }
+
+  fn node_rule(&self) -> Option<&dyn NodeRule> {
+    Some(self)
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows ternary expressions
+
+Some code bases consider conditional expressions harder to read than the
+equivalent `if`/`else` statements, especially when they are nested. This rule
+reports every use of the ternary operator.
+
+### Invalid:
+```typescript
+const foo = isBar ? baz : qux;
+```
+
+### Valid:
+```typescript
+let foo;
+if (isBar) {
+  foo = baz;
+} else {
+  foo = qux;
+}
+```
+"#
+  }
+}
+
+impl NodeRule for NoTernary {
+  fn node_kinds(&self) -> &'static [NodeKind] {
+    &[NodeKind::CondExpr]
+  }
+
+  fn check_node(&self, context: &mut Context, node: AstNode) {
+    if let AstNode::CondExpr(node) = node {
+      check_cond_expr(context, node);
+    }
+  }
+}
+
+fn check_cond_expr(context: &mut Context, cond_expr: &CondExpr) {
+  context.add_diagnostic_with_hint(cond_expr.span, CODE, MESSAGE, HINT);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_ternary_valid() {
+    assert_lint_ok! {
+      NoTernary,
+      "if (a) { b(); } else { c(); }",
+      "const foo = a || b;",
+      "const foo = a ?? b;",
+      "type A<T> = T extends string ? 1 : 2;",
+    };
+  }
+
+  #[test]
+  fn no_ternary_invalid() {
+    assert_lint_err! {
+      NoTernary,
+      "const foo = a ? b : c;": [{ col: 12, message: MESSAGE, hint: HINT }],
+      "foo = a ? b : c;": [{ col: 6, message: MESSAGE, hint: HINT }],
+      "foo(a ? b : c);": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "foo(1, a ? b : c, 2);": [{ col: 7, message: MESSAGE, hint: HINT }],
+      "const foo = a ? b : c ? d : e;": [
+        { col: 12, message: MESSAGE, hint: HINT },
+        { col: 20, message: MESSAGE, hint: HINT },
+      ],
+      "function foo() { return a ? b : c; }": [
+        { col: 24, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+}
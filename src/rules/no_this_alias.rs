@@ -1,16 +1,50 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
-use swc_ecmascript::ast::{ArrowExpr, Expr, Function, Pat, VarDecl};
+use serde::Deserialize;
+use swc_common::Span;
+use swc_ecmascript::ast::{AssignExpr, Expr, Pat, PatOrExpr, VarDecl};
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
 
-pub struct NoThisAlias;
+pub struct NoThisAlias {
+  options: NoThisAliasOptions,
+}
+
+const CODE: &str = "no-this-alias";
+const MESSAGE: &str = "assign `this` to declare a value is not allowed";
+const HINT: &str =
+  "Use an arrow function, which keeps the `this` of the enclosing scope";
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoThisAliasOptions {
+  /// Whether destructuring `this`, like `const { foo } = this`, is allowed.
+  pub allow_destructuring: bool,
+  /// Names of variables which `this` can be assigned to.
+  pub allowed_names: Vec<String>,
+}
+
+impl Default for NoThisAliasOptions {
+  fn default() -> Self {
+    Self {
+      allow_destructuring: true,
+      allowed_names: vec![],
+    }
+  }
+}
+
+impl NoThisAlias {
+  pub fn with_options(options: NoThisAliasOptions) -> Box<Self> {
+    Box::new(NoThisAlias { options })
+  }
+}
 
 impl LintRule for NoThisAlias {
   fn new() -> Box<Self> {
-    Box::new(NoThisAlias)
+    Self::with_options(NoThisAliasOptions::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -18,7 +52,7 @@ impl LintRule for NoThisAlias {
   }
 
   fn code(&self) -> &'static str {
-    "no-this-alias"
+    CODE
   }
 
   fn lint_program(
@@ -26,60 +60,106 @@ impl LintRule for NoThisAlias {
     context: &mut Context,
     program: &swc_ecmascript::ast::Program,
   ) {
-    let mut visitor = NoThisAliasVisitor::new(context);
+    let mut visitor = NoThisAliasVisitor::new(context, &self.options);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows assigning `this` to a variable
+
+Assigning `this` to a variable, like `const self = this`, used to be the way to
+access the `this` of an enclosing function from a nested function. Arrow
+functions don't have their own `this`, which makes such aliases unnecessary.
+
+Destructuring `this`, like `const { foo } = this`, is allowed by default, which
+can be changed with the `allowDestructuring` option. The `allowedNames` option
+lists variable names which `this` can be assigned to.
+
+### Invalid:
+```typescript
+const self = this;
+
+function foo() {
+  const self = this;
+  return function () {
+    self.bar();
+  };
+}
+```
+
+### Valid:
+```typescript
+const { props, state } = this;
+
+function foo() {
+  return () => {
+    this.bar();
+  };
+}
+```
+"#
+  }
 }
 
-struct NoThisAliasVisitor<'c> {
+struct NoThisAliasVisitor<'c, 'o> {
   context: &'c mut Context,
+  options: &'o NoThisAliasOptions,
 }
 
-impl<'c> NoThisAliasVisitor<'c> {
-  fn new(context: &'c mut Context) -> Self {
-    Self { context }
+impl<'c, 'o> NoThisAliasVisitor<'c, 'o> {
+  fn new(context: &'c mut Context, options: &'o NoThisAliasOptions) -> Self {
+    Self { context, options }
+  }
+
+  /// Checks an assignment of `this` to the given pattern.
+  fn check(&mut self, span: Span, pat: &Pat) {
+    let allowed = match pat {
+      Pat::Ident(ident) => self
+        .options
+        .allowed_names
+        .iter()
+        .any(|name| *name == *ident.sym),
+      Pat::Array(_) | Pat::Object(_) => self.options.allow_destructuring,
+      _ => true,
+    };
+    if !allowed {
+      self
+        .context
+        .add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
+    }
   }
 }
 
-impl<'c> Visit for NoThisAliasVisitor<'c> {
+impl<'c, 'o> Visit for NoThisAliasVisitor<'c, 'o> {
   noop_visit_type!();
 
   fn visit_var_decl(&mut self, var_decl: &VarDecl, _parent: &dyn Node) {
     for decl in &var_decl.decls {
       if let Some(init) = &decl.init {
-        if let Expr::Arrow(arrow) = &**init {
-          self.visit_arrow_expr(&arrow, _parent);
-        } else if let Expr::This(_) = &**init {
-          if let Pat::Ident(_ident) = &decl.name {
-            self.context.add_diagnostic(
-              var_decl.span,
-              "no-this-alias",
-              "assign `this` to declare a value is not allowed",
-            );
-          }
+        if let Expr::This(_) = &**init {
+          self.check(var_decl.span, &decl.name);
         }
       }
     }
+    var_decl.visit_children_with(self);
   }
 
-  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
-    self.visit_block_stmt_or_expr(&arrow_expr.body, _parent);
-  }
-
-  fn visit_expr_stmt(
+  fn visit_assign_expr(
     &mut self,
-    expr: &swc_ecmascript::ast::ExprStmt,
+    assign_expr: &AssignExpr,
     _parent: &dyn Node,
   ) {
-    if let Expr::Arrow(arrow) = &*expr.expr {
-      self.visit_arrow_expr(arrow, _parent);
-    }
-  }
-
-  fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
-    if let Some(stmt) = &function.body {
-      self.visit_block_stmt(stmt, _parent);
+    if let Expr::This(_) = &*assign_expr.right {
+      match &assign_expr.left {
+        PatOrExpr::Pat(pat) => self.check(assign_expr.span, pat),
+        PatOrExpr::Expr(expr) => {
+          if let Expr::Ident(ident) = &**expr {
+            self.check(assign_expr.span, &Pat::Ident(ident.clone()));
+          }
+        }
+      }
     }
+    assign_expr.visit_children_with(self);
   }
 }
 
@@ -134,4 +214,46 @@ class TestClass {
       vec![(4, 4), (5, 4), (13, 4)],
     );
   }
+
+  #[test]
+  fn no_this_alias_nested() {
+    assert_lint_err_on_line_n::<NoThisAlias>(
+      "
+let self;
+self = this;
+foo(function () {
+  if (bar) {
+    const that = this;
+  }
+});
+class A {
+  foo = () => {
+    const self = this;
+  };
+}",
+      vec![(3, 0), (6, 4), (11, 4)],
+    );
+
+    assert_lint_err! {
+      NoThisAlias,
+      "const self = this;": [{ col: 0, message: MESSAGE, hint: HINT }],
+    };
+  }
+
+  #[test]
+  fn no_this_alias_options() {
+    let options = NoThisAliasOptions {
+      allow_destructuring: false,
+      allowed_names: vec!["self".to_string()],
+    };
+    let rule = || NoThisAlias::with_options(options.clone());
+
+    assert_lint_ok_with_rule(rule(), "const self = this;");
+    assert_lint_ok_with_rule(rule(), "let self; self = this;");
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "const { foo } = this;\nconst [bar] = this;\nconst that = this;\n({ baz } = this);",
+      vec![(1, 0), (2, 0), (3, 0), (4, 1)],
+    );
+  }
 }
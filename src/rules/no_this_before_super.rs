@@ -1,13 +1,22 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
+use swc_atoms::JsWord;
+use swc_common::Span;
 use swc_ecmascript::ast::{
-  CallExpr, Class, Constructor, ExprOrSuper, Super, ThisExpr,
+  ArrowExpr, BinExpr, BinaryOp, BreakStmt, CallExpr, Class, CondExpr,
+  Constructor, ContinueStmt, DoWhileStmt, ExprOrSuper, ForInStmt, ForOfStmt,
+  ForStmt, Function, IfStmt, LabeledStmt, ReturnStmt, Super, SwitchStmt,
+  ThisExpr, ThrowStmt, TryStmt, WhileStmt,
 };
-use swc_ecmascript::visit::{noop_visit_type, Node, Visit};
+use swc_ecmascript::visit::{noop_visit_type, Node, Visit, VisitWith};
 
 pub struct NoThisBeforeSuper;
 
+const CODE: &str = "no-this-before-super";
+const MESSAGE: &str = "'this' / 'super' are not allowed before 'super()'.";
+const HINT: &str = "Call 'super()' before using 'this' or 'super' keywords";
+
 impl LintRule for NoThisBeforeSuper {
   fn new() -> Box<Self> {
     Box::new(NoThisBeforeSuper)
@@ -18,7 +27,7 @@ impl LintRule for NoThisBeforeSuper {
   }
 
   fn code(&self) -> &'static str {
-    "no-this-before-super"
+    CODE
   }
 
   fn lint_program(
@@ -29,6 +38,56 @@ impl LintRule for NoThisBeforeSuper {
     let mut visitor = NoThisBeforeSuperVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows use of `this` or `super` before calling `super()` in constructors.
+
+The access to `this` or `super` before calling `super()` in the constructor of
+derived classes leads to `ReferenceError`. A use is reported unless `super()`
+is called before it on every code path, so a call in only one branch of an
+`if` statement is not enough.
+
+### Invalid:
+```typescript
+class A extends B {
+  constructor() {
+    this.foo = 0;
+    super();
+  }
+}
+
+class C extends D {
+  constructor() {
+    if (a) {
+      super();
+    }
+    super.foo();
+  }
+}
+```
+
+### Valid:
+```typescript
+class A extends B {
+  constructor() {
+    super();
+    this.foo = 0;
+  }
+}
+
+class C extends D {
+  constructor() {
+    if (a) {
+      super(1);
+    } else {
+      super(2);
+    }
+    super.foo();
+  }
+}
+```
+"#
+  }
 }
 
 struct NoThisBeforeSuperVisitor<'c> {
@@ -84,9 +143,27 @@ impl<'a> Visit for ClassVisitor<'a> {
   }
 }
 
+/// Walks a derived class constructor in evaluation order, keeping track of
+/// whether `super()` has been called on every code path reaching the current
+/// point. Unreachable code counts as having called `super()`.
 struct ConstructorVisitor<'a> {
   context: &'a mut Context,
   super_called: bool,
+  /// For each enclosing loop or switch, whether `super()` has been called on
+  /// all paths leaving it with `break` or `continue`.
+  jumps: Vec<bool>,
+  /// Enclosing labeled statements.
+  labels: Vec<Label>,
+}
+
+/// A labeled statement enclosing the current code path.
+struct Label {
+  name: JsWord,
+  /// Whether `super()` has been called at every `break` targeting the label.
+  breaks: bool,
+  /// Index in `ConstructorVisitor::jumps` of the loop the label belongs to,
+  /// if the labeled statement is a loop.
+  continues: usize,
 }
 
 impl<'a> ConstructorVisitor<'a> {
@@ -94,17 +171,90 @@ impl<'a> ConstructorVisitor<'a> {
     Self {
       context,
       super_called: false,
+      jumps: vec![],
+      labels: vec![],
     }
   }
+
+  /// Visits the body of a loop or switch, returning whether `super()` has
+  /// been called at every `break` and `continue` in it.
+  fn visit_jump_target<N: VisitWith<Self> + ?Sized>(
+    &mut self,
+    node: &N,
+    parent: &dyn Node,
+  ) -> bool {
+    self.jumps.push(true);
+    node.visit_with(parent, self);
+    self.jumps.pop().unwrap()
+  }
+
+  fn check(&mut self, span: Span) {
+    if !self.super_called {
+      self
+        .context
+        .add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
+    }
+  }
+
+  /// Visits a node which may be skipped at runtime, so `super()` calls in it
+  /// don't count afterwards.
+  fn visit_conditionally<N: VisitWith<Self> + ?Sized>(
+    &mut self,
+    node: &N,
+    parent: &dyn Node,
+  ) {
+    let before = self.super_called;
+    self.visit_jump_target(node, parent);
+    self.super_called = before;
+  }
+
+  /// Visits two alternative branches, `super()` counts afterwards only if
+  /// it's called in both.
+  fn visit_branches<A, B>(
+    &mut self,
+    cons: &A,
+    alt: Option<&B>,
+    parent: &dyn Node,
+  ) where
+    A: VisitWith<Self> + ?Sized,
+    B: VisitWith<Self> + ?Sized,
+  {
+    let before = self.super_called;
+    cons.visit_with(parent, self);
+    let after_cons = self.super_called;
+    self.super_called = before;
+    if let Some(alt) = alt {
+      alt.visit_with(parent, self);
+    }
+    self.super_called = after_cons && self.super_called;
+  }
 }
 
 impl<'a> Visit for ConstructorVisitor<'a> {
+  noop_visit_type!();
+
   fn visit_class(&mut self, class: &Class, parent: &dyn Node) {
     let mut class_visitor =
       ClassVisitor::new(self.context, class.super_class.is_some());
     swc_ecmascript::visit::visit_class(&mut class_visitor, class, parent);
   }
 
+  // Nested functions may run at any time, so `this` and `super` in them are
+  // not checked. Classes declared inside still are.
+  fn visit_function(&mut self, function: &Function, parent: &dyn Node) {
+    let mut class_visitor = ClassVisitor::new(self.context, false);
+    swc_ecmascript::visit::visit_function(&mut class_visitor, function, parent);
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, parent: &dyn Node) {
+    let mut class_visitor = ClassVisitor::new(self.context, false);
+    swc_ecmascript::visit::visit_arrow_expr(
+      &mut class_visitor,
+      arrow_expr,
+      parent,
+    );
+  }
+
   fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
     for arg in &call_expr.args {
       self.visit_expr(&*arg.expr, call_expr);
@@ -117,24 +267,175 @@ impl<'a> Visit for ConstructorVisitor<'a> {
   }
 
   fn visit_this_expr(&mut self, this_expr: &ThisExpr, _parent: &dyn Node) {
-    if !self.super_called {
-      self.context.add_diagnostic(
-        this_expr.span,
-        "no-this-before-super",
-        "'this' / 'super' are not allowed before 'super()'.",
-      );
-    }
+    self.check(this_expr.span);
   }
 
   fn visit_super(&mut self, sup: &Super, _parent: &dyn Node) {
-    if !self.super_called {
-      self.context.add_diagnostic(
-        sup.span,
-        "no-this-before-super",
-        "'this' / 'super' are not allowed before 'super()'.",
-      );
+    self.check(sup.span);
+  }
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+    bin_expr.left.visit_with(bin_expr, self);
+    match bin_expr.op {
+      BinaryOp::LogicalAnd
+      | BinaryOp::LogicalOr
+      | BinaryOp::NullishCoalescing => {
+        self.visit_conditionally(&*bin_expr.right, bin_expr)
+      }
+      _ => bin_expr.right.visit_with(bin_expr, self),
+    }
+  }
+
+  fn visit_cond_expr(&mut self, cond_expr: &CondExpr, _parent: &dyn Node) {
+    cond_expr.test.visit_with(cond_expr, self);
+    self.visit_branches(&*cond_expr.cons, Some(&*cond_expr.alt), cond_expr);
+  }
+
+  fn visit_if_stmt(&mut self, if_stmt: &IfStmt, _parent: &dyn Node) {
+    if_stmt.test.visit_with(if_stmt, self);
+    self.visit_branches(&*if_stmt.cons, if_stmt.alt.as_deref(), if_stmt);
+  }
+
+  fn visit_switch_stmt(
+    &mut self,
+    switch_stmt: &SwitchStmt,
+    _parent: &dyn Node,
+  ) {
+    switch_stmt.discriminant.visit_with(switch_stmt, self);
+    let before = self.super_called;
+    for case in &switch_stmt.cases {
+      self.visit_jump_target(case, switch_stmt);
+      // Cases can be entered directly, without running the previous ones.
+      self.super_called = before;
     }
   }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _parent: &dyn Node) {
+    while_stmt.test.visit_with(while_stmt, self);
+    self.visit_conditionally(&*while_stmt.body, while_stmt);
+  }
+
+  fn visit_do_while_stmt(
+    &mut self,
+    do_while_stmt: &DoWhileStmt,
+    _parent: &dyn Node,
+  ) {
+    // The body runs at least once, but may be left early with `continue` or
+    // `break`.
+    let jumps = self.visit_jump_target(&*do_while_stmt.body, do_while_stmt);
+    self.super_called = self.super_called && jumps;
+    do_while_stmt.test.visit_with(do_while_stmt, self);
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _parent: &dyn Node) {
+    for_stmt.init.visit_with(for_stmt, self);
+    for_stmt.test.visit_with(for_stmt, self);
+    let before = self.super_called;
+    self.visit_jump_target(&*for_stmt.body, for_stmt);
+    for_stmt.update.visit_with(for_stmt, self);
+    self.super_called = before;
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, _parent: &dyn Node) {
+    for_in_stmt.right.visit_with(for_in_stmt, self);
+    self.visit_conditionally(&*for_in_stmt.body, for_in_stmt);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _parent: &dyn Node) {
+    for_of_stmt.right.visit_with(for_of_stmt, self);
+    self.visit_conditionally(&*for_of_stmt.body, for_of_stmt);
+  }
+
+  fn visit_try_stmt(&mut self, try_stmt: &TryStmt, _parent: &dyn Node) {
+    let before = self.super_called;
+    try_stmt.block.visit_with(try_stmt, self);
+    let after_block = self.super_called;
+
+    let mut after = after_block;
+    if let Some(handler) = &try_stmt.handler {
+      // The `try` block may throw before reaching `super()`.
+      self.super_called = before;
+      handler.visit_with(try_stmt, self);
+      after = after_block && self.super_called;
+    }
+
+    if let Some(finalizer) = &try_stmt.finalizer {
+      self.super_called = before;
+      finalizer.visit_with(try_stmt, self);
+      after = after || self.super_called;
+    }
+
+    self.super_called = after;
+  }
+
+  fn visit_return_stmt(
+    &mut self,
+    return_stmt: &ReturnStmt,
+    _parent: &dyn Node,
+  ) {
+    return_stmt.arg.visit_with(return_stmt, self);
+    self.super_called = true;
+  }
+
+  fn visit_throw_stmt(&mut self, throw_stmt: &ThrowStmt, _parent: &dyn Node) {
+    throw_stmt.arg.visit_with(throw_stmt, self);
+    self.super_called = true;
+  }
+
+  fn visit_labeled_stmt(
+    &mut self,
+    labeled_stmt: &LabeledStmt,
+    _parent: &dyn Node,
+  ) {
+    self.labels.push(Label {
+      name: labeled_stmt.label.sym.clone(),
+      breaks: true,
+      continues: self.jumps.len(),
+    });
+    labeled_stmt.body.visit_with(labeled_stmt, self);
+    let label = self.labels.pop().unwrap();
+    self.super_called = self.super_called && label.breaks;
+  }
+
+  fn visit_break_stmt(&mut self, break_stmt: &BreakStmt, _parent: &dyn Node) {
+    let super_called = self.super_called;
+    let jumps = match &break_stmt.label {
+      Some(name) => self
+        .labels
+        .iter_mut()
+        .rev()
+        .find(|label| label.name == name.sym)
+        .map(|label| &mut label.breaks),
+      None => self.jumps.last_mut(),
+    };
+    if let Some(jumps) = jumps {
+      *jumps = *jumps && super_called;
+    }
+    // The rest of the block is unreachable.
+    self.super_called = true;
+  }
+
+  fn visit_continue_stmt(
+    &mut self,
+    continue_stmt: &ContinueStmt,
+    _parent: &dyn Node,
+  ) {
+    let index = match &continue_stmt.label {
+      Some(name) => self
+        .labels
+        .iter()
+        .rev()
+        .find(|label| label.name == name.sym)
+        .map(|label| label.continues),
+      None => self.jumps.len().checked_sub(1),
+    };
+    let super_called = self.super_called;
+    if let Some(jumps) = index.and_then(|i| self.jumps.get_mut(i)) {
+      *jumps = *jumps && super_called;
+    }
+    // The rest of the block is unreachable.
+    self.super_called = true;
+  }
 }
 
 #[cfg(test)]
@@ -296,6 +597,59 @@ class A extends class extends B {
     );
   }
 
+  #[test]
+  fn no_this_before_super_branches() {
+    assert_lint_ok! {
+      NoThisBeforeSuper,
+      "class A extends B { constructor() { if (a) { super(); } else { super(); } this.a = 0; } }",
+      "class A extends B { constructor() { a ? super() : super(); this.a = 0; } }",
+      "class A extends B { constructor() { if (a) { throw new Error(); } super(); this.a = 0; } }",
+      "class A extends B { constructor() { if (a) { super(); } else { return; } this.a = 0; } }",
+      "class A extends B { constructor() { try { super(); } finally { } this.a = 0; } }",
+      "class A extends B { constructor() { try { } finally { super(); } this.a = 0; } }",
+      "class A extends B { constructor() { do { super(); } while (false); this.a = 0; } }",
+      "class A extends B { constructor() { const f = () => this.a; super(); f(); } }",
+      "class A extends B { constructor() { const f = function() { this.a = 0; }; super(); } }",
+      "class A extends B { constructor() { outer: while (a) { do { if (x) break outer; super(); } while (0); this.y = 1; } } }",
+      "class A extends B { constructor() { outer: do { do { if (x) continue outer; super(); } while (0); this.y = 1; } while (0); } }",
+      "class A extends B { constructor() { a: { super(); break a; } this.x = 1; } }",
+    };
+
+    assert_lint_err! {
+      NoThisBeforeSuper,
+      "class A extends B { constructor() { if (a) { super(); } this.a = 0; } }": [
+        { col: 56, message: MESSAGE, hint: HINT }
+      ],
+      "class A extends B { constructor() { a && super(); this.a = 0; } }": [
+        { col: 50, message: MESSAGE, hint: HINT }
+      ],
+      "class A extends B { constructor() { while (a) { super(); } this.a = 0; } }": [
+        { col: 59, message: MESSAGE, hint: HINT }
+      ],
+      "class A extends B { constructor() { for (const x of xs) { super(); } super.foo(); } }": [
+        { col: 69, message: MESSAGE, hint: HINT }
+      ],
+      "class A extends B { constructor() { switch (a) { case 0: super(); } this.a = 0; } }": [
+        { col: 68, message: MESSAGE, hint: HINT }
+      ],
+      "class A extends B { constructor() { try { super(); } catch (e) { } this.a = 0; } }": [
+        { col: 67, message: MESSAGE, hint: HINT }
+      ],
+      "class A extends B { constructor() { do { if (a) break; super(); } while (b); this.a = 0; } }": [
+        { col: 77, message: MESSAGE, hint: HINT }
+      ],
+      "class A extends B { constructor() { this.a = 0; super(); this.b = 1; } }": [
+        { col: 36, message: MESSAGE, hint: HINT }
+      ],
+      "class A extends B { constructor() { a: { if (x) break a; super(); } this.x = 1; } }": [
+        { col: 68, message: MESSAGE, hint: HINT }
+      ],
+      "class A extends B { constructor() { a: do { do { if (x) continue a; super(); } while (0); } while (this.x); } }": [
+        { col: 99, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+
   #[test]
   fn no_this_before_super_nested_class() {
     assert_lint_ok::<NoThisBeforeSuper>(
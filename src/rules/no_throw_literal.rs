@@ -25,6 +25,28 @@ impl LintRule for NoThrowLiteral {
     let mut visitor = NoThrowLiteralVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows throwing literals and `undefined` as exceptions
+
+Only `Error` objects capture a stack trace, which makes thrown errors much
+easier to debug. Throwing a literal, like a string or a number, or `undefined`
+loses this information.
+
+### Invalid:
+```typescript
+throw "error";
+throw 1;
+throw undefined;
+```
+
+### Valid:
+```typescript
+throw new Error("error");
+throw new TypeError("expected a number");
+```
+"#
+  }
 }
 
 struct NoThrowLiteralVisitor<'c> {
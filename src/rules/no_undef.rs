@@ -37,6 +37,30 @@ impl LintRule for NoUndef {
     let mut visitor = NoUndefVisitor::new(context, collector.declared);
     program.visit_with(program, &mut visitor);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the use of undeclared variables
+
+Referencing a variable which is neither declared in the file nor a known global
+usually means the name is misspelled or an import is missing, and throws a
+`ReferenceError` at runtime.
+
+### Invalid:
+```typescript
+const foo = someFunction();
+const bar = a + 1;
+```
+
+### Valid:
+```typescript
+import { someFunction } from "./some_module.ts";
+
+const a = 1;
+const foo = someFunction();
+const bar = a + 1;
+```
+"#
+  }
 }
 
 struct BindingCollector {
@@ -447,4 +471,21 @@ mod tests {
       ],
     };
   }
+
+  #[test]
+  fn no_undef_jsx() {
+    use crate::ast_parser::MediaType;
+    use crate::test_util::lint_with_media_type;
+
+    let diagnostics = lint_with_media_type(
+      NoUndef::new(),
+      MediaType::Tsx,
+      "const x = 1; export const a = <div title={y}>{x}{z}</div>;",
+    );
+    let messages = diagnostics
+      .iter()
+      .map(|diagnostic| diagnostic.message.as_str())
+      .collect::<Vec<_>>();
+    assert_eq!(messages, vec!["y is not defined", "z is not defined"]);
+  }
 }
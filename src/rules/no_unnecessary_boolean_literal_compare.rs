@@ -0,0 +1,354 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::Suggestion;
+use std::collections::HashSet;
+use swc_common::Spanned;
+use swc_ecmascript::ast::{
+  BinExpr, BinaryOp, Bool, Expr, ExprOrSuper, Lit, ParenExpr, Pat, Program,
+  TsKeywordType, TsKeywordTypeKind, TsType, TsTypeAnn, UnaryExpr, UnaryOp,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::utils::Id;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct NoUnnecessaryBooleanLiteralCompare;
+
+const CODE: &str = "no-unnecessary-boolean-literal-compare";
+const MESSAGE: &str =
+  "This expression unnecessarily compares a boolean value to a boolean literal";
+const DIRECT_HINT: &str = "Remove the comparison and use the value directly";
+const NEGATED_HINT: &str =
+  "Remove the comparison and negate the value instead, e.g. `!x`";
+const DIRECT_LABEL: &str = "Use the value directly";
+const NEGATED_LABEL: &str = "Negate the value";
+
+impl LintRule for NoUnnecessaryBooleanLiteralCompare {
+  fn new() -> Box<Self> {
+    Box::new(NoUnnecessaryBooleanLiteralCompare)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut collector = BooleanBindingCollector {
+      bindings: HashSet::new(),
+    };
+    program.visit_all_with(program, &mut collector);
+
+    let mut visitor = NoUnnecessaryBooleanLiteralCompareVisitor {
+      context,
+      boolean_bindings: collector.bindings,
+    };
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows comparing boolean values to boolean literals
+
+Comparing a boolean to `true` or `false` with `===` or `!==` is redundant: the
+value itself, or its negation, is the result of the comparison.
+
+The type of a value isn't known to the linter, so only values which are
+obviously booleans are checked: variables and parameters annotated with the
+`boolean` type, comparisons, negations and `Boolean()` calls.
+
+### Invalid:
+```typescript
+declare const someCondition: boolean;
+if (someCondition === true) {}
+if (someCondition !== false) {}
+if (someCondition === false) {}
+if (true !== someCondition) {}
+```
+
+### Valid:
+```typescript
+declare const someCondition: boolean;
+if (someCondition) {}
+if (!someCondition) {}
+
+declare const someValue: boolean | undefined;
+if (someValue === true) {}
+```
+"#
+  }
+}
+
+/// Collects the variables and parameters annotated with the `boolean` type.
+struct BooleanBindingCollector {
+  bindings: HashSet<Id>,
+}
+
+impl VisitAll for BooleanBindingCollector {
+  noop_visit_type!();
+
+  fn visit_pat(&mut self, pat: &Pat, _parent: &dyn Node) {
+    if let Pat::Ident(ident) = pat {
+      if !ident.optional
+        && ident.type_ann.as_ref().map_or(false, is_boolean_type)
+      {
+        self.bindings.insert(ident.to_id());
+      }
+    }
+  }
+}
+
+fn is_boolean_type(type_ann: &TsTypeAnn) -> bool {
+  matches!(
+    &*type_ann.type_ann,
+    TsType::TsKeywordType(TsKeywordType {
+      kind: TsKeywordTypeKind::TsBooleanKeyword,
+      ..
+    })
+  )
+}
+
+struct NoUnnecessaryBooleanLiteralCompareVisitor<'c> {
+  context: &'c mut Context,
+  boolean_bindings: HashSet<Id>,
+}
+
+impl<'c> NoUnnecessaryBooleanLiteralCompareVisitor<'c> {
+  fn is_boolean(&self, expr: &Expr) -> bool {
+    use BinaryOp::*;
+    match expr {
+      Expr::Ident(ident) => self.boolean_bindings.contains(&ident.to_id()),
+      Expr::Unary(UnaryExpr {
+        op: UnaryOp::Bang, ..
+      }) => true,
+      Expr::Bin(bin_expr) => matches!(
+        bin_expr.op,
+        EqEq
+          | NotEq
+          | EqEqEq
+          | NotEqEq
+          | Lt
+          | LtEq
+          | Gt
+          | GtEq
+          | In
+          | InstanceOf
+      ),
+      Expr::Call(call_expr) => matches!(
+        &call_expr.callee,
+        ExprOrSuper::Expr(callee)
+          if matches!(&**callee, Expr::Ident(ident) if ident.sym == *"Boolean")
+      ),
+      Expr::Paren(ParenExpr { expr, .. }) => self.is_boolean(expr),
+      _ => false,
+    }
+  }
+}
+
+impl<'c> VisitAll for NoUnnecessaryBooleanLiteralCompareVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+    let is_negated_operator = match bin_expr.op {
+      BinaryOp::EqEqEq => false,
+      BinaryOp::NotEqEq => true,
+      _ => return,
+    };
+    let (literal, value) =
+      match (bool_literal(&bin_expr.left), bool_literal(&bin_expr.right)) {
+        (Some(literal), None) => (literal, &bin_expr.right),
+        (None, Some(literal)) => (literal, &bin_expr.left),
+        _ => return,
+      };
+    if !self.is_boolean(value) {
+      return;
+    }
+
+    // `x === false` and `x !== true` are the negation of `x`.
+    let is_negated = literal == is_negated_operator;
+    let hint = if is_negated {
+      NEGATED_HINT
+    } else {
+      DIRECT_HINT
+    };
+    let suggestions = self
+      .context
+      .snippet(value.span())
+      .and_then(|value_text| {
+        let (label, new_text) = if is_negated {
+          // `!` binds tighter than comparisons, e.g. in `a > b === false`.
+          let operand = match &**value {
+            Expr::Bin(_) => format!("({})", value_text),
+            _ => value_text,
+          };
+          (NEGATED_LABEL, format!("!{}", operand))
+        } else {
+          (DIRECT_LABEL, value_text)
+        };
+        self
+          .context
+          .text_edit(bin_expr.span, new_text)
+          .map(|edit| Suggestion {
+            label: label.to_string(),
+            edit,
+          })
+      })
+      .into_iter()
+      .collect();
+    self.context.add_diagnostic_with_suggestions(
+      bin_expr.span,
+      CODE,
+      MESSAGE,
+      Some(hint.to_string()),
+      suggestions,
+    );
+  }
+}
+
+fn bool_literal(expr: &Expr) -> Option<bool> {
+  match expr {
+    Expr::Lit(Lit::Bool(Bool { value, .. })) => Some(*value),
+    _ => None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_unnecessary_boolean_literal_compare_valid() {
+    assert_lint_ok! {
+      NoUnnecessaryBooleanLiteralCompare,
+      "declare const x: boolean; if (x) {}",
+      "declare const x: boolean; if (!x) {}",
+      "declare const x: boolean | undefined; if (x === true) {}",
+      "declare const x: number; if (x === true) {}",
+      "function f(x?: boolean) { return x === true; }",
+      "if (x === true) {}",
+      "declare const x: boolean; if (x == true) {}",
+      "declare const x: boolean; if (x === y) {}",
+      "function f(x: boolean) {} x === true;",
+      "if (true === false) {}",
+    };
+  }
+
+  #[test]
+  fn no_unnecessary_boolean_literal_compare_invalid() {
+    assert_lint_err! {
+      NoUnnecessaryBooleanLiteralCompare,
+      "declare const x: boolean; if (x === true) {}": [
+        {
+          col: 30,
+          message: MESSAGE,
+          hint: DIRECT_HINT,
+        }
+      ],
+      "declare const x: boolean; if (x !== false) {}": [
+        {
+          col: 30,
+          message: MESSAGE,
+          hint: DIRECT_HINT,
+        }
+      ],
+      "declare const x: boolean; if (x === false) {}": [
+        {
+          col: 30,
+          message: MESSAGE,
+          hint: NEGATED_HINT,
+        }
+      ],
+      "declare const x: boolean; if (x !== true) {}": [
+        {
+          col: 30,
+          message: MESSAGE,
+          hint: NEGATED_HINT,
+        }
+      ],
+      "declare const x: boolean; if (true === x) {}": [
+        {
+          col: 30,
+          message: MESSAGE,
+          hint: DIRECT_HINT,
+        }
+      ],
+      "declare const x: boolean; if (false !== x) {}": [
+        {
+          col: 30,
+          message: MESSAGE,
+          hint: DIRECT_HINT,
+        }
+      ],
+      "function f(x: boolean) { return x === false; }": [
+        {
+          col: 32,
+          message: MESSAGE,
+          hint: NEGATED_HINT,
+        }
+      ],
+      "const f = (x: boolean) => x !== true;": [
+        {
+          col: 26,
+          message: MESSAGE,
+          hint: NEGATED_HINT,
+        }
+      ],
+      "if ((a > b) === true) {}": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: DIRECT_HINT,
+        }
+      ],
+      "if (!a === false) {}": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: NEGATED_HINT,
+        }
+      ],
+      "if (Boolean(a) !== false) {}": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: DIRECT_HINT,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_unnecessary_boolean_literal_compare_suggestions() {
+    let cases = [
+      (
+        "declare const x: boolean; if (x === true) {}",
+        DIRECT_LABEL,
+        "x",
+      ),
+      (
+        "declare const x: boolean; if (x === false) {}",
+        NEGATED_LABEL,
+        "!x",
+      ),
+      ("if ((a > b) === true) {}", DIRECT_LABEL, "(a > b)"),
+      ("if (a > b !== true) {}", NEGATED_LABEL, "!(a > b)"),
+    ];
+    for (source, label, new_text) in cases.iter() {
+      let diagnostics = crate::test_util::lint(
+        NoUnnecessaryBooleanLiteralCompare::new(),
+        source,
+      );
+      assert_eq!(diagnostics.len(), 1, "{}", source);
+      let suggestions = &diagnostics[0].suggestions;
+      assert_eq!(suggestions.len(), 1, "{}", source);
+      assert_eq!(suggestions[0].label, *label, "{}", source);
+      assert_eq!(suggestions[0].edit.new_text, *new_text, "{}", source);
+      assert_eq!(suggestions[0].edit.range, diagnostics[0].range);
+    }
+  }
+}
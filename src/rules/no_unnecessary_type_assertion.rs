@@ -0,0 +1,366 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use std::collections::HashMap;
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{
+  Expr, Lit, Pat, Program, TsAsExpr, TsConstAssertion, TsKeywordTypeKind,
+  TsLit, TsNonNullExpr, TsType, TsTypeAssertion, TsUnionOrIntersectionType,
+  VarDecl, VarDeclKind, VarDeclarator,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::utils::Id;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoUnnecessaryTypeAssertion;
+
+const CODE: &str = "no-unnecessary-type-assertion";
+const MESSAGE: &str =
+  "This assertion is unnecessary since it does not change the type of the expression";
+const HINT: &str = "Remove the type assertion";
+
+impl LintRule for NoUnnecessaryTypeAssertion {
+  fn new() -> Box<Self> {
+    Box::new(NoUnnecessaryTypeAssertion)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut collector = DeclaredTypesCollector::new(context);
+    program.visit_all_with(program, &mut collector);
+    let declared_types = collector.declared_types;
+
+    let mut visitor =
+      NoUnnecessaryTypeAssertionVisitor::new(context, declared_types);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows type assertions which don't change the type of an expression
+
+A type assertion which asserts the type an expression already has is noise.
+Without type information this rule only reports the assertions which are
+clearly redundant:
+
+- non-null assertions of expressions which can't be `null` or `undefined`,
+  such as literals, object and array literals or `new` expressions
+- literal type and `const` assertions of primitive literals in the initializer
+  of a `const` declaration, which already has the literal type
+- assertions of a `const` variable to the type it was declared with
+
+### Invalid:
+```typescript
+const a = "foo"!;
+const b = [1, 2]!;
+const c = "foo" as "foo";
+const d = 1 as const;
+
+const e: string = getName();
+const f = e as string;
+```
+
+### Valid:
+```typescript
+const a = foo!;
+let b = "foo" as "foo";
+const c = "foo" as string;
+const d = [1, 2] as const;
+
+const e: string | number = getValue();
+const f = e as string;
+```
+"#
+  }
+}
+
+/// Collects the type annotations of `const` variables, by their binding.
+struct DeclaredTypesCollector<'c> {
+  context: &'c Context,
+  declared_types: HashMap<Id, String>,
+}
+
+impl<'c> DeclaredTypesCollector<'c> {
+  fn new(context: &'c Context) -> Self {
+    Self {
+      context,
+      declared_types: HashMap::new(),
+    }
+  }
+}
+
+/// Checks if values of the type can't be narrowed to a more specific type,
+/// so that asserting a variable declared with the type is always redundant.
+fn is_exact_type(ts_type: &TsType) -> bool {
+  match ts_type {
+    TsType::TsUnionOrIntersectionType(
+      TsUnionOrIntersectionType::TsUnionType(_),
+    ) => false,
+    TsType::TsKeywordType(keyword) => !matches!(
+      keyword.kind,
+      TsKeywordTypeKind::TsAnyKeyword | TsKeywordTypeKind::TsUnknownKeyword
+    ),
+    TsType::TsParenthesizedType(paren) => is_exact_type(&paren.type_ann),
+    _ => true,
+  }
+}
+
+impl<'c> VisitAll for DeclaredTypesCollector<'c> {
+  noop_visit_type!();
+
+  fn visit_var_decl(&mut self, var_decl: &VarDecl, _parent: &dyn Node) {
+    if var_decl.kind != VarDeclKind::Const {
+      return;
+    }
+    for decl in &var_decl.decls {
+      if let Pat::Ident(ident) = &decl.name {
+        if let Some(type_ann) = &ident.type_ann {
+          if !is_exact_type(&type_ann.type_ann) {
+            continue;
+          }
+          if let Some(snippet) = type_snippet(self.context, &type_ann.type_ann)
+          {
+            self.declared_types.insert(ident.to_id(), snippet);
+          }
+        }
+      }
+    }
+  }
+}
+
+/// Returns the source of a type, without whitespace.
+fn type_snippet(context: &Context, ts_type: &TsType) -> Option<String> {
+  context
+    .snippet(ts_type.span())
+    .map(|snippet| snippet.split_whitespace().collect())
+}
+
+struct NoUnnecessaryTypeAssertionVisitor<'c> {
+  context: &'c mut Context,
+  declared_types: HashMap<Id, String>,
+}
+
+impl<'c> NoUnnecessaryTypeAssertionVisitor<'c> {
+  fn new(
+    context: &'c mut Context,
+    declared_types: HashMap<Id, String>,
+  ) -> Self {
+    Self {
+      context,
+      declared_types,
+    }
+  }
+
+  fn report(&mut self, span: Span) {
+    self
+      .context
+      .add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
+  }
+
+  fn check_assertion(&mut self, span: Span, expr: &Expr, type_ann: &TsType) {
+    if let Expr::Ident(ident) = expr {
+      let declared_type = match self.declared_types.get(&ident.to_id()) {
+        Some(declared_type) => declared_type,
+        None => return,
+      };
+      if type_snippet(self.context, type_ann).as_ref() == Some(declared_type) {
+        self.report(span);
+      }
+    }
+  }
+
+  /// Checks the initializer of a `const` declarator, whose type already is
+  /// the literal type of a primitive literal.
+  fn check_const_init(&mut self, declarator: &VarDeclarator) {
+    if let Pat::Ident(ident) = &declarator.name {
+      if ident.type_ann.is_some() {
+        return;
+      }
+    }
+    let init = match &declarator.init {
+      Some(init) => unwrap_parens(init),
+      None => return,
+    };
+    match init {
+      Expr::TsAs(as_expr)
+        if is_same_literal(&as_expr.type_ann, &as_expr.expr) =>
+      {
+        self.report(as_expr.span);
+      }
+      Expr::TsTypeAssertion(type_assertion)
+        if is_same_literal(&type_assertion.type_ann, &type_assertion.expr) =>
+      {
+        self.report(type_assertion.span);
+      }
+      Expr::TsConstAssertion(TsConstAssertion { span, expr })
+        if is_primitive_literal(unwrap_parens(expr)) =>
+      {
+        self.report(*span);
+      }
+      _ => {}
+    }
+  }
+}
+
+fn unwrap_parens(expr: &Expr) -> &Expr {
+  match expr {
+    Expr::Paren(paren) => unwrap_parens(&paren.expr),
+    _ => expr,
+  }
+}
+
+fn is_primitive_literal(expr: &Expr) -> bool {
+  matches!(
+    expr,
+    Expr::Lit(Lit::Str(_)) | Expr::Lit(Lit::Num(_)) | Expr::Lit(Lit::Bool(_))
+  )
+}
+
+/// Checks if the type is the literal type of the value of the expression.
+fn is_same_literal(type_ann: &TsType, expr: &Expr) -> bool {
+  let lit_type = match type_ann {
+    TsType::TsLitType(lit_type) => lit_type,
+    _ => return false,
+  };
+  match (unwrap_parens(expr), &lit_type.lit) {
+    (Expr::Lit(Lit::Str(value)), TsLit::Str(ty)) => value.value == ty.value,
+    (Expr::Lit(Lit::Num(value)), TsLit::Number(ty)) => {
+      value.value.to_bits() == ty.value.to_bits()
+    }
+    (Expr::Lit(Lit::Bool(value)), TsLit::Bool(ty)) => value.value == ty.value,
+    _ => false,
+  }
+}
+
+/// Checks if the expression evaluates to a value which is never `null` or
+/// `undefined`.
+fn is_non_nullable(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(Lit::Null(_)) => false,
+    Expr::Lit(_)
+    | Expr::Tpl(_)
+    | Expr::Array(_)
+    | Expr::Object(_)
+    | Expr::Fn(_)
+    | Expr::Arrow(_)
+    | Expr::Class(_)
+    | Expr::New(_)
+    | Expr::This(_) => true,
+    Expr::Paren(paren) => is_non_nullable(&paren.expr),
+    _ => false,
+  }
+}
+
+impl<'c> Visit for NoUnnecessaryTypeAssertionVisitor<'c> {
+  fn visit_ts_non_null_expr(
+    &mut self,
+    non_null_expr: &TsNonNullExpr,
+    _parent: &dyn Node,
+  ) {
+    if is_non_nullable(&non_null_expr.expr) {
+      self.report(non_null_expr.span);
+    }
+    non_null_expr.visit_children_with(self);
+  }
+
+  fn visit_ts_as_expr(&mut self, as_expr: &TsAsExpr, _parent: &dyn Node) {
+    self.check_assertion(as_expr.span, &as_expr.expr, &as_expr.type_ann);
+    as_expr.visit_children_with(self);
+  }
+
+  fn visit_ts_type_assertion(
+    &mut self,
+    type_assertion: &TsTypeAssertion,
+    _parent: &dyn Node,
+  ) {
+    self.check_assertion(
+      type_assertion.span,
+      &type_assertion.expr,
+      &type_assertion.type_ann,
+    );
+    type_assertion.visit_children_with(self);
+  }
+
+  fn visit_var_decl(&mut self, var_decl: &VarDecl, _parent: &dyn Node) {
+    if var_decl.kind == VarDeclKind::Const {
+      for declarator in &var_decl.decls {
+        self.check_const_init(declarator);
+      }
+    }
+    var_decl.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_unnecessary_type_assertion_valid() {
+    assert_lint_ok! {
+      NoUnnecessaryTypeAssertion,
+      "const a = foo!;",
+      "const a = foo.bar!.baz;",
+      "const a = null!;",
+      "const a = (foo as Bar)!;",
+      r#"const a = "foo" as string;"#,
+      r#"const a = <string>"foo";"#,
+      r#"let a = "foo" as "foo";"#,
+      r#"const a = { b: "foo" as "foo" };"#,
+      r#"const a: string = "foo" as "foo";"#,
+      r#"const a = "foo" as "bar";"#,
+      "const a = [1, 2] as const;",
+      "const a = { b: 1 } as const;",
+      "let a = 1 as const;",
+      "const a: string | number = foo(); const b = a as string;",
+      "const a: unknown = foo(); const b = a as unknown;",
+      "const a: Foo = foo(); const b = a as Bar;",
+      "let a: Foo = foo(); const b = a as Foo;",
+      "function foo(a: Foo) { const b = a as Foo; }",
+      "const a: Foo = foo(); function bar(a: Bar) { return a as Foo; }",
+    };
+  }
+
+  #[test]
+  fn no_unnecessary_type_assertion_invalid() {
+    assert_lint_err! {
+      NoUnnecessaryTypeAssertion,
+      r#"const a = "foo"!;"#: [{ col: 10, message: MESSAGE, hint: HINT }],
+      "const a = 1!;": [{ col: 10, message: MESSAGE, hint: HINT }],
+      "foo([1, 2]!);": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "const a = ({})!;": [{ col: 10, message: MESSAGE, hint: HINT }],
+      "const a = new Foo()!;": [{ col: 10, message: MESSAGE, hint: HINT }],
+      "const a = `foo${bar}`!;": [{ col: 10, message: MESSAGE, hint: HINT }],
+      "const a = (() => {})!;": [{ col: 10, message: MESSAGE, hint: HINT }],
+      r#"const a = "foo" as "foo";"#: [
+        { col: 10, message: MESSAGE, hint: HINT }
+      ],
+      "const a = <1>1;": [{ col: 10, message: MESSAGE, hint: HINT }],
+      "const a = true as true;": [{ col: 10, message: MESSAGE, hint: HINT }],
+      "const a = 1 as const;": [{ col: 10, message: MESSAGE, hint: HINT }],
+      r#"const a = ("foo" as const);"#: [
+        { col: 11, message: MESSAGE, hint: HINT }
+      ],
+      "const a: string = foo(); const b = a as string;": [
+        { col: 35, message: MESSAGE, hint: HINT }
+      ],
+      "const a: Foo<Bar> = foo(); bar(<Foo<Bar>>a);": [
+        { col: 31, message: MESSAGE, hint: HINT }
+      ],
+      "function bar() { return a as Array<string>; }\nconst a: Array< string > = [];": [
+        { col: 24, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+}
@@ -33,6 +33,38 @@ impl LintRule for NoUnreachable {
     let mut visitor = NoUnreachableVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows unreachable code after `return`, `throw`, `break` and `continue`
+
+Statements following a statement which always exits the current block are
+never executed, which is usually a mistake. Function declarations are hoisted
+and are therefore allowed after such statements.
+
+### Invalid:
+```typescript
+function foo() {
+  return true;
+  console.log("done");
+}
+
+for (const x of xs) {
+  continue;
+  bar(x);
+}
+```
+
+### Valid:
+```typescript
+function foo() {
+  return bar();
+  function bar() {
+    return 1;
+  }
+}
+```
+"#
+  }
 }
 
 struct NoUnreachableVisitor<'c> {
@@ -1,10 +1,10 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule};
+use crate::combined_visitor::{run_node_rule, AstNode, NodeKind, NodeRule};
 use swc_common::Span;
 use swc_ecmascript::ast::Program;
 use swc_ecmascript::ast::Stmt::{Break, Continue, Return, Throw};
 use swc_ecmascript::ast::TryStmt;
-use swc_ecmascript::visit::{noop_visit_type, Node, VisitAll, VisitAllWith};
 
 pub struct NoUnsafeFinally;
 
@@ -22,8 +22,11 @@ impl LintRule for NoUnsafeFinally {
   }
 
   fn lint_program(&self, context: &mut Context, program: &Program) {
-    let mut visitor = NoUnsafeFinallyVisitor::new(context);
-    program.visit_all_with(program, &mut visitor);
+    run_node_rule(self, context, program);
+  }
+
+  fn node_rule(&self) -> Option<&dyn NodeRule> {
+    Some(self)
   }
 
   fn docs(&self) -> &'static str {
@@ -69,38 +72,37 @@ let foo = function() {
   }
 }
 
-struct NoUnsafeFinallyVisitor<'c> {
-  context: &'c mut Context,
-}
-
-impl<'c> NoUnsafeFinallyVisitor<'c> {
-  fn new(context: &'c mut Context) -> Self {
-    Self { context }
+impl NodeRule for NoUnsafeFinally {
+  fn node_kinds(&self) -> &'static [NodeKind] {
+    &[NodeKind::TryStmt]
   }
 
-  fn add_diagnostic(&mut self, span: Span, stmt_type: &str) {
-    self.context.add_diagnostic(
-      span,
-      "no-unsafe-finally",
-      format!("Unsafe usage of {}Statement", stmt_type),
-    );
+  fn check_node(&self, context: &mut Context, node: AstNode) {
+    if let AstNode::TryStmt(node) = node {
+      check_try_stmt(context, node);
+    }
   }
 }
 
-impl<'c> VisitAll for NoUnsafeFinallyVisitor<'c> {
-  noop_visit_type!();
-
-  fn visit_try_stmt(&mut self, try_stmt: &TryStmt, _parent: &dyn Node) {
-    if let Some(finally_block) = &try_stmt.finalizer {
-      for stmt in &finally_block.stmts {
-        match stmt {
-          Break(_) => self.add_diagnostic(finally_block.span, "Break"),
-          Continue(_) => self.add_diagnostic(finally_block.span, "Continue"),
-          Return(_) => self.add_diagnostic(finally_block.span, "Return"),
-          Throw(_) => self.add_diagnostic(finally_block.span, "Throw"),
-          _ => {}
-        }
-      }
+fn add_diagnostic(context: &mut Context, span: Span, stmt_type: &str) {
+  context.add_diagnostic(
+    span,
+    "no-unsafe-finally",
+    format!("Unsafe usage of {}Statement", stmt_type),
+  );
+}
+
+fn check_try_stmt(context: &mut Context, try_stmt: &TryStmt) {
+  if let Some(finally_block) = &try_stmt.finalizer {
+    for stmt in &finally_block.stmts {
+      let stmt_type = match stmt {
+        Break(_) => "Break",
+        Continue(_) => "Continue",
+        Return(_) => "Return",
+        Throw(_) => "Throw",
+        _ => continue,
+      };
+      add_diagnostic(context, finally_block.span, stmt_type);
     }
   }
 }
@@ -32,6 +32,27 @@ impl LintRule for NoUnsafeNegation {
     let mut visitor = NoUnsafeNegationVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows negating the left operand of `in` and `instanceof`
+
+`!key in object` is parsed as `(!key) in object`, which checks whether `true`
+or `false` is a key of the object, rather than `!(key in object)`. The same
+applies to `instanceof`. Wrap the whole expression in parentheses to negate it.
+
+### Invalid:
+```typescript
+if (!key in object) {}
+if (!obj instanceof Foo) {}
+```
+
+### Valid:
+```typescript
+if (!(key in object)) {}
+if (!(obj instanceof Foo)) {}
+```
+"#
+  }
 }
 
 struct NoUnsafeNegationVisitor<'c> {
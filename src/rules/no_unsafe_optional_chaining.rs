@@ -0,0 +1,425 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  AssignExpr, AssignOp, AssignPat, BinExpr, BinaryOp, CallExpr, Class, Expr,
+  ExprOrSpread, ExprOrSuper, ForOfStmt, MemberExpr, NewExpr, OptChainExpr, Pat,
+  PatOrExpr, Program, TaggedTpl, UnaryExpr, UnaryOp, VarDeclarator,
+};
+use swc_ecmascript::visit::{noop_visit_type, Node, Visit, VisitWith};
+
+pub struct NoUnsafeOptionalChaining {
+  options: NoUnsafeOptionalChainingOptions,
+}
+
+const CODE: &str = "no-unsafe-optional-chaining";
+const MESSAGE: &str = "Unsafe usage of optional chaining, which throws a `TypeError` if it short-circuits with `undefined`";
+const ARITHMETIC_MESSAGE: &str = "Unsafe arithmetic operation on optional chaining, which results in `NaN` if it short-circuits with `undefined`";
+const HINT: &str =
+  "Check the value for `undefined` first, or provide a default value with `??`";
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoUnsafeOptionalChainingOptions {
+  /// Whether to report arithmetic on optional chains, like `a?.b + 1`.
+  pub disallow_arithmetic_operators: bool,
+}
+
+impl NoUnsafeOptionalChaining {
+  pub fn with_options(options: NoUnsafeOptionalChainingOptions) -> Box<Self> {
+    Box::new(NoUnsafeOptionalChaining { options })
+  }
+}
+
+impl LintRule for NoUnsafeOptionalChaining {
+  fn new() -> Box<Self> {
+    Self::with_options(NoUnsafeOptionalChainingOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = NoUnsafeOptionalChainingVisitor {
+      context,
+      disallow_arithmetic_operators: self.options.disallow_arithmetic_operators,
+    };
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows use of optional chaining where `undefined` is not allowed
+
+An optional chain like `obj?.foo` evaluates to `undefined` if `obj` is `null`
+or `undefined`. Calling the result, accessing its properties outside of the
+chain, spreading it into an array, destructuring it or using it with `in`,
+`instanceof` or `for-of` then throws a `TypeError`.
+
+With the `disallowArithmeticOperators` option, arithmetic on optional chains,
+which results in `NaN` if they short-circuit, is reported as well.
+
+### Invalid:
+```typescript
+(obj?.foo)();
+(obj?.foo).bar;
+new (obj?.foo)();
+[...obj?.foo];
+const { bar } = obj?.foo;
+for (const bar of obj?.foo) {}
+"bar" in obj?.foo;
+```
+
+### Valid:
+```typescript
+obj?.foo();
+obj?.foo.bar;
+(obj?.foo)?.();
+[...(obj?.foo ?? [])];
+const { bar } = obj?.foo ?? {};
+```
+"#
+  }
+}
+
+struct NoUnsafeOptionalChainingVisitor<'c> {
+  context: &'c mut Context,
+  disallow_arithmetic_operators: bool,
+}
+
+impl<'c> NoUnsafeOptionalChainingVisitor<'c> {
+  /// Reports optional chains whose value may become the value of `expr`.
+  fn check(&mut self, expr: &Expr, message: &str) {
+    match expr {
+      Expr::Paren(paren_expr) => self.check(&paren_expr.expr, message),
+      Expr::Await(await_expr) => self.check(&await_expr.arg, message),
+      Expr::Cond(cond_expr) => {
+        self.check(&cond_expr.cons, message);
+        self.check(&cond_expr.alt, message);
+      }
+      Expr::Seq(seq_expr) => {
+        if let Some(last) = seq_expr.exprs.last() {
+          self.check(last, message);
+        }
+      }
+      Expr::Bin(bin_expr) => match bin_expr.op {
+        BinaryOp::LogicalAnd => {
+          self.check(&bin_expr.left, message);
+          self.check(&bin_expr.right, message);
+        }
+        BinaryOp::LogicalOr | BinaryOp::NullishCoalescing => {
+          self.check(&bin_expr.right, message);
+        }
+        _ => {}
+      },
+      _ => {
+        if let Some(span) = optional_chain_span(expr) {
+          self
+            .context
+            .add_diagnostic_with_hint(span, CODE, message, HINT);
+        }
+      }
+    }
+  }
+
+  /// Like `check`, but a chain used directly as the object of a member or
+  /// call expression is continued by it, like in `obj?.foo.bar` or
+  /// `obj?.foo()`.
+  fn check_chain_continuation(&mut self, expr: &Expr) {
+    if optional_chain_span(expr).is_none() {
+      self.check(expr, MESSAGE);
+    }
+  }
+
+  fn check_arithmetic(&mut self, expr: &Expr) {
+    if self.disallow_arithmetic_operators {
+      self.check(expr, ARITHMETIC_MESSAGE);
+    }
+  }
+}
+
+/// Returns the span of the chain of member and call expressions ending with
+/// `expr`, if it contains an optional link.
+///
+/// Only the optional links are `OptChainExpr`s; `obj?.foo.bar` is a member
+/// expression with `obj?.foo` as its object.
+fn optional_chain_span(expr: &Expr) -> Option<Span> {
+  match expr {
+    Expr::OptChain(opt_chain) => Some(opt_chain.span),
+    Expr::Member(MemberExpr {
+      span,
+      obj: ExprOrSuper::Expr(obj),
+      ..
+    })
+    | Expr::Call(CallExpr {
+      span,
+      callee: ExprOrSuper::Expr(obj),
+      ..
+    }) => optional_chain_span(obj).map(|_| *span),
+    _ => None,
+  }
+}
+
+fn is_destructuring(pat: &Pat) -> bool {
+  matches!(pat, Pat::Object(_) | Pat::Array(_))
+}
+
+fn is_arithmetic(op: BinaryOp) -> bool {
+  matches!(
+    op,
+    BinaryOp::Add
+      | BinaryOp::Sub
+      | BinaryOp::Mul
+      | BinaryOp::Div
+      | BinaryOp::Mod
+      | BinaryOp::Exp
+  )
+}
+
+impl<'c> Visit for NoUnsafeOptionalChainingVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_opt_chain_expr(
+    &mut self,
+    opt_chain: &OptChainExpr,
+    _parent: &dyn Node,
+  ) {
+    // The member or call expression of an optional link is safe, like
+    // `(obj?.foo)?.bar`.
+    match &*opt_chain.expr {
+      Expr::Member(member_expr) => member_expr.visit_children_with(self),
+      Expr::Call(call_expr) => call_expr.visit_children_with(self),
+      expr => expr.visit_with(opt_chain, self),
+    }
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    if let ExprOrSuper::Expr(callee) = &call_expr.callee {
+      self.check_chain_continuation(callee);
+    }
+    call_expr.visit_children_with(self);
+  }
+
+  fn visit_member_expr(
+    &mut self,
+    member_expr: &MemberExpr,
+    _parent: &dyn Node,
+  ) {
+    if let ExprOrSuper::Expr(obj) = &member_expr.obj {
+      self.check_chain_continuation(obj);
+    }
+    member_expr.visit_children_with(self);
+  }
+
+  fn visit_new_expr(&mut self, new_expr: &NewExpr, _parent: &dyn Node) {
+    self.check(&new_expr.callee, MESSAGE);
+    new_expr.visit_children_with(self);
+  }
+
+  fn visit_tagged_tpl(&mut self, tagged_tpl: &TaggedTpl, _parent: &dyn Node) {
+    self.check(&tagged_tpl.tag, MESSAGE);
+    tagged_tpl.visit_children_with(self);
+  }
+
+  fn visit_expr_or_spread(
+    &mut self,
+    expr_or_spread: &ExprOrSpread,
+    _parent: &dyn Node,
+  ) {
+    // Spreads in arrays and arguments, but not in objects, which are
+    // `SpreadElement`s.
+    if expr_or_spread.spread.is_some() {
+      self.check(&expr_or_spread.expr, MESSAGE);
+    }
+    expr_or_spread.visit_children_with(self);
+  }
+
+  fn visit_var_declarator(
+    &mut self,
+    var_declarator: &VarDeclarator,
+    _parent: &dyn Node,
+  ) {
+    if let Some(init) = &var_declarator.init {
+      if is_destructuring(&var_declarator.name) {
+        self.check(init, MESSAGE);
+      }
+    }
+    var_declarator.visit_children_with(self);
+  }
+
+  fn visit_assign_pat(&mut self, assign_pat: &AssignPat, _parent: &dyn Node) {
+    if is_destructuring(&assign_pat.left) {
+      self.check(&assign_pat.right, MESSAGE);
+    }
+    assign_pat.visit_children_with(self);
+  }
+
+  fn visit_assign_expr(
+    &mut self,
+    assign_expr: &AssignExpr,
+    _parent: &dyn Node,
+  ) {
+    match assign_expr.op {
+      AssignOp::Assign => {
+        if let PatOrExpr::Pat(pat) = &assign_expr.left {
+          if is_destructuring(pat) {
+            self.check(&assign_expr.right, MESSAGE);
+          }
+        }
+      }
+      AssignOp::AddAssign
+      | AssignOp::SubAssign
+      | AssignOp::MulAssign
+      | AssignOp::DivAssign
+      | AssignOp::ModAssign
+      | AssignOp::ExpAssign => self.check_arithmetic(&assign_expr.right),
+      _ => {}
+    }
+    assign_expr.visit_children_with(self);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _parent: &dyn Node) {
+    self.check(&for_of_stmt.right, MESSAGE);
+    for_of_stmt.visit_children_with(self);
+  }
+
+  fn visit_class(&mut self, class: &Class, _parent: &dyn Node) {
+    if let Some(super_class) = &class.super_class {
+      self.check(super_class, MESSAGE);
+    }
+    class.visit_children_with(self);
+  }
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+    match bin_expr.op {
+      BinaryOp::In | BinaryOp::InstanceOf => {
+        self.check(&bin_expr.right, MESSAGE)
+      }
+      op if is_arithmetic(op) => {
+        self.check_arithmetic(&bin_expr.left);
+        self.check_arithmetic(&bin_expr.right);
+      }
+      _ => {}
+    }
+    bin_expr.visit_children_with(self);
+  }
+
+  fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr, _parent: &dyn Node) {
+    if let UnaryOp::Plus | UnaryOp::Minus = unary_expr.op {
+      self.check_arithmetic(&unary_expr.arg);
+    }
+    unary_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::{
+    assert_lint_err_on_line_n_with_rule, assert_lint_ok_with_rule,
+  };
+
+  #[test]
+  fn no_unsafe_optional_chaining_valid() {
+    assert_lint_ok! {
+      NoUnsafeOptionalChaining,
+      "obj?.foo();",
+      "obj?.foo.bar;",
+      "obj?.foo.bar.baz();",
+      "obj?.foo?.bar;",
+      "obj?.foo?.();",
+      "obj?.foo()();",
+      "obj?.[foo].bar;",
+      "(obj?.foo)?.();",
+      "(obj?.foo)?.bar;",
+      "(obj?.foo)?.bar.baz();",
+      "(obj?.foo ?? bar)();",
+      "(obj?.foo || bar).baz;",
+      "[...(obj?.foo ?? [])];",
+      "const a = { ...obj?.foo };",
+      "const a = obj?.foo;",
+      "const { a } = obj?.foo ?? {};",
+      "const [a] = obj?.foo || [];",
+      "function foo({ a } = obj?.foo ?? {}) {}",
+      "for (const a of obj?.foo ?? []) {}",
+      "for (const a in obj?.foo) {}",
+      "class A extends (obj?.foo ?? Object) {}",
+      "'a' in (obj?.foo ?? {});",
+      "foo(obj?.bar);",
+      "obj?.foo + 1;",
+      "-obj?.foo;",
+      "a += obj?.foo;",
+      "obj?.foo === 1;",
+    };
+  }
+
+  #[test]
+  fn no_unsafe_optional_chaining_invalid() {
+    assert_lint_err! {
+      NoUnsafeOptionalChaining,
+      "(obj?.foo)();": [{ col: 1, message: MESSAGE, hint: HINT }],
+      "(obj?.foo).bar;": [{ col: 1, message: MESSAGE, hint: HINT }],
+      "(obj?.foo)[bar];": [{ col: 1, message: MESSAGE, hint: HINT }],
+      "(obj?.foo)`bar`;": [{ col: 1, message: MESSAGE, hint: HINT }],
+      "new (obj?.foo)();": [{ col: 5, message: MESSAGE, hint: HINT }],
+      "(obj?.foo.bar)();": [{ col: 1, message: MESSAGE, hint: HINT }],
+      "(obj?.foo())();": [{ col: 1, message: MESSAGE, hint: HINT }],
+      "(a ? obj?.foo : bar)();": [{ col: 5, message: MESSAGE, hint: HINT }],
+      "(a, obj?.foo)();": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "(a || obj?.foo)();": [{ col: 6, message: MESSAGE, hint: HINT }],
+      "(a ?? obj?.foo)();": [{ col: 6, message: MESSAGE, hint: HINT }],
+      "(a && obj?.foo)();": [{ col: 6, message: MESSAGE, hint: HINT }],
+      "(obj?.foo && a)();": [{ col: 1, message: MESSAGE, hint: HINT }],
+      "(obj?.foo && a?.b)();": [
+        { col: 1, message: MESSAGE, hint: HINT },
+        { col: 13, message: MESSAGE, hint: HINT },
+      ],
+      "async function f() { (await obj?.foo)(); }": [
+        { col: 28, message: MESSAGE, hint: HINT },
+      ],
+      "[...obj?.foo];": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "foo(...obj?.bar);": [{ col: 7, message: MESSAGE, hint: HINT }],
+      "new Foo(...obj?.bar);": [{ col: 11, message: MESSAGE, hint: HINT }],
+      "const { a } = obj?.foo;": [{ col: 14, message: MESSAGE, hint: HINT }],
+      "const [a] = obj?.foo;": [{ col: 12, message: MESSAGE, hint: HINT }],
+      "({ a } = obj?.foo);": [{ col: 9, message: MESSAGE, hint: HINT }],
+      "[a] = obj?.foo;": [{ col: 6, message: MESSAGE, hint: HINT }],
+      "function foo({ a } = obj?.foo) {}": [
+        { col: 21, message: MESSAGE, hint: HINT },
+      ],
+      "for (const a of obj?.foo) {}": [{ col: 16, message: MESSAGE, hint: HINT }],
+      "class A extends obj?.foo {}": [{ col: 16, message: MESSAGE, hint: HINT }],
+      "'a' in obj?.foo;": [{ col: 7, message: MESSAGE, hint: HINT }],
+      "a instanceof obj?.foo;": [{ col: 13, message: MESSAGE, hint: HINT }],
+    };
+  }
+
+  #[test]
+  fn no_unsafe_optional_chaining_arithmetic() {
+    let rule = || {
+      NoUnsafeOptionalChaining::with_options(NoUnsafeOptionalChainingOptions {
+        disallow_arithmetic_operators: true,
+      })
+    };
+    assert_lint_ok_with_rule(
+      rule(),
+      "(obj?.foo ?? 0) - 1;\nobj?.foo === 1;\n!obj?.foo;",
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      r#"obj?.foo - 1;
+1 + obj?.foo;
+(obj?.foo) * 2;
+-obj?.foo;
+a -= obj?.foo;
+a ** (b || obj?.foo);
+(obj?.foo)();"#,
+      vec![(1, 0), (2, 4), (3, 1), (4, 1), (5, 5), (6, 11), (7, 1)],
+    );
+
+    let diagnostics = crate::test_util::lint(rule(), "obj?.foo - 1;");
+    assert_eq!(diagnostics[0].message, ARITHMETIC_MESSAGE);
+  }
+}
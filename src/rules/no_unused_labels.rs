@@ -32,6 +32,32 @@ impl LintRule for NoUnusedLabels {
     let mut visitor = NoUnusedLabelsVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows unused labels
+
+Labels which are never referenced by a `break` or `continue` statement are
+useless, and usually remain after refactoring a loop.
+
+### Invalid:
+```typescript
+LABEL1:
+for (const x of xs) {
+  if (x) break;
+}
+```
+
+### Valid:
+```typescript
+LABEL1:
+for (const x of xs) {
+  for (const y of ys) {
+    if (y) break LABEL1;
+  }
+}
+```
+"#
+  }
 }
 
 struct LabelScope {
@@ -11,10 +11,11 @@ use swc_ecmascript::{
     ArrowExpr, CatchClause, ClassDecl, ClassMethod, ClassProp, Constructor,
     Decl, ExportDecl, ExportNamedSpecifier, Expr, FnDecl, FnExpr, Ident,
     ImportDefaultSpecifier, ImportNamedSpecifier, ImportStarAsSpecifier,
-    KeyValueProp, MemberExpr, MethodKind, NamedExport, Param, Pat, Program,
-    Prop, SetterProp, TsEntityName, TsEnumDecl, TsExprWithTypeArgs,
-    TsModuleDecl, TsNamespaceDecl, TsPropertySignature, TsTypeRef, VarDecl,
-    VarDeclOrPat, VarDeclarator,
+    JSXElementName, JSXMemberExpr, JSXObject, KeyValueProp, MemberExpr,
+    MethodKind, NamedExport, Param, Pat, Program, Prop, SetterProp,
+    TsEntityName, TsEnumDecl, TsExprWithTypeArgs, TsModuleDecl,
+    TsNamespaceDecl, TsPropertySignature, TsTypeRef, VarDecl, VarDeclOrPat,
+    VarDeclarator,
   },
   visit::VisitWith,
 };
@@ -47,6 +48,35 @@ impl LintRule for NoUnusedVars {
     );
     program.visit_with(program, &mut visitor);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows declaring variables, functions and parameters which are never used
+
+Unused declarations are usually left over from refactoring, or indicate a
+mistake like a misspelled name where the variable is used. Exported
+declarations are considered used.
+
+### Invalid:
+```typescript
+const a = 1;
+
+function foo(x) {
+  const b = 2;
+  return 3;
+}
+```
+
+### Valid:
+```typescript
+const a = 1;
+console.log(a);
+
+export function foo(x) {
+  return x;
+}
+```
+"#
+  }
 }
 
 /// Collects information about variable usages.
@@ -163,6 +193,35 @@ impl Visit for Collector {
     }
   }
 
+  /// Components used as JSX elements are usages, unlike lowercase names
+  /// which refer to intrinsic elements like `div`.
+  fn visit_jsx_element_name(&mut self, name: &JSXElementName, _: &dyn Node) {
+    match name {
+      JSXElementName::Ident(i) => {
+        if !i.sym.starts_with(|c: char| c.is_ascii_lowercase()) {
+          self.mark_as_usage(i);
+        }
+      }
+      JSXElementName::JSXMemberExpr(member_expr) => {
+        self.visit_jsx_member_expr(member_expr, name);
+      }
+      JSXElementName::JSXNamespacedName(_) => {}
+    }
+  }
+
+  fn visit_jsx_member_expr(
+    &mut self,
+    member_expr: &JSXMemberExpr,
+    _: &dyn Node,
+  ) {
+    match &member_expr.obj {
+      JSXObject::Ident(i) => self.mark_as_usage(i),
+      JSXObject::JSXMemberExpr(obj) => {
+        self.visit_jsx_member_expr(obj, member_expr)
+      }
+    }
+  }
+
   /// export is kind of usage
   fn visit_export_named_specifier(
     &mut self,
@@ -1599,4 +1658,38 @@ export class App {
       ",
     );
   }
+
+  #[test]
+  fn no_unused_vars_jsx() {
+    use crate::ast_parser::MediaType;
+
+    let unused = |src: &str| {
+      lint_with_media_type(NoUnusedVars::new(), MediaType::Tsx, src)
+        .into_iter()
+        .map(|diagnostic| diagnostic.message)
+        .collect::<Vec<_>>()
+    };
+
+    for src in &[
+      "const x = 1; export const a = <div>{x}</div>;",
+      "const y = 1; export const a = <div a={y} {...{ y }} />;",
+      "import Foo from './foo.tsx'; export const a = <Foo />;",
+      "import Foo from './foo.tsx'; export const a = <Foo>text</Foo>;",
+      "import * as UI from './ui.tsx'; export const a = <UI.Button />;",
+      "import ui from './ui.tsx'; export const a = <ui.button />;",
+      "const f = (x: number) => <span>{x}</span>; export const a = f(1);",
+      "const x = 1; export const a = <><b>{[x].map((y) => <i>{y}</i>)}</b></>;",
+    ] {
+      assert!(unused(src).is_empty(), "{} should be valid", src);
+    }
+
+    assert_eq!(
+      unused("const div = 1; export const a = <div />;"),
+      vec!["\"div\" is never used"]
+    );
+    assert_eq!(
+      unused("const x = 1; const y = 2; export const a = <div>{x}</div>;"),
+      vec!["\"y\" is never used"]
+    );
+  }
 }
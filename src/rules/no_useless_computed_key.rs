@@ -0,0 +1,196 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_common::Spanned;
+use swc_ecmascript::ast::{
+  ClassMethod, ClassProp, ComputedPropName, Expr, Lit, Program, Prop, PropName,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct NoUselessComputedKey;
+
+const CODE: &str = "no-useless-computed-key";
+const MESSAGE: &str = "Computed key is unnecessary for a literal";
+const HINT: &str = "Remove the square brackets around the key";
+
+impl LintRule for NoUselessComputedKey {
+  fn new() -> Box<Self> {
+    Box::new(NoUselessComputedKey)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = NoUselessComputedKeyVisitor::new(context);
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows unnecessary computed keys in objects and classes
+
+Computing a key from a string or number literal, like `{ ["a"]: 1 }`, has the
+same effect as writing the literal key directly and only makes the code harder
+to read.
+
+The only exceptions are `["__proto__"]` in object literals, `["constructor"]`
+for instance members and `["prototype"]` for static members, since for those
+the computed form behaves differently from the plain one.
+
+### Invalid:
+```typescript
+const a = { ["a"]: 1 };
+const b = { [42]: 2 };
+class C {
+  ["foo"]() {}
+  static ["bar"] = 1;
+}
+```
+
+### Valid:
+```typescript
+const a = { a: 1 };
+const b = { 42: 2 };
+const c = { [foo]: 3 };
+const d = { ["__proto__"]: null };
+class C {
+  foo() {}
+  ["constructor"]() {}
+  static ["prototype"] = 1;
+}
+```
+"#
+  }
+}
+
+struct NoUselessComputedKeyVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoUselessComputedKeyVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  /// Reports on the span of the key expression itself, since class properties
+  /// don't keep the span of the surrounding brackets.
+  fn check_computed_key(&mut self, expr: &Expr, exempt: &str) {
+    let is_useless = match expr {
+      Expr::Lit(Lit::Str(s)) => s.value != *exempt,
+      Expr::Lit(Lit::Num(_)) => true,
+      _ => false,
+    };
+
+    if is_useless {
+      self
+        .context
+        .add_diagnostic_with_hint(expr.span(), CODE, MESSAGE, HINT);
+    }
+  }
+
+  fn check_prop_name(&mut self, prop_name: &PropName, exempt: &str) {
+    if let PropName::Computed(ComputedPropName { expr, .. }) = prop_name {
+      self.check_computed_key(expr, exempt);
+    }
+  }
+}
+
+/// Returns the key that behaves differently when written as a computed
+/// key for a class member.
+fn class_member_exemption(is_static: bool) -> &'static str {
+  if is_static {
+    "prototype"
+  } else {
+    "constructor"
+  }
+}
+
+impl<'c> VisitAll for NoUselessComputedKeyVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_prop(&mut self, prop: &Prop, _parent: &dyn Node) {
+    let key = match prop {
+      Prop::KeyValue(p) => &p.key,
+      Prop::Getter(p) => &p.key,
+      Prop::Setter(p) => &p.key,
+      Prop::Method(p) => &p.key,
+      Prop::Shorthand(_) | Prop::Assign(_) => return,
+    };
+    self.check_prop_name(key, "__proto__");
+  }
+
+  fn visit_class_method(
+    &mut self,
+    class_method: &ClassMethod,
+    _parent: &dyn Node,
+  ) {
+    self.check_prop_name(
+      &class_method.key,
+      class_member_exemption(class_method.is_static),
+    );
+  }
+
+  fn visit_class_prop(&mut self, class_prop: &ClassProp, _parent: &dyn Node) {
+    if class_prop.computed {
+      self.check_computed_key(
+        &class_prop.key,
+        class_member_exemption(class_prop.is_static),
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_useless_computed_key_valid() {
+    assert_lint_ok! {
+      NoUselessComputedKey,
+      "const a = { a: 1 };",
+      "const a = { 'a': 1 };",
+      "const a = { 42: 1 };",
+      "const a = { [a]: 1 };",
+      "const a = { [a + b]: 1 };",
+      "const a = { [`a${b}`]: 1 };",
+      "const a = { ['__proto__']: null };",
+      "const a = { get [a]() { return 1; } };",
+      "class C { foo() {} }",
+      "class C { [foo]() {} }",
+      "class C { ['constructor']() {} }",
+      "class C { static ['prototype'] = 1; }",
+      "class C { foo = 1; }",
+    };
+  }
+
+  #[test]
+  fn no_useless_computed_key_invalid() {
+    assert_lint_err! {
+      NoUselessComputedKey,
+      "const a = { ['a']: 1 };": [{ col: 13, message: MESSAGE, hint: HINT }],
+      "const a = { [42]: 1 };": [{ col: 13, message: MESSAGE, hint: HINT }],
+      "const a = { ['a']() {} };": [{ col: 13, message: MESSAGE, hint: HINT }],
+      "const a = { get ['a']() { return 1; } };": [{ col: 17, message: MESSAGE, hint: HINT }],
+      "const a = { set ['a'](v) {} };": [{ col: 17, message: MESSAGE, hint: HINT }],
+      "const a = { ['a']: { [0]: 1 } };": [
+        { col: 13, message: MESSAGE, hint: HINT },
+        { col: 22, message: MESSAGE, hint: HINT },
+      ],
+      "class C { ['foo']() {} }": [{ col: 11, message: MESSAGE, hint: HINT }],
+      "class C { static ['foo']() {} }": [{ col: 18, message: MESSAGE, hint: HINT }],
+      "class C { static ['constructor']() {} }": [{ col: 18, message: MESSAGE, hint: HINT }],
+      "class C { ['prototype']() {} }": [{ col: 11, message: MESSAGE, hint: HINT }],
+      "class C { ['foo'] = 1; }": [{ col: 11, message: MESSAGE, hint: HINT }],
+      "class C { static [42] = 1; }": [{ col: 18, message: MESSAGE, hint: HINT }],
+      "class C { [42] = 1; ['foo']() {} }": [
+        { col: 11, message: MESSAGE, hint: HINT },
+        { col: 21, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
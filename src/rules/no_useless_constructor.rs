@@ -0,0 +1,263 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_ecmascript::ast::{
+  Accessibility, BlockStmt, Class, ClassMember, Constructor, Expr,
+  ExprOrSpread, ExprOrSuper, Param, ParamOrTsParamProp, Pat, Program, Stmt,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct NoUselessConstructor;
+
+const CODE: &str = "no-useless-constructor";
+const MESSAGE: &str = "Useless constructor";
+const HINT: &str =
+  "Remove the constructor, the default one behaves in the same way";
+
+impl LintRule for NoUselessConstructor {
+  fn new() -> Box<Self> {
+    Box::new(NoUselessConstructor)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = NoUselessConstructorVisitor::new(context);
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows constructors that do nothing beyond the default behavior
+
+A class without an explicit constructor gets a default one. For a base class
+it is empty and for a derived class it forwards all arguments to the parent
+constructor, so writing either of those out by hand is redundant.
+
+Constructors with parameter properties or a `private`/`protected` modifier
+change the class and are not reported.
+
+### Invalid:
+```typescript
+class A {
+  constructor() {}
+}
+
+class B extends A {
+  constructor(...args) {
+    super(...args);
+  }
+}
+
+class C extends A {
+  constructor(a, b) {
+    super(a, b);
+  }
+}
+```
+
+### Valid:
+```typescript
+class A {
+  constructor() {
+    doSomething();
+  }
+}
+
+class B extends A {
+  constructor(a, b) {
+    super(b, a);
+  }
+}
+
+class C {
+  constructor(private name: string) {}
+}
+
+class D {
+  private constructor() {}
+}
+```
+"#
+  }
+}
+
+struct NoUselessConstructorVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoUselessConstructorVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c> VisitAll for NoUselessConstructorVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_class(&mut self, class: &Class, _parent: &dyn Node) {
+    let is_derived = class.super_class.is_some();
+
+    for member in &class.body {
+      if let ClassMember::Constructor(constructor) = member {
+        if is_useless_constructor(constructor, is_derived) {
+          self.context.add_diagnostic_with_hint(
+            constructor.span,
+            CODE,
+            MESSAGE,
+            HINT,
+          );
+        }
+      }
+    }
+  }
+}
+
+fn is_useless_constructor(constructor: &Constructor, is_derived: bool) -> bool {
+  // Overload signatures have no body.
+  let body = match &constructor.body {
+    Some(body) => body,
+    None => return false,
+  };
+
+  match constructor.accessibility {
+    Some(Accessibility::Private) | Some(Accessibility::Protected) => {
+      return false
+    }
+    // A public constructor can widen the visibility of a protected parent
+    // constructor.
+    Some(Accessibility::Public) if is_derived => return false,
+    _ => {}
+  }
+
+  let mut params = vec![];
+  for param in &constructor.params {
+    match param {
+      // Parameter properties declare and assign class fields.
+      ParamOrTsParamProp::TsParamProp(_) => return false,
+      ParamOrTsParamProp::Param(param) => {
+        if !param.decorators.is_empty() {
+          return false;
+        }
+        params.push(param);
+      }
+    }
+  }
+
+  if is_derived {
+    is_super_forwarding(body, &params)
+  } else {
+    body.stmts.is_empty()
+  }
+}
+
+/// Checks if the body consists only of a `super(...)` call passing the
+/// parameters through unchanged.
+fn is_super_forwarding(body: &BlockStmt, params: &[&Param]) -> bool {
+  if body.stmts.len() != 1 {
+    return false;
+  }
+
+  let call = match &body.stmts[0] {
+    Stmt::Expr(expr_stmt) => match &*expr_stmt.expr {
+      Expr::Call(call) => call,
+      _ => return false,
+    },
+    _ => return false,
+  };
+  if !matches!(call.callee, ExprOrSuper::Super(_)) {
+    return false;
+  }
+
+  if is_spread_arguments(&call.args) {
+    return true;
+  }
+
+  if params.len() != call.args.len() {
+    return false;
+  }
+
+  params
+    .iter()
+    .zip(&call.args)
+    .all(|(param, arg)| match (&param.pat, arg) {
+      (Pat::Ident(param), ExprOrSpread { spread: None, expr }) => {
+        matches!(&**expr, Expr::Ident(arg) if arg.sym == param.sym)
+      }
+      (
+        Pat::Rest(rest),
+        ExprOrSpread {
+          spread: Some(_),
+          expr,
+        },
+      ) => match (&*rest.arg, &**expr) {
+        (Pat::Ident(param), Expr::Ident(arg)) => arg.sym == param.sym,
+        _ => false,
+      },
+      _ => false,
+    })
+}
+
+/// Checks for `super(...arguments)`.
+fn is_spread_arguments(args: &[ExprOrSpread]) -> bool {
+  match args {
+    [ExprOrSpread {
+      spread: Some(_),
+      expr,
+    }] => matches!(&**expr, Expr::Ident(ident) if ident.sym == *"arguments"),
+    _ => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_useless_constructor_valid() {
+    assert_lint_ok! {
+      NoUselessConstructor,
+      "class A { }",
+      "class A { constructor() { doSomething(); } }",
+      "class A extends B { constructor() { } }",
+      "class A extends B { constructor() { super('foo'); } }",
+      "class A extends B { constructor(foo, bar) { super(foo, bar, 1); } }",
+      "class A extends B { constructor(foo, bar) { super(bar, foo); } }",
+      "class A extends B { constructor(foo) { super(foo); doSomething(); } }",
+      "class A extends B { constructor(...args) { super(args); } }",
+      "class A extends B { constructor(a = 1) { super(a); } }",
+      "class A extends B { constructor() { super.foo(); } }",
+      "class A { constructor(private x: number) {} }",
+      "class A { constructor(public x: number) {} }",
+      "class A { constructor(readonly x: number) {} }",
+      "class A extends B { constructor(protected x: number) { super(x); } }",
+      "class A { private constructor() {} }",
+      "class A { protected constructor() {} }",
+      "class A extends B { public constructor() { super(...arguments); } }",
+      "class A { constructor(@Inject() foo: Foo) {} }",
+      "class A { constructor(); constructor(a?: string) { doSomething(a); } }",
+      "declare class A { constructor(); }",
+    };
+  }
+
+  #[test]
+  fn no_useless_constructor_invalid() {
+    assert_lint_err! {
+      NoUselessConstructor,
+      "class A { constructor() {} }": [{ col: 10, message: MESSAGE, hint: HINT }],
+      "class A { public constructor() {} }": [{ col: 10, message: MESSAGE, hint: HINT }],
+      "class A extends B { constructor() { super(); } }": [{ col: 20, message: MESSAGE, hint: HINT }],
+      "class A extends B { constructor(foo) { super(foo); } }": [{ col: 20, message: MESSAGE, hint: HINT }],
+      "class A extends B { constructor(foo, bar) { super(foo, bar); } }": [{ col: 20, message: MESSAGE, hint: HINT }],
+      "class A extends B { constructor(...args) { super(...args); } }": [{ col: 20, message: MESSAGE, hint: HINT }],
+      "class A extends B { constructor(a, ...rest) { super(a, ...rest); } }": [{ col: 20, message: MESSAGE, hint: HINT }],
+      "class A extends B { constructor() { super(...arguments); } }": [{ col: 20, message: MESSAGE, hint: HINT }],
+      "class A extends B { constructor(foo: string) { super(foo); } }": [{ col: 20, message: MESSAGE, hint: HINT }],
+      "const A = class extends B { constructor(a) { super(a); } };": [{ col: 28, message: MESSAGE, hint: HINT }],
+    };
+  }
+}
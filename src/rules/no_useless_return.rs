@@ -0,0 +1,250 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_ecmascript::ast::{
+  ArrowExpr, BlockStmt, BlockStmtOrExpr, Constructor, Function, GetterProp,
+  Program, SetterProp, Stmt,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct NoUselessReturn;
+
+const CODE: &str = "no-useless-return";
+const MESSAGE: &str = "Unnecessary return statement";
+const HINT: &str =
+  "Remove the `return` statement, the function returns after it anyway";
+
+impl LintRule for NoUselessReturn {
+  fn new() -> Box<Self> {
+    Box::new(NoUselessReturn)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = NoUselessReturnVisitor { context };
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows `return` statements without a value at the end of functions
+
+A `return;` after which the function would return anyway has no effect and
+only adds noise. Returns which exit a loop, skip the rest of the function or
+complete a `try` block with a `finally` block are not reported.
+
+### Invalid:
+```typescript
+function foo() {
+  doSomething();
+  return;
+}
+
+function bar(a: boolean) {
+  if (a) {
+    doSomething();
+    return;
+  } else {
+    doSomethingElse();
+  }
+}
+```
+
+### Valid:
+```typescript
+function foo(a: boolean) {
+  if (a) {
+    return;
+  }
+  doSomething();
+}
+
+function bar(items: string[]) {
+  for (const item of items) {
+    if (item === "") {
+      return;
+    }
+  }
+  doSomething();
+}
+```
+"#
+  }
+}
+
+struct NoUselessReturnVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoUselessReturnVisitor<'c> {
+  fn check_body(&mut self, body: &BlockStmt) {
+    self.check_tail_stmts(&body.stmts);
+  }
+
+  /// Checks statements after which the function returns.
+  fn check_tail_stmts(&mut self, stmts: &[Stmt]) {
+    let last = stmts
+      .iter()
+      .rev()
+      .find(|stmt| !matches!(stmt, Stmt::Empty(_)));
+    if let Some(stmt) = last {
+      self.check_tail_stmt(stmt);
+    }
+  }
+
+  /// Checks a statement after which the function returns.
+  fn check_tail_stmt(&mut self, stmt: &Stmt) {
+    match stmt {
+      Stmt::Return(return_stmt) if return_stmt.arg.is_none() => {
+        self.context.add_diagnostic_with_hint(
+          return_stmt.span,
+          CODE,
+          MESSAGE,
+          HINT,
+        );
+      }
+      Stmt::Block(block) => self.check_tail_stmts(&block.stmts),
+      Stmt::Labeled(labeled) => self.check_tail_stmt(&labeled.body),
+      Stmt::If(if_stmt) => {
+        self.check_tail_stmt(&if_stmt.cons);
+        if let Some(alt) = &if_stmt.alt {
+          self.check_tail_stmt(alt);
+        }
+      }
+      // A return in a `try` or `catch` block decides whether a return in
+      // the `finally` block is reached, and one in the `finally` block
+      // overrides the value returned by them.
+      Stmt::Try(try_stmt) if try_stmt.finalizer.is_none() => {
+        self.check_tail_stmts(&try_stmt.block.stmts);
+        if let Some(handler) = &try_stmt.handler {
+          self.check_tail_stmts(&handler.body.stmts);
+        }
+      }
+      // Returns in other cases keep the next cases from being run.
+      Stmt::Switch(switch) => {
+        if let Some(case) = switch.cases.last() {
+          self.check_tail_stmts(&case.cons);
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+impl<'c> VisitAll for NoUselessReturnVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function, _: &dyn Node) {
+    if let Some(body) = &function.body {
+      self.check_body(body);
+    }
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _: &dyn Node) {
+    if let BlockStmtOrExpr::BlockStmt(body) = &arrow_expr.body {
+      self.check_body(body);
+    }
+  }
+
+  fn visit_constructor(&mut self, constructor: &Constructor, _: &dyn Node) {
+    if let Some(body) = &constructor.body {
+      self.check_body(body);
+    }
+  }
+
+  fn visit_getter_prop(&mut self, getter: &GetterProp, _: &dyn Node) {
+    if let Some(body) = &getter.body {
+      self.check_body(body);
+    }
+  }
+
+  fn visit_setter_prop(&mut self, setter: &SetterProp, _: &dyn Node) {
+    if let Some(body) = &setter.body {
+      self.check_body(body);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_useless_return_valid() {
+    assert_lint_ok! {
+      NoUselessReturn,
+      "function foo() { return 5; }",
+      "function foo() { return doSomething(); }",
+      "function foo() { if (bar) { doSomething(); return; } doSomethingElse(); }",
+      "function foo() { if (bar) return; doSomething(); }",
+      "function foo() { for (const a of b) { if (a) { return; } } bar(); }",
+      "function foo() { for (const a of b) { return; } }",
+      "function foo() { while (a) { doSomething(); return; } }",
+      "function foo() { try { return; } finally { bar(); } }",
+      "function foo() { try { bar(); } catch (e) { return; } finally { baz(); } }",
+      "function foo() { try { return 5; } finally { return; } }",
+      "function foo() { switch (bar) { case 1: doSomething(); return; default: doSomethingElse(); } }",
+      "function foo() { return; doSomething(); }",
+      "function foo() { return;\n bar(); }",
+      "const foo = () => { return 5; };",
+      "const foo = () => undefined;",
+      "return;",
+      "class A { constructor() { if (a) { return; } b(); } }",
+    };
+  }
+
+  #[test]
+  fn no_useless_return_invalid() {
+    assert_lint_err! {
+      NoUselessReturn,
+      "function foo() { return; }": [
+        { col: 17, message: MESSAGE, hint: HINT },
+      ],
+      "function foo() { doSomething(); return; }": [
+        { col: 32, message: MESSAGE, hint: HINT },
+      ],
+      "function foo() { doSomething(); return;; }": [
+        { col: 32, message: MESSAGE, hint: HINT },
+      ],
+      "function foo() { if (bar) { doSomething(); return; } else { doSomethingElse(); } }": [
+        { col: 43, message: MESSAGE, hint: HINT },
+      ],
+      "function foo() { if (bar) { a(); return; } else if (baz) { b(); return; } }": [
+        { col: 33, message: MESSAGE, hint: HINT },
+        { col: 64, message: MESSAGE, hint: HINT },
+      ],
+      "function foo() { try { a(); return; } catch (e) { b(); return; } }": [
+        { col: 28, message: MESSAGE, hint: HINT },
+        { col: 55, message: MESSAGE, hint: HINT },
+      ],
+      "function foo() { switch (bar) { case 1: a(); break; default: b(); return; } }": [
+        { col: 66, message: MESSAGE, hint: HINT },
+      ],
+      "function foo() { label: { a(); return; } }": [
+        { col: 31, message: MESSAGE, hint: HINT },
+      ],
+      "const foo = () => { a(); return; };": [
+        { col: 25, message: MESSAGE, hint: HINT },
+      ],
+      "const foo = function () { return; };": [
+        { col: 26, message: MESSAGE, hint: HINT },
+      ],
+      "class A { constructor() { a(); return; } m() { return; } }": [
+        { col: 31, message: MESSAGE, hint: HINT },
+        { col: 47, message: MESSAGE, hint: HINT },
+      ],
+      "const a = { get b() { return; }, set b(v) { return; } };": [
+        { col: 22, message: MESSAGE, hint: HINT },
+        { col: 44, message: MESSAGE, hint: HINT },
+      ],
+      "function foo() { for (const a of b) { function bar() { return; } } }": [
+        { col: 55, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
@@ -26,6 +26,26 @@ impl LintRule for NoVar {
     let mut visitor = NoVarVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the `var` keyword
+
+Variables declared with `var` are function scoped and hoisted, which is a
+common source of bugs. `let` and `const` are block scoped and can't be used
+before they are declared.
+
+### Invalid:
+```typescript
+var foo = "bar";
+```
+
+### Valid:
+```typescript
+const foo = "bar";
+let count = 0;
+```
+"#
+  }
 }
 
 struct NoVarVisitor<'c> {
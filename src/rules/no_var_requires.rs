@@ -0,0 +1,196 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::no_require_imports::is_require_call;
+use super::Context;
+use super::LintRule;
+use swc_common::Span;
+use swc_ecmascript::ast::{Expr, ExprOrSuper, Program, VarDeclarator};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct NoVarRequires;
+
+const CODE: &str = "no-var-requires";
+const MESSAGE: &str = "Require statement not part of import statement";
+const HINT: &str =
+  "Use an ES module `import` instead of assigning `require()` to a variable";
+
+impl LintRule for NoVarRequires {
+  fn new() -> Box<Self> {
+    Box::new(NoVarRequires)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = NoVarRequiresVisitor::new(context);
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows assigning the result of `require()` calls to variables
+
+In TypeScript, a module imported with `require()` and assigned to a variable is
+typed as `any`, unlike a module imported with an ES `import`. This rule reports
+`require()` calls in variable initializers, including when a property of the
+module is accessed or the module is called. Other `require()` calls are
+reported by `no-require-imports`.
+
+### Invalid:
+```typescript
+const foo = require("./foo.js");
+const bar = require("./bar.js").bar;
+```
+
+### Valid:
+```typescript
+import foo from "./foo.js";
+import { bar } from "./bar.js";
+require("./polyfill.js");
+```
+"#
+  }
+}
+
+struct NoVarRequiresVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoVarRequiresVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  /// Returns the span of the `require()` call whose result the expression
+  /// evaluates to or is derived from.
+  fn find_require_call(&self, expr: &Expr) -> Option<Span> {
+    match expr {
+      Expr::Call(call_expr) => {
+        if is_require_call(self.context, call_expr) {
+          return Some(call_expr.span);
+        }
+        match &call_expr.callee {
+          ExprOrSuper::Expr(callee) => self.find_require_call(callee),
+          ExprOrSuper::Super(_) => None,
+        }
+      }
+      Expr::Member(member_expr) => match &member_expr.obj {
+        ExprOrSuper::Expr(obj) => self.find_require_call(obj),
+        ExprOrSuper::Super(_) => None,
+      },
+      Expr::New(new_expr) => self.find_require_call(&new_expr.callee),
+      Expr::Paren(paren_expr) => self.find_require_call(&paren_expr.expr),
+      Expr::TsAs(as_expr) => self.find_require_call(&as_expr.expr),
+      Expr::TsTypeAssertion(type_assertion) => {
+        self.find_require_call(&type_assertion.expr)
+      }
+      Expr::TsNonNull(non_null_expr) => {
+        self.find_require_call(&non_null_expr.expr)
+      }
+      _ => None,
+    }
+  }
+}
+
+impl<'c> VisitAll for NoVarRequiresVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_var_declarator(
+    &mut self,
+    declarator: &VarDeclarator,
+    _parent: &dyn Node,
+  ) {
+    if let Some(init) = &declarator.init {
+      if let Some(span) = self.find_require_call(init) {
+        self
+          .context
+          .add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn no_var_requires_valid() {
+    assert_lint_ok! {
+      NoVarRequires,
+      r#"import foo = require("foo");"#,
+      r#"import foo from "foo";"#,
+      r#"require("foo");"#,
+      r#"require("foo").bar();"#,
+      r#"foo(require("foo"));"#,
+      r#"const foo = bar(require("foo"));"#,
+      r#"const foo = [require("foo")];"#,
+      r#"const foo = require.resolve("foo");"#,
+      r#"function foo(require) { const bar = require("bar"); }"#,
+    };
+  }
+
+  #[test]
+  fn no_var_requires_invalid() {
+    assert_lint_err! {
+      NoVarRequires,
+      r#"const foo = require("foo");"#: [
+        { col: 12, message: MESSAGE, hint: HINT }
+      ],
+      r#"let foo = require("foo").bar;"#: [
+        { col: 10, message: MESSAGE, hint: HINT }
+      ],
+      r#"var foo = require("foo")("bar");"#: [
+        { col: 10, message: MESSAGE, hint: HINT }
+      ],
+      r#"const foo = new (require("foo"))();"#: [
+        { col: 17, message: MESSAGE, hint: HINT }
+      ],
+      r#"const foo = require("foo") as Foo;"#: [
+        { col: 12, message: MESSAGE, hint: HINT }
+      ],
+      r#"const { foo } = <Foo>require("foo");"#: [
+        { col: 21, message: MESSAGE, hint: HINT }
+      ],
+      r#"function foo() { const a = 1, b = require("b")!; }"#: [
+        { col: 34, message: MESSAGE, hint: HINT }
+      ],
+      r#"namespace N { const foo = require("foo"); }"#: [
+        { col: 26, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_var_requires_not_double_reported() {
+    use crate::rules::no_require_imports::NoRequireImports;
+
+    let source = r#"require("foo");
+const bar = require("bar");"#;
+    assert_lint_err_on_line_n_with_rule(
+      NoVarRequires::new(),
+      source,
+      vec![(2, 12)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      NoRequireImports::new(),
+      source,
+      vec![(1, 0), (2, 12)],
+    );
+  }
+
+  #[test]
+  fn no_var_requires_skips_javascript() {
+    let source = r#"const foo = require("foo");"#;
+    assert!(lint_file(NoVarRequires::new(), "foo.js", source).is_empty());
+    assert_eq!(lint_file(NoVarRequires::new(), "foo.ts", source).len(), 1);
+  }
+}
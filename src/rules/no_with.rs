@@ -32,6 +32,27 @@ impl LintRule for NoWith {
     let mut visitor = NoWithVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows `with` statements
+
+`with` statements add the members of an object to the current scope, which
+makes it impossible to tell what a name refers to. They are not allowed in
+strict mode and ES modules.
+
+### Invalid:
+```typescript
+with (someObject) {
+  foo();
+}
+```
+
+### Valid:
+```typescript
+someObject.foo();
+```
+"#
+  }
 }
 
 struct NoWithVisitor<'c> {
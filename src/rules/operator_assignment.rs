@@ -0,0 +1,488 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::Suggestion;
+use serde::Deserialize;
+use swc_common::Spanned;
+use swc_ecmascript::ast::{
+  AssignExpr, AssignOp, BinaryOp, Expr, ExprOrSuper, Lit, MemberExpr, Pat,
+  PatOrExpr, Program,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct OperatorAssignment {
+  options: OperatorAssignmentOptions,
+}
+
+const CODE: &str = "operator-assignment";
+const ALWAYS_MESSAGE: &str =
+  "Assignment can be replaced with an operator assignment";
+const NEVER_HINT: &str =
+  "Use a plain assignment with the expanded expression, like `x = x + y`";
+
+const ALWAYS_LABEL: &str = "Use an operator assignment";
+const NEVER_LABEL: &str = "Use a plain assignment";
+
+fn always_hint(op: BinaryOp) -> String {
+  format!("Use `{}=` instead", op.as_str())
+}
+
+fn never_message(op: AssignOp) -> String {
+  format!("Unexpected operator assignment `{}`", op.as_str())
+}
+
+/// Whether operator assignments are required or disallowed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OperatorAssignmentMode {
+  /// Requires `x += y` over `x = x + y`.
+  Always,
+  /// Requires `x = x + y` over `x += y`.
+  Never,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OperatorAssignmentOptions {
+  pub mode: OperatorAssignmentMode,
+}
+
+impl Default for OperatorAssignmentOptions {
+  fn default() -> Self {
+    Self {
+      mode: OperatorAssignmentMode::Always,
+    }
+  }
+}
+
+impl OperatorAssignment {
+  pub fn with_options(options: OperatorAssignmentOptions) -> Box<Self> {
+    Box::new(OperatorAssignment { options })
+  }
+}
+
+impl LintRule for OperatorAssignment {
+  fn new() -> Box<Self> {
+    Self::with_options(OperatorAssignmentOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = OperatorAssignmentVisitor {
+      context,
+      mode: self.options.mode,
+    };
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces or disallows operator assignment shorthands
+
+Assignments like `x = x + y` can be shortened to `x += y`. By default (the
+`"always"` mode) this rule reports assignments which could use the shorthand.
+The `"never"` mode reports every operator assignment instead.
+
+Logical operators are not checked in the `"always"` mode, since `x = x || y`
+always assigns `x` while `x ||= y` only assigns it when `x` is falsy.
+
+### Invalid:
+```typescript
+x = x + 1;
+x = x * y;
+x = y * x;
+foo.bar = foo.bar << 2;
+```
+
+### Valid:
+```typescript
+x += 1;
+x = y - x;
+x = x || y;
+foo.bar = foo.baz + 1;
+```
+"#
+  }
+}
+
+struct OperatorAssignmentVisitor<'c> {
+  context: &'c mut Context,
+  mode: OperatorAssignmentMode,
+}
+
+impl<'c> OperatorAssignmentVisitor<'c> {
+  fn suggestion(
+    &self,
+    assign_expr: &AssignExpr,
+    label: &str,
+    new_text: Option<String>,
+  ) -> Option<Suggestion> {
+    self
+      .context
+      .text_edit(assign_expr.span, new_text?)
+      .map(|edit| Suggestion {
+        label: label.to_string(),
+        edit,
+      })
+  }
+
+  /// Returns `x += y` for `assign_expr` `x = x + y`, where `operand` is `y`.
+  fn shorthand(
+    &self,
+    assign_expr: &AssignExpr,
+    op: BinaryOp,
+    operand: &Expr,
+  ) -> Option<String> {
+    let target = self.context.snippet(assign_expr.left.span())?;
+    let operand = self.context.snippet(operand.span())?;
+    Some(format!("{} {}= {}", target, op.as_str(), operand))
+  }
+
+  /// Returns `x = x + y` for `assign_expr` `x += y`. Targets which may have
+  /// side effects, like `foo().bar`, would be evaluated twice, so they are
+  /// not expanded.
+  fn expanded(&self, assign_expr: &AssignExpr) -> Option<String> {
+    let op = binary_op(assign_expr.op)?;
+    let is_plain_target = match &assign_expr.left {
+      PatOrExpr::Expr(expr) => is_reference(expr),
+      PatOrExpr::Pat(pat) => match &**pat {
+        Pat::Ident(_) => true,
+        Pat::Expr(expr) => is_reference(expr),
+        _ => false,
+      },
+    };
+    if !is_plain_target {
+      return None;
+    }
+
+    let target = self.context.snippet(assign_expr.left.span())?;
+    let operand = self.context.snippet(assign_expr.right.span())?;
+    // `x *= a + b` multiplies by the whole right side.
+    let operand = match &*assign_expr.right {
+      Expr::Bin(_)
+      | Expr::Cond(_)
+      | Expr::Assign(_)
+      | Expr::Arrow(_)
+      | Expr::Yield(_)
+      | Expr::Seq(_) => format!("({})", operand),
+      _ => operand,
+    };
+    Some(format!(
+      "{} = {} {} {}",
+      target,
+      target,
+      op.as_str(),
+      operand
+    ))
+  }
+}
+
+impl<'c> VisitAll for OperatorAssignmentVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_assign_expr(
+    &mut self,
+    assign_expr: &AssignExpr,
+    _parent: &dyn Node,
+  ) {
+    match self.mode {
+      OperatorAssignmentMode::Always => {
+        if assign_expr.op != AssignOp::Assign {
+          return;
+        }
+        let bin_expr = match &*assign_expr.right {
+          Expr::Bin(bin_expr) if has_shorthand(bin_expr.op) => bin_expr,
+          _ => return,
+        };
+        let operand = if is_same_target(&assign_expr.left, &bin_expr.left) {
+          &bin_expr.right
+        } else if is_commutative(bin_expr.op)
+          && is_same_target(&assign_expr.left, &bin_expr.right)
+        {
+          &bin_expr.left
+        } else {
+          return;
+        };
+        let new_text = self.shorthand(assign_expr, bin_expr.op, operand);
+        let suggestions = self
+          .suggestion(assign_expr, ALWAYS_LABEL, new_text)
+          .into_iter()
+          .collect();
+        self.context.add_diagnostic_with_suggestions(
+          assign_expr.span,
+          CODE,
+          ALWAYS_MESSAGE,
+          Some(always_hint(bin_expr.op)),
+          suggestions,
+        );
+      }
+      OperatorAssignmentMode::Never => {
+        if assign_expr.op != AssignOp::Assign {
+          let new_text = self.expanded(assign_expr);
+          let suggestions = self
+            .suggestion(assign_expr, NEVER_LABEL, new_text)
+            .into_iter()
+            .collect();
+          self.context.add_diagnostic_with_suggestions(
+            assign_expr.span,
+            CODE,
+            never_message(assign_expr.op),
+            Some(NEVER_HINT.to_string()),
+            suggestions,
+          );
+        }
+      }
+    }
+  }
+}
+
+fn has_shorthand(op: BinaryOp) -> bool {
+  use BinaryOp::*;
+  matches!(
+    op,
+    Add
+      | Sub
+      | Mul
+      | Div
+      | Mod
+      | Exp
+      | LShift
+      | RShift
+      | ZeroFillRShift
+      | BitAnd
+      | BitOr
+      | BitXor
+  )
+}
+
+/// Returns the operator of an operator assignment. Logical assignments like
+/// `x ||= y` only assign conditionally, so they have no plain equivalent.
+fn binary_op(op: AssignOp) -> Option<BinaryOp> {
+  use AssignOp::*;
+  match op {
+    AddAssign => Some(BinaryOp::Add),
+    SubAssign => Some(BinaryOp::Sub),
+    MulAssign => Some(BinaryOp::Mul),
+    DivAssign => Some(BinaryOp::Div),
+    ModAssign => Some(BinaryOp::Mod),
+    ExpAssign => Some(BinaryOp::Exp),
+    LShiftAssign => Some(BinaryOp::LShift),
+    RShiftAssign => Some(BinaryOp::RShift),
+    ZeroFillRShiftAssign => Some(BinaryOp::ZeroFillRShift),
+    BitAndAssign => Some(BinaryOp::BitAnd),
+    BitOrAssign => Some(BinaryOp::BitOr),
+    BitXorAssign => Some(BinaryOp::BitXor),
+    Assign | AndAssign | OrAssign | NullishAssign => None,
+  }
+}
+
+fn is_commutative(op: BinaryOp) -> bool {
+  use BinaryOp::*;
+  matches!(op, Mul | BitAnd | BitOr | BitXor)
+}
+
+fn is_same_target(left: &PatOrExpr, expr: &Expr) -> bool {
+  match left {
+    PatOrExpr::Expr(left) => is_same_reference(left, expr),
+    PatOrExpr::Pat(pat) => match &**pat {
+      Pat::Ident(ident) => {
+        matches!(expr, Expr::Ident(other) if other.sym == ident.sym)
+      }
+      Pat::Expr(left) => is_same_reference(left, expr),
+      _ => false,
+    },
+  }
+}
+
+/// Checks if the expression refers to a variable or property without
+/// evaluating anything else, like `a`, `this.a` or `a.b["c"]`.
+fn is_reference(expr: &Expr) -> bool {
+  is_same_reference(expr, expr)
+}
+
+/// Checks if both expressions refer to the same variable or property without
+/// evaluating anything else, like `a`, `this.a` or `a.b["c"]`.
+fn is_same_reference(a: &Expr, b: &Expr) -> bool {
+  match (a, b) {
+    (Expr::Ident(a), Expr::Ident(b)) => a.sym == b.sym,
+    (Expr::This(_), Expr::This(_)) => true,
+    (Expr::Member(a), Expr::Member(b)) => {
+      let same_obj = match (&a.obj, &b.obj) {
+        (ExprOrSuper::Expr(a), ExprOrSuper::Expr(b)) => is_same_reference(a, b),
+        (ExprOrSuper::Super(_), ExprOrSuper::Super(_)) => true,
+        _ => false,
+      };
+      same_obj && is_same_property(a, b)
+    }
+    (Expr::Paren(a), _) => is_same_reference(&a.expr, b),
+    (_, Expr::Paren(b)) => is_same_reference(a, &b.expr),
+    _ => false,
+  }
+}
+
+fn is_same_property(a: &MemberExpr, b: &MemberExpr) -> bool {
+  match (a.computed, b.computed, &*a.prop, &*b.prop) {
+    (false, false, Expr::Ident(a), Expr::Ident(b)) => a.sym == b.sym,
+    (true, true, Expr::Lit(Lit::Str(a)), Expr::Lit(Lit::Str(b))) => {
+      a.value == b.value
+    }
+    (true, true, Expr::Lit(Lit::Num(a)), Expr::Lit(Lit::Num(b))) => {
+      a.value == b.value
+    }
+    _ => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn operator_assignment_valid() {
+    assert_lint_ok! {
+      OperatorAssignment,
+      "x = y;",
+      "x = y + x;",
+      "x = y - x;",
+      "x = y + 1;",
+      "x += 1;",
+      "x *= y;",
+      "x = x || y;",
+      "x = x && y;",
+      "x = x ?? y;",
+      "x = x === y;",
+      "foo.bar = foo.baz + 1;",
+      "foo.bar = bar.bar + 1;",
+      "foo[bar] = foo[bar] + 1;",
+      "foo().bar = foo().bar + 1;",
+      "x = (x + 1) * 2;",
+      "x = x - y - z;",
+      "let x = x + 1;",
+    };
+  }
+
+  #[test]
+  fn operator_assignment_invalid() {
+    assert_lint_err! {
+      OperatorAssignment,
+      "x = x + 1;": [
+        {
+          col: 0,
+          message: ALWAYS_MESSAGE,
+          hint: always_hint(BinaryOp::Add),
+        }
+      ],
+      "x = x * y;": [
+        {
+          col: 0,
+          message: ALWAYS_MESSAGE,
+          hint: always_hint(BinaryOp::Mul),
+        }
+      ],
+      "x = y * x;": [
+        {
+          col: 0,
+          message: ALWAYS_MESSAGE,
+          hint: always_hint(BinaryOp::Mul),
+        }
+      ],
+      "x = x ** 2;": [
+        {
+          col: 0,
+          message: ALWAYS_MESSAGE,
+          hint: always_hint(BinaryOp::Exp),
+        }
+      ],
+      "foo.bar = foo.bar << 2;": [
+        {
+          col: 0,
+          message: ALWAYS_MESSAGE,
+          hint: always_hint(BinaryOp::LShift),
+        }
+      ],
+      "this.a = this.a | b;": [
+        {
+          col: 0,
+          message: ALWAYS_MESSAGE,
+          hint: always_hint(BinaryOp::BitOr),
+        }
+      ],
+      r#"foo["bar"] = foo["bar"] / 2;"#: [
+        {
+          col: 0,
+          message: ALWAYS_MESSAGE,
+          hint: always_hint(BinaryOp::Div),
+        }
+      ],
+      "function f() { a.b.c = a.b.c % 2; }": [
+        {
+          col: 15,
+          message: ALWAYS_MESSAGE,
+          hint: always_hint(BinaryOp::Mod),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn operator_assignment_never() {
+    let rule = || {
+      OperatorAssignment::with_options(
+        serde_json::from_str(r#"{ "mode": "never" }"#).unwrap(),
+      )
+    };
+
+    assert_lint_ok_with_rule(rule(), "x = x + 1;");
+    assert_lint_ok_with_rule(rule(), "x = y;");
+    assert_lint_err_on_line_n_with_rule(rule(), "x += 1;", vec![(1, 0)]);
+    assert_lint_err_on_line_n_with_rule(rule(), "x *= y;", vec![(1, 0)]);
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "foo.bar >>>= 2;",
+      vec![(1, 0)],
+    );
+  }
+
+  #[test]
+  fn operator_assignment_suggestions() {
+    let cases = [
+      ("x = x + 1;", "x += 1;"),
+      ("x = y * x;", "x *= y;"),
+      ("foo.bar = foo.bar << 2;", "foo.bar <<= 2;"),
+      ("x = x - (y + z);", "x -= (y + z);"),
+    ];
+    for (source, expected) in cases.iter() {
+      assert_eq!(
+        apply_suggestions(OperatorAssignment::new(), source),
+        vec![*expected]
+      );
+    }
+
+    let never = || {
+      OperatorAssignment::with_options(
+        serde_json::from_str(r#"{ "mode": "never" }"#).unwrap(),
+      )
+    };
+    let cases = [
+      ("x += 1;", "x = x + 1;"),
+      ("x *= a + b;", "x = x * (a + b);"),
+      (
+        r#"this.a["b"] **= 2;"#,
+        r#"this.a["b"] = this.a["b"] ** 2;"#,
+      ),
+    ];
+    for (source, expected) in cases.iter() {
+      assert_eq!(apply_suggestions(never(), source), vec![*expected]);
+    }
+    // The target would be evaluated twice.
+    assert!(apply_suggestions(never(), "foo().bar += 1;").is_empty());
+    assert!(apply_suggestions(never(), "a[i++] -= 1;").is_empty());
+    assert!(apply_suggestions(never(), "x ||= y;").is_empty());
+  }
+}
@@ -0,0 +1,409 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_ecmascript::ast::{
+  CallExpr, ClassProp, Constructor, Expr, ExprOrSpread, ExprOrSuper, FnExpr,
+  Function, Ident, MemberExpr, MetaPropExpr, NewExpr, PrivateProp, Program,
+  Prop, ThisExpr,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::utils::Id;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct PreferArrowCallback {
+  options: PreferArrowCallbackOptions,
+}
+
+const CODE: &str = "prefer-arrow-callback";
+const MESSAGE: &str = "Unexpected function expression";
+const HINT: &str = "Use an arrow function instead";
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PreferArrowCallbackOptions {
+  /// Whether named function expressions are allowed as callbacks.
+  pub allow_named_functions: bool,
+  /// Whether callbacks using `this` without binding it are allowed.
+  pub allow_unbound_this: bool,
+}
+
+impl Default for PreferArrowCallbackOptions {
+  fn default() -> Self {
+    Self {
+      allow_named_functions: false,
+      allow_unbound_this: true,
+    }
+  }
+}
+
+impl PreferArrowCallback {
+  pub fn with_options(options: PreferArrowCallbackOptions) -> Box<Self> {
+    Box::new(PreferArrowCallback { options })
+  }
+}
+
+impl LintRule for PreferArrowCallback {
+  fn new() -> Box<Self> {
+    Self::with_options(PreferArrowCallbackOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = PreferArrowCallbackVisitor::new(context, &self.options);
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires arrow functions as callbacks
+
+Arrow functions are shorter than function expressions and bind `this`
+lexically, which is usually what a callback needs. This rule reports function
+expressions passed as arguments which could be replaced by an arrow function,
+i.e. ones which don't use `arguments`, `new.target` or their own name.
+
+Callbacks using `this` are only reported when they are bound with
+`.bind(this)`, unless the `allowUnboundThis` option is disabled. Named
+functions can be allowed with the `allowNamedFunctions` option. Generators are
+never reported.
+
+### Invalid:
+```typescript
+foo(function (a) {
+  return a;
+});
+
+foo(function () {
+  return this.a;
+}.bind(this));
+```
+
+### Valid:
+```typescript
+foo((a) => a);
+
+foo(function () {
+  return this.a;
+});
+
+foo(function bar(n) {
+  return n && bar(n - 1);
+});
+```
+"#
+  }
+}
+
+struct PreferArrowCallbackVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o PreferArrowCallbackOptions,
+}
+
+impl<'c, 'o> PreferArrowCallbackVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o PreferArrowCallbackOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn check_args(&mut self, args: &[ExprOrSpread]) {
+    for arg in args {
+      if arg.spread.is_some() {
+        continue;
+      }
+      match unwrap_paren(&arg.expr) {
+        Expr::Fn(fn_expr) => self.check_callback(fn_expr, false),
+        Expr::Call(call_expr) => {
+          if let Some(fn_expr) = bound_function(call_expr) {
+            let is_lexical_this = matches!(
+              call_expr.args.as_slice(),
+              [arg] if arg.spread.is_none() && matches!(&*arg.expr, Expr::This(_))
+            );
+            self.check_callback(fn_expr, is_lexical_this);
+          }
+        }
+        _ => {}
+      }
+    }
+  }
+
+  fn check_callback(&mut self, fn_expr: &FnExpr, is_lexical_this: bool) {
+    if fn_expr.function.is_generator {
+      return;
+    }
+    if self.options.allow_named_functions && fn_expr.ident.is_some() {
+      return;
+    }
+
+    let mut usage = FunctionUsage {
+      context: self.context,
+      name: fn_expr.ident.as_ref().map(|ident| ident.to_id()),
+      depth: 0,
+      uses_this: false,
+      uses_arguments: false,
+      uses_meta: false,
+      uses_name: false,
+    };
+    fn_expr.function.visit_children_with(&mut usage);
+
+    if usage.uses_arguments || usage.uses_meta || usage.uses_name {
+      return;
+    }
+    if usage.uses_this && self.options.allow_unbound_this && !is_lexical_this {
+      return;
+    }
+
+    self.context.add_diagnostic_with_hint(
+      fn_expr.function.span,
+      CODE,
+      MESSAGE,
+      HINT,
+    );
+  }
+}
+
+fn unwrap_paren(expr: &Expr) -> &Expr {
+  match expr {
+    Expr::Paren(paren) => unwrap_paren(&paren.expr),
+    _ => expr,
+  }
+}
+
+/// Returns the function of a `function () {}.bind(...)` call.
+fn bound_function(call_expr: &CallExpr) -> Option<&FnExpr> {
+  let member = match &call_expr.callee {
+    ExprOrSuper::Expr(callee) => match unwrap_paren(callee) {
+      Expr::Member(member) => member,
+      _ => return None,
+    },
+    ExprOrSuper::Super(_) => return None,
+  };
+  if member.computed {
+    return None;
+  }
+  match &*member.prop {
+    Expr::Ident(ident) if ident.sym == *"bind" => {}
+    _ => return None,
+  }
+  match &member.obj {
+    ExprOrSuper::Expr(obj) => match unwrap_paren(obj) {
+      Expr::Fn(fn_expr) => Some(fn_expr),
+      _ => None,
+    },
+    ExprOrSuper::Super(_) => None,
+  }
+}
+
+impl<'c, 'o> VisitAll for PreferArrowCallbackVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    self.check_args(&call_expr.args);
+  }
+
+  fn visit_new_expr(&mut self, new_expr: &NewExpr, _parent: &dyn Node) {
+    if let Some(args) = &new_expr.args {
+      self.check_args(args);
+    }
+  }
+}
+
+/// Collects what a function uses that an arrow function would change.
+struct FunctionUsage<'c> {
+  context: &'c Context,
+  name: Option<Id>,
+  /// Number of nested functions with their own `this` the visitor is in.
+  depth: usize,
+  uses_this: bool,
+  uses_arguments: bool,
+  uses_meta: bool,
+  uses_name: bool,
+}
+
+impl<'c> FunctionUsage<'c> {
+  fn nested<F>(&mut self, op: F)
+  where
+    F: FnOnce(&mut Self),
+  {
+    self.depth += 1;
+    op(self);
+    self.depth -= 1;
+  }
+
+  fn check_ident(&mut self, ident: &Ident) {
+    let id = ident.to_id();
+    if self.name.as_ref() == Some(&id) {
+      self.uses_name = true;
+    }
+    if self.depth == 0
+      && ident.sym == *"arguments"
+      && self.context.scope.var(&id).is_none()
+    {
+      self.uses_arguments = true;
+    }
+  }
+}
+
+impl<'c> Visit for FunctionUsage<'c> {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
+    self.nested(|v| function.visit_children_with(v));
+  }
+
+  fn visit_constructor(
+    &mut self,
+    constructor: &Constructor,
+    _parent: &dyn Node,
+  ) {
+    self.nested(|v| constructor.visit_children_with(v));
+  }
+
+  fn visit_class_prop(&mut self, class_prop: &ClassProp, _parent: &dyn Node) {
+    self.nested(|v| class_prop.visit_children_with(v));
+  }
+
+  fn visit_private_prop(
+    &mut self,
+    private_prop: &PrivateProp,
+    _parent: &dyn Node,
+  ) {
+    self.nested(|v| private_prop.visit_children_with(v));
+  }
+
+  fn visit_this_expr(&mut self, _this_expr: &ThisExpr, _parent: &dyn Node) {
+    if self.depth == 0 {
+      self.uses_this = true;
+    }
+  }
+
+  fn visit_meta_prop_expr(
+    &mut self,
+    meta_prop_expr: &MetaPropExpr,
+    _parent: &dyn Node,
+  ) {
+    if self.depth == 0 && meta_prop_expr.meta.sym == *"new" {
+      self.uses_meta = true;
+    }
+  }
+
+  fn visit_expr(&mut self, expr: &Expr, _parent: &dyn Node) {
+    if let Expr::Ident(ident) = expr {
+      self.check_ident(ident);
+    }
+    expr.visit_children_with(self);
+  }
+
+  fn visit_prop(&mut self, prop: &Prop, _parent: &dyn Node) {
+    if let Prop::Shorthand(ident) = prop {
+      self.check_ident(ident);
+    }
+    prop.visit_children_with(self);
+  }
+
+  fn visit_member_expr(
+    &mut self,
+    member_expr: &MemberExpr,
+    _parent: &dyn Node,
+  ) {
+    member_expr.obj.visit_with(member_expr, self);
+    if member_expr.computed {
+      member_expr.prop.visit_with(member_expr, self);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn prefer_arrow_callback_valid() {
+    assert_lint_ok! {
+      PreferArrowCallback,
+      "foo(a => a);",
+      "foo(() => this.a);",
+      "foo(function() { return this.a; });",
+      "foo(function() { return arguments; });",
+      "foo(function() { return new.target; });",
+      "foo(function bar(n) { return n && bar(n - 1); });",
+      "foo(function bar() { return () => bar; });",
+      "foo(function*() {});",
+      "foo(function() { this.a; }.bind(that));",
+      "foo(function() { this.a; }.bind(this, 1));",
+      "const f = function() {};",
+      "foo(bar);",
+      "foo(function() { const arguments = 1; return () => arguments + this.a; });",
+      "foo(function() { return () => this.a; });",
+    };
+  }
+
+  #[test]
+  fn prefer_arrow_callback_invalid() {
+    assert_lint_err! {
+      PreferArrowCallback,
+      "foo(function() {});": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "foo(function(a) { return a; });": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "foo(function bar() {});": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "foo(a, (function() {}));": [{ col: 8, message: MESSAGE, hint: HINT }],
+      "new Foo(function() {});": [{ col: 8, message: MESSAGE, hint: HINT }],
+      "foo(function() { this.a; }.bind(this));": [
+        { col: 4, message: MESSAGE, hint: HINT }
+      ],
+      "foo(function() { bar(); }.bind(that));": [
+        { col: 4, message: MESSAGE, hint: HINT }
+      ],
+      "foo(function() { return function() { return this.a + arguments[0]; }; });": [
+        { col: 4, message: MESSAGE, hint: HINT }
+      ],
+      "foo(function() { const arguments = []; return arguments; });": [
+        { col: 4, message: MESSAGE, hint: HINT }
+      ],
+      "foo(function() { return { bar() { return this; } }; });": [
+        { col: 4, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+
+  #[test]
+  fn prefer_arrow_callback_options() {
+    let named = || {
+      PreferArrowCallback::with_options(PreferArrowCallbackOptions {
+        allow_named_functions: true,
+        ..Default::default()
+      })
+    };
+    assert_lint_ok_with_rule(named(), "foo(function bar() {});");
+    assert_lint_err_on_line_n_with_rule(
+      named(),
+      "foo(function() {});",
+      vec![(1, 4)],
+    );
+
+    let unbound_this = || {
+      PreferArrowCallback::with_options(PreferArrowCallbackOptions {
+        allow_unbound_this: false,
+        ..Default::default()
+      })
+    };
+    assert_lint_ok_with_rule(
+      unbound_this(),
+      "foo(function() { return arguments; });",
+    );
+    assert_lint_err_on_line_n_with_rule(
+      unbound_this(),
+      "foo(function() { return this.a; });",
+      vec![(1, 4)],
+    );
+  }
+}
@@ -1,16 +1,24 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
-
+use swc_common::Span;
 use swc_ecmascript::ast::{
-  ArrayPat, Expr, Ident, Lit, ObjectPat, Pat, TsAsExpr, TsLit, TsType,
-  TsTypeAssertion, VarDecl,
+  ArrayPat, Expr, Ident, Lit, ObjectPat, Pat, Program, TsAsExpr, TsLit, TsType,
+  TsTypeAnn, TsTypeAssertion, VarDecl,
 };
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
 
 pub struct PreferAsConst;
 
+const CODE: &str = "prefer-as-const";
+const ASSERTION_MESSAGE: &str =
+  "Expected a `const` assertion instead of a literal type assertion";
+const ANNOTATION_MESSAGE: &str =
+  "Expected a `const` assertion instead of a literal type annotation";
+const HINT: &str = "Use `as const` instead of repeating the literal as a type";
+
 impl LintRule for PreferAsConst {
   fn new() -> Box<Self> {
     Box::new(PreferAsConst)
@@ -21,17 +29,42 @@ impl LintRule for PreferAsConst {
   }
 
   fn code(&self) -> &'static str {
-    "prefer-as-const"
+    CODE
   }
 
-  fn lint_program(
-    &self,
-    context: &mut Context,
-    program: &swc_ecmascript::ast::Program,
-  ) {
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
     let mut visitor = PreferAsConstVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Recommends using `as const` over literal types which repeat the value
+
+A literal type which is the same as the value it is applied to repeats the
+value, and both have to be kept in sync. A `const` assertion narrows the type
+to the literal in the same way without the repetition.
+
+### Invalid:
+```typescript
+let a = 2 as 2;
+let b = <"foo">"foo";
+let c = { bar: "baz" as "baz" };
+let d: "foo" = "foo";
+```
+
+### Valid:
+```typescript
+let a = 2 as const;
+let b = "foo" as const;
+let c = { bar: "baz" as const };
+let d: string = "foo";
+```
+"#
+  }
 }
 
 struct PreferAsConstVisitor<'c> {
@@ -43,44 +76,45 @@ impl<'c> PreferAsConstVisitor<'c> {
     Self { context }
   }
 
-  fn add_diagnostic_helper(&mut self, span: swc_common::Span) {
-    self.context.add_diagnostic(
-      span,
-      "prefer-as-const",
-      "strict equality between type and value is not allowed",
-    );
-  }
-
-  fn compare(
+  fn check(
     &mut self,
     type_ann: &TsType,
     expr: &Expr,
-    span: swc_common::Span,
+    span: Span,
+    message: &str,
   ) {
-    if let TsType::TsLitType(lit_type) = &*type_ann {
-      if let Expr::Lit(expr_lit) = &*expr {
-        match (expr_lit, &lit_type.lit) {
-          (Lit::Str(value_literal), TsLit::Str(type_literal)) => {
-            if value_literal.value == type_literal.value {
-              self.add_diagnostic_helper(span)
-            }
-          }
-          (Lit::Num(value_literal), TsLit::Number(type_literal)) => {
-            let error = 0.01f64;
-            if (value_literal.value - type_literal.value).abs() < error {
-              self.add_diagnostic_helper(span)
-            }
-          }
-          _ => return,
-        }
-      }
+    if is_same_literal(type_ann, expr) {
+      self
+        .context
+        .add_diagnostic_with_hint(span, CODE, message, HINT);
+    }
+  }
+}
+
+/// Checks if the type is a literal type of the value of the expression.
+fn is_same_literal(type_ann: &TsType, expr: &Expr) -> bool {
+  let lit_type = match type_ann {
+    TsType::TsLitType(lit_type) => lit_type,
+    _ => return false,
+  };
+  match (expr, &lit_type.lit) {
+    (Expr::Lit(Lit::Str(value)), TsLit::Str(ty)) => value.value == ty.value,
+    (Expr::Lit(Lit::Num(value)), TsLit::Number(ty)) => {
+      value.value.to_bits() == ty.value.to_bits()
     }
+    _ => false,
   }
 }
 
 impl<'c> Visit for PreferAsConstVisitor<'c> {
   fn visit_ts_as_expr(&mut self, as_expr: &TsAsExpr, _parent: &dyn Node) {
-    self.compare(&as_expr.type_ann, &as_expr.expr, as_expr.span);
+    self.check(
+      &as_expr.type_ann,
+      &as_expr.expr,
+      as_expr.span,
+      ASSERTION_MESSAGE,
+    );
+    as_expr.expr.visit_with(as_expr, self);
   }
 
   fn visit_ts_type_assertion(
@@ -88,41 +122,29 @@ impl<'c> Visit for PreferAsConstVisitor<'c> {
     type_assertion: &TsTypeAssertion,
     _parent: &dyn Node,
   ) {
-    self.compare(
+    self.check(
       &type_assertion.type_ann,
       &type_assertion.expr,
       type_assertion.span,
+      ASSERTION_MESSAGE,
     );
+    type_assertion.expr.visit_with(type_assertion, self);
   }
 
   fn visit_var_decl(&mut self, var_decl: &VarDecl, _parent: &dyn Node) {
-    if let Some(init) = &var_decl.decls[0].init {
-      match &**init {
-        Expr::TsAs(as_expr) => {
-          self.visit_ts_as_expr(&as_expr, _parent);
-          return;
-        }
-        Expr::Object(object) => {
-          self.visit_object_lit(&object, _parent);
-          return;
-        }
-        Expr::TsTypeAssertion(type_assert) => {
-          self.visit_ts_type_assertion(&type_assert, _parent);
-          return;
-        }
-        _ => {}
-      }
-
-      if let Pat::Array(ArrayPat { type_ann, .. })
-      | Pat::Object(ObjectPat { type_ann, .. })
-      | Pat::Ident(Ident { type_ann, .. }) = &var_decl.decls[0].name
-      {
-        if let Some(swc_ecmascript::ast::TsTypeAnn { type_ann, .. }) = &type_ann
+    for decl in &var_decl.decls {
+      if let Some(init) = &decl.init {
+        if let Pat::Array(ArrayPat { type_ann, .. })
+        | Pat::Object(ObjectPat { type_ann, .. })
+        | Pat::Ident(Ident { type_ann, .. }) = &decl.name
         {
-          self.compare(type_ann, &init, var_decl.span);
+          if let Some(TsTypeAnn { type_ann, .. }) = &type_ann {
+            self.check(type_ann, init, var_decl.span, ANNOTATION_MESSAGE);
+          }
         }
       }
     }
+    var_decl.visit_children_with(self);
   }
 }
 
@@ -183,4 +205,36 @@ mod tests {
     assert_lint_err::<PreferAsConst>(r#"let foo = "bar" as "bar";"#, 10);
     assert_lint_err::<PreferAsConst>(r#"let foo = 5 as 5;"#, 10);
   }
+
+  #[test]
+  fn prefer_as_const_messages() {
+    assert_lint_ok! {
+      PreferAsConst,
+      "let foo = 5 as 5.001;",
+      "let foo = 0.1 as 0.2;",
+      r#"let foo = "bar" as "baz";"#,
+    };
+
+    assert_lint_err! {
+      PreferAsConst,
+      "let foo = 1.5 as 1.5;": [
+        { col: 10, message: ASSERTION_MESSAGE, hint: HINT }
+      ],
+      r#"let foo: "bar" = "bar";"#: [
+        { col: 0, message: ANNOTATION_MESSAGE, hint: HINT }
+      ],
+      r#"let foo = [{ bar: "baz" as "baz" }];"#: [
+        { col: 18, message: ASSERTION_MESSAGE, hint: HINT }
+      ],
+      r#"function foo() { return { bar: 1 as 1 }; }"#: [
+        { col: 31, message: ASSERTION_MESSAGE, hint: HINT }
+      ],
+      r#"function foo() { let a = 1, b: 2 = 2; }"#: [
+        { col: 17, message: ANNOTATION_MESSAGE, hint: HINT }
+      ],
+      r#"foo(<"bar">"bar");"#: [
+        { col: 4, message: ASSERTION_MESSAGE, hint: HINT }
+      ],
+    };
+  }
 }
@@ -45,6 +45,37 @@ impl LintRule for PreferConst {
       PreferConstVisitor::new(context, mem::take(&mut collector.scopes));
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires `const` declarations for variables which are never reassigned
+
+Declaring a variable with `const` makes it clear to the reader that it will
+never be reassigned, and prevents it from being reassigned by accident.
+
+### Invalid:
+```typescript
+let a = 1;
+console.log(a);
+
+for (let b of items) {
+  console.log(b);
+}
+```
+
+### Valid:
+```typescript
+const a = 1;
+console.log(a);
+
+let b = 0;
+b += 1;
+
+for (const c of items) {
+  console.log(c);
+}
+```
+"#
+  }
 }
 
 #[derive(Debug, Clone, Copy)]
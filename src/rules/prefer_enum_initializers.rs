@@ -0,0 +1,179 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_ecmascript::ast::{Expr, Lit, Program, TsEnumDecl, TsEnumMemberId};
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct PreferEnumInitializers;
+
+const CODE: &str = "prefer-enum-initializers";
+
+impl LintRule for PreferEnumInitializers {
+  fn new() -> Box<Self> {
+    Box::new(PreferEnumInitializers)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = PreferEnumInitializersVisitor::new(context);
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires enum members to be initialized explicitly
+
+The value of an enum member without an initializer depends on its position in
+the enum. Adding, removing or reordering members then silently changes the
+values of the members after it, which breaks values that have been stored or
+sent elsewhere.
+
+### Invalid:
+```typescript
+enum Status {
+  Open,
+  Closed,
+}
+
+enum Direction {
+  Up = 1,
+  Down,
+}
+```
+
+### Valid:
+```typescript
+enum Status {
+  Open = 0,
+  Closed = 1,
+}
+
+enum Direction {
+  Up = "Up",
+  Down = "Down",
+}
+```
+"#
+  }
+}
+
+struct PreferEnumInitializersVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> PreferEnumInitializersVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c> VisitAll for PreferEnumInitializersVisitor<'c> {
+  fn visit_ts_enum_decl(&mut self, enum_decl: &TsEnumDecl, _parent: &dyn Node) {
+    // The value the next member without an initializer gets, if it is known.
+    let mut next_value = Some(0.0);
+    for member in &enum_decl.members {
+      if let Some(init) = &member.init {
+        next_value = match &**init {
+          Expr::Lit(Lit::Num(num)) => Some(num.value + 1.0),
+          _ => None,
+        };
+        continue;
+      }
+
+      let name = match &member.id {
+        TsEnumMemberId::Ident(ident) => ident.sym.to_string(),
+        TsEnumMemberId::Str(str_) => str_.value.to_string(),
+      };
+      let value = match next_value {
+        Some(value) => value.to_string(),
+        None => format!("\"{}\"", name),
+      };
+      next_value = next_value.map(|value| value + 1.0);
+
+      self.context.add_diagnostic_with_hint(
+        member.span,
+        CODE,
+        format!(
+          "The value of the member '{}' should be explicitly defined",
+          name
+        ),
+        format!(
+          "Initialize the member explicitly, e.g. `{} = {}`",
+          member_key(&member.id),
+          value
+        ),
+      );
+    }
+  }
+}
+
+fn member_key(id: &TsEnumMemberId) -> String {
+  match id {
+    TsEnumMemberId::Ident(ident) => ident.sym.to_string(),
+    TsEnumMemberId::Str(str_) => format!("\"{}\"", str_.value),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn message(name: &str) -> String {
+    format!(
+      "The value of the member '{}' should be explicitly defined",
+      name
+    )
+  }
+
+  fn hint(member: &str) -> String {
+    format!("Initialize the member explicitly, e.g. `{}`", member)
+  }
+
+  #[test]
+  fn prefer_enum_initializers_valid() {
+    assert_lint_ok! {
+      PreferEnumInitializers,
+      "enum E {}",
+      "enum E { A = 0, B = 1 }",
+      r#"enum E { A = "a", B = "b" }"#,
+      "enum E { A = 1 << 0, B = 1 << 1, C = A | B }",
+      "const enum E { A = 1 }",
+      "declare enum E { A = foo() }",
+    };
+  }
+
+  #[test]
+  fn prefer_enum_initializers_invalid() {
+    assert_lint_err! {
+      PreferEnumInitializers,
+      "enum E { A }": [
+        { col: 9, message: message("A"), hint: hint("A = 0") }
+      ],
+      "enum E { A, B }": [
+        { col: 9, message: message("A"), hint: hint("A = 0") },
+        { col: 12, message: message("B"), hint: hint("B = 1") }
+      ],
+      "enum E { A = 5, B, C }": [
+        { col: 16, message: message("B"), hint: hint("B = 6") },
+        { col: 19, message: message("C"), hint: hint("C = 7") }
+      ],
+      r#"enum E { A = "a", B }"#: [
+        { col: 18, message: message("B"), hint: hint(r#"B = "B""#) }
+      ],
+      r#"enum E { A = foo(), "b c" }"#: [
+        { col: 20, message: message("b c"), hint: hint(r#""b c" = "b c""#) }
+      ],
+      "function foo() { enum E { A = 0, B } }": [
+        { col: 33, message: message("B"), hint: hint("B = 1") }
+      ],
+    };
+  }
+}
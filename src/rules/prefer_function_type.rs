@@ -0,0 +1,248 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{
+  Program, TsCallSignatureDecl, TsEntityName, TsInterfaceDecl, TsTypeElement,
+  TsTypeLit,
+};
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct PreferFunctionType;
+
+const CODE: &str = "prefer-function-type";
+const INTERFACE_MESSAGE: &str =
+  "Interface only has a call signature, use a function type instead";
+const TYPE_LIT_MESSAGE: &str =
+  "Type literal only has a call signature, use a function type instead";
+
+impl LintRule for PreferFunctionType {
+  fn new() -> Box<Self> {
+    Box::new(PreferFunctionType)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = PreferFunctionTypeVisitor::new(context);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Recommends using function types instead of interfaces with a call signature
+
+An interface or an object type literal whose only member is a call signature
+describes a function, which is more clearly expressed with a function type.
+
+Interfaces which extend other types are not reported, unless they only extend
+`Function`.
+
+### Invalid:
+```typescript
+interface Foo {
+  (): void;
+}
+
+type Bar = { (a: string): number };
+```
+
+### Valid:
+```typescript
+type Foo = () => void;
+
+type Bar = (a: string) => number;
+
+interface Baz {
+  (): void;
+  prop: string;
+}
+```
+"#
+  }
+}
+
+struct PreferFunctionTypeVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> PreferFunctionTypeVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  fn snippet(&self, span: Span) -> String {
+    self.context.snippet(span).unwrap_or_default()
+  }
+
+  /// Returns the function type equivalent to the call signature.
+  fn function_type(&self, signature: &TsCallSignatureDecl) -> String {
+    match &signature.type_ann {
+      Some(type_ann) => {
+        let params = self.snippet(signature.span.with_hi(type_ann.span.lo));
+        format!(
+          "{} => {}",
+          params.trim_end(),
+          self.snippet(type_ann.type_ann.span())
+        )
+      }
+      None => {
+        let params = self.snippet(signature.span);
+        format!(
+          "{} => any",
+          params.trim_end_matches(&[';', ','][..]).trim_end()
+        )
+      }
+    }
+  }
+}
+
+/// Returns the call signature if it is the only member of the type.
+fn single_call_signature(
+  members: &[TsTypeElement],
+) -> Option<&TsCallSignatureDecl> {
+  match members {
+    [TsTypeElement::TsCallSignatureDecl(signature)] => Some(signature),
+    _ => None,
+  }
+}
+
+fn only_extends_function(interface_decl: &TsInterfaceDecl) -> bool {
+  match interface_decl.extends.as_slice() {
+    [] => true,
+    [heritage] => matches!(
+      &heritage.expr,
+      TsEntityName::Ident(ident) if ident.sym == *"Function"
+    ),
+    _ => false,
+  }
+}
+
+impl<'c> Visit for PreferFunctionTypeVisitor<'c> {
+  fn visit_ts_interface_decl(
+    &mut self,
+    interface_decl: &TsInterfaceDecl,
+    _parent: &dyn Node,
+  ) {
+    if let Some(signature) = single_call_signature(&interface_decl.body.body) {
+      if only_extends_function(interface_decl) {
+        let type_params = interface_decl
+          .type_params
+          .as_ref()
+          .map(|type_params| self.snippet(type_params.span))
+          .unwrap_or_default();
+        let hint = format!(
+          "Use `type {}{} = {}` instead",
+          interface_decl.id.sym,
+          type_params,
+          self.function_type(signature)
+        );
+        self.context.add_diagnostic_with_hint(
+          signature.span,
+          CODE,
+          INTERFACE_MESSAGE,
+          hint,
+        );
+      }
+    }
+    interface_decl.visit_children_with(self);
+  }
+
+  fn visit_ts_type_lit(&mut self, type_lit: &TsTypeLit, _parent: &dyn Node) {
+    if let Some(signature) = single_call_signature(&type_lit.members) {
+      let hint = format!("Use `{}` instead", self.function_type(signature));
+      self.context.add_diagnostic_with_hint(
+        signature.span,
+        CODE,
+        TYPE_LIT_MESSAGE,
+        hint,
+      );
+    }
+    type_lit.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_function_type_valid() {
+    assert_lint_ok! {
+      PreferFunctionType,
+      "type Foo = () => void;",
+      "interface Foo { (): void; bar: string; }",
+      "interface Foo { (): void; (a: string): void; }",
+      "interface Foo { new (): Foo; }",
+      "interface Foo extends Bar { (): void; }",
+      "interface Foo extends Function, Bar { (): void; }",
+      "interface Foo {}",
+      "type Foo = { (): void; bar: string };",
+      "type Foo = { bar(): void };",
+      "let foo: { new (): Foo };",
+    };
+  }
+
+  #[test]
+  fn prefer_function_type_invalid() {
+    assert_lint_err! {
+      PreferFunctionType,
+      "interface Foo { (): void; }": [
+        {
+          col: 16,
+          message: INTERFACE_MESSAGE,
+          hint: "Use `type Foo = () => void` instead",
+        }
+      ],
+      "interface Foo<T> extends Function { (a: T, b?: string): T }": [
+        {
+          col: 36,
+          message: INTERFACE_MESSAGE,
+          hint: "Use `type Foo<T> = (a: T, b?: string) => T` instead",
+        }
+      ],
+      "export interface Foo { <T>(a: T): string | T; }": [
+        {
+          col: 23,
+          message: INTERFACE_MESSAGE,
+          hint: "Use `type Foo = <T>(a: T) => string | T` instead",
+        }
+      ],
+      "type Foo = { (a: string): void };": [
+        {
+          col: 13,
+          message: TYPE_LIT_MESSAGE,
+          hint: "Use `(a: string) => void` instead",
+        }
+      ],
+      "function foo(bar: { (): number }) {}": [
+        {
+          col: 20,
+          message: TYPE_LIT_MESSAGE,
+          hint: "Use `() => number` instead",
+        }
+      ],
+      "let foo: { (...args: any[]) };": [
+        {
+          col: 11,
+          message: TYPE_LIT_MESSAGE,
+          hint: "Use `(...args: any[]) => any` instead",
+        }
+      ],
+      "interface Foo { bar: { (): void } }": [
+        {
+          col: 23,
+          message: TYPE_LIT_MESSAGE,
+          hint: "Use `() => void` instead",
+        }
+      ],
+    };
+  }
+}
@@ -11,6 +11,7 @@ pub struct PreferNamespaceKeyword;
 
 const CODE: &str = "prefer-namespace-keyword";
 const MESSAGE: &str = "`module` keyword in module decleration is not allowed";
+const HINT: &str = "Replace the `module` keyword with `namespace`";
 
 impl LintRule for PreferNamespaceKeyword {
   fn new() -> Box<Self> {
@@ -25,6 +26,10 @@ impl LintRule for PreferNamespaceKeyword {
     CODE
   }
 
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
   fn lint_program(
     &self,
     context: &mut Context,
@@ -33,6 +38,32 @@ impl LintRule for PreferNamespaceKeyword {
     let mut visitor = PreferNamespaceKeywordVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Recommends the use of `namespace` keyword over `module` keyword when declaring TypeScript module
+
+TypeScript supports the `module` keyword for organizing code, but this wording
+can lead to a confusion with the ECMAScript's module. Since TypeScript v1.5, it
+has provided us with the alternative keyword `namespace`, encouraging us to
+always use `namespace` instead whenever we write TypeScript these days.
+Declarations of external modules with a string name, like
+`declare module "foo" {}`, are not affected.
+
+### Invalid:
+```typescript
+module foo {}
+declare module bar {}
+```
+
+### Valid:
+```typescript
+namespace foo {}
+declare namespace bar {}
+declare module "baz" {}
+declare global {}
+```
+"#
+  }
 }
 
 struct PreferNamespaceKeywordVisitor<'c> {
@@ -54,19 +85,21 @@ impl<'c> Visit for PreferNamespaceKeywordVisitor<'c> {
     if let TsModuleName::Str(_) = &mod_decl.id {
       return;
     }
-    static KEYWORD: Lazy<Regex> =
-      Lazy::new(|| Regex::new(r"(declare\s)?(?P<keyword>\w+)").unwrap());
-
-    let snippet = self
-      .context
-      .source_map
-      .span_to_snippet(mod_decl.span)
-      .expect("error in load snippet");
-
-    if let Some(capt) = KEYWORD.captures(&snippet) {
-      let keyword = capt.name("keyword").unwrap().as_str();
-      if keyword == "module" && !mod_decl.global {
-        self.context.add_diagnostic(mod_decl.span, CODE, MESSAGE)
+    static KEYWORD: Lazy<Regex> = Lazy::new(|| {
+      Regex::new(r"^(export\s+)?(declare\s+)?(?P<keyword>\w+)").unwrap()
+    });
+
+    if let Some(snippet) = self.context.snippet(mod_decl.span) {
+      if let Some(capt) = KEYWORD.captures(&snippet) {
+        let keyword = capt.name("keyword").unwrap().as_str();
+        if keyword == "module" && !mod_decl.global {
+          self.context.add_diagnostic_with_hint(
+            mod_decl.span,
+            CODE,
+            MESSAGE,
+            HINT,
+          )
+        }
       }
     }
     for stmt in &mod_decl.body {
@@ -88,6 +121,9 @@ mod tests {
       "namespace foo {}",
       "declare namespace foo {}",
       "declare global {}",
+      "export namespace foo {}",
+      "export declare namespace foo.bar {}",
+      "declare module 'foo' { namespace bar {} }",
     };
   }
 
@@ -95,11 +131,21 @@ mod tests {
   fn prefer_namespace_keyword_invalid() {
     assert_lint_err! {
       PreferNamespaceKeyword,
-      r#"module foo {}"#: [{ col: 0, message: MESSAGE }],
+      r#"module foo {}"#: [{ col: 0, message: MESSAGE, hint: HINT }],
       r#"
       declare module foo {
         declare module bar {}
-      }"#: [{ line: 2, col: 6, message: MESSAGE}, { line: 3, col: 8, message: MESSAGE }],
+      }"#: [
+        { line: 2, col: 6, message: MESSAGE, hint: HINT },
+        { line: 3, col: 8, message: MESSAGE, hint: HINT }
+      ],
+      r#"export module foo {}"#: [{ col: 7, message: MESSAGE, hint: HINT }],
+      r#"export declare module foo.bar {}"#: [
+        { col: 7, message: MESSAGE, hint: HINT }
+      ],
+      r#"namespace foo { module bar {} }"#: [
+        { col: 16, message: MESSAGE, hint: HINT }
+      ],
     }
   }
 }
@@ -0,0 +1,460 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::Suggestion;
+use serde::Deserialize;
+use swc_common::Spanned;
+use swc_ecmascript::ast::{
+  BinExpr, BinaryOp, CondExpr, DoWhileStmt, Expr, ExprOrSuper, ForStmt, IfStmt,
+  Lit, ParenExpr, Program, UnaryExpr, UnaryOp, WhileStmt,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct PreferNullishCoalescing {
+  options: PreferNullishCoalescingOptions,
+}
+
+const CODE: &str = "prefer-nullish-coalescing";
+const MESSAGE: &str =
+  "Prefer the nullish coalescing operator (`??`) over a logical or (`||`)";
+const HINT: &str = "Use `??` instead, unless falsy values like `0` or `\"\"` should be replaced as well";
+const LABEL: &str = "Replace `||` with `??`";
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PreferNullishCoalescingOptions {
+  /// Ignores `||` in the condition of an `if`, a loop or a conditional
+  /// expression. Defaults to `true`.
+  pub ignore_conditional_tests: bool,
+  /// Ignores `||` which is mixed with `&&` in the same logical expression.
+  /// Defaults to `false`.
+  pub ignore_mixed_logical_expressions: bool,
+}
+
+impl Default for PreferNullishCoalescingOptions {
+  fn default() -> Self {
+    Self {
+      ignore_conditional_tests: true,
+      ignore_mixed_logical_expressions: false,
+    }
+  }
+}
+
+impl PreferNullishCoalescing {
+  pub fn with_options(options: PreferNullishCoalescingOptions) -> Box<Self> {
+    Box::new(PreferNullishCoalescing { options })
+  }
+}
+
+impl LintRule for PreferNullishCoalescing {
+  fn new() -> Box<Self> {
+    Self::with_options(PreferNullishCoalescingOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = PreferNullishCoalescingVisitor {
+      context,
+      options: &self.options,
+    };
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires `??` instead of `||` for default values
+
+`a || b` evaluates to `b` for every falsy `a`, including `0`, `""` and `false`.
+When only `null` and `undefined` should be replaced by a default value, the
+nullish coalescing operator `a ?? b` is the safer choice.
+
+Without type information this rule relies on a heuristic: a `||` is reported
+unless its left operand is obviously a boolean, like a comparison, a negation
+or a `Boolean()` call.
+
+By default `||` in the condition of an `if`, a loop or a conditional expression
+is not reported, which can be changed with `ignoreConditionalTests`. Logical
+expressions which mix `||` and `&&` can be ignored with
+`ignoreMixedLogicalExpressions`.
+
+### Invalid:
+```typescript
+const name = options.name || "default";
+function f(timeout) {
+  return timeout || 1000;
+}
+```
+
+### Valid:
+```typescript
+const name = options.name ?? "default";
+const isEmpty = a === 0 || b === 0;
+const isInvalid = !a || !b;
+if (a || b) {}
+```
+"#
+  }
+}
+
+struct PreferNullishCoalescingVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o PreferNullishCoalescingOptions,
+}
+
+impl<'c, 'o> PreferNullishCoalescingVisitor<'c, 'o> {
+  /// Checks a tree of logical expressions, i.e. a logical expression and all
+  /// the logical expressions directly nested in it.
+  fn check_logical_tree(&mut self, root: &BinExpr, is_conditional_test: bool) {
+    let mut tree = LogicalTree::default();
+    tree.collect(root, false);
+
+    let ignored = (is_conditional_test
+      && self.options.ignore_conditional_tests)
+      || (tree.has_and && self.options.ignore_mixed_logical_expressions);
+    if !ignored {
+      for (or_expr, is_operand) in &tree.or_exprs {
+        if !is_boolean(&or_expr.left) {
+          let suggestions =
+            self.suggestion(or_expr, *is_operand).into_iter().collect();
+          self.context.add_diagnostic_with_suggestions(
+            or_expr.span,
+            CODE,
+            MESSAGE,
+            Some(HINT.to_string()),
+            suggestions,
+          );
+        }
+      }
+    }
+
+    for operand in tree.operands {
+      operand.visit_with(root, self);
+    }
+  }
+
+  /// Returns the suggestion to replace `||` in `or_expr` with `??`. `??`
+  /// can't be mixed with `&&` or `||` without parentheses, so they are added
+  /// around `&&` and `||` operands, and around the result if `is_operand`,
+  /// i.e. `or_expr` is an operand of another `&&` or `||`.
+  fn suggestion(
+    &self,
+    or_expr: &BinExpr,
+    is_operand: bool,
+  ) -> Option<Suggestion> {
+    let left = self.operand_text(&or_expr.left)?;
+    let right = self.operand_text(&or_expr.right)?;
+    let new_text = if is_operand {
+      format!("({} ?? {})", left, right)
+    } else {
+      format!("{} ?? {}", left, right)
+    };
+    self
+      .context
+      .text_edit(or_expr.span, new_text)
+      .map(|edit| Suggestion {
+        label: LABEL.to_string(),
+        edit,
+      })
+  }
+
+  fn operand_text(&self, operand: &Expr) -> Option<String> {
+    let text = self.context.snippet(operand.span())?;
+    match operand {
+      Expr::Bin(bin_expr) if is_logical_op(bin_expr.op) => {
+        Some(format!("({})", text))
+      }
+      _ => Some(text),
+    }
+  }
+
+  fn visit_test(&mut self, test: &Expr, parent: &dyn Node) {
+    match logical_expr(test) {
+      Some(bin_expr) => self.check_logical_tree(bin_expr, true),
+      None => test.visit_with(parent, self),
+    }
+  }
+}
+
+impl<'c, 'o> Visit for PreferNullishCoalescingVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+    if is_logical_op(bin_expr.op) {
+      self.check_logical_tree(bin_expr, false);
+    } else {
+      bin_expr.visit_children_with(self);
+    }
+  }
+
+  fn visit_if_stmt(&mut self, if_stmt: &IfStmt, _parent: &dyn Node) {
+    self.visit_test(&if_stmt.test, if_stmt);
+    if_stmt.cons.visit_with(if_stmt, self);
+    if let Some(alt) = &if_stmt.alt {
+      alt.visit_with(if_stmt, self);
+    }
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _parent: &dyn Node) {
+    self.visit_test(&while_stmt.test, while_stmt);
+    while_stmt.body.visit_with(while_stmt, self);
+  }
+
+  fn visit_do_while_stmt(
+    &mut self,
+    do_while_stmt: &DoWhileStmt,
+    _parent: &dyn Node,
+  ) {
+    do_while_stmt.body.visit_with(do_while_stmt, self);
+    self.visit_test(&do_while_stmt.test, do_while_stmt);
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _parent: &dyn Node) {
+    if let Some(init) = &for_stmt.init {
+      init.visit_with(for_stmt, self);
+    }
+    if let Some(test) = &for_stmt.test {
+      self.visit_test(test, for_stmt);
+    }
+    if let Some(update) = &for_stmt.update {
+      update.visit_with(for_stmt, self);
+    }
+    for_stmt.body.visit_with(for_stmt, self);
+  }
+
+  fn visit_cond_expr(&mut self, cond_expr: &CondExpr, _parent: &dyn Node) {
+    self.visit_test(&cond_expr.test, cond_expr);
+    cond_expr.cons.visit_with(cond_expr, self);
+    cond_expr.alt.visit_with(cond_expr, self);
+  }
+}
+
+#[derive(Default)]
+struct LogicalTree<'a> {
+  /// `||` expressions, and whether each is directly an operand of another
+  /// `&&` or `||`.
+  or_exprs: Vec<(&'a BinExpr, bool)>,
+  operands: Vec<&'a Expr>,
+  has_and: bool,
+}
+
+impl<'a> LogicalTree<'a> {
+  fn collect(&mut self, bin_expr: &'a BinExpr, is_operand: bool) {
+    match bin_expr.op {
+      BinaryOp::LogicalOr => self.or_exprs.push((bin_expr, is_operand)),
+      BinaryOp::LogicalAnd => self.has_and = true,
+      _ => {}
+    }
+    for operand in &[&bin_expr.left, &bin_expr.right] {
+      match logical_expr(operand) {
+        Some(nested) => {
+          self.collect(nested, matches!(***operand, Expr::Bin(_)))
+        }
+        None => self.operands.push(operand),
+      }
+    }
+  }
+}
+
+fn is_logical_op(op: BinaryOp) -> bool {
+  matches!(op, BinaryOp::LogicalAnd | BinaryOp::LogicalOr)
+}
+
+/// Returns the `&&` or `||` expression `expr` consists of, ignoring
+/// parentheses and negations.
+fn logical_expr(expr: &Expr) -> Option<&BinExpr> {
+  match expr {
+    Expr::Bin(bin_expr) if is_logical_op(bin_expr.op) => Some(bin_expr),
+    Expr::Paren(ParenExpr { expr, .. })
+    | Expr::Unary(UnaryExpr {
+      op: UnaryOp::Bang,
+      arg: expr,
+      ..
+    }) => logical_expr(expr),
+    _ => None,
+  }
+}
+
+/// Checks if `expr` obviously evaluates to a boolean, in which case `||` is
+/// the right operator.
+fn is_boolean(expr: &Expr) -> bool {
+  use BinaryOp::*;
+  match expr {
+    Expr::Lit(Lit::Bool(_)) => true,
+    Expr::Unary(UnaryExpr {
+      op: UnaryOp::Bang, ..
+    }) => true,
+    Expr::Bin(bin_expr) => match bin_expr.op {
+      EqEq | NotEq | EqEqEq | NotEqEq | Lt | LtEq | Gt | GtEq | In
+      | InstanceOf => true,
+      LogicalAnd | LogicalOr => {
+        is_boolean(&bin_expr.left) && is_boolean(&bin_expr.right)
+      }
+      _ => false,
+    },
+    Expr::Call(call_expr) => matches!(
+      &call_expr.callee,
+      ExprOrSuper::Expr(callee)
+        if matches!(&**callee, Expr::Ident(ident) if ident.sym == *"Boolean")
+    ),
+    Expr::Paren(ParenExpr { expr, .. }) => is_boolean(expr),
+    _ => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn prefer_nullish_coalescing_valid() {
+    assert_lint_ok! {
+      PreferNullishCoalescing,
+      "const a = b ?? c;",
+      "const a = b === 0 || c === 0;",
+      "const a = !b || !c;",
+      "const a = b instanceof C || c;",
+      "const a = Boolean(b) || c;",
+      "const a = true || b;",
+      "const a = (b > 1 && c > 1) || d;",
+      "if (a || b) {}",
+      "if (!(a || b)) {}",
+      "if ((a && b) || c) {}",
+      "while (a || b) {}",
+      "do {} while (a || b);",
+      "for (; a || b;) {}",
+      "const x = a || b ? c : d;",
+      "const a = b && c;",
+    };
+  }
+
+  #[test]
+  fn prefer_nullish_coalescing_invalid() {
+    assert_lint_err! {
+      PreferNullishCoalescing,
+      r#"const name = options.name || "default";"#: [
+        {
+          col: 13,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "function f(timeout) { return timeout || 1000; }": [
+        {
+          col: 29,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "f(a || b);": [
+        {
+          col: 2,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "const a = b || c || d;": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+        },
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "const a = b && c || d;": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "if (a) { x = b || c; }": [
+        {
+          col: 13,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "if (f(a || b)) {}": [
+        {
+          col: 6,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "const x = a ? b || c : d;": [
+        {
+          col: 14,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn prefer_nullish_coalescing_options() {
+    let rule = |options: &str| {
+      PreferNullishCoalescing::with_options(
+        serde_json::from_str(options).unwrap(),
+      )
+    };
+
+    let conditional = r#"{ "ignoreConditionalTests": false }"#;
+    assert_lint_err_on_line_n_with_rule(
+      rule(conditional),
+      "if (a || b) {}",
+      vec![(1, 4)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(conditional),
+      "const x = a || b ? c : d;",
+      vec![(1, 10)],
+    );
+    assert_lint_ok_with_rule(rule(conditional), "if (a === 1 || b) {}");
+
+    let mixed = r#"{ "ignoreMixedLogicalExpressions": true }"#;
+    assert_lint_ok_with_rule(rule(mixed), "const a = b && c || d;");
+    assert_lint_ok_with_rule(rule(mixed), "const a = b || (c && d);");
+    assert_lint_err_on_line_n_with_rule(
+      rule(mixed),
+      "const a = b || c;",
+      vec![(1, 10)],
+    );
+  }
+
+  #[test]
+  fn prefer_nullish_coalescing_suggestions() {
+    let cases: &[(&str, &[&str])] = &[
+      (
+        r#"const name = options.name || "default";"#,
+        &[r#"const name = options.name ?? "default";"#],
+      ),
+      ("const a = (b || c) && d;", &["const a = (b ?? c) && d;"]),
+      ("const a = b && c || d;", &["const a = (b && c) ?? d;"]),
+      ("const a = b || c && d;", &["const a = b ?? (c && d);"]),
+      (
+        "const a = b || c || d;",
+        &["const a = (b || c) ?? d;", "const a = (b ?? c) || d;"],
+      ),
+      ("const a = !(b || c);", &["const a = !(b ?? c);"]),
+    ];
+    for (source, expected) in cases {
+      assert_eq!(
+        apply_suggestions(PreferNullishCoalescing::new(), source),
+        *expected,
+        "{}",
+        source
+      );
+    }
+  }
+}
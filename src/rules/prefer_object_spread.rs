@@ -0,0 +1,344 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::Suggestion;
+use std::collections::HashSet;
+use swc_common::{BytePos, Span, Spanned};
+use swc_ecmascript::ast::{
+  ArrowExpr, BlockStmtOrExpr, CallExpr, Expr, ExprOrSpread, ExprOrSuper,
+  ExprStmt, Lit, MemberExpr, ObjectLit, Program, Prop, PropOrSpread,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct PreferObjectSpread;
+
+const CODE: &str = "prefer-object-spread";
+const MESSAGE: &str = "Use an object spread instead of `Object.assign`";
+const HINT: &str =
+  "Use an object literal with spread properties, e.g. `{ ...a, ...b }`";
+const LITERAL_MESSAGE: &str =
+  "Use an object literal instead of `Object.assign`";
+const LITERAL_HINT: &str =
+  "Remove the `Object.assign` call around the object literal";
+const LABEL: &str = "Replace with an object literal";
+
+impl LintRule for PreferObjectSpread {
+  fn new() -> Box<Self> {
+    Box::new(PreferObjectSpread)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = PreferObjectSpreadVisitor {
+      context,
+      statement_starts: HashSet::new(),
+    };
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires object spread instead of `Object.assign` with an object literal
+
+When the first argument of `Object.assign` is an object literal, the call just
+creates a new object, which an object literal with spread properties does in a
+shorter and more readable way.
+
+Calls which assign to an existing object are not reported, and neither are
+calls with spread arguments or with getters or setters in an object literal
+argument, since those can't be expressed with object spread.
+
+### Invalid:
+```typescript
+Object.assign({}, foo);
+Object.assign({}, { foo: "bar" });
+Object.assign({ foo: "bar" }, baz);
+Object.assign({});
+```
+
+### Valid:
+```typescript
+({ ...foo });
+({ foo: "bar", ...baz });
+
+// assigns to an existing object
+Object.assign(foo, bar);
+Object.assign(foo, { bar: "baz" });
+
+// spread arguments
+Object.assign({}, ...objects);
+```
+"#
+  }
+}
+
+struct PreferObjectSpreadVisitor<'c> {
+  context: &'c mut Context,
+  /// Start positions of expression statements and arrow function bodies,
+  /// where an object literal would be parsed as a block.
+  statement_starts: HashSet<BytePos>,
+}
+
+impl<'c> PreferObjectSpreadVisitor<'c> {
+  fn is_object_assign(&self, callee: &ExprOrSuper) -> bool {
+    let member_expr = match callee {
+      ExprOrSuper::Expr(expr) => match &**expr {
+        Expr::Member(member_expr) => member_expr,
+        _ => return false,
+      },
+      ExprOrSuper::Super(_) => return false,
+    };
+    let is_global_object = match &member_expr.obj {
+      ExprOrSuper::Expr(obj) => matches!(
+        &**obj,
+        Expr::Ident(ident)
+          if ident.sym == *"Object"
+            && self.context.scope.var(&ident.to_id()).is_none()
+      ),
+      ExprOrSuper::Super(_) => false,
+    };
+    is_global_object && property_is_assign(member_expr)
+  }
+
+  /// Returns the suggestion to replace `call_expr` with an object literal,
+  /// spreading its arguments except for object literals, whose properties
+  /// are inlined.
+  fn suggestion(&self, call_expr: &CallExpr) -> Option<Suggestion> {
+    let mut props = vec![];
+    for arg in &call_expr.args {
+      match &*arg.expr {
+        Expr::Object(object_lit) => {
+          let span = object_lit.span;
+          let inner = self.context.snippet(Span::new(
+            span.lo + BytePos(1),
+            span.hi - BytePos(1),
+            span.ctxt,
+          ))?;
+          let inner = inner.trim().trim_end_matches(',').trim_end();
+          if !inner.is_empty() {
+            props.push(inner.to_string());
+          }
+        }
+        expr => {
+          let text = self.context.snippet(expr.span())?;
+          props.push(format!("...{}", text));
+        }
+      }
+    }
+
+    let object = if props.is_empty() {
+      "{}".to_string()
+    } else {
+      format!("{{ {} }}", props.join(", "))
+    };
+    let new_text = if self.statement_starts.contains(&call_expr.span.lo) {
+      format!("({})", object)
+    } else {
+      object
+    };
+    self
+      .context
+      .text_edit(call_expr.span, new_text)
+      .map(|edit| Suggestion {
+        label: LABEL.to_string(),
+        edit,
+      })
+  }
+}
+
+impl<'c> VisitAll for PreferObjectSpreadVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_expr_stmt(&mut self, expr_stmt: &ExprStmt, _parent: &dyn Node) {
+    self.statement_starts.insert(expr_stmt.expr.span().lo);
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    if let BlockStmtOrExpr::Expr(expr) = &arrow_expr.body {
+      self.statement_starts.insert(expr.span().lo);
+    }
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    let first_arg_is_object = match call_expr.args.first() {
+      Some(ExprOrSpread { spread: None, expr }) => {
+        matches!(&**expr, Expr::Object(_))
+      }
+      _ => false,
+    };
+    if !first_arg_is_object
+      || call_expr.args.iter().any(|arg| arg.spread.is_some())
+      || !self.is_object_assign(&call_expr.callee)
+    {
+      return;
+    }
+
+    let (message, hint) = if call_expr.args.len() == 1 {
+      (LITERAL_MESSAGE, LITERAL_HINT)
+    } else if !call_expr.args.iter().any(|arg| has_accessors(&arg.expr)) {
+      (MESSAGE, HINT)
+    } else {
+      return;
+    };
+    let suggestions = self.suggestion(call_expr).into_iter().collect();
+    self.context.add_diagnostic_with_suggestions(
+      call_expr.span,
+      CODE,
+      message,
+      Some(hint.to_string()),
+      suggestions,
+    );
+  }
+}
+
+fn property_is_assign(member_expr: &MemberExpr) -> bool {
+  match &*member_expr.prop {
+    Expr::Ident(ident) if !member_expr.computed => ident.sym == *"assign",
+    Expr::Lit(Lit::Str(s)) if member_expr.computed => s.value == *"assign",
+    _ => false,
+  }
+}
+
+/// Checks if `expr` is an object literal with a getter or a setter, which
+/// would be called instead of copied by an object spread.
+fn has_accessors(expr: &Expr) -> bool {
+  match expr {
+    Expr::Object(ObjectLit { props, .. }) => props.iter().any(|prop| {
+      matches!(
+        prop,
+        PropOrSpread::Prop(prop)
+          if matches!(&**prop, Prop::Getter(_) | Prop::Setter(_))
+      )
+    }),
+    _ => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn prefer_object_spread_valid() {
+    assert_lint_ok! {
+      PreferObjectSpread,
+      "({ ...foo });",
+      "({ foo: 'bar', ...baz });",
+      "Object.assign(foo, bar);",
+      "Object.assign(foo, { bar: 'baz' });",
+      "Object.assign(foo.bar, baz);",
+      "Object.assign();",
+      "Object.assign(...foo);",
+      "Object.assign({}, ...objects);",
+      "Object.assign({ foo: 'bar' }, ...objects);",
+      "Object.assign({}, { get a() { return 1; } });",
+      "Object.assign({ set a(v) {} }, foo);",
+      "Object.keys({}, foo);",
+      "foo.assign({}, bar);",
+      "const Object = {}; Object.assign({}, foo);",
+      "function f(Object) { return Object.assign({}, foo); }",
+    };
+  }
+
+  #[test]
+  fn prefer_object_spread_invalid() {
+    assert_lint_err! {
+      PreferObjectSpread,
+      "Object.assign({}, foo);": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "Object.assign({}, { foo: 'bar' });": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "Object.assign({ foo: 'bar' }, baz);": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "const a = Object.assign({}, b, c);": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "Object['assign']({}, foo);": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "Object.assign({}, Object.assign({}, foo));": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        },
+        {
+          col: 18,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "Object.assign({});": [
+        {
+          col: 0,
+          message: LITERAL_MESSAGE,
+          hint: LITERAL_HINT,
+        }
+      ],
+      "Object.assign({ foo: 'bar' });": [
+        {
+          col: 0,
+          message: LITERAL_MESSAGE,
+          hint: LITERAL_HINT,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn prefer_object_spread_suggestions() {
+    let cases = [
+      (
+        "const a = Object.assign({}, b, c);",
+        "const a = { ...b, ...c };",
+      ),
+      (
+        "const a = Object.assign({ foo: 'bar', }, b, { baz });",
+        "const a = { foo: 'bar', ...b, baz };",
+      ),
+      ("const a = Object.assign({});", "const a = {};"),
+      ("Object.assign({}, foo);", "({ ...foo });"),
+      ("Object.assign({ a }).a;", "({ a }).a;"),
+      (
+        "const f = () => Object.assign({}, b);",
+        "const f = () => ({ ...b });",
+      ),
+    ];
+    for (source, expected) in cases.iter() {
+      assert_eq!(
+        apply_suggestions(PreferObjectSpread::new(), source),
+        vec![*expected]
+      );
+    }
+  }
+}
@@ -0,0 +1,325 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::Suggestion;
+use crate::swc_util::DropSpan;
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{BinExpr, BinaryOp, Expr, ExprOrSuper, Program};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct PreferOptionalChain;
+
+const CODE: &str = "prefer-optional-chain";
+const MESSAGE: &str =
+  "Prefer an optional chain over chained `&&` checks of the same object";
+const HINT: &str =
+  "Use optional chaining instead, e.g. `a?.b?.c` instead of `a && a.b && a.b.c`";
+const LABEL: &str = "Replace with an optional chain";
+
+fn chain_hint(chain: &str) -> String {
+  format!("Use the optional chain `{}` instead", chain)
+}
+
+impl LintRule for PreferOptionalChain {
+  fn new() -> Box<Self> {
+    Box::new(PreferOptionalChain)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = PreferOptionalChainVisitor { context };
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires optional chaining instead of chained `&&` existence checks
+
+Checking every level of a property access with `&&` before accessing the next
+one, as in `a && a.b && a.b.c`, repeats the same expression over and over.
+Optional chaining (`a?.b?.c`) does the same check in a shorter way.
+
+### Invalid:
+```typescript
+foo && foo.bar;
+foo && foo.bar && foo.bar.baz;
+foo.bar && foo.bar.baz();
+```
+
+### Valid:
+```typescript
+foo?.bar;
+foo?.bar?.baz;
+foo && bar.baz;
+foo.bar && foo.baz;
+```
+"#
+  }
+}
+
+struct PreferOptionalChainVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> PreferOptionalChainVisitor<'c> {
+  /// Reports each run of operands in which every operand accesses a member
+  /// of the previous one.
+  fn check_operands(&mut self, operands: &[&Expr]) {
+    let mut start = 0;
+    for end in 1..=operands.len() {
+      let continues_chain = end < operands.len()
+        && is_reference(operands[end - 1])
+        && find_object(operands[end - 1], operands[end]).is_some();
+      if continues_chain {
+        continue;
+      }
+      if end - start >= 2 {
+        let span = Span::new(
+          operands[start].span().lo,
+          operands[end - 1].span().hi,
+          Default::default(),
+        );
+        match self.optional_chain(&operands[start..end]) {
+          Some(chain) => {
+            let suggestions = self
+              .context
+              .text_edit(span, &chain)
+              .map(|edit| Suggestion {
+                label: LABEL.to_string(),
+                edit,
+              })
+              .into_iter()
+              .collect();
+            self.context.add_diagnostic_with_suggestions(
+              span,
+              CODE,
+              MESSAGE,
+              Some(chain_hint(&chain)),
+              suggestions,
+            );
+          }
+          None => self
+            .context
+            .add_diagnostic_with_hint(span, CODE, MESSAGE, HINT),
+        }
+      }
+      start = end;
+    }
+  }
+
+  /// Returns the optional chain equivalent to a run of operands, e.g.
+  /// `a?.b?.c` for `a && a.b && a.b.c`.
+  fn optional_chain(&self, operands: &[&Expr]) -> Option<String> {
+    let mut chain = self.context.snippet(operands[0].span())?;
+    for pair in operands.windows(2) {
+      let object = find_object(pair[0], pair[1])?;
+      let access = self.context.snippet(Span::new(
+        object.span().hi,
+        pair[1].span().hi,
+        Default::default(),
+      ))?;
+      let access = access.trim_start();
+      chain.push_str("?.");
+      chain.push_str(access.strip_prefix('.').unwrap_or(access));
+    }
+    Some(chain)
+  }
+}
+
+impl<'c> Visit for PreferOptionalChainVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+    if bin_expr.op != BinaryOp::LogicalAnd {
+      bin_expr.visit_children_with(self);
+      return;
+    }
+
+    let mut operands = Vec::new();
+    collect_and_operands(bin_expr, &mut operands);
+    self.check_operands(&operands);
+    for operand in operands {
+      operand.visit_with(bin_expr, self);
+    }
+  }
+}
+
+/// Collects the operands of a chain of `&&` expressions from left to right.
+fn collect_and_operands<'a>(
+  bin_expr: &'a BinExpr,
+  operands: &mut Vec<&'a Expr>,
+) {
+  match &*bin_expr.left {
+    Expr::Bin(left) if left.op == BinaryOp::LogicalAnd => {
+      collect_and_operands(left, operands)
+    }
+    left => operands.push(left),
+  }
+  operands.push(&bin_expr.right);
+}
+
+/// Checks if `expr` can be evaluated more than once without side effects,
+/// like `a`, `this.a` or `a.b[c]`.
+fn is_reference(expr: &Expr) -> bool {
+  match expr {
+    Expr::Ident(_) | Expr::This(_) => true,
+    Expr::Member(member_expr) => match &member_expr.obj {
+      ExprOrSuper::Expr(obj) => {
+        is_reference(obj)
+          && (!member_expr.computed
+            || matches!(&*member_expr.prop, Expr::Ident(_) | Expr::Lit(_)))
+      }
+      ExprOrSuper::Super(_) => false,
+    },
+    _ => false,
+  }
+}
+
+/// If `expr` accesses a member of `object`, or calls a method of it, returns
+/// the part of `expr` equal to `object`, e.g. `a` of `a.b.c` or `a.b()` for
+/// `a`.
+fn find_object<'a>(object: &Expr, expr: &'a Expr) -> Option<&'a Expr> {
+  let inner = match expr {
+    Expr::Member(member_expr) => match &member_expr.obj {
+      ExprOrSuper::Expr(obj) => obj,
+      ExprOrSuper::Super(_) => return None,
+    },
+    Expr::Call(call_expr) => match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => callee,
+      ExprOrSuper::Super(_) => return None,
+    },
+    _ => return None,
+  };
+  if inner.clone().drop_span() == object.clone().drop_span() {
+    Some(inner)
+  } else {
+    find_object(object, inner)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn prefer_optional_chain_valid() {
+    assert_lint_ok! {
+      PreferOptionalChain,
+      "foo?.bar;",
+      "foo?.bar?.baz;",
+      "foo && bar;",
+      "foo && bar.baz;",
+      "foo.bar && foo.baz;",
+      "foo.bar && foo.bar;",
+      "foo || foo.bar;",
+      "foo && foo;",
+      "foo() && foo().bar;",
+      "foo[bar()] && foo[bar()].baz;",
+      "(foo && bar) && foo.baz;",
+      "foo.bar && baz && foo.bar.qux;",
+    };
+  }
+
+  #[test]
+  fn prefer_optional_chain_invalid() {
+    assert_lint_err! {
+      PreferOptionalChain,
+      "foo && foo.bar;": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: chain_hint("foo?.bar"),
+        }
+      ],
+      "foo && foo.bar && foo.bar.baz;": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: chain_hint("foo?.bar?.baz"),
+        }
+      ],
+      "foo && foo.bar.baz;": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: chain_hint("foo?.bar.baz"),
+        }
+      ],
+      "foo.bar && foo.bar.baz();": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: chain_hint("foo.bar?.baz()"),
+        }
+      ],
+      "foo && foo[bar] && foo[bar].baz;": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: chain_hint("foo?.[bar]?.baz"),
+        }
+      ],
+      "this.foo && this.foo.bar;": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: chain_hint("this.foo?.bar"),
+        }
+      ],
+      "if (x && foo && foo.bar) {}": [
+        {
+          col: 9,
+          message: MESSAGE,
+          hint: chain_hint("foo?.bar"),
+        }
+      ],
+      "foo && foo.bar && baz && baz.qux;": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: chain_hint("foo?.bar"),
+        },
+        {
+          col: 18,
+          message: MESSAGE,
+          hint: chain_hint("baz?.qux"),
+        }
+      ],
+      "f(a && a.b, () => c && c.d);": [
+        {
+          col: 2,
+          message: MESSAGE,
+          hint: chain_hint("a?.b"),
+        },
+        {
+          col: 18,
+          message: MESSAGE,
+          hint: chain_hint("c?.d"),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn prefer_optional_chain_suggestions() {
+    let cases = [
+      ("foo && foo.bar && foo.bar.baz;", "foo?.bar?.baz;"),
+      ("foo.bar && foo.bar.baz();", "foo.bar?.baz();"),
+      ("foo && foo[bar];", "foo?.[bar];"),
+      ("foo.bar && foo.bar();", "foo.bar?.();"),
+      ("x && foo && foo.bar;", "x && foo?.bar;"),
+    ];
+    for (source, expected) in cases.iter() {
+      assert_eq!(
+        apply_suggestions(PreferOptionalChain::new(), source),
+        vec![*expected]
+      );
+    }
+  }
+}
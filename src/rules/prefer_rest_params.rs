@@ -0,0 +1,223 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_ecmascript::ast::{
+  Constructor, Expr, ExprOrSuper, Function, GetterProp, Ident, MemberExpr,
+  Program, Prop, SetterProp,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct PreferRestParams;
+
+const CODE: &str = "prefer-rest-params";
+const MESSAGE: &str = "Use the rest parameters instead of 'arguments'";
+const HINT: &str =
+  "Declare a rest parameter, e.g. `function foo(...args) {}`, and use it instead";
+
+impl LintRule for PreferRestParams {
+  fn new() -> Box<Self> {
+    Box::new(PreferRestParams)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = PreferRestParamsVisitor::new(context);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires rest parameters instead of `arguments`
+
+The `arguments` object is not a real array, so array methods can't be used on
+it directly, and it's easy to miss that a function accepts a variable number
+of arguments when they are not declared. Rest parameters are a real array and
+appear in the signature of the function.
+
+Reading `arguments.length` is allowed, as are local variables named
+`arguments`.
+
+### Invalid:
+```typescript
+function foo() {
+  console.log(arguments);
+}
+
+function bar() {
+  return Array.prototype.slice.call(arguments, 1);
+}
+```
+
+### Valid:
+```typescript
+function foo(...args) {
+  console.log(args);
+}
+
+function bar(first, ...rest) {
+  return rest;
+}
+
+function baz() {
+  return arguments.length;
+}
+```
+"#
+  }
+}
+
+struct PreferRestParamsVisitor<'c> {
+  context: &'c mut Context,
+  /// Number of non-arrow functions the visitor is in.
+  function_depth: usize,
+}
+
+impl<'c> PreferRestParamsVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self {
+      context,
+      function_depth: 0,
+    }
+  }
+
+  fn with_function<F>(&mut self, op: F)
+  where
+    F: FnOnce(&mut Self),
+  {
+    self.function_depth += 1;
+    op(self);
+    self.function_depth -= 1;
+  }
+
+  fn check_reference(&mut self, ident: &Ident) {
+    if ident.sym == *"arguments"
+      && self.function_depth > 0
+      && self.context.scope.var(&ident.to_id()).is_none()
+    {
+      self
+        .context
+        .add_diagnostic_with_hint(ident.span, CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+impl<'c> Visit for PreferRestParamsVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
+    self.with_function(|v| function.visit_children_with(v));
+  }
+
+  fn visit_constructor(
+    &mut self,
+    constructor: &Constructor,
+    _parent: &dyn Node,
+  ) {
+    self.with_function(|v| constructor.visit_children_with(v));
+  }
+
+  fn visit_getter_prop(
+    &mut self,
+    getter_prop: &GetterProp,
+    _parent: &dyn Node,
+  ) {
+    self.with_function(|v| getter_prop.visit_children_with(v));
+  }
+
+  fn visit_setter_prop(
+    &mut self,
+    setter_prop: &SetterProp,
+    _parent: &dyn Node,
+  ) {
+    self.with_function(|v| setter_prop.visit_children_with(v));
+  }
+
+  fn visit_expr(&mut self, expr: &Expr, _parent: &dyn Node) {
+    if let Expr::Ident(ident) = expr {
+      self.check_reference(ident);
+    }
+    expr.visit_children_with(self);
+  }
+
+  fn visit_prop(&mut self, prop: &Prop, _parent: &dyn Node) {
+    if let Prop::Shorthand(ident) = prop {
+      self.check_reference(ident);
+    }
+    prop.visit_children_with(self);
+  }
+
+  fn visit_member_expr(
+    &mut self,
+    member_expr: &MemberExpr,
+    _parent: &dyn Node,
+  ) {
+    if !member_expr.computed {
+      // `arguments.length` is fine
+      if let ExprOrSuper::Expr(obj) = &member_expr.obj {
+        if matches!(&**obj, Expr::Ident(ident) if ident.sym == *"arguments") {
+          return;
+        }
+      }
+    }
+    member_expr.obj.visit_with(member_expr, self);
+    if member_expr.computed {
+      member_expr.prop.visit_with(member_expr, self);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_rest_params_valid() {
+    assert_lint_ok! {
+      PreferRestParams,
+      "function foo(...args) { console.log(args); }",
+      "function foo() { return arguments.length; }",
+      "function foo(arguments) { console.log(arguments); }",
+      "function foo() { const arguments = 1; console.log(arguments); }",
+      "function foo() { var arguments; return arguments[0]; }",
+      "console.log(arguments);",
+      "const foo = () => arguments;",
+      "function foo() { return bar.arguments; }",
+      "function foo() { return { arguments: 1 }; }",
+    };
+  }
+
+  #[test]
+  fn prefer_rest_params_invalid() {
+    assert_lint_err! {
+      PreferRestParams,
+      "function foo() { console.log(arguments); }": [
+        { col: 29, message: MESSAGE, hint: HINT }
+      ],
+      "function foo() { return arguments[0]; }": [
+        { col: 24, message: MESSAGE, hint: HINT }
+      ],
+      "function foo() { return Array.prototype.slice.call(arguments, 1); }": [
+        { col: 51, message: MESSAGE, hint: HINT }
+      ],
+      "function foo() { const bar = () => arguments; }": [
+        { col: 35, message: MESSAGE, hint: HINT }
+      ],
+      "const foo = function() { return { arguments }; };": [
+        { col: 34, message: MESSAGE, hint: HINT }
+      ],
+      "class A { constructor() { foo(arguments); } bar() { foo(arguments); } }": [
+        { col: 30, message: MESSAGE, hint: HINT },
+        { col: 56, message: MESSAGE, hint: HINT },
+      ],
+      "const obj = { get foo() { return arguments; } };": [
+        { col: 33, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+}
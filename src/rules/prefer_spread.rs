@@ -0,0 +1,188 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_common::Spanned;
+use swc_ecmascript::ast::{CallExpr, Expr, ExprOrSuper, Lit, Program};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct PreferSpread;
+
+const CODE: &str = "prefer-spread";
+const MESSAGE: &str = "Use the spread operator instead of `.apply()`";
+const HINT: &str =
+  "Call the function directly with spread arguments, e.g. `foo(...args)`";
+
+impl LintRule for PreferSpread {
+  fn new() -> Box<Self> {
+    Box::new(PreferSpread)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = PreferSpreadVisitor::new(context);
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires spread syntax instead of `.apply()`
+
+Before ES2015, `Function.prototype.apply()` was the only way to call a function
+with a variable number of arguments. Spread syntax does the same in a shorter
+and more readable way.
+
+A call is only reported when `.apply()` doesn't change the `this` value of the
+function, i.e. when it is passed `null` or `undefined` for a plain function, or
+the object the method is accessed on.
+
+### Invalid:
+```typescript
+foo.apply(undefined, args);
+foo.apply(null, args);
+obj.foo.apply(obj, args);
+```
+
+### Valid:
+```typescript
+foo(...args);
+obj.foo(...args);
+
+// `this` is changed
+foo.apply(obj, args);
+obj.foo.apply(other, args);
+
+// the arguments are not variadic
+foo.apply(null, [1, 2, 3]);
+```
+"#
+  }
+}
+
+struct PreferSpreadVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> PreferSpreadVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  /// Checks if `this_arg` is the `this` value the function would be called
+  /// with anyway.
+  fn is_same_this(&self, function: &Expr, this_arg: &Expr) -> bool {
+    match function {
+      Expr::Member(member) => {
+        let obj = match &member.obj {
+          ExprOrSuper::Expr(obj) => obj.span(),
+          ExprOrSuper::Super(_) => return false,
+        };
+        match (
+          self.context.snippet(obj),
+          self.context.snippet(this_arg.span()),
+        ) {
+          (Some(obj), Some(this_arg)) => obj == this_arg,
+          _ => false,
+        }
+      }
+      _ => is_null_or_undefined(this_arg),
+    }
+  }
+}
+
+fn is_null_or_undefined(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(Lit::Null(_)) => true,
+    Expr::Ident(ident) => ident.sym == *"undefined",
+    _ => false,
+  }
+}
+
+impl<'c> VisitAll for PreferSpreadVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    let member = match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => match &**callee {
+        Expr::Member(member) => member,
+        _ => return,
+      },
+      ExprOrSuper::Super(_) => return,
+    };
+    if member.computed {
+      return;
+    }
+    match &*member.prop {
+      Expr::Ident(ident) if ident.sym == *"apply" => {}
+      _ => return,
+    }
+    let function = match &member.obj {
+      ExprOrSuper::Expr(function) => function,
+      ExprOrSuper::Super(_) => return,
+    };
+
+    let (this_arg, args) = match call_expr.args.as_slice() {
+      [this_arg, args] => (this_arg, args),
+      _ => return,
+    };
+    if this_arg.spread.is_some()
+      || args.spread.is_some()
+      || matches!(&*args.expr, Expr::Array(_))
+    {
+      return;
+    }
+
+    if self.is_same_this(function, &this_arg.expr) {
+      self.context.add_diagnostic_with_hint(
+        call_expr.span,
+        CODE,
+        MESSAGE,
+        HINT,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_spread_valid() {
+    assert_lint_ok! {
+      PreferSpread,
+      "foo(...args);",
+      "obj.foo(...args);",
+      "foo.apply(obj, args);",
+      "foo.apply(this, args);",
+      "obj.foo.apply(null, args);",
+      "obj.foo.apply(other, args);",
+      "a.b.c.apply(a.c, args);",
+      "foo.apply(null, [1, 2, 3]);",
+      "foo.apply(undefined, [a, b]);",
+      "foo.apply(null);",
+      "foo.apply(null, args, extra);",
+      "foo.call(null, ...args);",
+      "foo[apply](null, args);",
+    };
+  }
+
+  #[test]
+  fn prefer_spread_invalid() {
+    assert_lint_err! {
+      PreferSpread,
+      "foo.apply(null, args);": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "foo.apply(undefined, args);": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "obj.foo.apply(obj, args);": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "a.b.c.apply(a.b, args);": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "a.b(x).c.apply(a.b(x), args);": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "const x = foo.apply(null, getArgs());": [
+        { col: 10, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+}
@@ -0,0 +1,269 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  ArrowExpr, AwaitExpr, BlockStmtOrExpr, ForOfStmt, Function, Program,
+};
+use swc_ecmascript::visit::{noop_visit_type, Node, Visit, VisitWith};
+
+pub struct RequireAwait;
+
+const CODE: &str = "require-await";
+const MESSAGE: &str = "Async function has no `await` expression";
+const HINT: &str =
+  "Remove `async` keyword from the function or use `await` expression inside";
+
+impl LintRule for RequireAwait {
+  fn new() -> Box<Self> {
+    Box::new(RequireAwait)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = RequireAwaitVisitor::new(context);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows async functions that have no await expression
+
+In general, the primary reason to use async functions is to use await
+expressions inside. If an async function has no await expression, it is most
+likely an unintentional mistake. Awaits in nested functions don't count for the
+enclosing function. Empty async functions and async generators are allowed.
+
+### Invalid:
+```typescript
+async function f1() {
+  doSomething();
+}
+
+const f2 = async () => {
+  doSomething();
+};
+
+const f3 = async () => doSomething();
+
+const obj = {
+  async method() {
+    doSomething();
+  },
+};
+
+class MyClass {
+  async method() {
+    doSomething();
+  }
+}
+```
+
+### Valid:
+```typescript
+await asyncFunction();
+
+function normalFunction() {
+  doSomething();
+}
+
+async function f1() {
+  await asyncFunction();
+}
+
+const f2 = async () => {
+  await asyncFunction();
+};
+
+const f3 = async () => await asyncFunction();
+
+async function f4() {
+  for await (const num of asyncIterable) {
+    console.log(num);
+  }
+}
+
+// empty functions are valid
+async function emptyFunction() {}
+const emptyArrowFunction = async () => {};
+
+// generators are also valid
+async function* gen() {
+  console.log(42);
+}
+```
+"#
+  }
+}
+
+struct RequireAwaitVisitor<'c> {
+  context: &'c mut Context,
+  /// Whether the innermost enclosing function has an await.
+  has_await: bool,
+}
+
+impl<'c> RequireAwaitVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self {
+      context,
+      has_await: false,
+    }
+  }
+
+  /// Visits the children of a function with its own await tracking, and
+  /// returns whether an await was found directly in the function.
+  fn visit_function_body(&mut self, visit: impl FnOnce(&mut Self)) -> bool {
+    let outer_has_await = self.has_await;
+    self.has_await = false;
+    visit(self);
+    let has_await = self.has_await;
+    self.has_await = outer_has_await;
+    has_await
+  }
+
+  fn add_diagnostic(&mut self, span: Span) {
+    self
+      .context
+      .add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
+  }
+}
+
+impl<'c> Visit for RequireAwaitVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
+    let has_await = self.visit_function_body(|visitor| {
+      function.visit_children_with(visitor);
+    });
+    let is_empty = match &function.body {
+      Some(body) => body.stmts.is_empty(),
+      // Overload signatures and declarations
+      None => true,
+    };
+    if function.is_async && !function.is_generator && !is_empty && !has_await {
+      self.add_diagnostic(function.span);
+    }
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    let has_await = self.visit_function_body(|visitor| {
+      arrow_expr.visit_children_with(visitor);
+    });
+    let is_empty = match &arrow_expr.body {
+      BlockStmtOrExpr::BlockStmt(block) => block.stmts.is_empty(),
+      BlockStmtOrExpr::Expr(_) => false,
+    };
+    if arrow_expr.is_async && !is_empty && !has_await {
+      self.add_diagnostic(arrow_expr.span);
+    }
+  }
+
+  fn visit_await_expr(&mut self, await_expr: &AwaitExpr, _parent: &dyn Node) {
+    self.has_await = true;
+    await_expr.visit_children_with(self);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _parent: &dyn Node) {
+    if for_of_stmt.await_token.is_some() {
+      self.has_await = true;
+    }
+    for_of_stmt.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn require_await_valid() {
+    assert_lint_ok! {
+      RequireAwait,
+      "function foo() { doSomething(); }",
+      "async function foo() { await doSomething(); }",
+      "const foo = async () => { await doSomething(); };",
+      "const foo = async () => await doSomething();",
+      "const foo = async function() { await doSomething(); };",
+      "async function foo() { for await (const x of xs) { console.log(x); } }",
+      "async function foo() { if (a) { return await b(); } }",
+      "async function foo() { await (async () => {})(); }",
+      "class A { async foo() { await bar(); } }",
+      "const a = { async foo() { await bar(); } };",
+
+      // empty functions
+      "async function foo() {}",
+      "const foo = async () => {};",
+      "class A { async foo() {} }",
+
+      // generators
+      "async function* foo() { yield 1; }",
+      "class A { async *foo() { doSomething(); } }",
+
+      // declarations without bodies
+      "declare function foo(): Promise<void>;",
+      r#"
+async function foo(a: string): Promise<void>;
+async function foo(a: number): Promise<void>;
+async function foo(a: any) {
+  await bar(a);
+}
+      "#,
+
+      // top level await
+      "await foo();",
+    };
+  }
+
+  #[test]
+  fn require_await_invalid() {
+    assert_lint_err! {
+      RequireAwait,
+      "async function foo() { doSomething(); }": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "const foo = async () => { doSomething(); };": [
+        { col: 12, message: MESSAGE, hint: HINT },
+      ],
+      "const foo = async () => doSomething();": [
+        { col: 12, message: MESSAGE, hint: HINT },
+      ],
+      "const foo = async function() { doSomething(); };": [
+        { col: 12, message: MESSAGE, hint: HINT },
+      ],
+      "async function foo() { for (const x of xs) { console.log(x); } }": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+
+      "class A { async foo() { doSomething(); } }": [
+        { col: 10, message: MESSAGE, hint: HINT },
+      ],
+      "const a = { async foo() { doSomething(); } };": [
+        { col: 12, message: MESSAGE, hint: HINT },
+      ],
+
+      // awaits in nested functions don't count for the outer function
+      "async function foo() { async function bar() { await baz(); } }": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "async function foo() { const bar = async () => await baz(); }": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      // and the other way around
+      "async function foo() { await bar(); async function baz() { qux(); } }": [
+        { col: 36, message: MESSAGE, hint: HINT },
+      ],
+      r#"
+async function foo() {
+  const bar = async () => {
+    const baz = async () => await qux();
+  };
+  await bar();
+}
+      "#: [
+        { line: 3, col: 14, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
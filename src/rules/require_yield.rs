@@ -38,6 +38,32 @@ impl LintRule for RequireYield {
     let mut visitor = RequireYieldVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires generator functions to contain a `yield` expression
+
+Generator functions which never `yield` produce an iterator without any
+values. This is usually a mistake, or the function doesn't need to be a
+generator.
+
+### Invalid:
+```typescript
+function* foo() {
+  return 10;
+}
+```
+
+### Valid:
+```typescript
+function* foo() {
+  yield 5;
+  return 10;
+}
+
+function* bar() {}
+```
+"#
+  }
 }
 
 struct RequireYieldVisitor<'c> {
@@ -25,6 +25,26 @@ impl LintRule for SingleVarDeclarator {
     let mut visitor = SingleVarDeclaratorVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows declaring more than one variable in a single declaration
+
+Declaring each variable in its own statement makes declarations easier to read
+and to reorder, and keeps diffs smaller when a variable is added or removed.
+
+### Invalid:
+```typescript
+const foo = 1, bar = "2";
+let a, b;
+```
+
+### Valid:
+```typescript
+const foo = 1;
+const bar = "2";
+```
+"#
+  }
 }
 
 struct SingleVarDeclaratorVisitor<'c> {
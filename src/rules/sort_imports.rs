@@ -0,0 +1,399 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use serde::Deserialize;
+use swc_common::Spanned;
+use swc_ecmascript::ast::{
+  ImportDecl, ImportSpecifier, Module, ModuleDecl, ModuleItem, Program,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+
+pub struct SortImports {
+  options: SortImportsOptions,
+}
+
+const CODE: &str = "sort-imports";
+const SORT_MESSAGE: &str = "Imports should be sorted alphabetically";
+const SORT_HINT: &str =
+  "Sort the import declarations by the name of their first imported member";
+const MEMBER_HINT: &str = "Sort the imported members alphabetically";
+
+/// Kinds of import declarations, by the way their members are imported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MemberSyntax {
+  /// `import "foo";`
+  None,
+  /// `import * as foo from "foo";`
+  All,
+  /// `import { a, b } from "foo";`
+  Multiple,
+  /// `import a from "foo";` or `import { a } from "foo";`
+  Single,
+}
+
+impl MemberSyntax {
+  fn of(import_decl: &ImportDecl) -> Self {
+    match import_decl.specifiers.as_slice() {
+      [] => MemberSyntax::None,
+      [ImportSpecifier::Namespace(_)] => MemberSyntax::All,
+      [_] => MemberSyntax::Single,
+      _ => MemberSyntax::Multiple,
+    }
+  }
+
+  fn as_str(self) -> &'static str {
+    match self {
+      MemberSyntax::None => "none",
+      MemberSyntax::All => "all",
+      MemberSyntax::Multiple => "multiple",
+      MemberSyntax::Single => "single",
+    }
+  }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SortImportsOptions {
+  /// Whether names are compared case-insensitively.
+  pub ignore_case: bool,
+  /// Whether the order of the import declarations is left unchecked.
+  pub ignore_declaration_sort: bool,
+  /// Whether the order of the members within an import is left unchecked.
+  pub ignore_member_sort: bool,
+  /// Order of the kinds of import declarations.
+  pub member_syntax_sort_order: Vec<MemberSyntax>,
+}
+
+impl Default for SortImportsOptions {
+  fn default() -> Self {
+    Self {
+      ignore_case: false,
+      ignore_declaration_sort: false,
+      ignore_member_sort: false,
+      member_syntax_sort_order: vec![
+        MemberSyntax::None,
+        MemberSyntax::All,
+        MemberSyntax::Multiple,
+        MemberSyntax::Single,
+      ],
+    }
+  }
+}
+
+impl SortImports {
+  pub fn with_options(options: SortImportsOptions) -> Box<Self> {
+    Box::new(SortImports { options })
+  }
+}
+
+impl LintRule for SortImports {
+  fn new() -> Box<Self> {
+    Self::with_options(SortImportsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = SortImportsVisitor::new(context, &self.options);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires import declarations and imported members to be sorted
+
+Sorted imports are easier to scan and produce fewer merge conflicts. Import
+declarations are first ordered by their kind, as set with the
+`memberSyntaxSortOrder` option (`["none", "all", "multiple", "single"]` by
+default), and then alphabetically by the local name of their first member.
+Members imported by a single declaration must be sorted alphabetically as well.
+
+The rule can be configured with:
+- `ignoreCase`: compares names case-insensitively
+- `ignoreDeclarationSort`: doesn't check the order of the declarations
+- `ignoreMemberSort`: doesn't check the order of the members of a declaration
+- `memberSyntaxSortOrder`: the order of `"none"` (side effect only), `"all"`
+  (namespace), `"multiple"` and `"single"` member imports
+
+### Invalid:
+```typescript
+import b from "./b.ts";
+import a from "./a.ts";
+
+import { d, c } from "./c.ts";
+```
+
+### Valid:
+```typescript
+import "./polyfill.ts";
+import * as path from "./path.ts";
+import { c, d } from "./c.ts";
+import a from "./a.ts";
+import b from "./b.ts";
+```
+"#
+  }
+}
+
+struct SortImportsVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o SortImportsOptions,
+}
+
+impl<'c, 'o> SortImportsVisitor<'c, 'o> {
+  fn new(context: &'c mut Context, options: &'o SortImportsOptions) -> Self {
+    Self { context, options }
+  }
+
+  fn normalize(&self, name: &str) -> String {
+    if self.options.ignore_case {
+      name.to_lowercase()
+    } else {
+      name.to_string()
+    }
+  }
+
+  fn syntax_index(&self, syntax: MemberSyntax) -> usize {
+    self
+      .options
+      .member_syntax_sort_order
+      .iter()
+      .position(|s| *s == syntax)
+      .unwrap_or(self.options.member_syntax_sort_order.len())
+  }
+
+  fn first_member_name(&self, import_decl: &ImportDecl) -> Option<String> {
+    import_decl
+      .specifiers
+      .first()
+      .map(|specifier| self.normalize(&local_name(specifier)))
+  }
+
+  fn check_declarations(&mut self, prev: &ImportDecl, current: &ImportDecl) {
+    let prev_syntax = MemberSyntax::of(prev);
+    let current_syntax = MemberSyntax::of(current);
+    let prev_index = self.syntax_index(prev_syntax);
+    let current_index = self.syntax_index(current_syntax);
+
+    if current_index != prev_index {
+      if current_index < prev_index {
+        self.context.add_diagnostic_with_hint(
+          current.span,
+          CODE,
+          format!(
+            "Expected '{}' syntax before '{}' syntax",
+            current_syntax.as_str(),
+            prev_syntax.as_str()
+          ),
+          format!(
+            "Move the imports with '{}' syntax before the imports with '{}' syntax",
+            current_syntax.as_str(),
+            prev_syntax.as_str()
+          ),
+        );
+      }
+      return;
+    }
+
+    if let (Some(prev_name), Some(current_name)) = (
+      self.first_member_name(prev),
+      self.first_member_name(current),
+    ) {
+      if current_name < prev_name {
+        self.context.add_diagnostic_with_hint(
+          current.span,
+          CODE,
+          SORT_MESSAGE,
+          SORT_HINT,
+        );
+      }
+    }
+  }
+
+  fn check_members(&mut self, import_decl: &ImportDecl) {
+    let mut prev: Option<String> = None;
+    for specifier in &import_decl.specifiers {
+      if !matches!(specifier, ImportSpecifier::Named(_)) {
+        continue;
+      }
+      let name = local_name(specifier);
+      let normalized = self.normalize(&name);
+      if let Some(prev) = &prev {
+        if normalized < *prev {
+          self.context.add_diagnostic_with_hint(
+            specifier.span(),
+            CODE,
+            format!(
+              "Member '{}' of the import declaration should be sorted alphabetically",
+              name
+            ),
+            MEMBER_HINT,
+          );
+          return;
+        }
+      }
+      prev = Some(normalized);
+    }
+  }
+}
+
+fn local_name(specifier: &ImportSpecifier) -> String {
+  match specifier {
+    ImportSpecifier::Named(named) => named.local.sym.to_string(),
+    ImportSpecifier::Default(default) => default.local.sym.to_string(),
+    ImportSpecifier::Namespace(namespace) => namespace.local.sym.to_string(),
+  }
+}
+
+impl<'c, 'o> Visit for SortImportsVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_module(&mut self, module: &Module, _parent: &dyn Node) {
+    let mut prev: Option<&ImportDecl> = None;
+    for item in &module.body {
+      let import_decl = match item {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) => import_decl,
+        _ => continue,
+      };
+      if !self.options.ignore_declaration_sort {
+        if let Some(prev) = prev {
+          self.check_declarations(prev, import_decl);
+        }
+      }
+      if !self.options.ignore_member_sort {
+        self.check_members(import_decl);
+      }
+      prev = Some(import_decl);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  fn member_message(name: &str) -> String {
+    format!(
+      "Member '{}' of the import declaration should be sorted alphabetically",
+      name
+    )
+  }
+
+  #[test]
+  fn sort_imports_valid() {
+    assert_lint_ok! {
+      SortImports,
+      r#"import a from "a";"#,
+      r#"import a from "a"; import b from "b";"#,
+      r#"import a from "b"; import b from "a";"#,
+      r#"import A from "a"; import a from "b";"#,
+      r#"import "polyfill"; import * as ns from "ns"; import { a, b } from "ab"; import c from "c";"#,
+      r#"import { a, b, c } from "abc";"#,
+      r#"import { B, a } from "ab";"#,
+      r#"import { b as a, a as b } from "ab";"#,
+      r#"import d, { a, b } from "abd"; import c from "c";"#,
+      r#"import a from "a"; const b = 1; import c from "c";"#,
+      r#"import "b"; import "a";"#,
+    };
+  }
+
+  #[test]
+  fn sort_imports_invalid() {
+    assert_lint_err! {
+      SortImports,
+      r#"import b from "b"; import a from "a";"#: [
+        { col: 19, message: SORT_MESSAGE, hint: SORT_HINT }
+      ],
+      r#"import a from "a"; import B from "b";"#: [
+        { col: 19, message: SORT_MESSAGE, hint: SORT_HINT }
+      ],
+      r#"import { b, c } from "bc"; import { a, d } from "ad";"#: [
+        { col: 27, message: SORT_MESSAGE, hint: SORT_HINT }
+      ],
+      r#"import a from "a"; import * as ns from "ns";"#: [
+        {
+          col: 19,
+          message: "Expected 'all' syntax before 'single' syntax",
+          hint: "Move the imports with 'all' syntax before the imports with 'single' syntax",
+        }
+      ],
+      r#"import { a, b } from "ab"; import "polyfill";"#: [
+        {
+          col: 27,
+          message: "Expected 'none' syntax before 'multiple' syntax",
+          hint: "Move the imports with 'none' syntax before the imports with 'multiple' syntax",
+        }
+      ],
+      r#"import { b, a } from "ab";"#: [
+        { col: 12, message: member_message("a"), hint: MEMBER_HINT }
+      ],
+      r#"import { a, c, b, d } from "abcd";"#: [
+        { col: 15, message: member_message("b"), hint: MEMBER_HINT }
+      ],
+      r#"import { a, B } from "ab";"#: [
+        { col: 12, message: member_message("B"), hint: MEMBER_HINT }
+      ],
+      r#"import { a as c, b } from "ab";"#: [
+        { col: 17, message: member_message("b"), hint: MEMBER_HINT }
+      ],
+    };
+  }
+
+  #[test]
+  fn sort_imports_options() {
+    let rule = |options| SortImports::with_options(options);
+
+    let ignore_case = SortImportsOptions {
+      ignore_case: true,
+      ..Default::default()
+    };
+    assert_lint_ok_with_rule(
+      rule(ignore_case.clone()),
+      r#"import { c, D } from "cd"; import a from "a"; import B from "b";"#,
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(ignore_case),
+      "import { D, c } from \"cd\";\nimport B from \"b\";\nimport a from \"a\";",
+      vec![(1, 12), (3, 0)],
+    );
+
+    assert_lint_ok_with_rule(
+      rule(SortImportsOptions {
+        ignore_declaration_sort: true,
+        ..Default::default()
+      }),
+      r#"import b from "b"; import * as a from "a";"#,
+    );
+    assert_lint_ok_with_rule(
+      rule(SortImportsOptions {
+        ignore_member_sort: true,
+        ..Default::default()
+      }),
+      r#"import { b, a } from "ab";"#,
+    );
+
+    let single_first = SortImportsOptions {
+      member_syntax_sort_order: vec![
+        MemberSyntax::Single,
+        MemberSyntax::Multiple,
+        MemberSyntax::All,
+        MemberSyntax::None,
+      ],
+      ..Default::default()
+    };
+    assert_lint_ok_with_rule(
+      rule(single_first.clone()),
+      r#"import a from "a"; import { b, c } from "bc"; import * as ns from "ns"; import "polyfill";"#,
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(single_first),
+      "import \"polyfill\";\nimport a from \"a\";",
+      vec![(2, 0)],
+    );
+  }
+}
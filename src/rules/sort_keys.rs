@@ -0,0 +1,368 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::StringRepr;
+use serde::Deserialize;
+use std::cmp::Ordering;
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{ObjectLit, Program, Prop, PropOrSpread};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct SortKeys {
+  options: SortKeysOptions,
+}
+
+const CODE: &str = "sort-keys";
+
+/// Order in which object keys are expected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SortOrder {
+  Asc,
+  Desc,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SortKeysOptions {
+  /// Whether keys are sorted ascending or descending.
+  pub order: SortOrder,
+  /// Whether upper and lower case letters are ordered differently.
+  pub case_sensitive: bool,
+  /// Whether numbers within keys are compared by their value.
+  pub natural: bool,
+  /// Minimum number of keys an object must have to be checked.
+  pub min_keys: usize,
+}
+
+impl Default for SortKeysOptions {
+  fn default() -> Self {
+    Self {
+      order: SortOrder::Asc,
+      case_sensitive: true,
+      natural: false,
+      min_keys: 2,
+    }
+  }
+}
+
+impl SortKeys {
+  pub fn with_options(options: SortKeysOptions) -> Box<Self> {
+    Box::new(SortKeys { options })
+  }
+}
+
+impl LintRule for SortKeys {
+  fn new() -> Box<Self> {
+    Self::with_options(SortKeysOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = SortKeysVisitor::new(context, &self.options);
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires object keys to be sorted
+
+Sorted keys make it easier to find a property in large object literals. This
+rule reports the first key of an object literal which is out of order. Spread
+elements and keys computed at runtime start a new group of keys, which is
+sorted independently.
+
+The rule can be configured with:
+- `order`: `"asc"` (default) or `"desc"`
+- `caseSensitive`: whether upper case letters sort before lower case ones
+  (default `true`)
+- `natural`: whether numbers in keys are compared by value, so that `a2` comes
+  before `a10` (default `false`)
+- `minKeys`: the minimum number of keys for an object to be checked
+  (default `2`)
+
+### Invalid:
+```typescript
+const obj = { b: 1, a: 2 };
+const obj2 = { a: 1, c: 2, b: 3 };
+```
+
+### Valid:
+```typescript
+const obj = { a: 1, b: 2 };
+const obj2 = { b: 1, c: 2, ...rest, a: 3 };
+```
+"#
+  }
+}
+
+struct SortKeysVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o SortKeysOptions,
+}
+
+impl<'c, 'o> SortKeysVisitor<'c, 'o> {
+  fn new(context: &'c mut Context, options: &'o SortKeysOptions) -> Self {
+    Self { context, options }
+  }
+
+  fn is_sorted(&self, prev: &str, current: &str) -> bool {
+    let (prev, current) = if self.options.case_sensitive {
+      (prev.to_string(), current.to_string())
+    } else {
+      (prev.to_lowercase(), current.to_lowercase())
+    };
+    let ordering = if self.options.natural {
+      natural_cmp(&prev, &current)
+    } else {
+      prev.cmp(&current)
+    };
+    match self.options.order {
+      SortOrder::Asc => ordering != Ordering::Greater,
+      SortOrder::Desc => ordering != Ordering::Less,
+    }
+  }
+
+  fn report(&mut self, span: Span, prev: &str, current: &str) {
+    let order = match self.options.order {
+      SortOrder::Asc => "ascending",
+      SortOrder::Desc => "descending",
+    };
+    let natural = if self.options.natural { "natural " } else { "" };
+    let insensitive = if self.options.case_sensitive {
+      ""
+    } else {
+      "insensitive "
+    };
+    self.context.add_diagnostic_with_hint(
+      span,
+      CODE,
+      format!(
+        "Expected object keys to be in {}{}{} order. '{}' should be before '{}'.",
+        natural, insensitive, order, current, prev
+      ),
+      format!("Move '{}' before '{}'", current, prev),
+    );
+  }
+}
+
+/// Returns the static name and the span of the key of a property.
+fn static_key(prop: &Prop) -> Option<(String, Span)> {
+  match prop {
+    Prop::Shorthand(ident) => Some((ident.sym.to_string(), ident.span)),
+    Prop::KeyValue(key_value) => key_value
+      .key
+      .string_repr()
+      .map(|name| (name, key_value.key.span())),
+    Prop::Getter(getter) => getter
+      .key
+      .string_repr()
+      .map(|name| (name, getter.key.span())),
+    Prop::Setter(setter) => setter
+      .key
+      .string_repr()
+      .map(|name| (name, setter.key.span())),
+    Prop::Method(method) => method
+      .key
+      .string_repr()
+      .map(|name| (name, method.key.span())),
+    Prop::Assign(_) => None,
+  }
+}
+
+/// Compares two strings, treating runs of digits as numbers.
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+  let mut a = a.chars().peekable();
+  let mut b = b.chars().peekable();
+  loop {
+    match (a.peek().copied(), b.peek().copied()) {
+      (None, None) => return Ordering::Equal,
+      (None, Some(_)) => return Ordering::Less,
+      (Some(_), None) => return Ordering::Greater,
+      (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+        let mut x_digits = String::new();
+        while let Some(c) = a.peek().copied().filter(char::is_ascii_digit) {
+          x_digits.push(c);
+          a.next();
+        }
+        let mut y_digits = String::new();
+        while let Some(c) = b.peek().copied().filter(char::is_ascii_digit) {
+          y_digits.push(c);
+          b.next();
+        }
+        let x_digits = x_digits.trim_start_matches('0');
+        let y_digits = y_digits.trim_start_matches('0');
+        let ordering = x_digits
+          .len()
+          .cmp(&y_digits.len())
+          .then_with(|| x_digits.cmp(y_digits));
+        if ordering != Ordering::Equal {
+          return ordering;
+        }
+      }
+      (Some(x), Some(y)) => {
+        if x != y {
+          return x.cmp(&y);
+        }
+        a.next();
+        b.next();
+      }
+    }
+  }
+}
+
+impl<'c, 'o> VisitAll for SortKeysVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_object_lit(&mut self, object_lit: &ObjectLit, _parent: &dyn Node) {
+    if object_lit.props.len() < self.options.min_keys {
+      return;
+    }
+
+    let mut prev: Option<String> = None;
+    for prop in &object_lit.props {
+      let prop = match prop {
+        PropOrSpread::Prop(prop) => prop,
+        PropOrSpread::Spread(_) => {
+          prev = None;
+          continue;
+        }
+      };
+      let (name, span) = match static_key(prop) {
+        Some(key) => key,
+        None => {
+          prev = None;
+          continue;
+        }
+      };
+      if let Some(prev) = &prev {
+        if !self.is_sorted(prev, &name) {
+          self.report(span, prev, &name);
+          return;
+        }
+      }
+      prev = Some(name);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  fn message(prev: &str, current: &str) -> String {
+    format!(
+      "Expected object keys to be in ascending order. '{}' should be before '{}'.",
+      current, prev
+    )
+  }
+
+  fn hint(prev: &str, current: &str) -> String {
+    format!("Move '{}' before '{}'", current, prev)
+  }
+
+  #[test]
+  fn sort_keys_valid() {
+    assert_lint_ok! {
+      SortKeys,
+      "const obj = {};",
+      "const obj = { a: 1 };",
+      "const obj = { a: 1, b: 2, c: 3 };",
+      "const obj = { a: 1, b: 2, b: 3 };",
+      "const obj = { A: 1, B: 2, a: 3, b: 4 };",
+      "const obj = { 1: 1, 2: 2, a: 3 };",
+      "const obj = { a, b, c() {}, get d() { return 1; } };",
+      "const obj = { b: 1, [foo]: 2, a: 3 };",
+      "const obj = { ['a']: 1, b: 2 };",
+      "const obj = { a: { x: 1, y: 2 }, b: 2 };",
+
+      // spread elements start a new group
+      "const obj = { b: 1, c: 2, ...rest, a: 3 };",
+      "const obj = { c: 1, ...a, b: 2, ...b, a: 3 };",
+    };
+  }
+
+  #[test]
+  fn sort_keys_invalid() {
+    assert_lint_err! {
+      SortKeys,
+      "const obj = { b: 1, a: 2 };": [
+        { col: 20, message: message("b", "a"), hint: hint("b", "a") }
+      ],
+      "const obj = { a: 1, c: 2, b: 3, a: 4 };": [
+        { col: 26, message: message("c", "b"), hint: hint("c", "b") }
+      ],
+      "const obj = { a: 1, B: 2 };": [
+        { col: 20, message: message("a", "B"), hint: hint("a", "B") }
+      ],
+      "const obj = { b, a };": [
+        { col: 17, message: message("b", "a"), hint: hint("b", "a") }
+      ],
+      "const obj = { ...rest, b: 1, a: 2 };": [
+        { col: 29, message: message("b", "a"), hint: hint("b", "a") }
+      ],
+      "const obj = { a: 1, ...rest, c: 2, b: 3 };": [
+        { col: 35, message: message("c", "b"), hint: hint("c", "b") }
+      ],
+      "const obj = { a: { y: 1, x: 2 } };": [
+        { col: 25, message: message("y", "x"), hint: hint("y", "x") }
+      ],
+      "const obj = { a2: 1, a10: 2 };": [
+        { col: 21, message: message("a2", "a10"), hint: hint("a2", "a10") }
+      ],
+    };
+  }
+
+  #[test]
+  fn sort_keys_case_sensitive() {
+    let rule = || {
+      SortKeys::with_options(SortKeysOptions {
+        case_sensitive: false,
+        ..Default::default()
+      })
+    };
+    assert_lint_ok_with_rule(rule(), "const obj = { a: 1, B: 2, c: 3 };");
+    assert_lint_ok_with_rule(rule(), "const obj = { a: 1, A: 2 };");
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "const obj = { b: 1, A: 2 };",
+      vec![(1, 20)],
+    );
+  }
+
+  #[test]
+  fn sort_keys_options() {
+    let natural = SortKeys::with_options(SortKeysOptions {
+      natural: true,
+      ..Default::default()
+    });
+    assert_lint_ok_with_rule(natural, "const obj = { a2: 1, a10: 2, b: 3 };");
+
+    let desc = SortKeys::with_options(SortKeysOptions {
+      order: SortOrder::Desc,
+      ..Default::default()
+    });
+    assert_lint_ok_with_rule(desc, "const obj = { c: 1, b: 2, a: 3 };");
+    let desc = SortKeys::with_options(SortKeysOptions {
+      order: SortOrder::Desc,
+      ..Default::default()
+    });
+    assert_lint_err_on_line_n_with_rule(
+      desc,
+      "const obj = { a: 1, b: 2 };",
+      vec![(1, 20)],
+    );
+
+    let min_keys = SortKeys::with_options(SortKeysOptions {
+      min_keys: 3,
+      ..Default::default()
+    });
+    assert_lint_ok_with_rule(min_keys, "const obj = { b: 1, a: 2 };");
+  }
+}
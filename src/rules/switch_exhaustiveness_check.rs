@@ -0,0 +1,268 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use std::collections::HashMap;
+use swc_ecmascript::ast::{
+  Expr, ExprOrSuper, Ident, Lit, Program, SwitchStmt, TsEnumDecl,
+  TsEnumMemberId,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::utils::Id;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct SwitchExhaustivenessCheck;
+
+const CODE: &str = "switch-exhaustiveness-check";
+const MESSAGE: &str = "Switch is not exhaustive";
+
+fn hint(missing: &[String]) -> String {
+  format!(
+    "Add cases for {}, or a `default` case",
+    missing
+      .iter()
+      .map(|member| format!("`{}`", member))
+      .collect::<Vec<_>>()
+      .join(", ")
+  )
+}
+
+impl LintRule for SwitchExhaustivenessCheck {
+  fn new() -> Box<Self> {
+    Box::new(SwitchExhaustivenessCheck)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn is_typescript_only(&self) -> bool {
+    true
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut collector = EnumCollector {
+      enums: HashMap::new(),
+    };
+    program.visit_all_with(program, &mut collector);
+    if collector.enums.is_empty() {
+      return;
+    }
+
+    let mut visitor = SwitchExhaustivenessCheckVisitor {
+      context,
+      enums: collector.enums,
+    };
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires `switch` statements over enum members to handle every member
+
+When a member is added to an enum, switches over its members are easily
+forgotten. A `switch` whose cases are all members of an enum declared in the
+same file, and which has no `default` case, is reported if it doesn't have a
+case for every member of the enum.
+
+The type of the discriminant isn't known to the linter, so the enum is inferred
+from the cases of the switch.
+
+### Invalid:
+```typescript
+enum Direction {
+  Up,
+  Down,
+  Left,
+}
+
+function move(direction: Direction) {
+  switch (direction) {
+    case Direction.Up:
+      return "up";
+    case Direction.Down:
+      return "down";
+  }
+}
+```
+
+### Valid:
+```typescript
+enum Direction {
+  Up,
+  Down,
+  Left,
+}
+
+function move(direction: Direction) {
+  switch (direction) {
+    case Direction.Up:
+      return "up";
+    case Direction.Down:
+      return "down";
+    case Direction.Left:
+      return "left";
+  }
+}
+
+function moveVertically(direction: Direction) {
+  switch (direction) {
+    case Direction.Up:
+      return "up";
+    default:
+      return "down";
+  }
+}
+```
+"#
+  }
+}
+
+/// Collects the members of the enums declared in a program.
+struct EnumCollector {
+  /// Member names of each enum, in declaration order. Members of merged
+  /// declarations of an enum are combined.
+  enums: HashMap<Id, Vec<String>>,
+}
+
+impl VisitAll for EnumCollector {
+  fn visit_ts_enum_decl(&mut self, enum_decl: &TsEnumDecl, _: &dyn Node) {
+    let members = self.enums.entry(enum_decl.id.to_id()).or_default();
+    for member in &enum_decl.members {
+      let name = match &member.id {
+        TsEnumMemberId::Ident(ident) => ident.sym.to_string(),
+        TsEnumMemberId::Str(s) => s.value.to_string(),
+      };
+      if !members.contains(&name) {
+        members.push(name);
+      }
+    }
+  }
+}
+
+struct SwitchExhaustivenessCheckVisitor<'c> {
+  context: &'c mut Context,
+  enums: HashMap<Id, Vec<String>>,
+}
+
+/// Returns the enum and member name of an `Enum.Member` or `Enum["Member"]`
+/// expression.
+fn enum_member(expr: &Expr) -> Option<(&Ident, &str)> {
+  let member_expr = match expr {
+    Expr::Member(member_expr) => member_expr,
+    Expr::Paren(paren) => return enum_member(&paren.expr),
+    _ => return None,
+  };
+  let enum_ident = match &member_expr.obj {
+    ExprOrSuper::Expr(obj) => match &**obj {
+      Expr::Ident(ident) => ident,
+      _ => return None,
+    },
+    ExprOrSuper::Super(_) => return None,
+  };
+  let member = match &*member_expr.prop {
+    Expr::Ident(ident) if !member_expr.computed => &*ident.sym,
+    Expr::Lit(Lit::Str(s)) if member_expr.computed => &*s.value,
+    _ => return None,
+  };
+  Some((enum_ident, member))
+}
+
+impl<'c> VisitAll for SwitchExhaustivenessCheckVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_switch_stmt(&mut self, switch: &SwitchStmt, _: &dyn Node) {
+    let mut enum_ident: Option<&Ident> = None;
+    let mut covered = vec![];
+    for case in &switch.cases {
+      let test = match &case.test {
+        Some(test) => test,
+        // Members not covered by cases are handled by the default case.
+        None => return,
+      };
+      let (ident, member) = match enum_member(test) {
+        Some(enum_member) => enum_member,
+        None => return,
+      };
+      match enum_ident {
+        Some(enum_ident) if enum_ident.to_id() != ident.to_id() => return,
+        Some(_) => {}
+        None => enum_ident = Some(ident),
+      }
+      covered.push(member);
+    }
+
+    let enum_ident = match enum_ident {
+      Some(enum_ident) => enum_ident,
+      None => return,
+    };
+    let members = match self.enums.get(&enum_ident.to_id()) {
+      Some(members) => members,
+      None => return,
+    };
+    let missing = members
+      .iter()
+      .filter(|member| !covered.contains(&member.as_str()))
+      .map(|member| format!("{}.{}", enum_ident.sym, member))
+      .collect::<Vec<_>>();
+    if !missing.is_empty() {
+      self.context.add_diagnostic_with_hint(
+        switch.span,
+        CODE,
+        MESSAGE,
+        hint(&missing),
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn switch_exhaustiveness_check_valid() {
+    assert_lint_ok! {
+      SwitchExhaustivenessCheck,
+      "enum E { A, B } function f(e: E) { switch (e) { case E.A: break; case E.B: break; } }",
+      "enum E { A, B } function f(e: E) { switch (e) { case E.B: case E.A: break; } }",
+      "enum E { A, B } function f(e: E) { switch (e) { case E.A: break; default: break; } }",
+      "enum E { A, 'b-c' } function f(e: E) { switch (e) { case E.A: break; case E['b-c']: break; } }",
+      "enum E { A } enum E { B = 1 } function f(e: E) { switch (e) { case E.A: case E.B: break; } }",
+      "const enum E { A, B } function f(e: E) { switch (e) { case E.A: case E.B: break; } }",
+      "enum E { A, B } function f(e: E) { switch (e) { case E.A: break; case 1: break; } }",
+      "enum E { A, B } enum F { A } function f(e: E) { switch (e) { case E.A: break; case F.A: break; } }",
+      "const E = { A: 0, B: 1 }; function f(e) { switch (e) { case E.A: break; } }",
+      "enum E { A, B } function f() { const E = { A: 0 }; switch (e) { case E.A: break; } }",
+      "enum E { A, B } function f(e: E) { switch (e) {} }",
+    };
+  }
+
+  #[test]
+  fn switch_exhaustiveness_check_invalid() {
+    assert_lint_err! {
+      SwitchExhaustivenessCheck,
+      "enum E { A, B, C } function f(e: E) { switch (e) { case E.A: break; } }": [
+        {
+          col: 38,
+          message: MESSAGE,
+          hint: hint(&["E.B".to_string(), "E.C".to_string()]),
+        },
+      ],
+      "enum E { A, B } function f(e: E) { switch (e) { case (E.B): break; } }": [
+        { col: 35, message: MESSAGE, hint: hint(&["E.A".to_string()]) },
+      ],
+      "enum E { A } enum E { B = 1 } function f(e: E) { switch (e) { case E.A: break; } }": [
+        { col: 49, message: MESSAGE, hint: hint(&["E.B".to_string()]) },
+      ],
+      "enum E { A, 'b-c' } function f(e: E) { switch (e) { case E.A: break; } }": [
+        { col: 39, message: MESSAGE, hint: hint(&["E.b-c".to_string()]) },
+      ],
+      "enum E { A, B }\nswitch (a) { case E.A: switch (b) { case E.B: break; } break; }": [
+        { line: 2, col: 0, message: MESSAGE, hint: hint(&["E.B".to_string()]) },
+        { line: 2, col: 23, message: MESSAGE, hint: hint(&["E.A".to_string()]) },
+      ],
+    };
+  }
+}
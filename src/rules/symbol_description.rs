@@ -0,0 +1,117 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use swc_ecmascript::ast::{CallExpr, Expr, ExprOrSuper, Program};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct SymbolDescription;
+
+const CODE: &str = "symbol-description";
+const MESSAGE: &str = "`Symbol()` should have a description";
+const HINT: &str =
+  "Pass a string describing the symbol, e.g. `Symbol(\"description\")`";
+
+impl LintRule for SymbolDescription {
+  fn new() -> Box<Self> {
+    Box::new(SymbolDescription)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = SymbolDescriptionVisitor::new(context);
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires a description when creating symbols
+
+The description of a symbol is shown by `toString()` and in debuggers, which
+makes it much easier to tell symbols apart. A local binding named `Symbol` is
+not reported.
+
+### Invalid:
+```typescript
+const foo = Symbol();
+```
+
+### Valid:
+```typescript
+const foo = Symbol("some description");
+const bar = Symbol(description);
+```
+"#
+  }
+}
+
+struct SymbolDescriptionVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> SymbolDescriptionVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c> VisitAll for SymbolDescriptionVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    if !call_expr.args.is_empty() {
+      return;
+    }
+
+    if let ExprOrSuper::Expr(callee) = &call_expr.callee {
+      if let Expr::Ident(ident) = &**callee {
+        if ident.sym == *"Symbol"
+          && self.context.scope.var(&ident.to_id()).is_none()
+        {
+          self.context.add_diagnostic_with_hint(
+            call_expr.span,
+            CODE,
+            MESSAGE,
+            HINT,
+          );
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn symbol_description_valid() {
+    assert_lint_ok! {
+      SymbolDescription,
+      "Symbol('desc');",
+      "const foo = Symbol(\"desc\");",
+      "const foo = Symbol(desc);",
+      "Symbol.for('foo');",
+      "Symbol.iterator;",
+      "function f(Symbol) { Symbol(); }",
+      "const Symbol = () => {}; Symbol();",
+    };
+  }
+
+  #[test]
+  fn symbol_description_invalid() {
+    assert_lint_err! {
+      SymbolDescription,
+      "Symbol();": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "const foo = Symbol();": [{ col: 12, message: MESSAGE, hint: HINT }],
+      "function f() { return Symbol(); }": [{ col: 22, message: MESSAGE, hint: HINT }],
+      "foo(Symbol());": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "function f(Symbol) {} Symbol();": [{ col: 22, message: MESSAGE, hint: HINT }],
+    };
+  }
+}
@@ -3,29 +3,94 @@ use super::Context;
 use super::LintRule;
 use once_cell::sync::Lazy;
 use regex::Regex;
+use serde::Deserialize;
 use swc_common::comments::Comment;
 use swc_common::comments::CommentKind;
 use swc_common::Span;
 
-pub struct TripleSlashReference;
+pub struct TripleSlashReference {
+  options: TripleSlashReferenceOptions,
+}
+
+const CODE: &str = "triple-slash-reference";
+const IMPORT_HINT: &str = "Use an `import` instead";
+const LIB_HINT: &str =
+  "Add the library to the `lib` compiler option in the TypeScript configuration instead";
+
+/// Whether a kind of triple slash reference is allowed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ReferenceOption {
+  Always,
+  Never,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TripleSlashReferenceOptions {
+  /// Whether `/// <reference path="..." />` is allowed.
+  pub path: ReferenceOption,
+  /// Whether `/// <reference types="..." />` is allowed.
+  pub types: ReferenceOption,
+  /// Whether `/// <reference lib="..." />` is allowed.
+  pub lib: ReferenceOption,
+}
+
+impl Default for TripleSlashReferenceOptions {
+  fn default() -> Self {
+    Self {
+      path: ReferenceOption::Never,
+      types: ReferenceOption::Never,
+      lib: ReferenceOption::Never,
+    }
+  }
+}
 
 impl TripleSlashReference {
-  fn report(&self, context: &mut Context, span: Span) {
-    context.add_diagnostic(
-      span,
-      "triple-slash-reference",
-      "`triple slash reference` is not allowed",
-    );
+  pub fn with_options(options: TripleSlashReferenceOptions) -> Box<Self> {
+    Box::new(TripleSlashReference { options })
+  }
+
+  /// Returns the message and hint to report the comment with, if it is a
+  /// triple slash reference which isn't allowed.
+  fn check_comment(&self, comment: &Comment) -> Option<(String, &'static str)> {
+    if comment.kind != CommentKind::Line {
+      return None;
+    }
+
+    static TSR_REGEX: Lazy<Regex> = Lazy::new(|| {
+      Regex::new(r#"^/\s*<reference\s*(types|path|lib)\s*=\s*["']([^"']*)["']"#)
+        .unwrap()
+    });
+
+    let captures = TSR_REGEX.captures(&comment.text)?;
+    let kind = captures.get(1).unwrap().as_str();
+    let (option, hint) = match kind {
+      "path" => (self.options.path, IMPORT_HINT),
+      "types" => (self.options.types, IMPORT_HINT),
+      "lib" => (self.options.lib, LIB_HINT),
+      _ => unreachable!(),
+    };
+    if option == ReferenceOption::Always {
+      return None;
+    }
+    Some((
+      format!(
+        "Do not use a triple slash reference for `{}`",
+        captures.get(2).unwrap().as_str()
+      ),
+      hint,
+    ))
   }
 }
 
 impl LintRule for TripleSlashReference {
   fn new() -> Box<Self> {
-    Box::new(TripleSlashReference)
+    Self::with_options(TripleSlashReferenceOptions::default())
   }
 
   fn code(&self) -> &'static str {
-    "triple-slash-reference"
+    CODE
   }
 
   fn lint_program(
@@ -33,43 +98,47 @@ impl LintRule for TripleSlashReference {
     context: &mut Context,
     _program: &swc_ecmascript::ast::Program,
   ) {
-    let mut violated_comment_spans = Vec::new();
-
-    violated_comment_spans.extend(
-      context.leading_comments.values().flatten().filter_map(|c| {
-        if check_comment(c) {
-          Some(c.span)
-        } else {
-          None
-        }
-      }),
-    );
-    violated_comment_spans.extend(
-      context
-        .trailing_comments
-        .values()
-        .flatten()
-        .filter_map(|c| if check_comment(c) { Some(c.span) } else { None }),
-    );
+    let mut violations: Vec<(Span, String, &'static str)> = Vec::new();
 
-    for span in violated_comment_spans {
-      self.report(context, span);
+    for comment in context
+      .leading_comments
+      .values()
+      .chain(context.trailing_comments.values())
+      .flatten()
+    {
+      if let Some((message, hint)) = self.check_comment(comment) {
+        violations.push((comment.span, message, hint));
+      }
     }
-  }
-}
 
-/// Returns `true` if the comment should be reported.
-fn check_comment(comment: &Comment) -> bool {
-  if comment.kind != CommentKind::Line {
-    return false;
+    for (span, message, hint) in violations {
+      context.add_diagnostic_with_hint(span, CODE, message, hint);
+    }
   }
 
-  static TSR_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r#"^/\s*<reference\s*(types|path|lib)\s*=\s*["|'](.*)["|']"#)
-      .unwrap()
-  });
+  fn docs(&self) -> &'static str {
+    r#"Disallows triple slash reference directives
+
+Triple slash references, like `/// <reference types="foo" />`, predate ES
+modules. Dependencies are better expressed with `import` statements, which are
+understood by all tools.
+
+Each kind of reference can be allowed with the `path`, `types` and `lib`
+options, set to `"always"` to allow it or `"never"` (default) to report it.
 
-  TSR_REGEX.is_match(&comment.text)
+### Invalid:
+```typescript
+/// <reference path="./foo.ts" />
+/// <reference types="node" />
+/// <reference lib="dom" />
+```
+
+### Valid:
+```typescript
+import * as foo from "./foo.ts";
+```
+"#
+  }
 }
 
 #[cfg(test)]
@@ -136,4 +205,68 @@ import foo = require('foo');
       0,
     );
   }
+
+  #[test]
+  fn triple_slash_reference_message() {
+    assert_lint_err! {
+      TripleSlashReference,
+      r#"/// <reference types="foo" />"#: [
+        {
+          col: 0,
+          message: "Do not use a triple slash reference for `foo`",
+          hint: IMPORT_HINT,
+        }
+      ],
+      r#"/// <reference path='./foo.ts' />"#: [
+        {
+          col: 0,
+          message: "Do not use a triple slash reference for `./foo.ts`",
+          hint: IMPORT_HINT,
+        }
+      ],
+      r#"/// <reference lib="dom" />"#: [
+        {
+          col: 0,
+          message: "Do not use a triple slash reference for `dom`",
+          hint: LIB_HINT,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn triple_slash_reference_options() {
+    let source = "/// <reference path=\"foo\" />\n/// <reference types=\"bar\" />\n/// <reference lib=\"baz\" />";
+    let rule = |path, types, lib| {
+      TripleSlashReference::with_options(TripleSlashReferenceOptions {
+        path,
+        types,
+        lib,
+      })
+    };
+    use ReferenceOption::{Always, Never};
+
+    assert_lint_ok_with_rule(rule(Always, Always, Always), source);
+    assert_lint_err_on_line_n_with_rule(
+      rule(Never, Always, Always),
+      source,
+      vec![(1, 0)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(Always, Never, Always),
+      source,
+      vec![(2, 0)],
+    );
+    assert_lint_err_on_line_n_with_rule(
+      rule(Always, Always, Never),
+      source,
+      vec![(3, 0)],
+    );
+
+    let options: TripleSlashReferenceOptions =
+      serde_json::from_str(r#"{ "types": "always" }"#).unwrap();
+    assert_eq!(options.types, Always);
+    assert_eq!(options.path, Never);
+    assert_eq!(options.lib, Never);
+  }
 }
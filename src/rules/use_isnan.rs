@@ -28,6 +28,33 @@ impl LintRule for UseIsNaN {
     let mut visitor = UseIsNaNVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires `isNaN()` for checking against `NaN`
+
+`NaN` is not equal to anything, including itself, so comparisons like
+`x === NaN` are always `false` and `x !== NaN` is always `true`. A `switch` on
+`NaN` never matches any of its cases either. Use `isNaN()` or `Number.isNaN()`
+instead.
+
+### Invalid:
+```typescript
+if (foo == NaN) {}
+if (foo !== NaN) {}
+
+switch (NaN) {
+  case foo:
+    break;
+}
+```
+
+### Valid:
+```typescript
+if (isNaN(foo)) {}
+if (!Number.isNaN(foo)) {}
+```
+"#
+  }
 }
 
 struct UseIsNaNVisitor<'c> {
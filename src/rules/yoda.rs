@@ -0,0 +1,493 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::Suggestion;
+use crate::swc_util::DropSpan;
+use serde::Deserialize;
+use std::collections::HashSet;
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{
+  BinExpr, BinaryOp, DoWhileStmt, Expr, IfStmt, Lit, ParenExpr, Program, Tpl,
+  UnaryExpr, UnaryOp, WhileStmt,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::VisitAll;
+use swc_ecmascript::visit::VisitAllWith;
+
+pub struct Yoda {
+  options: YodaOptions,
+}
+
+const CODE: &str = "yoda";
+const HINT: &str = "Swap the operands of the comparison";
+const LABEL: &str = "Swap the operands";
+
+fn message(side: &str, op: BinaryOp) -> String {
+  format!(
+    "Expected literal to be on the {} side of `{}`",
+    side,
+    op.as_str()
+  )
+}
+
+/// Which side of a comparison a literal has to be on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum YodaMode {
+  /// Literals have to be on the right side, e.g. `color === "red"`.
+  Never,
+  /// Literals have to be on the left side, e.g. `"red" === color`.
+  Always,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct YodaOptions {
+  pub mode: YodaMode,
+  /// Allows range checks like `(0 <= x && x < 10)`, in which the literal
+  /// comes first in one of the comparisons. The range check has to be
+  /// wrapped in parentheses or be the condition of an `if` or a loop.
+  pub except_range: bool,
+  /// Only checks the `==` and `===` operators.
+  pub only_equality: bool,
+}
+
+impl Default for YodaOptions {
+  fn default() -> Self {
+    Self {
+      mode: YodaMode::Never,
+      except_range: false,
+      only_equality: false,
+    }
+  }
+}
+
+impl Yoda {
+  pub fn with_options(options: YodaOptions) -> Box<Self> {
+    Box::new(Yoda { options })
+  }
+}
+
+impl LintRule for Yoda {
+  fn new() -> Box<Self> {
+    Self::with_options(YodaOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = YodaVisitor {
+      context,
+      options: &self.options,
+      range_comparisons: HashSet::new(),
+    };
+    program.visit_all_with(program, &mut visitor);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces a consistent side for literals in comparisons
+
+A "Yoda condition" puts the literal first, as in `"red" === color`. It reads
+backwards compared to `color === "red"`. By default (the `"never"` mode) this
+rule reports Yoda conditions; the `"always"` mode requires them instead.
+
+With `exceptRange`, range checks such as `(0 <= x && x < 10)` are allowed, since
+they read like the mathematical notation. They have to be wrapped in parentheses
+or be the condition of an `if` or a loop. With `onlyEquality`, only the `==`
+and `===` operators are checked.
+
+### Invalid:
+```typescript
+if ("red" === color) {}
+if (5 < value) {}
+if (null != foo) {}
+```
+
+### Valid:
+```typescript
+if (color === "red") {}
+if (value > 5) {}
+if (foo != null) {}
+if (a === b) {}
+```
+"#
+  }
+}
+
+struct YodaVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o YodaOptions,
+  /// Spans of the comparisons which are part of a range check.
+  range_comparisons: HashSet<Span>,
+}
+
+impl<'c, 'o> YodaVisitor<'c, 'o> {
+  fn is_checked_operator(&self, op: BinaryOp) -> bool {
+    use BinaryOp::*;
+    match op {
+      EqEq | EqEqEq => true,
+      NotEq | NotEqEq | Lt | LtEq | Gt | GtEq => !self.options.only_equality,
+      _ => false,
+    }
+  }
+
+  /// Returns the suggestion to swap the operands of `bin_expr`, flipping the
+  /// operator if it's a relational one.
+  fn suggestion(&self, bin_expr: &BinExpr) -> Option<Suggestion> {
+    let left = self.context.snippet(bin_expr.left.span())?;
+    let right = self.context.snippet(bin_expr.right.span())?;
+    // Comparisons are left-associative, so a comparison of the same
+    // precedence needs parentheses when moved to the right.
+    let left = match &*bin_expr.left {
+      Expr::Bin(left_expr)
+        if precedence(left_expr.op) == precedence(bin_expr.op) =>
+      {
+        format!("({})", left)
+      }
+      _ => left,
+    };
+    let new_text = format!("{} {} {}", right, flip(bin_expr.op).as_str(), left);
+    self
+      .context
+      .text_edit(bin_expr.span, new_text)
+      .map(|edit| Suggestion {
+        label: LABEL.to_string(),
+        edit,
+      })
+  }
+
+  fn check_range_test(&mut self, expr: &Expr) {
+    if !self.options.except_range {
+      return;
+    }
+    if let Expr::Bin(bin_expr) = expr {
+      if let Some((left, right)) = range_test(bin_expr) {
+        self.range_comparisons.insert(left.span);
+        self.range_comparisons.insert(right.span);
+      }
+    }
+  }
+}
+
+impl<'c, 'o> VisitAll for YodaVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_paren_expr(&mut self, paren_expr: &ParenExpr, _parent: &dyn Node) {
+    self.check_range_test(&paren_expr.expr);
+  }
+
+  fn visit_if_stmt(&mut self, if_stmt: &IfStmt, _parent: &dyn Node) {
+    self.check_range_test(&if_stmt.test);
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _parent: &dyn Node) {
+    self.check_range_test(&while_stmt.test);
+  }
+
+  fn visit_do_while_stmt(
+    &mut self,
+    do_while_stmt: &DoWhileStmt,
+    _parent: &dyn Node,
+  ) {
+    self.check_range_test(&do_while_stmt.test);
+  }
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+    if !self.is_checked_operator(bin_expr.op)
+      || self.range_comparisons.contains(&bin_expr.span)
+    {
+      return;
+    }
+
+    let left_is_literal = is_literal(&bin_expr.left);
+    let right_is_literal = is_literal(&bin_expr.right);
+    let side = match self.options.mode {
+      YodaMode::Never if left_is_literal && !right_is_literal => "right",
+      YodaMode::Always if right_is_literal && !left_is_literal => "left",
+      _ => return,
+    };
+    let suggestions = self.suggestion(bin_expr).into_iter().collect();
+    self.context.add_diagnostic_with_suggestions(
+      bin_expr.span,
+      CODE,
+      message(side, bin_expr.op),
+      Some(HINT.to_string()),
+      suggestions,
+    );
+  }
+}
+
+/// Returns the operator which compares the swapped operands the same way.
+fn flip(op: BinaryOp) -> BinaryOp {
+  use BinaryOp::*;
+  match op {
+    Lt => Gt,
+    LtEq => GtEq,
+    Gt => Lt,
+    GtEq => LtEq,
+    op => op,
+  }
+}
+
+/// Returns the precedence level of comparison operators, higher levels bind
+/// tighter.
+fn precedence(op: BinaryOp) -> Option<u8> {
+  use BinaryOp::*;
+  match op {
+    EqEq | NotEq | EqEqEq | NotEqEq => Some(1),
+    Lt | LtEq | Gt | GtEq | In | InstanceOf => Some(2),
+    _ => None,
+  }
+}
+
+fn is_literal(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(Lit::JSXText(_)) => false,
+    Expr::Lit(_) => true,
+    Expr::Tpl(Tpl { exprs, .. }) => exprs.is_empty(),
+    Expr::Unary(UnaryExpr {
+      op: UnaryOp::Minus,
+      arg,
+      ..
+    }) => matches!(&**arg, Expr::Lit(Lit::Num(_))),
+    Expr::Paren(ParenExpr { expr, .. }) => is_literal(expr),
+    _ => false,
+  }
+}
+
+/// A literal value which can be ordered in a range check.
+enum RangeBound {
+  Num(f64),
+  Str(String),
+}
+
+fn range_bound(expr: &Expr) -> Option<RangeBound> {
+  match expr {
+    Expr::Lit(Lit::Num(num)) => Some(RangeBound::Num(num.value)),
+    Expr::Lit(Lit::Str(s)) => Some(RangeBound::Str(s.value.to_string())),
+    Expr::Unary(UnaryExpr {
+      op: UnaryOp::Minus,
+      arg,
+      ..
+    }) => match &**arg {
+      Expr::Lit(Lit::Num(num)) => Some(RangeBound::Num(-num.value)),
+      _ => None,
+    },
+    Expr::Paren(ParenExpr { expr, .. }) => range_bound(expr),
+    _ => None,
+  }
+}
+
+/// Checks whether the bounds of a range check are in ascending order. A
+/// bound which isn't a literal is assumed to be in order.
+fn bounds_in_order(lower: &Expr, upper: &Expr) -> bool {
+  match (range_bound(lower), range_bound(upper)) {
+    (Some(RangeBound::Num(lower)), Some(RangeBound::Num(upper))) => {
+      lower <= upper
+    }
+    (Some(RangeBound::Str(lower)), Some(RangeBound::Str(upper))) => {
+      lower <= upper
+    }
+    (Some(_), Some(_)) | (None, None) => false,
+    _ => true,
+  }
+}
+
+fn is_same_reference(a: &Expr, b: &Expr) -> bool {
+  matches!(a, Expr::Ident(_) | Expr::Member(_) | Expr::This(_))
+    && a.clone().drop_span() == b.clone().drop_span()
+}
+
+/// Returns both comparisons of a range check, which is either a "between"
+/// test like `a <= x && x < b` or an "outside" test like `x < a || b <= x`.
+fn range_test(bin_expr: &BinExpr) -> Option<(&BinExpr, &BinExpr)> {
+  let (left, right) = match (&*bin_expr.left, &*bin_expr.right) {
+    (Expr::Bin(left), Expr::Bin(right)) => (left, right),
+    _ => return None,
+  };
+  let is_range_operator =
+    |op: BinaryOp| matches!(op, BinaryOp::Lt | BinaryOp::LtEq);
+  if !is_range_operator(left.op) || !is_range_operator(right.op) {
+    return None;
+  }
+
+  let is_range = match bin_expr.op {
+    BinaryOp::LogicalAnd => {
+      is_same_reference(&left.right, &right.left)
+        && bounds_in_order(&left.left, &right.right)
+    }
+    BinaryOp::LogicalOr => {
+      is_same_reference(&left.left, &right.right)
+        && bounds_in_order(&left.right, &right.left)
+    }
+    _ => false,
+  };
+  if is_range {
+    Some((left, right))
+  } else {
+    None
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn yoda_valid() {
+    assert_lint_ok! {
+      Yoda,
+      r#"if (color === "red") {}"#,
+      "if (value > 5) {}",
+      "if (foo != null) {}",
+      "if (a === b) {}",
+      "if (1 === 2) {}",
+      "if (x < -1) {}",
+      "if (x === `red`) {}",
+      "if (`${a}` === b) {}",
+      "if (a + 1 === b) {}",
+      "a = 1 + b;",
+    };
+  }
+
+  #[test]
+  fn yoda_invalid() {
+    assert_lint_err! {
+      Yoda,
+      r#"if ("red" === color) {}"#: [
+        {
+          col: 4,
+          message: message("right", BinaryOp::EqEqEq),
+          hint: HINT,
+        }
+      ],
+      "if (5 < value) {}": [
+        {
+          col: 4,
+          message: message("right", BinaryOp::Lt),
+          hint: HINT,
+        }
+      ],
+      "if (null != foo) {}": [
+        {
+          col: 4,
+          message: message("right", BinaryOp::NotEq),
+          hint: HINT,
+        }
+      ],
+      "if (-1 >= x.y) {}": [
+        {
+          col: 4,
+          message: message("right", BinaryOp::GtEq),
+          hint: HINT,
+        }
+      ],
+      "if (`red` == color) {}": [
+        {
+          col: 4,
+          message: message("right", BinaryOp::EqEq),
+          hint: HINT,
+        }
+      ],
+      // Range checks are reported without `exceptRange`.
+      "if (0 <= x && x < 10) {}": [
+        {
+          col: 4,
+          message: message("right", BinaryOp::LtEq),
+          hint: HINT,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn yoda_except_range() {
+    let rule = || {
+      Yoda::with_options(
+        serde_json::from_str(r#"{ "exceptRange": true }"#).unwrap(),
+      )
+    };
+
+    assert_lint_ok_with_rule(rule(), "if (0 <= x && x < 10) {}");
+    assert_lint_ok_with_rule(rule(), "while (x < -1 || 1 < x) {}");
+    assert_lint_ok_with_rule(rule(), "const a = (0 <= x && x < 10);");
+    assert_lint_ok_with_rule(rule(), "f((x < 0 || 10 <= x));");
+    assert_lint_ok_with_rule(rule(), "f((-1 < x.y && x.y < 1));");
+    assert_lint_ok_with_rule(rule(), "f((min <= x && x <= 10));");
+    assert_lint_ok_with_rule(rule(), "f(('a' <= c && c <= 'z'));");
+    // Not parenthesized.
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "const a = 0 <= x && x < 10;",
+      vec![(1, 10)],
+    );
+    // Bounds in the wrong order.
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "f((10 <= x && x < 0));",
+      vec![(1, 3)],
+    );
+    // Different references.
+    assert_lint_err_on_line_n_with_rule(
+      rule(),
+      "f((0 <= x && y < 10));",
+      vec![(1, 3)],
+    );
+  }
+
+  #[test]
+  fn yoda_options() {
+    let rule = |options: &str| {
+      Yoda::with_options(serde_json::from_str(options).unwrap())
+    };
+
+    let always = r#"{ "mode": "always" }"#;
+    assert_lint_ok_with_rule(rule(always), r#"if ("red" === color) {}"#);
+    assert_lint_ok_with_rule(rule(always), "if (1 === 2) {}");
+    assert_lint_err_on_line_n_with_rule(
+      rule(always),
+      r#"if (color === "red") {}"#,
+      vec![(1, 4)],
+    );
+
+    let only_equality = r#"{ "onlyEquality": true }"#;
+    assert_lint_ok_with_rule(rule(only_equality), "if (5 < value) {}");
+    assert_lint_ok_with_rule(rule(only_equality), "if (null !== foo) {}");
+    assert_lint_err_on_line_n_with_rule(
+      rule(only_equality),
+      "if (null === foo) {}",
+      vec![(1, 4)],
+    );
+  }
+
+  #[test]
+  fn yoda_suggestions() {
+    let cases = [
+      (r#"if ("red" === color) {}"#, r#"if (color === "red") {}"#),
+      ("if (5 < value) {}", "if (value > 5) {}"),
+      ("if (-1 >= x.y) {}", "if (x.y <= -1) {}"),
+      ("if (null != a + b) {}", "if (a + b != null) {}"),
+    ];
+    for (source, expected) in cases.iter() {
+      assert_eq!(apply_suggestions(Yoda::new(), source), vec![*expected]);
+    }
+
+    let always = || {
+      Yoda::with_options(
+        serde_json::from_str(r#"{ "mode": "always" }"#).unwrap(),
+      )
+    };
+    assert_eq!(
+      apply_suggestions(always(), "if (x <= 1) {}"),
+      vec!["if (1 >= x) {}"]
+    );
+    assert_eq!(
+      apply_suggestions(always(), "if (a === b === true) {}"),
+      vec!["if (true === (a === b)) {}"]
+    );
+  }
+}
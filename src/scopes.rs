@@ -3,9 +3,10 @@ use swc_atoms::JsWord;
 use swc_common::DUMMY_SP;
 use swc_ecmascript::ast::{
   ArrowExpr, BlockStmt, BlockStmtOrExpr, CatchClause, ClassDecl, ClassExpr,
-  DoWhileStmt, Expr, FnDecl, ForInStmt, ForOfStmt, ForStmt, Function, Ident,
-  ImportDefaultSpecifier, ImportNamedSpecifier, ImportStarAsSpecifier, Invalid,
-  Param, Pat, Program, SwitchStmt, VarDecl, VarDeclKind, WhileStmt, WithStmt,
+  DoWhileStmt, Expr, FnDecl, FnExpr, ForInStmt, ForOfStmt, ForStmt, Function,
+  Ident, ImportDefaultSpecifier, ImportNamedSpecifier, ImportStarAsSpecifier,
+  Invalid, Param, Pat, Program, SwitchStmt, VarDecl, VarDeclKind, WhileStmt,
+  WithStmt,
 };
 use swc_ecmascript::utils::find_ids;
 use swc_ecmascript::utils::ident::IdentLike;
@@ -143,7 +144,12 @@ impl Analyzer<'_> {
 
 impl Visit for Analyzer<'_> {
   fn visit_arrow_expr(&mut self, n: &ArrowExpr, _: &dyn Node) {
-    self.with(ScopeKind::Arrow, |a| n.visit_children_with(a))
+    self.with(ScopeKind::Arrow, |a| {
+      for param in &n.params {
+        a.declare_pat(BindingKind::Param, param);
+      }
+      n.visit_children_with(a)
+    })
   }
 
   /// Overriden not to add ScopeKind::Block
@@ -204,6 +210,16 @@ impl Visit for Analyzer<'_> {
     self.visit_with_path(ScopeKind::Function, &n.function);
   }
 
+  fn visit_fn_expr(&mut self, n: &FnExpr, _: &dyn Node) {
+    self.with(ScopeKind::Function, |a| {
+      // The name of a function expression is only visible inside of it.
+      if let Some(ident) = &n.ident {
+        a.declare(BindingKind::Function, ident);
+      }
+      n.function.visit_with(n, a);
+    })
+  }
+
   fn visit_class_decl(&mut self, n: &ClassDecl, _: &dyn Node) {
     self.declare(BindingKind::Class, &n.ident);
 
@@ -371,4 +387,27 @@ try {
     assert_eq!(var(&scope, "e").kind(), BindingKind::CatchClause);
     assert_eq!(var(&scope, "e").path(), &[]);
   }
+
+  #[test]
+  fn function_expression_name() {
+    let source_code = r#"
+const foo = function bar(baz) {};
+"#;
+    let scope = test_scope(source_code);
+    assert_eq!(var(&scope, "foo").kind(), BindingKind::Const);
+    assert_eq!(var(&scope, "bar").kind(), BindingKind::Function);
+    assert_eq!(var(&scope, "bar").path(), &[ScopeKind::Function]);
+    assert_eq!(var(&scope, "baz").path(), &[ScopeKind::Function]);
+  }
+
+  #[test]
+  fn arrow_params() {
+    let source_code = r#"
+const foo = (bar, { baz }) => bar;
+"#;
+    let scope = test_scope(source_code);
+    assert_eq!(var(&scope, "bar").kind(), BindingKind::Param);
+    assert_eq!(var(&scope, "bar").path(), &[ScopeKind::Arrow]);
+    assert_eq!(var(&scope, "baz").kind(), BindingKind::Param);
+  }
 }
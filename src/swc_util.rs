@@ -1,10 +1,15 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use crate::linter::Context;
 use crate::scopes::Scope;
+use regex::Regex;
+use std::collections::HashMap;
+use swc_common::comments::Comment;
+use swc_common::BytePos;
 use swc_common::Span;
 use swc_common::DUMMY_SP;
 use swc_ecmascript::ast::{
-  ComputedPropName, Expr, ExprOrSpread, Ident, Lit, MemberExpr, PatOrExpr,
-  PrivateName, Prop, PropName, PropOrSpread, Str, Tpl,
+  BlockStmt, ComputedPropName, Expr, ExprOrSpread, Ident, Lit, MemberExpr,
+  PatOrExpr, PrivateName, Prop, PropName, PropOrSpread, Str, Tpl,
 };
 use swc_ecmascript::utils::{find_ids, ident::IdentLike};
 use swc_ecmascript::visit::Fold;
@@ -184,3 +189,101 @@ where
     PatOrExpr::Pat(p) => find_ids(p),
   }
 }
+
+/// Provides a method to check whether a node has comments inside of it.
+pub(crate) trait ContainsComments {
+  fn contains_comments(&self, context: &Context) -> bool;
+}
+
+impl ContainsComments for BlockStmt {
+  fn contains_comments(&self, context: &Context) -> bool {
+    let contains = |comments: &HashMap<BytePos, Vec<Comment>>| {
+      comments
+        .values()
+        .flatten()
+        .any(|comment| self.span.contains(comment.span))
+    };
+
+    contains(&context.leading_comments) || contains(&context.trailing_comments)
+  }
+}
+
+/// Counts the lines touched by `span`, optionally leaving out lines which
+/// are blank or contain nothing but comments.
+pub(crate) fn count_lines(
+  context: &Context,
+  span: Span,
+  skip_blank_lines: bool,
+  skip_comments: bool,
+) -> usize {
+  let file = context.source_map.lookup_char_pos(span.lo).file;
+  let first_line = file.lookup_line(span.lo).unwrap_or(0);
+  let last_line = file.lookup_line(span.hi).unwrap_or(first_line);
+
+  let comments: Vec<Span> = if skip_comments {
+    context
+      .leading_comments
+      .values()
+      .chain(context.trailing_comments.values())
+      .flatten()
+      .map(|comment| comment.span)
+      .filter(|comment| comment.lo < span.hi && span.lo < comment.hi)
+      .collect()
+  } else {
+    vec![]
+  };
+  let in_comment =
+    |pos: BytePos| comments.iter().any(|c| c.lo <= pos && pos < c.hi);
+
+  (first_line..=last_line)
+    .filter(|&line_index| {
+      let line = file.get_line(line_index).unwrap_or_default();
+      let (line_start, _) = file.line_bounds(line_index);
+      let has_code = line.char_indices().any(|(offset, c)| {
+        !c.is_whitespace() && !in_comment(line_start + BytePos(offset as u32))
+      });
+      if has_code {
+        true
+      } else if line.trim().is_empty() {
+        !skip_blank_lines
+      } else {
+        // The line consists of comments only.
+        false
+      }
+    })
+    .count()
+}
+
+/// Returns the comments between two adjacent tokens, the first one ending at
+/// `lo` and the second one starting at `hi`, in source order.
+///
+/// The parser attaches a comment either as a trailing comment of the token
+/// before it or as a leading comment of the token after it, so only those two
+/// positions are looked up.
+pub(crate) fn comments_between(
+  context: &Context,
+  lo: BytePos,
+  hi: BytePos,
+) -> impl Iterator<Item = &Comment> {
+  let trailing = context.trailing_comments.get(&lo).into_iter().flatten();
+  let leading = context.leading_comments.get(&hi).into_iter().flatten();
+  trailing.chain(leading)
+}
+
+/// Compiles the configured `pattern` that comments are matched against by the
+/// rule with `code`, or returns `default` if there's none. An invalid pattern
+/// is logged and `default` is used instead.
+pub(crate) fn comment_pattern(
+  code: &str,
+  pattern: Option<String>,
+  default: &Regex,
+) -> Regex {
+  match pattern.map(|pattern| Regex::new(&pattern)) {
+    Some(Ok(regex)) => regex,
+    Some(Err(err)) => {
+      warn!("Invalid commentPattern option of \"{}\": {}", code, err);
+      default.clone()
+    }
+    None => default.clone(),
+  }
+}
@@ -1,6 +1,7 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 
 use crate::ast_parser;
+use crate::ast_parser::MediaType;
 use crate::diagnostic::LintDiagnostic;
 use crate::linter::LinterBuilder;
 use crate::rules::LintRule;
@@ -158,13 +159,43 @@ impl<T: LintRule + 'static> LintErrTester<T> {
   }
 }
 
-fn lint(rule: Box<dyn LintRule>, source: &str) -> Vec<LintDiagnostic> {
+pub fn lint(rule: Box<dyn LintRule>, source: &str) -> Vec<LintDiagnostic> {
+  lint_file(rule, "deno_lint_test.tsx", source)
+}
+
+pub fn lint_file(
+  rule: Box<dyn LintRule>,
+  file_name: &str,
+  source: &str,
+) -> Vec<LintDiagnostic> {
   let mut linter = LinterBuilder::default()
     .lint_unused_ignore_directives(false)
     .lint_unknown_rules(false)
     .syntax(ast_parser::get_default_ts_config())
     .rules(vec![rule])
-    .build();
+    .build()
+    .expect("Failed to create linter");
+
+  let (_, diagnostics) = linter
+    .lint(file_name.to_string(), source.to_string())
+    .expect("Failed to lint");
+  diagnostics
+}
+
+/// Like `lint_file`, but parses the source with the syntax of `media_type`,
+/// e.g. to lint JSX.
+pub fn lint_with_media_type(
+  rule: Box<dyn LintRule>,
+  media_type: MediaType,
+  source: &str,
+) -> Vec<LintDiagnostic> {
+  let mut linter = LinterBuilder::default()
+    .lint_unused_ignore_directives(false)
+    .lint_unknown_rules(false)
+    .media_type(media_type)
+    .rules(vec![rule])
+    .build()
+    .expect("Failed to create linter");
 
   let (_, diagnostics) = linter
     .lint("deno_lint_test.tsx".to_string(), source.to_string())
@@ -172,6 +203,24 @@ fn lint(rule: Box<dyn LintRule>, source: &str) -> Vec<LintDiagnostic> {
   diagnostics
 }
 
+/// Lints `source` and returns it with each suggestion applied separately, in
+/// the order of the diagnostics.
+pub fn apply_suggestions(rule: Box<dyn LintRule>, source: &str) -> Vec<String> {
+  lint(rule, source)
+    .iter()
+    .flat_map(|diagnostic| diagnostic.suggestions.iter())
+    .map(|suggestion| {
+      let range = &suggestion.edit.range;
+      let mut fixed = source.to_string();
+      fixed.replace_range(
+        range.start.byte_pos..range.end.byte_pos,
+        &suggestion.edit.new_text,
+      );
+      fixed
+    })
+    .collect()
+}
+
 pub fn assert_diagnostic(
   diagnostic: &LintDiagnostic,
   code: &str,
@@ -247,6 +296,18 @@ pub fn assert_lint_ok<T: LintRule + 'static>(source: &str) {
   }
 }
 
+/// Like `assert_lint_ok`, but runs an already constructed rule, e.g. one
+/// created with non-default options.
+pub fn assert_lint_ok_with_rule(rule: Box<dyn LintRule>, source: &str) {
+  let diagnostics = lint(rule, source);
+  if !diagnostics.is_empty() {
+    panic!(
+      "Unexpected diagnostics found:\n{:#?}\n\nsource:\n{}\n",
+      diagnostics, source
+    );
+  }
+}
+
 pub fn assert_lint_err<T: LintRule + 'static>(source: &str, col: usize) {
   assert_lint_err_on_line::<T>(source, 1, col)
 }
@@ -301,6 +362,29 @@ pub fn assert_lint_err_on_line_n<T: LintRule + 'static>(
   }
 }
 
+/// Like `assert_lint_err_on_line_n`, but runs an already constructed rule,
+/// e.g. one created with non-default options.
+pub fn assert_lint_err_on_line_n_with_rule(
+  rule: Box<dyn LintRule>,
+  source: &str,
+  expected: Vec<(usize, usize)>,
+) {
+  let rule_code = rule.code();
+  let diagnostics = lint(rule, source);
+  assert_eq!(
+    diagnostics.len(),
+    expected.len(),
+    "{} diagnostics expected, but got {}.\n\nsource:\n{}\n",
+    expected.len(),
+    diagnostics.len(),
+    source
+  );
+  for i in 0..diagnostics.len() {
+    let (line, col) = expected[i];
+    assert_diagnostic(&diagnostics[i], rule_code, line, col, source);
+  }
+}
+
 pub fn parse(source_code: &str) -> Program {
   let ast_parser = ast_parser::AstParser::new();
   let syntax = ast_parser::get_default_ts_config();
@@ -0,0 +1,150 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use deno_lint::linter::{Context, LinterBuilder};
+use deno_lint::rules::no_debugger::NoDebugger;
+use deno_lint::rules::LintRule;
+use swc_ecmascript::ast::{Ident, Program};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::visit::{noop_visit_type, Node, Visit};
+
+/// Disallows identifiers named `foo`.
+struct NoFoo;
+
+impl LintRule for NoFoo {
+  fn new() -> Box<Self> {
+    Box::new(NoFoo)
+  }
+
+  fn code(&self) -> &'static str {
+    "no-foo"
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = NoFooVisitor { context };
+    visitor.visit_program(program, program);
+  }
+}
+
+struct NoFooVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> Visit for NoFooVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_ident(&mut self, ident: &Ident, _parent: &dyn Node) {
+    if &*ident.sym == "foo" {
+      self.context.add_diagnostic_with_hint(
+        ident.span,
+        "no-foo",
+        "`foo` is not allowed",
+        "Use a descriptive name",
+      );
+    }
+  }
+}
+
+/// Disallows references to a global `bar`, using the scope analysis of the
+/// linter.
+struct NoGlobalBar;
+
+impl LintRule for NoGlobalBar {
+  fn new() -> Box<Self> {
+    Box::new(NoGlobalBar)
+  }
+
+  fn code(&self) -> &'static str {
+    "no-global-bar"
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    let mut visitor = NoGlobalBarVisitor { context };
+    visitor.visit_program(program, program);
+  }
+}
+
+struct NoGlobalBarVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> Visit for NoGlobalBarVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_ident(&mut self, ident: &Ident, _parent: &dyn Node) {
+    if &*ident.sym == "bar"
+      && self.context.scope().var(&ident.to_id()).is_none()
+    {
+      self.context.add_diagnostic(
+        ident.span,
+        "no-global-bar",
+        "Global `bar` is not allowed",
+      );
+    }
+  }
+}
+
+#[test]
+fn custom_rule_runs_through_linter() {
+  let mut linter = LinterBuilder::default()
+    .rules(vec![NoDebugger::new()])
+    .build()
+    .expect("Failed to create linter");
+  linter.add_rule(NoFoo::new()).expect("Failed to add rule");
+
+  let (_, diagnostics) = linter
+    .lint(
+      "custom_rule.ts".to_string(),
+      "const foo = 1;\ndebugger;\n// deno-lint-ignore no-foo\nbar(foo);\nfoo;"
+        .to_string(),
+    )
+    .expect("Failed to lint");
+
+  let found = diagnostics
+    .iter()
+    .map(|d| (d.code.as_str(), d.range.start.line, d.range.start.col))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    found,
+    vec![("no-foo", 1, 6), ("no-debugger", 2, 0), ("no-foo", 5, 0)]
+  );
+  assert_eq!(diagnostics[0].message, "`foo` is not allowed");
+  assert_eq!(
+    diagnostics[0].hint.as_deref(),
+    Some("Use a descriptive name")
+  );
+}
+
+#[test]
+fn custom_rule_code_must_be_unique() {
+  let mut linter = LinterBuilder::default()
+    .rules(vec![NoFoo::new()])
+    .build()
+    .expect("Failed to create linter");
+  let error = linter.add_rule(NoFoo::new()).unwrap_err();
+  assert_eq!(error.code, "no-foo");
+
+  let result = LinterBuilder::default()
+    .rules(vec![NoFoo::new(), NoFoo::new()])
+    .build();
+  assert!(result.is_err());
+}
+
+#[test]
+fn custom_rule_uses_scope() {
+  let mut linter = LinterBuilder::default()
+    .rules(vec![NoGlobalBar::new()])
+    .build()
+    .expect("Failed to create linter");
+
+  let (_, diagnostics) = linter
+    .lint(
+      "custom_rule.ts".to_string(),
+      "bar();\nfunction foo(bar) {\n  bar();\n}".to_string(),
+    )
+    .expect("Failed to lint");
+
+  let found = diagnostics
+    .iter()
+    .map(|d| (d.code.as_str(), d.range.start.line, d.range.start.col))
+    .collect::<Vec<_>>();
+  assert_eq!(found, vec![("no-global-bar", 1, 0)]);
+}